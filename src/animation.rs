@@ -0,0 +1,313 @@
+use super::{call_python3, Error, GraphMaker, Plot};
+use std::ffi::OsStr;
+use std::fmt::Write;
+use std::path::{Path, PathBuf};
+
+/// Generates an animation (GIF or MP4) from a sequence of frames
+///
+/// The [Plot] given to [Animation::save] holds the commands shared by all frames (e.g., labels,
+/// grid, and figure size) and the frames hold the features that change with time (e.g., curves).
+/// The frames are drawn on the current axes by `matplotlib.animation.FuncAnimation` and the
+/// features of the previous frame are removed before drawing the next one.
+///
+/// # Notes
+///
+/// * The limits of the axes are computed once from all frames (unless given by the Plot); thus,
+///   the limits and the axes stay fixed across frames.
+/// * GIF files are written by the Pillow writer and MP4 files are written by the FFmpeg writer,
+///   which requires `ffmpeg` to be installed.
+///
+/// # Example
+///
+/// ```
+/// use plotpy::{Animation, Curve, Error, Plot};
+///
+/// fn main() -> Result<(), Error> {
+///     // frames
+///     let x: Vec<f64> = (0..21).map(|i| (i as f64) / 20.0).collect();
+///     let mut animation = Animation::new();
+///     animation.set_fps(5.0).add_frames(10, |i, frame| {
+///         let t = (i as f64) / 10.0;
+///         let y: Vec<f64> = x.iter().map(|x| f64::sin(6.0 * (x - t))).collect();
+///         let mut curve = Curve::new();
+///         curve.draw(&x, &y);
+///         frame.add(&curve).set_title(&format!("t = {:.1}", t));
+///         Ok(())
+///     })?;
+///
+///     // shared commands
+///     let mut plot = Plot::new();
+///     plot.grid_and_labels("x", "y");
+///
+///     // save animation
+///     animation.save(&plot, "/tmp/plotpy/doc_tests/doc_animation.gif")?;
+///     Ok(())
+/// }
+/// ```
+pub struct Animation {
+    fps: f64,          // frames per second
+    frames: Vec<Plot>, // python commands (and primitives) of each frame
+}
+
+impl Animation {
+    /// Creates a new Animation object
+    pub fn new() -> Self {
+        Animation {
+            fps: 10.0,
+            frames: Vec::new(),
+        }
+    }
+
+    /// Adds a frame with the features drawn by the graph entities
+    pub fn add_frame(&mut self, graphs: &[&dyn GraphMaker]) -> &mut Self {
+        let mut plot = Plot::new();
+        for graph in graphs {
+            plot.add(*graph);
+        }
+        self.frames.push(plot);
+        self
+    }
+
+    /// Adds frames generated by a closure
+    ///
+    /// # Input
+    ///
+    /// * `nframe` -- the number of frames
+    /// * `frame` -- a function `(i, plot)` that adds the features of the i-th frame to a new (empty) plot
+    ///
+    /// # Note
+    ///
+    /// Only the python commands of the plot given to the closure are used (e.g., the title);
+    /// the figure options and the python interpreter are taken from the plot given to [Animation::save].
+    pub fn add_frames<F>(&mut self, nframe: usize, mut frame: F) -> Result<(), Error>
+    where
+        F: FnMut(usize, &mut Plot) -> Result<(), Error>,
+    {
+        for i in 0..nframe {
+            let mut plot = Plot::new();
+            frame(i, &mut plot)?;
+            self.frames.push(plot);
+        }
+        Ok(())
+    }
+
+    /// Sets the number of frames per second
+    pub fn set_fps(&mut self, fps: f64) -> &mut Self {
+        self.fps = fps;
+        self
+    }
+
+    /// Returns the number of frames
+    pub fn num_frames(&self) -> usize {
+        self.frames.len()
+    }
+
+    /// Calls python3 and saves the python script and animation
+    ///
+    /// # Input
+    ///
+    /// * `plot` -- the commands shared by all frames and the configuration of python (see [Plot])
+    /// * `figure_path` -- may be a String, &str, or Path with the `.gif` or `.mp4` extension
+    ///
+    /// # Note
+    ///
+    /// As in [Plot::save], the python script is saved with the `.py` extension and, if an
+    /// error occurs, the log file is saved with the `.log` extension.
+    pub fn save<S>(&self, plot: &Plot, figure_path: &S) -> Result<(), Error>
+    where
+        S: AsRef<OsStr> + ?Sized,
+    {
+        let fig_path = plot.figure_path(figure_path)?;
+        let commands = self.script(plot, &fig_path)?;
+        let py_path = fig_path.with_extension("py");
+        let output = call_python3(&commands, &py_path, plot.interpreter())?;
+        plot.check_output(output, &fig_path, &py_path)
+    }
+
+    /// Returns the python commands (without header) to save the animation
+    fn script(&self, plot: &Plot, fig_path: &Path) -> Result<String, Error> {
+        // writer
+        let extension = fig_path.extension().and_then(|e| e.to_str()).unwrap_or("");
+        let writer = match extension.to_lowercase().as_str() {
+            "gif" => "pillow",
+            "mp4" => "ffmpeg",
+            _ => {
+                return Err(Error::InvalidInput {
+                    argument: "figure_path",
+                    message: "the extension must be gif or mp4",
+                })
+            }
+        };
+        if self.fps <= 0.0 {
+            return Err(Error::InvalidInput {
+                argument: "fps",
+                message: "fps must be positive",
+            });
+        }
+
+        // shared commands
+        let mut buffer = plot.commands(fig_path, plot.npy_threshold())?;
+        write!(&mut buffer, "\nimport matplotlib.animation as ani\n").unwrap();
+
+        // frames
+        for (i, frame) in self.frames.iter().enumerate() {
+            let commands = frame.commands(&frame_path(fig_path, i), plot.npy_threshold())?;
+            write!(&mut buffer, "def frame_{}():\n", i).unwrap();
+            for line in commands.lines() {
+                write!(&mut buffer, "    {}\n", line).unwrap();
+            }
+            write!(&mut buffer, "    pass\n").unwrap();
+        }
+        write!(&mut buffer, "FRAMES=[").unwrap();
+        for i in 0..self.frames.len() {
+            write!(&mut buffer, "frame_{},", i).unwrap();
+        }
+        write!(&mut buffer, "]\n").unwrap();
+
+        // draw all frames to fix the limits, then animate
+        write!(
+            &mut buffer,
+            "def draw_frame(i):\n\
+             \x20\x20\x20\x20before=set(plt.gca().get_children())\n\
+             \x20\x20\x20\x20FRAMES[i]()\n\
+             \x20\x20\x20\x20return [a for a in plt.gca().get_children() if a not in before]\n\
+             ARTISTS=[]\n\
+             for i in range(len(FRAMES)):\n\
+             \x20\x20\x20\x20ARTISTS+=draw_frame(i)\n\
+             plt.axis(plt.axis())\n\
+             def update(i):\n\
+             \x20\x20\x20\x20global ARTISTS\n\
+             \x20\x20\x20\x20for a in ARTISTS: a.remove()\n\
+             \x20\x20\x20\x20ARTISTS=draw_frame(i)\n\
+             \x20\x20\x20\x20return ARTISTS\n\
+             fn=r'{}'\n\
+             if not ani.writers.is_available('{}'):\n\
+             \x20\x20\x20\x20print('ERROR: the {} writer is not available to save the animation')\n\
+             else:\n\
+             \x20\x20\x20\x20anim=ani.FuncAnimation(plt.gcf(),update,frames=len(FRAMES),interval=1000/{},repeat=False)\n\
+             \x20\x20\x20\x20anim.save(fn,writer='{}',fps={})\n",
+            fig_path.to_string_lossy(),
+            writer,
+            writer,
+            self.fps,
+            writer,
+            self.fps
+        )
+        .unwrap();
+        Ok(buffer)
+    }
+}
+
+/// Returns the path used to name the .npy files of a frame
+fn frame_path(fig_path: &Path, index: usize) -> PathBuf {
+    let mut path = fig_path.with_extension("").into_os_string();
+    path.push(format!("_frame{}", index));
+    PathBuf::from(path)
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{frame_path, Animation};
+    use crate::{Curve, Error, GraphMaker, Plot};
+    use std::path::Path;
+
+    #[test]
+    fn new_works() {
+        let animation = Animation::new();
+        assert_eq!(animation.fps, 10.0);
+        assert_eq!(animation.num_frames(), 0);
+    }
+
+    #[test]
+    fn add_frames_work() {
+        let mut curve = Curve::new();
+        curve.draw(&[0.0, 1.0], &[0.0, 1.0]);
+        let mut animation = Animation::new();
+        let path = Path::new("/tmp/plotpy/unit_tests/add_frames_work.gif");
+        animation.add_frame(&[&curve, &curve]);
+        assert_eq!(
+            animation.frames[0].commands(path, 0).unwrap(),
+            format!("{}{}", curve.get_buffer(), curve.get_buffer())
+        );
+        animation
+            .add_frames(2, |i, frame| {
+                frame.set_title(&format!("frame {}", i));
+                Ok(())
+            })
+            .unwrap();
+        assert_eq!(animation.num_frames(), 3);
+        assert_eq!(
+            animation.frames[1].commands(path, 0).unwrap(),
+            "plt.title(r'frame 0')\n"
+        );
+        assert_eq!(
+            animation.frames[2].commands(path, 0).unwrap(),
+            "plt.title(r'frame 1')\n"
+        );
+        let res = animation.add_frames(2, |_, _| Err(Error::Message("stop")));
+        assert_eq!(res, Err(Error::Message("stop")));
+        assert_eq!(animation.num_frames(), 3);
+    }
+
+    #[test]
+    fn script_works() {
+        let mut animation = Animation::new();
+        animation.set_fps(5.0).add_frame(&[]);
+        animation
+            .add_frames(1, |_, frame| {
+                frame.set_title("t = 1");
+                Ok(())
+            })
+            .unwrap();
+        let mut plot = Plot::new();
+        plot.set_label_x("x");
+        let script = animation.script(&plot, Path::new("/tmp/plotpy/anim.gif")).unwrap();
+        let correct: &str = "plt.xlabel(r'x')\n\
+                             \n\
+                             import matplotlib.animation as ani\n\
+                             def frame_0():\n\
+                             \x20\x20\x20\x20pass\n\
+                             def frame_1():\n\
+                             \x20\x20\x20\x20plt.title(r't = 1')\n\
+                             \x20\x20\x20\x20pass\n\
+                             FRAMES=[frame_0,frame_1,]\n";
+        assert!(script.starts_with(correct));
+        assert!(script.contains("plt.axis(plt.axis())\n"));
+        assert!(script.contains("fn=r'/tmp/plotpy/anim.gif'\n"));
+        assert!(script.contains("interval=1000/5,repeat=False)\n"));
+        assert!(script.ends_with("anim.save(fn,writer='pillow',fps=5)\n"));
+        let script = animation.script(&plot, Path::new("/tmp/plotpy/anim.mp4")).unwrap();
+        assert!(script.ends_with("anim.save(fn,writer='ffmpeg',fps=5)\n"));
+    }
+
+    #[test]
+    fn script_captures_errors() {
+        let mut animation = Animation::new();
+        let plot = Plot::new();
+        assert_eq!(
+            animation.script(&plot, Path::new("/tmp/plotpy/anim.svg")).err(),
+            Some(Error::InvalidInput {
+                argument: "figure_path",
+                message: "the extension must be gif or mp4",
+            })
+        );
+        animation.set_fps(0.0);
+        assert_eq!(
+            animation.script(&plot, Path::new("/tmp/plotpy/anim.gif")).err(),
+            Some(Error::InvalidInput {
+                argument: "fps",
+                message: "fps must be positive",
+            })
+        );
+    }
+
+    #[test]
+    fn frame_path_works() {
+        assert_eq!(
+            frame_path(Path::new("/tmp/plotpy/anim.gif"), 3),
+            Path::new("/tmp/plotpy/anim_frame3")
+        );
+    }
+}
@@ -0,0 +1,461 @@
+use super::{matrix_to_f64, matrix_to_list, vector_to_strings, AsMatrix, Error, GraphMaker, Primitive};
+use num_traits::AsPrimitive;
+use std::fmt::Write;
+
+/// Generates a Barplot with one or more series of values per category
+///
+/// The series may be grouped (side by side; the default) or stacked and the bars may be
+/// vertical (the default) or horizontal.
+///
+/// # Example
+///
+/// ```
+/// use plotpy::{Barplot, Error, Plot};
+///
+/// fn main() -> Result<(), Error> {
+///     // set categories and values
+///     let categories = ["apple", "banana", "cherry", "date"];
+///     let values = vec![
+///         vec![3.0, 5.0, 2.0, 4.0], // first series
+///         vec![2.0, 1.0, 4.0, 3.0], // second series
+///     ];
+///     let errors = vec![
+///         vec![0.5, 0.3, 0.2, 0.4], // first series
+///         vec![0.2, 0.1, 0.6, 0.3], // second series
+///     ];
+///
+///     // configure and draw barplot
+///     let mut barplot = Barplot::new();
+///     barplot
+///         .set_colors(&["#9de19a", "#98a7f2"])
+///         .set_labels(&["2023", "2024"])
+///         .set_edge_color("black")
+///         .set_line_width(0.5)
+///         .set_with_value_labels(true);
+///     barplot.draw_with_errors(&categories, &values, &errors)?;
+///
+///     // add barplot to plot
+///     let mut plot = Plot::new();
+///     plot.add(&barplot)
+///         .set_frame_border(true, false, true, false)
+///         .grid_labels_legend("fruit", "sales");
+///
+///     // save figure
+///     plot.save("/tmp/plotpy/doc_tests/doc_barplot.svg")?;
+///     Ok(())
+/// }
+/// ```
+///
+/// See also integration test in the **tests** directory.
+pub struct Barplot {
+    style: BarplotStyle,        // Style
+    buffer: String,             // buffer
+    primitives: Vec<Primitive>, // primitives
+}
+
+/// Holds the style of a barplot (see [Barplot])
+///
+/// The style is recorded by the [Primitive] drawn by [Barplot] and may be modified afterwards.
+#[derive(Clone, Debug, PartialEq)]
+pub struct BarplotStyle {
+    pub colors: Vec<String>,     // Colors for each series (or each bar if single series)
+    pub labels: Vec<String>,     // Labels for each series (shown by the legend)
+    pub edge_color: String,      // Color of the edges of the bars
+    pub line_width: f64,         // Line width of the edges of the bars
+    pub width: f64,              // Width of a group of bars (or of each bar if stacked)
+    pub stacked: bool,           // Draws stacked bars instead of grouped bars
+    pub horizontal: bool,        // Draws horizontal bars
+    pub error_capsize: f64,      // Length of the caps of the error bars
+    pub with_value_labels: bool, // Writes the values on the bars
+    pub value_format: String,    // Format of the values on the bars; e.g. "%.2f"
+}
+
+impl Barplot {
+    /// Creates a new Barplot object
+    pub fn new() -> Self {
+        Barplot {
+            style: BarplotStyle::default(),
+            buffer: String::new(),
+            primitives: Vec::new(),
+        }
+    }
+
+    /// Draws barplot
+    ///
+    /// # Input
+    ///
+    /// * `categories` -- labels of the categories (ticks of the x-axis or y-axis if horizontal)
+    /// * `values` -- matrix with one series per row; each row has one value per category
+    ///
+    /// # Notes
+    ///
+    /// * The type `U` of the input matrix must be a number.
+    /// * The type `V` must be a String or &str.
+    ///
+    pub fn draw<'a, T, U, V>(&mut self, categories: &[V], values: &'a T)
+    where
+        T: AsMatrix<'a, U>,
+        U: 'a + AsPrimitive<f64>,
+        V: std::fmt::Display,
+    {
+        let categories = categories.iter().map(|category| category.to_string()).collect();
+        let values = matrix_to_f64(values);
+        let style = self.style.clone();
+        self.push(Primitive::Barplot {
+            categories,
+            values,
+            errors: Vec::new(),
+            style,
+        });
+    }
+
+    /// Draws barplot with error bars
+    ///
+    /// # Input
+    ///
+    /// * `categories` -- labels of the categories (ticks of the x-axis or y-axis if horizontal)
+    /// * `values` -- matrix with one series per row; each row has one value per category
+    /// * `errors` -- matrix with the (symmetric) errors of each series; same shape as `values`
+    ///
+    /// # Notes
+    ///
+    /// * The type `U` of the input matrices must be a number.
+    /// * The type `V` must be a String or &str.
+    ///
+    pub fn draw_with_errors<'a, T, U, V>(&mut self, categories: &[V], values: &'a T, errors: &'a T) -> Result<(), Error>
+    where
+        T: AsMatrix<'a, U>,
+        U: 'a + AsPrimitive<f64>,
+        V: std::fmt::Display,
+    {
+        if errors.size() != values.size() {
+            return Err(Error::InvalidInput {
+                argument: "errors",
+                message: "errors must have the same shape as values",
+            });
+        }
+        let (values, errors) = (matrix_to_f64(values), matrix_to_f64(errors));
+        let categories = categories.iter().map(|category| category.to_string()).collect();
+        let style = self.style.clone();
+        self.push(Primitive::Barplot {
+            categories,
+            values,
+            errors,
+            style,
+        });
+        Ok(())
+    }
+
+    /// Sets the colors for each series
+    ///
+    /// If there is only one series, sets the colors for each bar.
+    pub fn set_colors(&mut self, colors: &[&str]) -> &mut Self {
+        self.style.colors = colors.iter().map(|color| color.to_string()).collect();
+        self
+    }
+
+    /// Sets the labels for each series (to be shown by the legend)
+    pub fn set_labels(&mut self, labels: &[&str]) -> &mut Self {
+        self.style.labels = labels.iter().map(|label| label.to_string()).collect();
+        self
+    }
+
+    /// Sets the color of the edges of the bars
+    pub fn set_edge_color(&mut self, color: &str) -> &mut Self {
+        self.style.edge_color = String::from(color);
+        self
+    }
+
+    /// Sets the width of the lines (edges of the bars)
+    pub fn set_line_width(&mut self, width: f64) -> &mut Self {
+        self.style.line_width = width;
+        self
+    }
+
+    /// Sets the width of the bars
+    ///
+    /// In grouped mode, this is the width of the whole group of bars of a category.
+    pub fn set_width(&mut self, width: f64) -> &mut Self {
+        self.style.width = width;
+        self
+    }
+
+    /// Sets option to draw stacked bars
+    pub fn set_stacked(&mut self, flag: bool) -> &mut Self {
+        self.style.stacked = flag;
+        self
+    }
+
+    /// Sets option to draw horizontal bars
+    pub fn set_horizontal(&mut self, flag: bool) -> &mut Self {
+        self.style.horizontal = flag;
+        self
+    }
+
+    /// Sets the length of the caps of the error bars
+    pub fn set_error_capsize(&mut self, capsize: f64) -> &mut Self {
+        self.style.error_capsize = capsize;
+        self
+    }
+
+    /// Sets option to write the values on the bars
+    pub fn set_with_value_labels(&mut self, flag: bool) -> &mut Self {
+        self.style.with_value_labels = flag;
+        self
+    }
+
+    /// Sets the format of the values written on the bars
+    ///
+    /// Example: `"%.2f"` (see <https://matplotlib.org/stable/api/_as_gen/matplotlib.pyplot.bar_label.html>)
+    pub fn set_value_format(&mut self, format: &str) -> &mut Self {
+        self.style.value_format = String::from(format);
+        self
+    }
+
+    /// Writes the primitive to the buffer and records it
+    fn push(&mut self, primitive: Primitive) {
+        primitive.write_python(&mut self.buffer);
+        self.primitives.push(primitive);
+    }
+}
+
+impl Default for BarplotStyle {
+    fn default() -> Self {
+        BarplotStyle {
+            colors: Vec::new(),
+            labels: Vec::new(),
+            edge_color: String::new(),
+            line_width: 0.0,
+            width: 0.8,
+            stacked: false,
+            horizontal: false,
+            error_capsize: 3.0,
+            with_value_labels: false,
+            value_format: String::new(),
+        }
+    }
+}
+
+impl BarplotStyle {
+    /// Returns options for the bars of a series
+    pub(crate) fn options(&self, index: usize, nseries: usize) -> String {
+        let mut opt = String::new();
+        if self.colors.len() > 0 {
+            if nseries == 1 {
+                write!(&mut opt, ",color=colors").unwrap();
+            } else {
+                write!(&mut opt, ",color=colors[{}]", index % self.colors.len()).unwrap();
+            }
+        }
+        if index < self.labels.len() {
+            write!(&mut opt, ",label=r'{}'", self.labels[index]).unwrap();
+        }
+        if self.edge_color != "" {
+            write!(&mut opt, ",edgecolor='{}'", self.edge_color).unwrap();
+        }
+        if self.line_width > 0.0 {
+            write!(&mut opt, ",linewidth={}", self.line_width).unwrap();
+        }
+        opt
+    }
+
+    /// Returns options for the labels with values
+    pub(crate) fn options_value_labels(&self) -> String {
+        let mut opt = String::new();
+        if self.value_format != "" {
+            write!(&mut opt, ",fmt='{}'", self.value_format).unwrap();
+        }
+        if self.stacked {
+            write!(&mut opt, ",label_type='center'").unwrap();
+        }
+        opt
+    }
+}
+
+/// Writes the Python commands of a barplot (see [Primitive::Barplot])
+pub(crate) fn write_barplot(
+    buf: &mut String,
+    categories: &[String],
+    values: &Vec<Vec<f64>>,
+    errors: &Vec<Vec<f64>>,
+    style: &BarplotStyle,
+) {
+    vector_to_strings(buf, "categories", categories);
+    matrix_to_list(buf, "values", values);
+    if errors.len() > 0 {
+        matrix_to_list(buf, "errors", errors);
+    }
+    if style.colors.len() > 0 {
+        vector_to_strings(buf, "colors", style.colors.as_slice());
+    }
+    write!(buf, "x=np.arange(len(categories))\n").unwrap();
+    let (function, size, base, error, ticks) = if style.horizontal {
+        ("barh", "height", "left", "xerr", "yticks")
+    } else {
+        ("bar", "width", "bottom", "yerr", "xticks")
+    };
+    if style.stacked {
+        write!(buf, "bottom=np.zeros(len(categories))\n").unwrap();
+    }
+    let nseries = values.len();
+    for i in 0..nseries {
+        let opt = style.options(i, nseries);
+        let mut opt_error = String::new();
+        if i < errors.len() {
+            write!(
+                &mut opt_error,
+                ",{}=errors[{}],capsize={}",
+                error, i, style.error_capsize
+            )
+            .unwrap();
+        }
+        if style.stacked {
+            write!(
+                buf,
+                "p=plt.{}(x,values[{}],{}={},{}=bottom{}{})\n",
+                function, i, size, style.width, base, opt_error, &opt
+            )
+            .unwrap();
+            write!(buf, "bottom+=np.array(values[{}])\n", i).unwrap();
+        } else {
+            let width = style.width / (nseries as f64);
+            let offset = ((i as f64) - (nseries as f64 - 1.0) / 2.0) * width;
+            write!(
+                buf,
+                "p=plt.{}(x+({}),values[{}],{}={}{}{})\n",
+                function, offset, i, size, width, opt_error, &opt
+            )
+            .unwrap();
+        }
+        if style.with_value_labels {
+            write!(buf, "plt.bar_label(p{})\n", style.options_value_labels()).unwrap();
+        }
+    }
+    write!(buf, "plt.{}(x,categories)\n", ticks).unwrap();
+}
+
+impl GraphMaker for Barplot {
+    fn get_buffer<'a>(&'a self) -> &'a String {
+        &self.buffer
+    }
+    fn clear_buffer(&mut self) {
+        self.buffer.clear();
+        self.primitives.clear();
+    }
+    fn get_primitives(&self) -> &[Primitive] {
+        &self.primitives
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::Barplot;
+    use crate::{Error, GraphMaker, Primitive};
+
+    #[test]
+    fn new_works() {
+        let barplot = Barplot::new();
+        assert_eq!(barplot.style.colors.len(), 0);
+        assert_eq!(barplot.style.labels.len(), 0);
+        assert_eq!(barplot.style.edge_color.len(), 0);
+        assert_eq!(barplot.style.line_width, 0.0);
+        assert_eq!(barplot.style.width, 0.8);
+        assert_eq!(barplot.style.stacked, false);
+        assert_eq!(barplot.style.horizontal, false);
+        assert_eq!(barplot.style.error_capsize, 3.0);
+        assert_eq!(barplot.style.with_value_labels, false);
+        assert_eq!(barplot.style.value_format.len(), 0);
+        assert_eq!(barplot.buffer.len(), 0);
+        assert_eq!(barplot.primitives.len(), 0);
+    }
+
+    #[test]
+    fn options_works() {
+        let mut barplot = Barplot::new();
+        barplot
+            .set_colors(&["red", "green"])
+            .set_labels(&["first"])
+            .set_edge_color("black")
+            .set_line_width(2.0)
+            .set_stacked(true)
+            .set_value_format("%.1f");
+        assert_eq!(
+            barplot.style.options(0, 2),
+            ",color=colors[0]\
+             ,label=r'first'\
+             ,edgecolor='black'\
+             ,linewidth=2"
+        );
+        assert_eq!(
+            barplot.style.options(3, 4),
+            ",color=colors[1]\
+             ,edgecolor='black'\
+             ,linewidth=2"
+        );
+        assert_eq!(
+            barplot.style.options(0, 1),
+            ",color=colors,label=r'first',edgecolor='black',linewidth=2"
+        );
+        assert_eq!(barplot.style.options_value_labels(), ",fmt='%.1f',label_type='center'");
+    }
+
+    #[test]
+    fn draw_works() {
+        let mut barplot = Barplot::new();
+        barplot.set_with_value_labels(true);
+        barplot.draw(&["a", "b"], &vec![vec![1.0, 2.0], vec![3.0, 4.0]]);
+        let b: &str = "categories=['a','b',]\n\
+                       values=[[1,2,],[3,4,],]\n\
+                       x=np.arange(len(categories))\n\
+                       p=plt.bar(x+(-0.2),values[0],width=0.4)\n\
+                       plt.bar_label(p)\n\
+                       p=plt.bar(x+(0.2),values[1],width=0.4)\n\
+                       plt.bar_label(p)\n\
+                       plt.xticks(x,categories)\n";
+        assert_eq!(barplot.buffer, b);
+        assert_eq!(barplot.get_primitives().len(), 1);
+        barplot.clear_buffer();
+        assert_eq!(barplot.buffer, "");
+        assert_eq!(barplot.primitives.len(), 0);
+    }
+
+    #[test]
+    fn draw_stacked_horizontal_works() {
+        let mut barplot = Barplot::new();
+        barplot.set_stacked(true).set_horizontal(true).set_error_capsize(5.0);
+        barplot
+            .draw_with_errors(&["a", "b"], &[[1, 2], [3, 4]], &[[0, 1], [1, 0]])
+            .unwrap();
+        let b: &str = "categories=['a','b',]\n\
+                       values=[[1,2,],[3,4,],]\n\
+                       errors=[[0,1,],[1,0,],]\n\
+                       x=np.arange(len(categories))\n\
+                       bottom=np.zeros(len(categories))\n\
+                       p=plt.barh(x,values[0],height=0.8,left=bottom,xerr=errors[0],capsize=5)\n\
+                       bottom+=np.array(values[0])\n\
+                       p=plt.barh(x,values[1],height=0.8,left=bottom,xerr=errors[1],capsize=5)\n\
+                       bottom+=np.array(values[1])\n\
+                       plt.yticks(x,categories)\n";
+        assert_eq!(barplot.buffer, b);
+        assert_eq!(
+            barplot.get_primitives(),
+            &[Primitive::Barplot {
+                categories: vec!["a".to_string(), "b".to_string()],
+                values: vec![vec![1.0, 2.0], vec![3.0, 4.0]],
+                errors: vec![vec![0.0, 1.0], vec![1.0, 0.0]],
+                style: barplot.style.clone(),
+            }]
+        );
+        let values = vec![vec![1, 2], vec![3, 4]];
+        assert_eq!(
+            barplot.draw_with_errors(&["a", "b"], &values, &vec![vec![0, 1]]).err(),
+            Some(Error::InvalidInput {
+                argument: "errors",
+                message: "errors must have the same shape as values",
+            })
+        );
+        assert_eq!(barplot.primitives.len(), 1);
+    }
+}
@@ -0,0 +1,139 @@
+use super::{Error, Plot, PythonWorker};
+use std::ffi::OsStr;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::thread;
+
+/// Saves many figures concurrently using a pool of persistent Python workers
+///
+/// # Input
+///
+/// * `figures` -- the plots and their figure paths (String, &str, or Path)
+/// * `jobs` -- the maximum number of concurrent Python processes; if zero, the number of
+///   available CPUs is used
+///
+/// # Output
+///
+/// Returns the result of each figure in the same order as `figures`. If a figure fails,
+/// its log file is written as in [Plot::save] and [Error::PythonFailed] holds the captured output.
+///
+/// # Note
+///
+/// Each thread of the pool owns a [PythonWorker] and picks the next pending figure when done.
+///
+/// # Example
+///
+/// ```
+/// use plotpy::{render_all, Curve, Error, Plot};
+///
+/// fn main() -> Result<(), Error> {
+///     let mut plots = Vec::new();
+///     for i in 0..4 {
+///         let mut curve = Curve::new();
+///         curve.draw(&[0.0, 1.0, 2.0], &[0.0, i as f64, 0.0]);
+///         let mut plot = Plot::new();
+///         plot.add(&curve);
+///         plots.push((plot, format!("/tmp/plotpy/doc_tests/doc_render_all_{}.svg", i)));
+///     }
+///     let figures: Vec<_> = plots.iter().map(|(plot, path)| (plot, path)).collect();
+///     let results = render_all(&figures, 2);
+///     for ((_, path), result) in figures.iter().zip(&results) {
+///         if let Err(e) = result {
+///             println!("{} failed: {}", path, e);
+///         }
+///     }
+///     Ok(())
+/// }
+/// ```
+pub fn render_all<P>(figures: &[(&Plot, P)], jobs: usize) -> Vec<Result<(), Error>>
+where
+    P: AsRef<OsStr> + Sync,
+{
+    // number of threads
+    let jobs = if jobs == 0 {
+        thread::available_parallelism().map_or(1, |n| n.get())
+    } else {
+        jobs
+    };
+    let jobs = usize::min(jobs, figures.len());
+
+    // run the pool
+    let next = AtomicUsize::new(0);
+    let mut results: Vec<Option<Result<(), Error>>> = vec![None; figures.len()];
+    thread::scope(|scope| {
+        let handles: Vec<_> = (0..jobs)
+            .map(|_| {
+                scope.spawn(|| {
+                    let mut worker = PythonWorker::new();
+                    let mut done = Vec::new();
+                    loop {
+                        let i = next.fetch_add(1, Ordering::Relaxed);
+                        if i >= figures.len() {
+                            break;
+                        }
+                        let (plot, path) = &figures[i];
+                        done.push((i, worker.save(plot, path)));
+                    }
+                    done
+                })
+            })
+            .collect();
+        for handle in handles {
+            for (i, result) in handle.join().unwrap() {
+                results[i] = Some(result);
+            }
+        }
+    });
+
+    // done
+    results.into_iter().map(|r| r.unwrap()).collect()
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::render_all;
+    use crate::{Error, Plot};
+    use std::path::{Path, PathBuf};
+
+    const OUT_DIR: &str = "/tmp/plotpy/unit_tests";
+
+    #[test]
+    fn render_all_handles_empty_input() {
+        let figures: Vec<(&Plot, &str)> = Vec::new();
+        assert_eq!(render_all(&figures, 0).len(), 0);
+    }
+
+    #[test]
+    fn render_all_works() {
+        const WRONG: usize = 0;
+        let mut plots = Vec::new();
+        for i in 0..7 {
+            let mut plot = Plot::new();
+            if i == 3 {
+                plot.set_subplot(1, 1, WRONG);
+            } else {
+                plot.set_title(&format!("figure {}", i));
+            }
+            let path = Path::new(OUT_DIR).join(format!("render_all_works_{}.svg", i));
+            plots.push((plot, path));
+        }
+        let figures: Vec<(&Plot, &PathBuf)> = plots.iter().map(|(plot, path)| (plot, path)).collect();
+        let results = render_all(&figures, 3);
+        assert_eq!(results.len(), 7);
+        for (i, result) in results.iter().enumerate() {
+            if i == 3 {
+                match result {
+                    Err(Error::PythonFailed { exception, .. }) => {
+                        assert_eq!(exception, &Some("ValueError".to_string()))
+                    }
+                    _ => panic!("figure 3 should have failed"),
+                }
+                assert!(plots[i].1.with_extension("log").exists());
+            } else {
+                assert_eq!(result, &Ok(()));
+                assert!(plots[i].1.exists());
+            }
+        }
+    }
+}
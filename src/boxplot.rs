@@ -0,0 +1,453 @@
+use super::{matrix_to_list, to_f64, vector_to_strings, GraphMaker, Primitive};
+use num_traits::AsPrimitive;
+use std::fmt::Write;
+
+/// Generates a box plot (or violin plot) comparing the distributions of several series of samples
+///
+/// # Notes
+///
+/// * In the violin mode, the width of each violin is given by a kernel density estimate (KDE) of the samples.
+/// * The notches, whiskers, and outliers apply to the box plot only.
+///
+/// # Example
+///
+/// ```
+/// use plotpy::{Boxplot, Error, Plot};
+///
+/// fn main() -> Result<(), Error> {
+///     // set values (e.g., number of iterations of three solvers)
+///     let values = vec![
+///         vec![12, 15, 14, 13, 18, 16, 15, 14, 30], // first series
+///         vec![8, 9, 9, 10, 11, 9, 8, 12],          // second series
+///         vec![20, 22, 19, 25, 21, 23, 24, 20],     // third series
+///     ];
+///
+///     // set labels
+///     let labels = ["GMRES", "BiCGSTAB", "CG"];
+///
+///     // configure and draw box plot
+///     let mut boxplot = Boxplot::new();
+///     boxplot.set_colors(&["#9de19a", "#e7eca3", "#98a7f2"])
+///         .set_notch(true)
+///         .set_with_means(true);
+///     boxplot.draw(&values, &labels);
+///
+///     // add box plot to plot
+///     let mut plot = Plot::new();
+///     plot.add(&boxplot)
+///         .set_label_y("iterations");
+///
+///     // save figure
+///     plot.save("/tmp/plotpy/doc_tests/doc_boxplot.svg")?;
+///     Ok(())
+/// }
+/// ```
+///
+/// See also integration test in the **tests** directory.
+pub struct Boxplot {
+    style: BoxplotStyle,        // Style
+    buffer: String,             // buffer
+    primitives: Vec<Primitive>, // primitives
+}
+
+/// Holds the style of a box plot (see [Boxplot])
+///
+/// The style is recorded by the [Primitive] drawn by [Boxplot] and may be modified afterwards.
+#[derive(Clone, Debug, PartialEq)]
+pub struct BoxplotStyle {
+    pub colors: Vec<String>,    // Face colors of the boxes (or violins)
+    pub violin: bool,           // Draws violins instead of boxes
+    pub horizontal: bool,       // Draws horizontal boxes (or violins)
+    pub width: f64,             // Width of the boxes (or violins)
+    pub with_means: bool,       // Shows the mean of each series
+    pub notch: bool,            // Draws notched boxes
+    pub whisker: f64,           // Reach of the whiskers as a factor of the interquartile range
+    pub no_outliers: bool,      // Skip drawing the outliers
+    pub outlier_marker: String, // Marker of the outliers
+    pub median_color: String,   // Color of the median line
+    pub bandwidth: f64,         // Bandwidth factor of the KDE (violin)
+    pub with_quartiles: bool,   // Draws the quartile lines (violin)
+}
+
+impl Boxplot {
+    /// Creates a new Boxplot object
+    pub fn new() -> Self {
+        Boxplot {
+            style: BoxplotStyle::default(),
+            buffer: String::new(),
+            primitives: Vec::new(),
+        }
+    }
+
+    /// Draws box plot (or violin plot)
+    ///
+    /// # Input
+    ///
+    /// * `values` - values (one series of samples per box)
+    /// * `labels` - labels of the boxes along the axis (may be empty)
+    ///
+    /// # Notes
+    ///
+    /// * The type `T` must be a number.
+    /// * The type `U` must be a String or &str.
+    ///
+    pub fn draw<T, U>(&mut self, values: &[Vec<T>], labels: &[U])
+    where
+        T: AsPrimitive<f64>,
+        U: std::fmt::Display,
+    {
+        let values = values
+            .iter()
+            .map(|row| row.iter().map(|v| to_f64(*v)).collect())
+            .collect();
+        let labels = labels.iter().map(|label| label.to_string()).collect();
+        let style = self.style.clone();
+        self.push(Primitive::Boxplot { values, labels, style });
+    }
+
+    /// Sets the face colors of the boxes (or violins)
+    ///
+    /// The colors are cycled if there are more series than colors.
+    pub fn set_colors(&mut self, colors: &[&str]) -> &mut Self {
+        self.style.colors = colors.iter().map(|color| color.to_string()).collect();
+        self
+    }
+
+    /// Sets option to draw violins instead of boxes
+    pub fn set_violin(&mut self, flag: bool) -> &mut Self {
+        self.style.violin = flag;
+        self
+    }
+
+    /// Sets option to draw horizontal boxes (or violins)
+    pub fn set_horizontal(&mut self, flag: bool) -> &mut Self {
+        self.style.horizontal = flag;
+        self
+    }
+
+    /// Sets the width of the boxes (or violins)
+    pub fn set_width(&mut self, width: f64) -> &mut Self {
+        self.style.width = width;
+        self
+    }
+
+    /// Sets option to show the mean of each series
+    pub fn set_with_means(&mut self, flag: bool) -> &mut Self {
+        self.style.with_means = flag;
+        self
+    }
+
+    /// Sets option to draw notched boxes (indicating the confidence interval of the median)
+    pub fn set_notch(&mut self, flag: bool) -> &mut Self {
+        self.style.notch = flag;
+        self
+    }
+
+    /// Sets the reach of the whiskers as a factor of the interquartile range (IQR)
+    ///
+    /// The whiskers extend to the farthest samples within `whisker × IQR` from the box.
+    /// Matplotlib uses 1.5 if zero. Use a large value (e.g., 1e9) to extend the whiskers to the min and max.
+    pub fn set_whisker(&mut self, whisker: f64) -> &mut Self {
+        self.style.whisker = whisker;
+        self
+    }
+
+    /// Sets option to skip drawing the outliers (beyond the whiskers)
+    pub fn set_no_outliers(&mut self, flag: bool) -> &mut Self {
+        self.style.no_outliers = flag;
+        self
+    }
+
+    /// Sets the marker of the outliers
+    ///
+    /// Examples:
+    ///
+    /// * "`o`", "`+`", "`x`", "`.`"
+    /// * As defined in <https://matplotlib.org/stable/api/markers_api.html>
+    pub fn set_outlier_marker(&mut self, marker: &str) -> &mut Self {
+        self.style.outlier_marker = String::from(marker);
+        self
+    }
+
+    /// Sets the color of the median line
+    pub fn set_median_color(&mut self, color: &str) -> &mut Self {
+        self.style.median_color = String::from(color);
+        self
+    }
+
+    /// Sets the bandwidth factor of the kernel density estimate (violin)
+    ///
+    /// Smaller values yield less smooth violins. Matplotlib uses Scott's rule if zero.
+    pub fn set_bandwidth(&mut self, bandwidth: f64) -> &mut Self {
+        self.style.bandwidth = bandwidth;
+        self
+    }
+
+    /// Sets option to draw the lines of the first, second (median), and third quartiles (violin)
+    pub fn set_with_quartiles(&mut self, flag: bool) -> &mut Self {
+        self.style.with_quartiles = flag;
+        self
+    }
+
+    /// Writes the primitive to the buffer and records it
+    fn push(&mut self, primitive: Primitive) {
+        primitive.write_python(&mut self.buffer);
+        self.primitives.push(primitive);
+    }
+}
+
+impl Default for BoxplotStyle {
+    fn default() -> Self {
+        BoxplotStyle {
+            colors: Vec::new(),
+            violin: false,
+            horizontal: false,
+            width: 0.0,
+            with_means: false,
+            notch: false,
+            whisker: 0.0,
+            no_outliers: false,
+            outlier_marker: String::new(),
+            median_color: String::new(),
+            bandwidth: 0.0,
+            with_quartiles: false,
+        }
+    }
+}
+
+impl BoxplotStyle {
+    /// Returns options for box plot
+    pub(crate) fn options_boxplot(&self) -> String {
+        let mut opt = String::new();
+        if self.horizontal {
+            write!(&mut opt, ",vert=False").unwrap();
+        }
+        if self.width > 0.0 {
+            write!(&mut opt, ",widths={}", self.width).unwrap();
+        }
+        if self.with_means {
+            write!(&mut opt, ",showmeans=True").unwrap();
+        }
+        if self.notch {
+            write!(&mut opt, ",notch=True").unwrap();
+        }
+        if self.whisker > 0.0 {
+            write!(&mut opt, ",whis={}", self.whisker).unwrap();
+        }
+        if self.no_outliers {
+            write!(&mut opt, ",showfliers=False").unwrap();
+        }
+        if self.outlier_marker != "" {
+            write!(&mut opt, ",flierprops={{'marker':'{}'}}", self.outlier_marker).unwrap();
+        }
+        if self.median_color != "" {
+            write!(&mut opt, ",medianprops={{'color':'{}'}}", self.median_color).unwrap();
+        }
+        if self.colors.len() > 0 {
+            write!(&mut opt, ",patch_artist=True").unwrap();
+        }
+        opt
+    }
+
+    /// Returns options for violin plot
+    pub(crate) fn options_violin(&self) -> String {
+        let mut opt = String::new();
+        if self.horizontal {
+            write!(&mut opt, ",vert=False").unwrap();
+        }
+        if self.width > 0.0 {
+            write!(&mut opt, ",widths={}", self.width).unwrap();
+        }
+        if self.with_means {
+            write!(&mut opt, ",showmeans=True").unwrap();
+        }
+        if self.bandwidth > 0.0 {
+            write!(&mut opt, ",bw_method={}", self.bandwidth).unwrap();
+        }
+        if self.with_quartiles {
+            write!(&mut opt, ",quantiles=[[0.25,0.5,0.75]]*len(values)").unwrap();
+        }
+        opt
+    }
+}
+
+/// Writes the Python commands of a box plot or violin plot (see [Primitive::Boxplot])
+pub(crate) fn write_boxplot(buf: &mut String, values: &Vec<Vec<f64>>, labels: &[String], style: &BoxplotStyle) {
+    matrix_to_list(buf, "values", values);
+    if style.colors.len() > 0 {
+        vector_to_strings(buf, "colors", style.colors.as_slice());
+    }
+    if style.violin {
+        let opt = style.options_violin();
+        write!(buf, "vp=plt.violinplot(values{})\n", &opt).unwrap();
+        if style.colors.len() > 0 {
+            write!(
+                buf,
+                "for i,b in enumerate(vp['bodies']):\n    b.set_facecolor(colors[i%len(colors)])\n"
+            )
+            .unwrap();
+        }
+        if style.median_color != "" && style.with_quartiles {
+            write!(buf, "vp['cquantiles'].set_color('{}')\n", style.median_color).unwrap();
+        }
+    } else {
+        let opt = style.options_boxplot();
+        write!(buf, "bp=plt.boxplot(values{})\n", &opt).unwrap();
+        if style.colors.len() > 0 {
+            write!(
+                buf,
+                "for i,b in enumerate(bp['boxes']):\n    b.set_facecolor(colors[i%len(colors)])\n"
+            )
+            .unwrap();
+        }
+    }
+    if labels.len() > 0 {
+        vector_to_strings(buf, "labels", labels);
+        let ticks = if style.horizontal { "yticks" } else { "xticks" };
+        write!(buf, "plt.{}(range(1,len(values)+1),labels)\n", ticks).unwrap();
+    }
+}
+
+impl GraphMaker for Boxplot {
+    fn get_buffer<'a>(&'a self) -> &'a String {
+        &self.buffer
+    }
+    fn clear_buffer(&mut self) {
+        self.buffer.clear();
+        self.primitives.clear();
+    }
+    fn get_primitives(&self) -> &[Primitive] {
+        &self.primitives
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::Boxplot;
+    use crate::{GraphMaker, Primitive};
+
+    #[test]
+    fn new_works() {
+        let boxplot = Boxplot::new();
+        assert_eq!(boxplot.style.colors.len(), 0);
+        assert_eq!(boxplot.style.violin, false);
+        assert_eq!(boxplot.style.horizontal, false);
+        assert_eq!(boxplot.style.width, 0.0);
+        assert_eq!(boxplot.style.with_means, false);
+        assert_eq!(boxplot.style.notch, false);
+        assert_eq!(boxplot.style.whisker, 0.0);
+        assert_eq!(boxplot.style.no_outliers, false);
+        assert_eq!(boxplot.style.outlier_marker.len(), 0);
+        assert_eq!(boxplot.style.median_color.len(), 0);
+        assert_eq!(boxplot.style.bandwidth, 0.0);
+        assert_eq!(boxplot.style.with_quartiles, false);
+        assert_eq!(boxplot.buffer.len(), 0);
+        assert_eq!(boxplot.primitives.len(), 0);
+    }
+
+    #[test]
+    fn options_boxplot_works() {
+        let mut boxplot = Boxplot::new();
+        boxplot
+            .set_colors(&["red"])
+            .set_horizontal(true)
+            .set_width(0.5)
+            .set_with_means(true)
+            .set_notch(true)
+            .set_whisker(2.0)
+            .set_no_outliers(true)
+            .set_outlier_marker("x")
+            .set_median_color("black");
+        assert_eq!(
+            boxplot.style.options_boxplot(),
+            ",vert=False\
+             ,widths=0.5\
+             ,showmeans=True\
+             ,notch=True\
+             ,whis=2\
+             ,showfliers=False\
+             ,flierprops={'marker':'x'}\
+             ,medianprops={'color':'black'}\
+             ,patch_artist=True"
+        );
+    }
+
+    #[test]
+    fn options_violin_works() {
+        let mut boxplot = Boxplot::new();
+        boxplot
+            .set_violin(true)
+            .set_horizontal(true)
+            .set_width(0.7)
+            .set_with_means(true)
+            .set_bandwidth(0.3)
+            .set_with_quartiles(true);
+        assert_eq!(
+            boxplot.style.options_violin(),
+            ",vert=False\
+             ,widths=0.7\
+             ,showmeans=True\
+             ,bw_method=0.3\
+             ,quantiles=[[0.25,0.5,0.75]]*len(values)"
+        );
+    }
+
+    #[test]
+    fn draw_works() {
+        let values = vec![vec![1, 2, 3, 4, 5], vec![2, 3, 4]];
+        let labels = ["first", "second"];
+        let mut boxplot = Boxplot::new();
+        boxplot.set_colors(&["red", "blue"]);
+        boxplot.draw(&values, &labels);
+        let b: &str = "values=[[1,2,3,4,5,],[2,3,4,],]\n\
+                       colors=['red','blue',]\n\
+                       bp=plt.boxplot(values,patch_artist=True)\n\
+                       for i,b in enumerate(bp['boxes']):\n\
+                       \x20\x20\x20\x20b.set_facecolor(colors[i%len(colors)])\n\
+                       labels=['first','second',]\n\
+                       plt.xticks(range(1,len(values)+1),labels)\n";
+        assert_eq!(boxplot.buffer, b);
+        assert_eq!(
+            boxplot.get_primitives(),
+            &[Primitive::Boxplot {
+                values: vec![vec![1.0, 2.0, 3.0, 4.0, 5.0], vec![2.0, 3.0, 4.0]],
+                labels: vec!["first".to_string(), "second".to_string()],
+                style: boxplot.style.clone(),
+            }]
+        );
+        boxplot.clear_buffer();
+        assert_eq!(boxplot.buffer, "");
+        assert_eq!(boxplot.primitives.len(), 0);
+    }
+
+    #[test]
+    fn draw_violin_works() {
+        let values = vec![vec![1.0, 2.0, 3.0]];
+        let labels: [&str; 0] = [];
+        let mut boxplot = Boxplot::new();
+        boxplot
+            .set_violin(true)
+            .set_horizontal(true)
+            .set_with_quartiles(true)
+            .set_median_color("black");
+        boxplot.draw(&values, &labels);
+        let b: &str = "values=[[1,2,3,],]\n\
+                       vp=plt.violinplot(values,vert=False,quantiles=[[0.25,0.5,0.75]]*len(values))\n\
+                       vp['cquantiles'].set_color('black')\n";
+        assert_eq!(boxplot.buffer, b);
+        boxplot.clear_buffer();
+        boxplot.set_colors(&["red"]);
+        boxplot.draw(&values, &["a"]);
+        let b: &str = "values=[[1,2,3,],]\n\
+                       colors=['red',]\n\
+                       vp=plt.violinplot(values,vert=False,quantiles=[[0.25,0.5,0.75]]*len(values))\n\
+                       for i,b in enumerate(vp['bodies']):\n\
+                       \x20\x20\x20\x20b.set_facecolor(colors[i%len(colors)])\n\
+                       vp['cquantiles'].set_color('black')\n\
+                       labels=['a',]\n\
+                       plt.yticks(range(1,len(values)+1),labels)\n";
+        assert_eq!(boxplot.buffer, b);
+    }
+}
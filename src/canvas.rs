@@ -1,11 +1,13 @@
-use super::{GraphMaker, StrError};
+use super::{to_f64, Error, GraphMaker, Primitive, TextStyle};
 use crate::AsMatrix;
+use num_traits::AsPrimitive;
+use std::collections::{HashMap, HashSet};
 use std::fmt::Write;
 
 /// Defines the poly-curve code
 ///
 /// Reference: [Matplotlib](https://matplotlib.org/stable/api/path_api.html)
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, PartialEq)]
 pub enum PolyCode {
     /// Move to coordinate (first point)
     ///
@@ -35,9 +37,9 @@ pub enum PolyCode {
 /// ## Drawing functions with polyline set by an array
 ///
 /// ```
-/// use plotpy::{Canvas, Plot};
+/// use plotpy::{Canvas, Error, Plot};
 ///
-/// fn main() -> Result<(), &'static str> {
+/// fn main() -> Result<(), Error> {
 ///     // canvas object and common options
 ///     let mut canvas = Canvas::new();
 ///     canvas.set_line_width(3.0).set_edge_color("#cd0000").set_face_color("#eeea83");
@@ -80,9 +82,9 @@ pub enum PolyCode {
 /// ## Cubic Bezier and use of begin/end functions
 ///
 /// ```
-/// use plotpy::{Canvas, Plot, PolyCode, StrError};
+/// use plotpy::{Canvas, Error, Plot, PolyCode};
 ///
-/// fn main() -> Result<(), StrError> {
+/// fn main() -> Result<(), Error> {
 ///     // codes
 ///     let data = [
 ///         (3.0, 0.0, PolyCode::MoveTo),
@@ -124,109 +126,94 @@ pub enum PolyCode {
 ///
 /// See also integration tests in the [tests directory](https://github.com/cpmech/plotpy/tree/main/tests)
 pub struct Canvas {
+    style: CanvasStyle,         // Style of features and text
+    path_points: Vec<[f64; 2]>, // Points of the current polycurve
+    path_codes: Vec<PolyCode>,  // Codes of the current polycurve
+    polyline_3d: Vec<[f64; 3]>, // Points of the current 3D polyline
+    buffer: String,             // buffer
+    primitives: Vec<Primitive>, // primitives
+}
+
+/// Holds the style of the features drawn by [Canvas]
+///
+/// The style is recorded by the [Primitive]s drawn by [Canvas] and may be modified afterwards.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CanvasStyle {
     // features
-    edge_color: String,  // Edge color (shared)
-    face_color: String,  // Face color (shared)
-    line_width: f64,     // Line width of edge (shared)
-    arrow_scale: f64,    // Arrow scale
-    arrow_style: String, // Arrow style
+    pub edge_color: String,  // Edge color (shared)
+    pub face_color: String,  // Face color (shared)
+    pub line_width: f64,     // Line width of edge (shared)
+    pub arrow_scale: f64,    // Arrow scale
+    pub arrow_style: String, // Arrow style
+
+    // mesh
+    pub cell_colors: Vec<String>, // Face colors of cells, selected by the cell attributes
+    pub boundary_color: String,   // Color of the boundary edges (no highlighting if empty)
+    pub boundary_line_width: f64, // Line width of the boundary edges
 
     // text
-    text_color: String,            // Text color
-    text_align_horizontal: String, // Horizontal alignment
-    text_align_vertical: String,   // Vertical alignment
-    text_fontsize: f64,            // Font size
-    text_rotation: f64,            // Text rotation
-
-    // alternative text
-    alt_text_color: String,            // Text color
-    alt_text_align_horizontal: String, // Horizontal alignment
-    alt_text_align_vertical: String,   // Vertical alignment
-    alt_text_fontsize: f64,            // Font size
-    alt_text_rotation: f64,            // Text rotation
+    pub text: TextStyle,     // Text (e.g., point ids)
+    pub alt_text: TextStyle, // Alternative text (e.g., cell ids)
 
     // options
-    stop_clip: bool, // Stop clipping features within margins
-
-    // buffer
-    buffer: String, // buffer
+    pub stop_clip: bool, // Stop clipping features within margins
 }
 
 impl Canvas {
     pub fn new() -> Self {
         Canvas {
-            // features
-            edge_color: "#427ce5".to_string(),
-            face_color: String::new(),
-            line_width: 0.0,
-            arrow_scale: 0.0,
-            arrow_style: String::new(),
-            // text
-            text_color: "#a81414".to_string(),
-            text_align_horizontal: String::new(),
-            text_align_vertical: String::new(),
-            text_fontsize: 8.0,
-            text_rotation: 45.0,
-            // alternative text
-            alt_text_color: "#343434".to_string(),
-            alt_text_align_horizontal: "center".to_string(),
-            alt_text_align_vertical: "center".to_string(),
-            alt_text_fontsize: 10.0,
-            alt_text_rotation: 0.0,
-            // options
-            stop_clip: false,
-            // buffer
+            style: CanvasStyle::default(),
+            path_points: Vec::new(),
+            path_codes: Vec::new(),
+            polyline_3d: Vec::new(),
             buffer: String::new(),
+            primitives: Vec::new(),
         }
     }
 
     /// Draws arc (2D only)
     pub fn draw_arc<T>(&mut self, xc: T, yc: T, r: T, ini_angle: T, fin_angle: T)
     where
-        T: std::fmt::Display,
+        T: AsPrimitive<f64>,
     {
-        let opt = self.options_shared();
-        write!(
-            &mut self.buffer,
-            "p=pat.Arc(({},{}),2*{},2*{},theta1={},theta2={},angle=0{})\n\
-             plt.gca().add_patch(p)\n",
-            xc, yc, r, r, ini_angle, fin_angle, &opt
-        )
-        .unwrap();
+        let style = self.style.clone();
+        self.push(Primitive::Arc {
+            xc: to_f64(xc),
+            yc: to_f64(yc),
+            r: to_f64(r),
+            ini_angle: to_f64(ini_angle),
+            fin_angle: to_f64(fin_angle),
+            style,
+        });
     }
 
     /// Draws arrow (2D only)
     pub fn draw_arrow<T>(&mut self, xi: T, yi: T, xf: T, yf: T)
     where
-        T: std::fmt::Display,
+        T: AsPrimitive<f64>,
     {
-        let opt_shared = self.options_shared();
-        let opt_arrow = self.options_arrow();
-        write!(
-            &mut self.buffer,
-            "p=pat.FancyArrowPatch(({},{}),({},{})\
-                    ,shrinkA=0,shrinkB=0\
-                    ,path_effects=[pff.Stroke(joinstyle='miter')]\
-                    {}{})\n\
-             plt.gca().add_patch(p)\n",
-            xi, yi, xf, yf, &opt_shared, &&opt_arrow,
-        )
-        .unwrap();
+        let style = self.style.clone();
+        self.push(Primitive::Arrow {
+            xi: to_f64(xi),
+            yi: to_f64(yi),
+            xf: to_f64(xf),
+            yf: to_f64(yf),
+            style,
+        });
     }
 
     /// Draws circle (2D only)
     pub fn draw_circle<T>(&mut self, xc: T, yc: T, r: T)
     where
-        T: std::fmt::Display,
+        T: AsPrimitive<f64>,
     {
-        let opt = self.options_shared();
-        write!(
-            &mut self.buffer,
-            "p=pat.Circle(({},{}),{}{})\n\
-             plt.gca().add_patch(p)\n",
-            xc, yc, r, &opt
-        )
-        .unwrap();
+        let style = self.style.clone();
+        self.push(Primitive::Circle {
+            xc: to_f64(xc),
+            yc: to_f64(yc),
+            r: to_f64(r),
+            style,
+        });
     }
 
     /// Begins drawing a polycurve (straight segments, quadratic Bezier, and cubic Bezier) (2D only)
@@ -234,9 +221,10 @@ impl Canvas {
     /// # Warning
     ///
     /// You must call [Canvas::polycurve_add] next, followed by [Canvas::polycurve_end] when finishing adding points.
-    /// Otherwise, Python/Matplotlib will fail.
+    /// Otherwise, the polycurve is not drawn.
     pub fn polycurve_begin(&mut self) -> &mut Self {
-        write!(&mut self.buffer, "dat=[",).unwrap();
+        self.path_points.clear();
+        self.path_codes.clear();
         self
     }
 
@@ -244,19 +232,14 @@ impl Canvas {
     ///
     /// # Warning
     ///
-    /// You must call [Canvas::polycurve_begin] first, otherwise Python/Matplotlib will fail.
+    /// You must call [Canvas::polycurve_begin] first.
     /// Afterwards, you must call [Canvas::polycurve_end] when finishing adding points.
     pub fn polycurve_add<T>(&mut self, x: T, y: T, code: PolyCode) -> &mut Self
     where
-        T: std::fmt::Display,
+        T: AsPrimitive<f64>,
     {
-        let keyword = match code {
-            PolyCode::MoveTo => "MOVETO",
-            PolyCode::LineTo => "LINETO",
-            PolyCode::Curve3 => "CURVE3",
-            PolyCode::Curve4 => "CURVE4",
-        };
-        write!(&mut self.buffer, "[pth.Path.{},({},{})],", keyword, x, y).unwrap();
+        self.path_points.push([to_f64(x), to_f64(y)]);
+        self.path_codes.push(code);
         self
     }
 
@@ -265,80 +248,57 @@ impl Canvas {
     /// # Warning
     ///
     /// This function must be the last one called after [Canvas::polycurve_begin] and [Canvas::polycurve_add].
-    /// Otherwise, Python/Matplotlib will fail.
+    /// Otherwise, the polycurve is not drawn.
     pub fn polycurve_end(&mut self, closed: bool) -> &mut Self {
-        if closed {
-            write!(&mut self.buffer, "[pth.Path.CLOSEPOLY,(None,None)]").unwrap();
-        }
-        let opt = self.options_shared();
-        write!(
-            &mut self.buffer,
-            "]\n\
-            cmd,pts=zip(*dat)\n\
-            h=pth.Path(pts,cmd)\n\
-            p=pat.PathPatch(h{})\n\
-            plt.gca().add_patch(p)\n",
-            &opt
-        )
-        .unwrap();
+        let points = std::mem::take(&mut self.path_points);
+        let codes = std::mem::take(&mut self.path_codes);
+        let style = self.style.clone();
+        self.push(Primitive::Path {
+            points,
+            codes,
+            closed,
+            style,
+        });
         self
     }
 
     /// Draws polyline with straight segments, quadratic Bezier, or cubic Bezier (2D only)
     ///
     /// **Note:** The first and last commands are ignored.
-    pub fn draw_polycurve<'a, T, U>(&mut self, points: &'a T, codes: &[PolyCode], closed: bool) -> Result<(), StrError>
+    pub fn draw_polycurve<'a, T, U>(&mut self, points: &'a T, codes: &[PolyCode], closed: bool) -> Result<(), Error>
     where
         T: AsMatrix<'a, U>,
-        U: 'a + std::fmt::Display,
+        U: 'a + AsPrimitive<f64>,
     {
         let (npoint, ndim) = points.size();
         if npoint < 3 {
-            return Err("npoint must be ≥ 3");
+            return Err(Error::InvalidInput {
+                argument: "points",
+                message: "npoint must be ≥ 3",
+            });
         }
         if ndim != 2 {
-            return Err("ndim must be equal to 2");
+            return Err(Error::InvalidInput {
+                argument: "points",
+                message: "ndim must be equal to 2",
+            });
         }
         if codes.len() != npoint {
-            return Err("codes.len() must be equal to npoint");
-        }
-        write!(
-            &mut self.buffer,
-            "dat=[[pth.Path.MOVETO,({},{})]",
-            points.at(0, 0),
-            points.at(0, 1)
-        )
-        .unwrap();
-        for i in 1..npoint {
-            let keyword = match codes[i] {
-                PolyCode::MoveTo => "MOVETO",
-                PolyCode::LineTo => "LINETO",
-                PolyCode::Curve3 => "CURVE3",
-                PolyCode::Curve4 => "CURVE4",
-            };
-            write!(
-                &mut self.buffer,
-                ",[pth.Path.{},({},{})]",
-                keyword,
-                points.at(i, 0),
-                points.at(i, 1)
-            )
-            .unwrap();
-        }
-        if closed {
-            write!(&mut self.buffer, ",[pth.Path.CLOSEPOLY,(None,None)]").unwrap();
+            return Err(Error::InvalidInput {
+                argument: "codes",
+                message: "codes.len() must be equal to npoint",
+            });
         }
-        let opt = self.options_shared();
-        write!(
-            &mut self.buffer,
-            "]\n\
-            cmd,pts=zip(*dat)\n\
-            h=pth.Path(pts,cmd)\n\
-            p=pat.PathPatch(h{})\n\
-            plt.gca().add_patch(p)\n",
-            &opt
-        )
-        .unwrap();
+        let points = (0..npoint)
+            .map(|i| [to_f64(points.at(i, 0)), to_f64(points.at(i, 1))])
+            .collect();
+        let style = self.style.clone();
+        self.push(Primitive::Path {
+            points,
+            codes: codes.to_vec(),
+            closed,
+            style,
+        });
         Ok(())
     }
 
@@ -347,9 +307,9 @@ impl Canvas {
     /// # Warning
     ///
     /// This function must be followed by [Canvas::polyline_3d_add] and [Canvas::polyline_3d_end],
-    /// otherwise Python/Matplotlib will fail
+    /// otherwise the polyline is not drawn.
     pub fn polyline_3d_begin(&mut self) -> &mut Self {
-        write!(&mut self.buffer, "maybe_create_ax3d()\nxyz=np.array([").unwrap();
+        self.polyline_3d.clear();
         self
     }
 
@@ -358,12 +318,12 @@ impl Canvas {
     /// # Warning
     ///
     /// This function must be called after [Canvas::polyline_3d_begin] and must be followed by [Canvas::polyline_3d_end],
-    /// otherwise Python/Matplotlib will fail.
+    /// otherwise the polyline is not drawn.
     pub fn polyline_3d_add<T>(&mut self, x: T, y: T, z: T) -> &mut Self
     where
-        T: std::fmt::Display,
+        T: AsPrimitive<f64>,
     {
-        write!(&mut self.buffer, "[{},{},{}],", x, y, z).unwrap();
+        self.polyline_3d.push([to_f64(x), to_f64(y), to_f64(z)]);
         self
     }
 
@@ -372,10 +332,11 @@ impl Canvas {
     /// # Warning
     ///
     /// This function must be called after [Canvas::polyline_3d_begin] and [Canvas::polyline_3d_add],
-    /// otherwise Python/Matplotlib will fail.
+    /// otherwise the polyline is not drawn.
     pub fn polyline_3d_end(&mut self) -> &mut Self {
-        let opt = self.options_line_3d();
-        write!(&mut self.buffer, "])\nAX3D.plot(xyz[:,0],xyz[:,1],xyz[:,2]{})\n", &opt).unwrap();
+        let points = std::mem::take(&mut self.polyline_3d);
+        let style = self.style.clone();
+        self.push(Primitive::Polyline3d { points, style });
         self
     }
 
@@ -383,53 +344,41 @@ impl Canvas {
     pub fn draw_polyline<'a, T, U>(&mut self, points: &'a T, closed: bool)
     where
         T: AsMatrix<'a, U>,
-        U: 'a + std::fmt::Display,
+        U: 'a + AsPrimitive<f64>,
     {
         let (npoint, ndim) = points.size();
         if npoint < 2 {
             return;
         }
         if ndim == 2 {
-            write!(
-                &mut self.buffer,
-                "dat=[[pth.Path.MOVETO,({},{})]",
-                points.at(0, 0),
-                points.at(0, 1)
-            )
-            .unwrap();
-            for i in 1..npoint {
-                write!(
-                    &mut self.buffer,
-                    ",[pth.Path.LINETO,({},{})]",
-                    points.at(i, 0),
-                    points.at(i, 1)
-                )
-                .unwrap();
-            }
-            if closed {
-                write!(&mut self.buffer, ",[pth.Path.CLOSEPOLY,(None,None)]").unwrap();
-            }
-            let opt = self.options_shared();
-            write!(
-                &mut self.buffer,
-                "]\n\
-                cmd,pts=zip(*dat)\n\
-                h=pth.Path(pts,cmd)\n\
-                p=pat.PathPatch(h{})\n\
-                plt.gca().add_patch(p)\n",
-                &opt
-            )
-            .unwrap();
+            let points = (0..npoint)
+                .map(|i| [to_f64(points.at(i, 0)), to_f64(points.at(i, 1))])
+                .collect();
+            let mut codes = vec![PolyCode::LineTo; npoint];
+            codes[0] = PolyCode::MoveTo;
+            let style = self.style.clone();
+            self.push(Primitive::Path {
+                points,
+                codes,
+                closed,
+                style,
+            });
         }
         if ndim == 3 {
-            self.polyline_3d_begin();
-            for i in 0..npoint {
-                self.polyline_3d_add(points.at(i, 0), points.at(i, 1), points.at(i, 2));
-            }
+            let mut points: Vec<_> = (0..npoint)
+                .map(|i| {
+                    [
+                        to_f64(points.at(i, 0)),
+                        to_f64(points.at(i, 1)),
+                        to_f64(points.at(i, 2)),
+                    ]
+                })
+                .collect();
             if closed && npoint > 2 {
-                self.polyline_3d_add(points.at(0, 0), points.at(0, 1), points.at(0, 2));
+                points.push(points[0]);
             }
-            self.polyline_3d_end();
+            let style = self.style.clone();
+            self.push(Primitive::Polyline3d { points, style });
         }
     }
 
@@ -446,161 +395,158 @@ impl Canvas {
         ndiv: &[usize],
         with_point_ids: bool,
         with_cell_ids: bool,
-    ) -> Result<(), StrError> {
+    ) -> Result<(), Error> {
         // check input
         let ndim = ndiv.len();
         if ndim < 2 || ndim > 3 {
-            return Err("len(ndiv) == ndim must be 2 or 3");
+            return Err(Error::InvalidInput {
+                argument: "ndiv",
+                message: "len(ndiv) == ndim must be 2 or 3",
+            });
         }
         if xmin.len() != ndim {
-            return Err("size of xmin must equal ndim == len(ndiv)");
+            return Err(Error::InvalidInput {
+                argument: "xmin",
+                message: "size of xmin must equal ndim == len(ndiv)",
+            });
         }
         if xmax.len() != ndim {
-            return Err("size of xmax must equal ndim == len(ndiv)");
+            return Err(Error::InvalidInput {
+                argument: "xmax",
+                message: "size of xmax must equal ndim == len(ndiv)",
+            });
         }
-
-        // compute delta
-        let mut npoint = [1; 3];
-        let mut delta = [0.0; 3];
         for i in 0..ndim {
-            npoint[i] = ndiv[i] + 1;
-            delta[i] = xmax[i] - xmin[i];
-            if delta[i] <= 0.0 {
-                return Err("xmax must be greater than xmin");
+            if xmax[i] - xmin[i] <= 0.0 {
+                return Err(Error::InvalidInput {
+                    argument: "xmax",
+                    message: "xmax must be greater than xmin",
+                });
             }
-            delta[i] /= ndiv[i] as f64;
         }
 
-        // auxiliary points
-        let mut a = [0.0; 3];
-        let mut b = [0.0; 3];
+        // record grid
+        let style = self.style.clone();
+        self.push(Primitive::CanvasGrid {
+            xmin: xmin.to_vec(),
+            xmax: xmax.to_vec(),
+            ndiv: ndiv.to_vec(),
+            with_point_ids,
+            with_cell_ids,
+            style,
+        });
+        Ok(())
+    }
 
-        // loop over lines
-        if ndim == 2 {
-            write!(&mut self.buffer, "dat=[\n").unwrap();
-        } else {
-            write!(&mut self.buffer, "maybe_create_ax3d()\n").unwrap();
+    /// Draws a 2D or 3D unstructured mesh
+    ///
+    /// The cell type is given by the space dimension and the number of points of the cell:
+    ///
+    /// * 2D: tri3, tri6, qua4, qua8
+    /// * 3D: tet4, hex8
+    ///
+    /// The points of each cell follow the usual finite element ordering: the corners come first
+    /// (counterclockwise in 2D) and the middle points of the edges come afterwards.
+    ///
+    /// # Input
+    ///
+    /// * `points` -- the coordinates of the points (npoint × ndim with ndim = 2 or 3)
+    /// * `cells` -- the (0-based) indices of the points of each cell
+    /// * `attributes` -- (optional) the attribute of each cell selecting its face color (2D only)
+    /// * `with_point_ids` -- draws the point ids using the text style
+    /// * `with_cell_ids` -- draws the cell ids (at the center of the cells) using the alternative text style
+    ///
+    /// # Notes
+    ///
+    /// * The edges of quadratic cells (tri6 and qua8) are drawn as quadratic Bezier curves through the middle points.
+    /// * The face colors are selected from `cell_colors` by the attribute of each cell (cycling through the list);
+    ///   Matplotlib's default colors "C0", "C1", ... are used if `cell_colors` is empty.
+    /// * The cells are not filled if there are no attributes and `face_color` is empty.
+    /// * The boundary edges are highlighted if `boundary_color` is not empty.
+    pub fn draw_mesh<'a, T, U>(
+        &mut self,
+        points: &'a T,
+        cells: &[Vec<usize>],
+        attributes: Option<&[usize]>,
+        with_point_ids: bool,
+        with_cell_ids: bool,
+    ) -> Result<(), Error>
+    where
+        T: AsMatrix<'a, U>,
+        U: 'a + AsPrimitive<f64>,
+    {
+        // check input
+        let (npoint, ndim) = points.size();
+        if !(2..=3).contains(&ndim) {
+            return Err(Error::InvalidInput {
+                argument: "points",
+                message: "ndim must be 2 or 3",
+            });
         }
-        let opt = self.options_shared();
-        let mut id_point = 0;
-        for k in 0..npoint[2] {
-            if ndim == 3 {
-                a[2] = xmin[2] + delta[2] * (k as f64);
-                b[2] = a[2];
-            }
-
-            // vertical lines
-            a[1] = xmin[1];
-            b[1] = xmax[1];
-            for i in 0..npoint[0] {
-                a[0] = xmin[0] + delta[0] * (i as f64);
-                b[0] = a[0];
-                self.line(ndim, &a, &b);
+        for cell in cells {
+            if mesh_cell_edges(ndim, cell.len()).is_none() {
+                return Err(Error::InvalidInput {
+                    argument: "cells",
+                    message: "cells must be tri3, tri6, qua4, or qua8 in 2D and tet4 or hex8 in 3D",
+                });
             }
-
-            // horizontal lines
-            a[0] = xmin[0];
-            b[0] = xmax[0];
-            for j in 0..npoint[1] {
-                a[1] = xmin[1] + delta[1] * (j as f64);
-                b[1] = a[1];
-                self.line(ndim, &a, &b);
-            }
-
-            // add patch
-            if ndim == 2 {
-                write!(
-                    &mut self.buffer,
-                    "]\n\
-                    cmd,pts=zip(*dat)\n\
-                    h=pth.Path(pts,cmd)\n\
-                    p=pat.PathPatch(h{})\n\
-                    plt.gca().add_patch(p)\n",
-                    &opt
-                )
-                .unwrap();
-            }
-
-            // labels
-            if with_point_ids {
-                for j in 0..npoint[1] {
-                    a[1] = xmin[1] + delta[1] * (j as f64);
-                    for i in 0..npoint[0] {
-                        a[0] = xmin[0] + delta[0] * (i as f64);
-                        let txt = format!("{}", id_point);
-                        self.text(ndim, &a, &txt, false);
-                        id_point += 1;
-                    }
-                }
-            }
-        }
-
-        // cell ids
-        if with_cell_ids {
-            let mut id_cell = 0;
-            let nz = if ndim == 2 { 1 } else { ndiv[2] };
-            for k in 0..nz {
-                if ndim == 3 {
-                    a[2] = xmin[2] + delta[2] * (k as f64);
-                    b[2] = a[2] + delta[2] / 2.0;
-                }
-                for j in 0..ndiv[1] {
-                    a[1] = xmin[1] + delta[1] * (j as f64);
-                    b[1] = a[1] + delta[1] / 2.0;
-                    for i in 0..ndiv[0] {
-                        a[0] = xmin[0] + delta[0] * (i as f64);
-                        b[0] = a[0] + delta[0] / 2.0;
-                        let txt = format!("{}", id_cell);
-                        self.text(ndim, &b, &txt, true);
-                        id_cell += 1;
-                    }
-                }
+            if cell.iter().any(|p| *p >= npoint) {
+                return Err(Error::InvalidInput {
+                    argument: "cells",
+                    message: "the indices of points must be smaller than npoint",
+                });
             }
         }
-
-        // z-lines
-        if ndim == 3 {
-            a[2] = xmin[2];
-            b[2] = xmax[2];
-            for j in 0..npoint[1] {
-                a[1] = xmin[1] + delta[1] * (j as f64);
-                b[1] = a[1];
-                for i in 0..npoint[0] {
-                    a[0] = xmin[0] + delta[0] * (i as f64);
-                    b[0] = a[0];
-                    self.line(ndim, &a, &b);
-                }
+        if let Some(attributes) = attributes {
+            if attributes.len() != cells.len() {
+                return Err(Error::InvalidInput {
+                    argument: "attributes",
+                    message: "attributes.len() must be equal to cells.len()",
+                });
             }
         }
 
-        // adjust limits
-        self.limits(ndim, xmin, xmax);
-
-        // done
+        // record mesh
+        let points = (0..npoint)
+            .map(|i| {
+                let z = if ndim == 3 { to_f64(points.at(i, 2)) } else { 0.0 };
+                [to_f64(points.at(i, 0)), to_f64(points.at(i, 1)), z]
+            })
+            .collect();
+        let style = self.style.clone();
+        self.push(Primitive::CanvasMesh {
+            ndim,
+            points,
+            cells: cells.to_vec(),
+            attributes: attributes.map(|a| a.to_vec()).unwrap_or_default(),
+            with_point_ids,
+            with_cell_ids,
+            style,
+        });
         Ok(())
     }
 
     /// Sets the edge color (shared among features)
     pub fn set_edge_color(&mut self, color: &str) -> &mut Self {
-        self.edge_color = String::from(color);
+        self.style.edge_color = String::from(color);
         self
     }
 
     /// Sets the face color (shared among features)
     pub fn set_face_color(&mut self, color: &str) -> &mut Self {
-        self.face_color = String::from(color);
+        self.style.face_color = String::from(color);
         self
     }
 
     /// Sets the line width of edge (shared among features)
     pub fn set_line_width(&mut self, width: f64) -> &mut Self {
-        self.line_width = width;
+        self.style.line_width = width;
         self
     }
 
     /// Sets the arrow scale
     pub fn set_arrow_scale(&mut self, scale: f64) -> &mut Self {
-        self.arrow_scale = scale;
+        self.style.arrow_scale = scale;
         self
     }
 
@@ -624,13 +570,31 @@ impl Canvas {
     /// * "`|-|`"    -- BarAB         : widthA=1.0,angleA=None,widthB=1.0,angleB=None
     /// * As defined in <https://matplotlib.org/stable/api/_as_gen/matplotlib.patches.FancyArrowPatch.html>
     pub fn set_arrow_style(&mut self, style: &str) -> &mut Self {
-        self.arrow_style = String::from(style);
+        self.style.arrow_style = String::from(style);
+        self
+    }
+
+    /// Sets the face colors of the cells of a mesh, selected by the cell attributes
+    pub fn set_cell_colors(&mut self, colors: &[&str]) -> &mut Self {
+        self.style.cell_colors = colors.iter().map(|color| color.to_string()).collect();
+        self
+    }
+
+    /// Sets the color of the boundary edges of a mesh (no highlighting if empty)
+    pub fn set_boundary_color(&mut self, color: &str) -> &mut Self {
+        self.style.boundary_color = String::from(color);
+        self
+    }
+
+    /// Sets the line width of the boundary edges of a mesh
+    pub fn set_boundary_line_width(&mut self, width: f64) -> &mut Self {
+        self.style.boundary_line_width = width;
         self
     }
 
     /// Sets the text color
     pub fn set_text_color(&mut self, color: &str) -> &mut Self {
-        self.text_color = String::from(color);
+        self.style.text.color = String::from(color);
         self
     }
 
@@ -638,7 +602,7 @@ impl Canvas {
     ///
     /// Options: "center", "left", "right"
     pub fn set_text_align_horizontal(&mut self, option: &str) -> &mut Self {
-        self.text_align_horizontal = String::from(option);
+        self.style.text.align_horizontal = String::from(option);
         self
     }
 
@@ -646,25 +610,25 @@ impl Canvas {
     ///
     /// Options: "center", "top", "bottom", "baseline", "center_baseline"
     pub fn set_text_align_vertical(&mut self, option: &str) -> &mut Self {
-        self.text_align_vertical = String::from(option);
+        self.style.text.align_vertical = String::from(option);
         self
     }
 
     /// Sets the text font size
     pub fn set_text_fontsize(&mut self, fontsize: f64) -> &mut Self {
-        self.text_fontsize = fontsize;
+        self.style.text.fontsize = fontsize;
         self
     }
 
     /// Sets the text rotation
     pub fn set_text_rotation(&mut self, rotation: f64) -> &mut Self {
-        self.text_rotation = rotation;
+        self.style.text.rotation = rotation;
         self
     }
 
     /// Sets the alternative text color
     pub fn set_alt_text_color(&mut self, color: &str) -> &mut Self {
-        self.alt_text_color = String::from(color);
+        self.style.alt_text.color = String::from(color);
         self
     }
 
@@ -672,7 +636,7 @@ impl Canvas {
     ///
     /// Options: "center", "left", "right"
     pub fn set_alt_text_align_horizontal(&mut self, option: &str) -> &mut Self {
-        self.alt_text_align_horizontal = String::from(option);
+        self.style.alt_text.align_horizontal = String::from(option);
         self
     }
 
@@ -680,30 +644,73 @@ impl Canvas {
     ///
     /// Options: "center", "top", "bottom", "baseline", "center_baseline"
     pub fn set_alt_text_align_vertical(&mut self, option: &str) -> &mut Self {
-        self.alt_text_align_vertical = String::from(option);
+        self.style.alt_text.align_vertical = String::from(option);
         self
     }
 
     /// Sets the alternative text font size
     pub fn set_alt_text_fontsize(&mut self, fontsize: f64) -> &mut Self {
-        self.alt_text_fontsize = fontsize;
+        self.style.alt_text.fontsize = fontsize;
         self
     }
 
     /// Sets the alternative text rotation
     pub fn set_alt_text_rotation(&mut self, rotation: f64) -> &mut Self {
-        self.alt_text_rotation = rotation;
+        self.style.alt_text.rotation = rotation;
         self
     }
 
     /// Sets the flag to stop clipping features within margins
     pub fn set_stop_clip(&mut self, flag: bool) -> &mut Self {
-        self.stop_clip = flag;
+        self.style.stop_clip = flag;
         self
     }
 
+    /// Writes the primitive to the buffer and records it
+    fn push(&mut self, primitive: Primitive) {
+        primitive.write_python(&mut self.buffer);
+        self.primitives.push(primitive);
+    }
+}
+
+impl Default for CanvasStyle {
+    fn default() -> Self {
+        CanvasStyle {
+            // features
+            edge_color: "#427ce5".to_string(),
+            face_color: String::new(),
+            line_width: 0.0,
+            arrow_scale: 0.0,
+            arrow_style: String::new(),
+            // mesh
+            cell_colors: Vec::new(),
+            boundary_color: String::new(),
+            boundary_line_width: 2.0,
+            // text
+            text: TextStyle {
+                color: "#a81414".to_string(),
+                fontsize: 8.0,
+                rotation: 45.0,
+                ..Default::default()
+            },
+            // alternative text
+            alt_text: TextStyle {
+                color: "#343434".to_string(),
+                align_horizontal: "center".to_string(),
+                align_vertical: "center".to_string(),
+                fontsize: 10.0,
+                rotation: 0.0,
+                ..Default::default()
+            },
+            // options
+            stop_clip: false,
+        }
+    }
+}
+
+impl CanvasStyle {
     /// Returns shared options
-    fn options_shared(&self) -> String {
+    pub(crate) fn options_shared(&self) -> String {
         let mut opt = String::new();
         if self.edge_color != "" {
             write!(&mut opt, ",edgecolor='{}'", self.edge_color).unwrap();
@@ -721,7 +728,7 @@ impl Canvas {
     }
 
     /// Returns options for arrows
-    fn options_arrow(&self) -> String {
+    pub(crate) fn options_arrow(&self) -> String {
         let mut opt = String::new();
         if self.arrow_scale > 0.0 {
             write!(&mut opt, ",mutation_scale={}", self.arrow_scale).unwrap();
@@ -732,50 +739,40 @@ impl Canvas {
         opt
     }
 
-    /// Returns options for text
-    fn options_text(&self) -> String {
+    /// Returns options for the cells of a mesh with the given face color
+    pub(crate) fn options_mesh_cells(&self, face_color: &str) -> String {
         let mut opt = String::new();
-        if self.text_color != "" {
-            write!(&mut opt, ",color='{}'", self.text_color).unwrap();
-        }
-        if self.text_align_horizontal != "" {
-            write!(&mut opt, ",ha='{}'", self.text_align_horizontal).unwrap();
+        if self.edge_color != "" {
+            write!(&mut opt, ",edgecolor='{}'", self.edge_color).unwrap();
         }
-        if self.text_align_vertical != "" {
-            write!(&mut opt, ",va='{}'", self.text_align_vertical).unwrap();
+        if face_color != "" {
+            write!(&mut opt, ",facecolor='{}'", face_color).unwrap();
+        } else {
+            write!(&mut opt, ",facecolor='none'").unwrap();
         }
-        if self.text_fontsize > 0.0 {
-            write!(&mut opt, ",fontsize={}", self.text_fontsize).unwrap();
+        if self.line_width > 0.0 {
+            write!(&mut opt, ",linewidth={}", self.line_width).unwrap();
         }
-        if self.text_rotation > 0.0 {
-            write!(&mut opt, ",rotation={}", self.text_rotation).unwrap();
+        if self.stop_clip {
+            write!(&mut opt, ",clip_on=False").unwrap();
         }
         opt
     }
 
-    /// Returns options for alternative text
-    fn options_alt_text(&self) -> String {
-        let mut opt = String::new();
-        if self.alt_text_color != "" {
-            write!(&mut opt, ",color='{}'", self.alt_text_color).unwrap();
-        }
-        if self.alt_text_align_horizontal != "" {
-            write!(&mut opt, ",ha='{}'", self.alt_text_align_horizontal).unwrap();
+    /// Returns options for the boundary edges of a 2D mesh
+    pub(crate) fn options_mesh_boundary(&self) -> String {
+        let mut opt = format!(",fill=False,edgecolor='{}'", self.boundary_color);
+        if self.boundary_line_width > 0.0 {
+            write!(&mut opt, ",linewidth={}", self.boundary_line_width).unwrap();
         }
-        if self.alt_text_align_vertical != "" {
-            write!(&mut opt, ",va='{}'", self.alt_text_align_vertical).unwrap();
-        }
-        if self.alt_text_fontsize > 0.0 {
-            write!(&mut opt, ",fontsize={}", self.alt_text_fontsize).unwrap();
-        }
-        if self.alt_text_rotation > 0.0 {
-            write!(&mut opt, ",rotation={}", self.alt_text_rotation).unwrap();
+        if self.stop_clip {
+            write!(&mut opt, ",clip_on=False").unwrap();
         }
         opt
     }
 
     /// Returns options for 3D line
-    fn options_line_3d(&self) -> String {
+    pub(crate) fn options_line_3d(&self) -> String {
         let mut opt = String::new();
         if self.edge_color != "" {
             write!(&mut opt, ",color='{}'", self.edge_color).unwrap();
@@ -785,78 +782,580 @@ impl Canvas {
         }
         opt
     }
+}
 
-    /// Draws 2D or 3D line
-    fn line(&mut self, ndim: usize, a: &[f64; 3], b: &[f64; 3]) {
-        if ndim == 2 {
-            write!(
-                &mut self.buffer,
-                "    [pth.Path.MOVETO,({},{})],[pth.Path.LINETO,({},{})],\n",
-                a[0], a[1], b[0], b[1]
-            )
-            .unwrap();
-        } else {
-            let opt = self.options_line_3d();
-            write!(
-                &mut self.buffer,
-                "AX3D.plot([{},{}],[{},{}],[{},{}]{})\n",
-                a[0], b[0], a[1], b[1], a[2], b[2], opt,
-            )
-            .unwrap();
+/// Holds an item of the grid drawn by [Canvas::draw_grid]
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) enum GridItem {
+    /// Line from a to b
+    Line([f64; 3], [f64; 3]),
+
+    /// Indicates the end of a group of lines (one group per z-layer)
+    EndLines,
+
+    /// Point id (or cell id if alternative) at a
+    Text([f64; 3], String, bool),
+}
+
+/// Generates the lines and ids of a grid (the input must have been validated by [Canvas::draw_grid])
+pub(crate) fn grid_items(
+    xmin: &[f64],
+    xmax: &[f64],
+    ndiv: &[usize],
+    with_point_ids: bool,
+    with_cell_ids: bool,
+) -> Vec<GridItem> {
+    // compute delta
+    let ndim = ndiv.len();
+    let mut npoint = [1; 3];
+    let mut delta = [0.0; 3];
+    for i in 0..ndim {
+        npoint[i] = ndiv[i] + 1;
+        delta[i] = (xmax[i] - xmin[i]) / (ndiv[i] as f64);
+    }
+
+    // auxiliary points
+    let mut a = [0.0; 3];
+    let mut b = [0.0; 3];
+
+    // loop over lines
+    let mut items = Vec::new();
+    let mut id_point = 0;
+    for k in 0..npoint[2] {
+        if ndim == 3 {
+            a[2] = xmin[2] + delta[2] * (k as f64);
+            b[2] = a[2];
+        }
+
+        // vertical lines
+        a[1] = xmin[1];
+        b[1] = xmax[1];
+        for i in 0..npoint[0] {
+            a[0] = xmin[0] + delta[0] * (i as f64);
+            b[0] = a[0];
+            items.push(GridItem::Line(a, b));
+        }
+
+        // horizontal lines
+        a[0] = xmin[0];
+        b[0] = xmax[0];
+        for j in 0..npoint[1] {
+            a[1] = xmin[1] + delta[1] * (j as f64);
+            b[1] = a[1];
+            items.push(GridItem::Line(a, b));
+        }
+        items.push(GridItem::EndLines);
+
+        // labels
+        if with_point_ids {
+            for j in 0..npoint[1] {
+                a[1] = xmin[1] + delta[1] * (j as f64);
+                for i in 0..npoint[0] {
+                    a[0] = xmin[0] + delta[0] * (i as f64);
+                    items.push(GridItem::Text(a, format!("{}", id_point), false));
+                    id_point += 1;
+                }
+            }
         }
     }
 
-    /// Draws 2D or 3D text
-    fn text(&mut self, ndim: usize, a: &[f64; 3], txt: &str, alternative: bool) {
-        let opt = if alternative {
-            self.options_alt_text()
-        } else {
-            self.options_text()
-        };
-        if ndim == 2 {
-            write!(&mut self.buffer, "plt.text({},{},'{}'{})\n", a[0], a[1], txt, &opt).unwrap();
-        } else {
-            write!(
-                &mut self.buffer,
-                "AX3D.text({},{},{},'{}'{})\n",
-                a[0], a[1], a[2], txt, &opt
-            )
-            .unwrap();
+    // cell ids
+    if with_cell_ids {
+        let mut id_cell = 0;
+        let nz = if ndim == 2 { 1 } else { ndiv[2] };
+        for k in 0..nz {
+            if ndim == 3 {
+                a[2] = xmin[2] + delta[2] * (k as f64);
+                b[2] = a[2] + delta[2] / 2.0;
+            }
+            for j in 0..ndiv[1] {
+                a[1] = xmin[1] + delta[1] * (j as f64);
+                b[1] = a[1] + delta[1] / 2.0;
+                for i in 0..ndiv[0] {
+                    a[0] = xmin[0] + delta[0] * (i as f64);
+                    b[0] = a[0] + delta[0] / 2.0;
+                    items.push(GridItem::Text(b, format!("{}", id_cell), true));
+                    id_cell += 1;
+                }
+            }
         }
     }
 
-    /// Adjust 2D or 3D limits
-    fn limits(&mut self, ndim: usize, xmin: &[f64], xmax: &[f64]) {
-        const FACTOR: f64 = 0.1;
-        let mut gap = [0.0; 3];
-        for i in 0..ndim {
-            gap[i] = (xmax[i] - xmin[i]) * FACTOR;
+    // z-lines
+    if ndim == 3 {
+        a[2] = xmin[2];
+        b[2] = xmax[2];
+        for j in 0..npoint[1] {
+            a[1] = xmin[1] + delta[1] * (j as f64);
+            b[1] = a[1];
+            for i in 0..npoint[0] {
+                a[0] = xmin[0] + delta[0] * (i as f64);
+                b[0] = a[0];
+                items.push(GridItem::Line(a, b));
+            }
         }
-        if ndim == 2 {
+    }
+    items
+}
+
+/// Returns the limits of a grid, including a gap, as (min, max) pairs
+pub(crate) fn grid_limits(xmin: &[f64], xmax: &[f64]) -> Vec<(f64, f64)> {
+    const FACTOR: f64 = 0.1;
+    xmin.iter()
+        .zip(xmax)
+        .map(|(a, b)| {
+            let gap = (b - a) * FACTOR;
+            (a - gap, b + gap)
+        })
+        .collect()
+}
+
+/// Returns the local edges of a mesh cell given the space dimension and the number of points
+///
+/// Each edge is given by its two corners followed by its middle point (quadratic cells only).
+/// Returns None if the cell type is not supported.
+pub(crate) fn mesh_cell_edges(ndim: usize, npoint: usize) -> Option<&'static [&'static [usize]]> {
+    match (ndim, npoint) {
+        (2, 3) => Some(&[&[0, 1], &[1, 2], &[2, 0]]),
+        (2, 6) => Some(&[&[0, 1, 3], &[1, 2, 4], &[2, 0, 5]]),
+        (2, 4) => Some(&[&[0, 1], &[1, 2], &[2, 3], &[3, 0]]),
+        (2, 8) => Some(&[&[0, 1, 4], &[1, 2, 5], &[2, 3, 6], &[3, 0, 7]]),
+        (3, 4) => Some(&[&[0, 1], &[1, 2], &[2, 0], &[0, 3], &[1, 3], &[2, 3]]),
+        (3, 8) => Some(&[
+            &[0, 1],
+            &[1, 2],
+            &[2, 3],
+            &[3, 0],
+            &[4, 5],
+            &[5, 6],
+            &[6, 7],
+            &[7, 4],
+            &[0, 4],
+            &[1, 5],
+            &[2, 6],
+            &[3, 7],
+        ]),
+        _ => None,
+    }
+}
+
+/// Returns the local faces of a 3D mesh cell (tet4 or hex8) given the number of points
+fn mesh_cell_faces(npoint: usize) -> &'static [&'static [usize]] {
+    match npoint {
+        4 => &[&[0, 2, 1], &[0, 1, 3], &[0, 3, 2], &[1, 2, 3]],
+        _ => &[
+            &[0, 4, 7, 3],
+            &[1, 2, 6, 5],
+            &[0, 1, 5, 4],
+            &[2, 3, 7, 6],
+            &[0, 3, 2, 1],
+            &[4, 5, 6, 7],
+        ],
+    }
+}
+
+/// Returns the unique (global) edges of a mesh with the middle point, if any, as the third entry
+///
+/// The input must have been validated by [Canvas::draw_mesh]. If `boundary_only`, returns only the
+/// edges on the boundary: in 2D, the edges shared by a single cell; in 3D, the edges of the faces
+/// shared by a single cell.
+pub(crate) fn mesh_edges(ndim: usize, cells: &[Vec<usize>], boundary_only: bool) -> Vec<Vec<usize>> {
+    let key = |a: usize, b: usize| if a < b { (a, b) } else { (b, a) };
+    let mut edges = Vec::new();
+    if ndim == 2 || !boundary_only {
+        let mut count: HashMap<(usize, usize), usize> = HashMap::new();
+        for cell in cells {
+            for e in mesh_cell_edges(ndim, cell.len()).unwrap() {
+                *count.entry(key(cell[e[0]], cell[e[1]])).or_insert(0) += 1;
+            }
+        }
+        let mut done = HashSet::new();
+        for cell in cells {
+            for e in mesh_cell_edges(ndim, cell.len()).unwrap() {
+                let k = key(cell[e[0]], cell[e[1]]);
+                let on_boundary = ndim == 2 && count[&k] == 1;
+                if (!boundary_only || on_boundary) && done.insert(k) {
+                    edges.push(e.iter().map(|i| cell[*i]).collect());
+                }
+            }
+        }
+    } else {
+        let mut count: HashMap<Vec<usize>, usize> = HashMap::new();
+        for cell in cells {
+            for f in mesh_cell_faces(cell.len()) {
+                let mut k: Vec<usize> = f.iter().map(|i| cell[*i]).collect();
+                k.sort();
+                *count.entry(k).or_insert(0) += 1;
+            }
+        }
+        let mut done = HashSet::new();
+        for cell in cells {
+            for f in mesh_cell_faces(cell.len()) {
+                let mut k: Vec<usize> = f.iter().map(|i| cell[*i]).collect();
+                k.sort();
+                if count[&k] == 1 {
+                    for i in 0..f.len() {
+                        let (a, b) = (cell[f[i]], cell[f[(i + 1) % f.len()]]);
+                        if done.insert(key(a, b)) {
+                            edges.push(vec![a, b]);
+                        }
+                    }
+                }
+            }
+        }
+    }
+    edges
+}
+
+/// Returns the Python keyword of a poly-curve code
+fn keyword(code: PolyCode) -> &'static str {
+    match code {
+        PolyCode::MoveTo => "MOVETO",
+        PolyCode::LineTo => "LINETO",
+        PolyCode::Curve3 => "CURVE3",
+        PolyCode::Curve4 => "CURVE4",
+    }
+}
+
+/// Writes the Python commands of an arc (see [Primitive::Arc])
+pub(crate) fn write_arc(
+    buf: &mut String,
+    xc: f64,
+    yc: f64,
+    r: f64,
+    ini_angle: f64,
+    fin_angle: f64,
+    style: &CanvasStyle,
+) {
+    write!(
+        buf,
+        "p=pat.Arc(({},{}),2*{},2*{},theta1={},theta2={},angle=0{})\n\
+         plt.gca().add_patch(p)\n",
+        xc,
+        yc,
+        r,
+        r,
+        ini_angle,
+        fin_angle,
+        style.options_shared()
+    )
+    .unwrap();
+}
+
+/// Writes the Python commands of an arrow (see [Primitive::Arrow])
+pub(crate) fn write_arrow(buf: &mut String, xi: f64, yi: f64, xf: f64, yf: f64, style: &CanvasStyle) {
+    write!(
+        buf,
+        "p=pat.FancyArrowPatch(({},{}),({},{})\
+                ,shrinkA=0,shrinkB=0\
+                ,path_effects=[pff.Stroke(joinstyle='miter')]\
+                {}{})\n\
+         plt.gca().add_patch(p)\n",
+        xi,
+        yi,
+        xf,
+        yf,
+        style.options_shared(),
+        style.options_arrow(),
+    )
+    .unwrap();
+}
+
+/// Writes the Python commands of a circle (see [Primitive::Circle])
+pub(crate) fn write_circle(buf: &mut String, xc: f64, yc: f64, r: f64, style: &CanvasStyle) {
+    write!(
+        buf,
+        "p=pat.Circle(({},{}),{}{})\n\
+         plt.gca().add_patch(p)\n",
+        xc,
+        yc,
+        r,
+        style.options_shared()
+    )
+    .unwrap();
+}
+
+/// Writes the Python commands of a path (see [Primitive::Path])
+///
+/// **Note:** The first code is written as MOVETO.
+pub(crate) fn write_path(buf: &mut String, points: &[[f64; 2]], codes: &[PolyCode], closed: bool, style: &CanvasStyle) {
+    let mut items: Vec<String> = points
+        .iter()
+        .zip(codes)
+        .enumerate()
+        .map(|(i, ([x, y], code))| {
+            let code = if i == 0 { PolyCode::MoveTo } else { *code };
+            format!("[pth.Path.{},({},{})]", keyword(code), x, y)
+        })
+        .collect();
+    if closed {
+        items.push("[pth.Path.CLOSEPOLY,(None,None)]".to_string());
+    }
+    write!(
+        buf,
+        "dat=[{}]\n\
+         cmd,pts=zip(*dat)\n\
+         h=pth.Path(pts,cmd)\n\
+         p=pat.PathPatch(h{})\n\
+         plt.gca().add_patch(p)\n",
+        items.join(","),
+        style.options_shared()
+    )
+    .unwrap();
+}
+
+/// Writes the Python commands of a 3D polyline (see [Primitive::Polyline3d])
+pub(crate) fn write_polyline_3d(buf: &mut String, points: &[[f64; 3]], style: &CanvasStyle) {
+    write!(buf, "maybe_create_ax3d()\nxyz=np.array([").unwrap();
+    for [x, y, z] in points {
+        write!(buf, "[{},{},{}],", x, y, z).unwrap();
+    }
+    write!(
+        buf,
+        "])\nAX3D.plot(xyz[:,0],xyz[:,1],xyz[:,2]{})\n",
+        style.options_line_3d()
+    )
+    .unwrap();
+}
+
+/// Writes the Python commands of a grid (see [Primitive::CanvasGrid])
+pub(crate) fn write_canvas_grid(
+    buf: &mut String,
+    xmin: &[f64],
+    xmax: &[f64],
+    ndiv: &[usize],
+    with_point_ids: bool,
+    with_cell_ids: bool,
+    style: &CanvasStyle,
+) {
+    let ndim = ndiv.len();
+    if ndim == 2 {
+        write!(buf, "dat=[\n").unwrap();
+    } else {
+        write!(buf, "maybe_create_ax3d()\n").unwrap();
+    }
+    for item in grid_items(xmin, xmax, ndiv, with_point_ids, with_cell_ids) {
+        match item {
+            GridItem::Line(a, b) => write_grid_line(buf, ndim, &a, &b, style),
+            GridItem::EndLines => {
+                if ndim == 2 {
+                    write!(
+                        buf,
+                        "]\n\
+                         cmd,pts=zip(*dat)\n\
+                         h=pth.Path(pts,cmd)\n\
+                         p=pat.PathPatch(h{})\n\
+                         plt.gca().add_patch(p)\n",
+                        style.options_shared()
+                    )
+                    .unwrap();
+                }
+            }
+            GridItem::Text(a, txt, alternative) => {
+                let text_style = if alternative { &style.alt_text } else { &style.text };
+                write_grid_text(buf, ndim, &a, &txt, text_style);
+            }
+        }
+    }
+    write_grid_limits(buf, &grid_limits(xmin, xmax));
+}
+
+/// Writes the Python commands of a mesh (see [Primitive::CanvasMesh])
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn write_canvas_mesh(
+    buf: &mut String,
+    ndim: usize,
+    points: &[[f64; 3]],
+    cells: &[Vec<usize>],
+    attributes: &[usize],
+    with_point_ids: bool,
+    with_cell_ids: bool,
+    style: &CanvasStyle,
+) {
+    if ndim == 2 {
+        // cells grouped by face color (one compound path per group)
+        let mut groups: Vec<(String, Vec<usize>)> = Vec::new();
+        for c in 0..cells.len() {
+            let face_color = match attributes.get(c) {
+                Some(a) if style.cell_colors.len() > 0 => style.cell_colors[a % style.cell_colors.len()].clone(),
+                Some(a) => format!("C{}", a % 10),
+                None => style.face_color.clone(),
+            };
+            match groups.iter_mut().find(|(color, _)| *color == face_color) {
+                Some((_, ids)) => ids.push(c),
+                None => groups.push((face_color, vec![c])),
+            }
+        }
+        for (face_color, ids) in &groups {
+            write!(buf, "dat=[\n").unwrap();
+            for c in ids {
+                let cell = &cells[*c];
+                let a = &points[cell[0]];
+                write!(buf, "    [pth.Path.MOVETO,({},{})],", a[0], a[1]).unwrap();
+                for e in mesh_cell_edges(ndim, cell.len()).unwrap() {
+                    let edge: Vec<usize> = e.iter().map(|i| cell[*i]).collect();
+                    write_mesh_edge_to(buf, points, &edge);
+                }
+                write!(buf, "[pth.Path.CLOSEPOLY,(None,None)],\n").unwrap();
+            }
             write!(
-                &mut self.buffer,
-                "plt.axis([{},{},{},{}])\n",
-                xmin[0] - gap[0],
-                xmax[0] + gap[0],
-                xmin[1] - gap[1],
-                xmax[1] + gap[1]
+                buf,
+                "]\n\
+                 cmd,pts=zip(*dat)\n\
+                 h=pth.Path(pts,cmd)\n\
+                 p=pat.PathPatch(h{})\n\
+                 plt.gca().add_patch(p)\n",
+                style.options_mesh_cells(face_color)
             )
             .unwrap();
-        } else {
+        }
+        // boundary
+        if style.boundary_color != "" {
+            write!(buf, "dat=[\n").unwrap();
+            for edge in mesh_edges(ndim, cells, true) {
+                let a = &points[edge[0]];
+                write!(buf, "    [pth.Path.MOVETO,({},{})],", a[0], a[1]).unwrap();
+                write_mesh_edge_to(buf, points, &edge);
+                write!(buf, "\n").unwrap();
+            }
             write!(
-                &mut self.buffer,
-                "AX3D.set_xlim3d({},{})\n\
-                 AX3D.set_ylim3d({},{})\n\
-                 AX3D.set_zlim3d({},{})\n",
-                xmin[0] - gap[0],
-                xmax[0] + gap[0],
-                xmin[1] - gap[1],
-                xmax[1] + gap[1],
-                xmin[2] - gap[2],
-                xmax[2] + gap[2]
+                buf,
+                "]\n\
+                 cmd,pts=zip(*dat)\n\
+                 h=pth.Path(pts,cmd)\n\
+                 p=pat.PathPatch(h{})\n\
+                 plt.gca().add_patch(p)\n",
+                style.options_mesh_boundary()
             )
             .unwrap();
         }
+    } else {
+        write!(buf, "maybe_create_ax3d()\n").unwrap();
+        for edge in mesh_edges(ndim, cells, false) {
+            write_grid_line(buf, ndim, &points[edge[0]], &points[edge[1]], style);
+        }
+        if style.boundary_color != "" {
+            let mut boundary_style = style.clone();
+            boundary_style.edge_color = style.boundary_color.clone();
+            boundary_style.line_width = style.boundary_line_width;
+            for edge in mesh_edges(ndim, cells, true) {
+                write_grid_line(buf, ndim, &points[edge[0]], &points[edge[1]], &boundary_style);
+            }
+        }
+    }
+
+    // ids
+    if with_point_ids {
+        for (i, a) in points.iter().enumerate() {
+            write_grid_text(buf, ndim, a, &format!("{}", i), &style.text);
+        }
+    }
+    if with_cell_ids {
+        for (c, cell) in cells.iter().enumerate() {
+            let ncorner = if ndim == 2 && (cell.len() == 6 || cell.len() == 8) {
+                cell.len() / 2
+            } else {
+                cell.len()
+            };
+            let mut center = [0.0; 3];
+            for p in &cell[..ncorner] {
+                for j in 0..3 {
+                    center[j] += points[*p][j] / (ncorner as f64);
+                }
+            }
+            write_grid_text(buf, ndim, &center, &format!("{}", c), &style.alt_text);
+        }
+    }
+
+    // limits
+    let mut xmin = vec![f64::MAX; ndim];
+    let mut xmax = vec![f64::MIN; ndim];
+    for a in points {
+        for j in 0..ndim {
+            xmin[j] = f64::min(xmin[j], a[j]);
+            xmax[j] = f64::max(xmax[j], a[j]);
+        }
+    }
+    if points.len() > 0 {
+        write_grid_limits(buf, &grid_limits(&xmin, &xmax));
+    }
+}
+
+/// Writes the path commands from the first corner of a 2D mesh edge to the second corner
+///
+/// The edge is a straight line if there are two points or a quadratic Bezier curve passing
+/// through the middle point (third point) otherwise.
+fn write_mesh_edge_to(buf: &mut String, points: &[[f64; 3]], edge: &[usize]) {
+    let b = &points[edge[1]];
+    if edge.len() == 3 {
+        let a = &points[edge[0]];
+        let m = &points[edge[2]];
+        let cx = 2.0 * m[0] - (a[0] + b[0]) / 2.0;
+        let cy = 2.0 * m[1] - (a[1] + b[1]) / 2.0;
+        write!(
+            buf,
+            "[pth.Path.CURVE3,({},{})],[pth.Path.CURVE3,({},{})],",
+            cx, cy, b[0], b[1]
+        )
+        .unwrap();
+    } else {
+        write!(buf, "[pth.Path.LINETO,({},{})],", b[0], b[1]).unwrap();
+    }
+}
+
+/// Writes a 2D or 3D line of a grid
+fn write_grid_line(buf: &mut String, ndim: usize, a: &[f64; 3], b: &[f64; 3], style: &CanvasStyle) {
+    if ndim == 2 {
+        write!(
+            buf,
+            "    [pth.Path.MOVETO,({},{})],[pth.Path.LINETO,({},{})],\n",
+            a[0], a[1], b[0], b[1]
+        )
+        .unwrap();
+    } else {
+        write!(
+            buf,
+            "AX3D.plot([{},{}],[{},{}],[{},{}]{})\n",
+            a[0],
+            b[0],
+            a[1],
+            b[1],
+            a[2],
+            b[2],
+            style.options_line_3d(),
+        )
+        .unwrap();
+    }
+}
+
+/// Writes a 2D or 3D text of a grid
+fn write_grid_text(buf: &mut String, ndim: usize, a: &[f64; 3], txt: &str, style: &TextStyle) {
+    let opt = style.options();
+    if ndim == 2 {
+        write!(buf, "plt.text({},{},'{}'{})\n", a[0], a[1], txt, &opt).unwrap();
+    } else {
+        write!(buf, "AX3D.text({},{},{},'{}'{})\n", a[0], a[1], a[2], txt, &opt).unwrap();
+    }
+}
+
+/// Writes the 2D or 3D limits of a grid
+fn write_grid_limits(buf: &mut String, limits: &[(f64, f64)]) {
+    if limits.len() == 2 {
+        write!(
+            buf,
+            "plt.axis([{},{},{},{}])\n",
+            limits[0].0, limits[0].1, limits[1].0, limits[1].1
+        )
+        .unwrap();
+    } else {
+        write!(
+            buf,
+            "AX3D.set_xlim3d({},{})\n\
+             AX3D.set_ylim3d({},{})\n\
+             AX3D.set_zlim3d({},{})\n",
+            limits[0].0, limits[0].1, limits[1].0, limits[1].1, limits[2].0, limits[2].1
+        )
+        .unwrap();
     }
 }
 
@@ -866,6 +1365,10 @@ impl GraphMaker for Canvas {
     }
     fn clear_buffer(&mut self) {
         self.buffer.clear();
+        self.primitives.clear();
+    }
+    fn get_primitives(&self) -> &[Primitive] {
+        &self.primitives
     }
 }
 
@@ -873,8 +1376,10 @@ impl GraphMaker for Canvas {
 
 #[cfg(test)]
 mod tests {
-    use super::Canvas;
-    use crate::{GraphMaker, PolyCode};
+    use super::{
+        mesh_cell_edges, mesh_edges, write_grid_limits, write_grid_line, write_grid_text, Canvas, CanvasStyle,
+    };
+    use crate::{Error, GraphMaker, PolyCode, Primitive};
 
     #[test]
     fn derive_works() {
@@ -888,17 +1393,21 @@ mod tests {
     #[test]
     fn new_works() {
         let canvas = Canvas::new();
-        assert_eq!(canvas.edge_color.len(), 7);
-        assert_eq!(canvas.face_color.len(), 0);
-        assert_eq!(canvas.line_width, 0.0);
-        assert_eq!(canvas.arrow_scale, 0.0);
-        assert_eq!(canvas.arrow_style.len(), 0);
-        assert_eq!(canvas.text_color.len(), 7);
-        assert_eq!(canvas.text_align_horizontal.len(), 0);
-        assert_eq!(canvas.text_align_vertical.len(), 0);
-        assert_eq!(canvas.text_fontsize, 8.0);
-        assert_eq!(canvas.text_rotation, 45.0);
+        assert_eq!(canvas.style.edge_color.len(), 7);
+        assert_eq!(canvas.style.face_color.len(), 0);
+        assert_eq!(canvas.style.line_width, 0.0);
+        assert_eq!(canvas.style.arrow_scale, 0.0);
+        assert_eq!(canvas.style.arrow_style.len(), 0);
+        assert_eq!(canvas.style.cell_colors.len(), 0);
+        assert_eq!(canvas.style.boundary_color.len(), 0);
+        assert_eq!(canvas.style.boundary_line_width, 2.0);
+        assert_eq!(canvas.style.text.color.len(), 7);
+        assert_eq!(canvas.style.text.align_horizontal.len(), 0);
+        assert_eq!(canvas.style.text.align_vertical.len(), 0);
+        assert_eq!(canvas.style.text.fontsize, 8.0);
+        assert_eq!(canvas.style.text.rotation, 45.0);
         assert_eq!(canvas.buffer.len(), 0);
+        assert_eq!(canvas.primitives.len(), 0);
     }
 
     #[test]
@@ -909,7 +1418,7 @@ mod tests {
             .set_face_color("blue")
             .set_line_width(2.5)
             .set_stop_clip(true);
-        let opt = canvas.options_shared();
+        let opt = canvas.style.options_shared();
         assert_eq!(
             opt,
             ",edgecolor='red'\
@@ -923,7 +1432,7 @@ mod tests {
     fn options_arrow_works() {
         let mut canvas = Canvas::new();
         canvas.set_arrow_scale(25.0).set_arrow_style("fancy");
-        let opt = canvas.options_arrow();
+        let opt = canvas.style.options_arrow();
         assert_eq!(
             opt,
             ",mutation_scale=25\
@@ -940,7 +1449,7 @@ mod tests {
             .set_text_align_vertical("center")
             .set_text_fontsize(8.0)
             .set_text_rotation(45.0);
-        let opt = canvas.options_text();
+        let opt = canvas.style.text.options();
         assert_eq!(
             opt,
             ",color='red'\
@@ -960,7 +1469,7 @@ mod tests {
             .set_alt_text_align_vertical("bottom")
             .set_alt_text_fontsize(10.0)
             .set_alt_text_rotation(30.0);
-        let opt = canvas.options_alt_text();
+        let opt = canvas.style.alt_text.options();
         assert_eq!(
             opt,
             ",color='blue'\
@@ -975,39 +1484,39 @@ mod tests {
     fn options_line_3d_works() {
         let mut canvas = Canvas::new();
         canvas.set_edge_color("red");
-        let opt = canvas.options_line_3d();
+        let opt = canvas.style.options_line_3d();
         assert_eq!(opt, ",color='red'");
 
         let mut canvas = Canvas::new();
         canvas.set_edge_color("red").set_line_width(5.0);
-        let opt = canvas.options_line_3d();
+        let opt = canvas.style.options_line_3d();
         assert_eq!(opt, ",color='red',linewidth=5");
     }
 
     #[test]
     fn line_works() {
-        let mut canvas = Canvas::new();
+        let mut buffer = String::new();
+        let style = CanvasStyle::default();
         let a = [0.0; 3];
         let b = [0.0; 3];
-        canvas.line(2, &a, &b);
-        canvas.line(3, &a, &b);
+        write_grid_line(&mut buffer, 2, &a, &b, &style);
+        write_grid_line(&mut buffer, 3, &a, &b, &style);
         assert_eq!(
-            canvas.buffer,
+            buffer,
             "\x20\x20\x20\x20[pth.Path.MOVETO,(0,0)],[pth.Path.LINETO,(0,0)],\n\
              AX3D.plot([0,0],[0,0],[0,0],color='#427ce5')\n"
         );
-        canvas.clear_buffer();
-        assert_eq!(canvas.buffer, "");
     }
 
     #[test]
     fn text_works() {
-        let mut canvas = Canvas::new();
+        let mut buffer = String::new();
+        let style = CanvasStyle::default();
         let a = [0.0; 3];
-        canvas.text(2, &a, "hello", false);
-        canvas.text(3, &a, "hello", true);
+        write_grid_text(&mut buffer, 2, &a, "hello", &style.text);
+        write_grid_text(&mut buffer, 3, &a, "hello", &style.alt_text);
         assert_eq!(
-            canvas.buffer,
+            buffer,
             "plt.text(0,0,'hello',color='#a81414',fontsize=8,rotation=45)\n\
              AX3D.text(0,0,0,'hello',color='#343434',ha='center',va='center',fontsize=10)\n"
         );
@@ -1015,13 +1524,11 @@ mod tests {
 
     #[test]
     fn limits_works() {
-        let mut canvas = Canvas::new();
-        let xmin = [0.0; 3];
-        let xmax = [0.0; 3];
-        canvas.limits(2, &xmin, &xmax);
-        canvas.limits(3, &xmin, &xmax);
+        let mut buffer = String::new();
+        write_grid_limits(&mut buffer, &[(0.0, 0.0); 2]);
+        write_grid_limits(&mut buffer, &[(0.0, 0.0); 3]);
         assert_eq!(
-            canvas.buffer,
+            buffer,
             "plt.axis([0,0,0,0])\n\
             AX3D.set_xlim3d(0,0)\n\
             AX3D.set_ylim3d(0,0)\n\
@@ -1062,21 +1569,11 @@ mod tests {
         // note the following sequence of codes won't work in Matplotlib because Curve3 and Curve4 are wrong
         let mut canvas = Canvas::new();
         canvas.polycurve_begin();
-        assert_eq!(canvas.buffer, "dat=[");
         canvas.polycurve_add(0, 0, PolyCode::MoveTo);
-        assert_eq!(canvas.buffer, "dat=[[pth.Path.MOVETO,(0,0)],");
         canvas.polycurve_add(1, 0, PolyCode::LineTo);
-        assert_eq!(canvas.buffer, "dat=[[pth.Path.MOVETO,(0,0)],[pth.Path.LINETO,(1,0)],");
         canvas.polycurve_add(2, 0, PolyCode::Curve3);
-        assert_eq!(
-            canvas.buffer,
-            "dat=[[pth.Path.MOVETO,(0,0)],[pth.Path.LINETO,(1,0)],[pth.Path.CURVE3,(2,0)],"
-        );
         canvas.polycurve_add(3, 0, PolyCode::Curve4);
-        assert_eq!(
-            canvas.buffer,
-            "dat=[[pth.Path.MOVETO,(0,0)],[pth.Path.LINETO,(1,0)],[pth.Path.CURVE3,(2,0)],[pth.Path.CURVE4,(3,0)],"
-        );
+        assert_eq!(canvas.buffer, "");
         canvas.polycurve_end(true);
         assert_eq!(
             canvas.buffer,
@@ -1086,6 +1583,18 @@ mod tests {
             p=pat.PathPatch(h,edgecolor='#427ce5')\n\
             plt.gca().add_patch(p)\n"
         );
+        assert_eq!(
+            canvas.get_primitives(),
+            &[Primitive::Path {
+                points: vec![[0.0, 0.0], [1.0, 0.0], [2.0, 0.0], [3.0, 0.0]],
+                codes: vec![PolyCode::MoveTo, PolyCode::LineTo, PolyCode::Curve3, PolyCode::Curve4],
+                closed: true,
+                style: CanvasStyle::default(),
+            }]
+        );
+        canvas.clear_buffer();
+        assert_eq!(canvas.buffer, "");
+        assert_eq!(canvas.primitives.len(), 0);
     }
 
     #[test]
@@ -1093,7 +1602,10 @@ mod tests {
         let mut canvas = Canvas::new();
         assert_eq!(
             canvas.draw_polycurve(&[[0, 0]], &[PolyCode::MoveTo], true).err(),
-            Some("npoint must be ≥ 3")
+            Some(Error::InvalidInput {
+                argument: "points",
+                message: "npoint must be ≥ 3"
+            })
         );
         assert_eq!(
             canvas
@@ -1103,13 +1615,19 @@ mod tests {
                     true
                 )
                 .err(),
-            Some("ndim must be equal to 2")
+            Some(Error::InvalidInput {
+                argument: "points",
+                message: "ndim must be equal to 2"
+            })
         );
         assert_eq!(
             canvas
                 .draw_polycurve(&[[0, 0], [0, 0], [0, 0]], &[PolyCode::MoveTo], true)
                 .err(),
-            Some("codes.len() must be equal to npoint")
+            Some(Error::InvalidInput {
+                argument: "codes",
+                message: "codes.len() must be equal to npoint"
+            })
         );
     }
 
@@ -1200,13 +1718,37 @@ mod tests {
     fn grid_fails_on_wrong_input() {
         let mut canvas = Canvas::new();
         let res = canvas.draw_grid(&[0.0, 0.0], &[1.0, 1.0], &[1], true, false);
-        assert_eq!(res, Err("len(ndiv) == ndim must be 2 or 3"));
+        assert_eq!(
+            res,
+            Err(Error::InvalidInput {
+                argument: "ndiv",
+                message: "len(ndiv) == ndim must be 2 or 3"
+            })
+        );
         let res = canvas.draw_grid(&[0.0], &[1.0, 1.0], &[1, 1], true, false);
-        assert_eq!(res, Err("size of xmin must equal ndim == len(ndiv)"));
+        assert_eq!(
+            res,
+            Err(Error::InvalidInput {
+                argument: "xmin",
+                message: "size of xmin must equal ndim == len(ndiv)"
+            })
+        );
         let res = canvas.draw_grid(&[0.0, 0.0], &[1.0], &[1, 1], true, false);
-        assert_eq!(res, Err("size of xmax must equal ndim == len(ndiv)"));
+        assert_eq!(
+            res,
+            Err(Error::InvalidInput {
+                argument: "xmax",
+                message: "size of xmax must equal ndim == len(ndiv)"
+            })
+        );
         let res = canvas.draw_grid(&[0.0, 0.0], &[0.0, 1.0], &[1, 1], true, false);
-        assert_eq!(res, Err("xmax must be greater than xmin"));
+        assert_eq!(
+            res,
+            Err(Error::InvalidInput {
+                argument: "xmax",
+                message: "xmax must be greater than xmin"
+            })
+        );
     }
 
     #[test]
@@ -1285,4 +1827,171 @@ mod tests {
                        AX3D.set_zlim3d(-0.1,1.1)\n";
         assert_eq!(canvas.buffer, b);
     }
+
+    #[test]
+    fn options_mesh_works() {
+        let mut canvas = Canvas::new();
+        canvas.set_line_width(1.5).set_stop_clip(true);
+        assert_eq!(
+            canvas.style.options_mesh_cells(""),
+            ",edgecolor='#427ce5',facecolor='none',linewidth=1.5,clip_on=False"
+        );
+        assert_eq!(
+            canvas.style.options_mesh_cells("C1"),
+            ",edgecolor='#427ce5',facecolor='C1',linewidth=1.5,clip_on=False"
+        );
+        canvas.set_boundary_color("black").set_boundary_line_width(3.0);
+        assert_eq!(
+            canvas.style.options_mesh_boundary(),
+            ",fill=False,edgecolor='black',linewidth=3,clip_on=False"
+        );
+    }
+
+    #[test]
+    fn mesh_edges_works() {
+        assert_eq!(mesh_cell_edges(2, 5), None);
+        assert_eq!(mesh_cell_edges(3, 6), None);
+        assert_eq!(mesh_cell_edges(2, 6).unwrap()[2], &[2, 0, 5]);
+        // two qua4 sharing the edge (1,4)
+        let cells = vec![vec![0, 1, 4, 3], vec![1, 2, 5, 4]];
+        assert_eq!(mesh_edges(2, &cells, false).len(), 7);
+        assert_eq!(
+            mesh_edges(2, &cells, true),
+            vec![vec![0, 1], vec![4, 3], vec![3, 0], vec![1, 2], vec![2, 5], vec![5, 4]]
+        );
+        // two tet4 sharing the face (1,2,3)
+        let cells = vec![vec![0, 1, 2, 3], vec![1, 2, 3, 4]];
+        assert_eq!(mesh_edges(3, &cells, false).len(), 9);
+        assert_eq!(mesh_edges(3, &cells, true).len(), 9);
+        // one hex8
+        let cells = vec![vec![0, 1, 2, 3, 4, 5, 6, 7]];
+        assert_eq!(mesh_edges(3, &cells, false).len(), 12);
+        assert_eq!(mesh_edges(3, &cells, true).len(), 12);
+    }
+
+    #[test]
+    fn mesh_fails_on_wrong_input() {
+        let mut canvas = Canvas::new();
+        let points = [[0.0], [1.0], [2.0]];
+        assert_eq!(
+            canvas.draw_mesh(&points, &[vec![0, 1, 2]], None, false, false),
+            Err(Error::InvalidInput {
+                argument: "points",
+                message: "ndim must be 2 or 3"
+            })
+        );
+        let points = [[0.0, 0.0], [1.0, 0.0], [0.0, 1.0]];
+        assert_eq!(
+            canvas.draw_mesh(&points, &[vec![0, 1]], None, false, false),
+            Err(Error::InvalidInput {
+                argument: "cells",
+                message: "cells must be tri3, tri6, qua4, or qua8 in 2D and tet4 or hex8 in 3D"
+            })
+        );
+        assert_eq!(
+            canvas.draw_mesh(&points, &[vec![0, 1, 3]], None, false, false),
+            Err(Error::InvalidInput {
+                argument: "cells",
+                message: "the indices of points must be smaller than npoint"
+            })
+        );
+        assert_eq!(
+            canvas.draw_mesh(&points, &[vec![0, 1, 2]], Some(&[0, 1]), false, false),
+            Err(Error::InvalidInput {
+                argument: "attributes",
+                message: "attributes.len() must be equal to cells.len()"
+            })
+        );
+        assert_eq!(canvas.primitives.len(), 0);
+    }
+
+    #[test]
+    fn mesh_2d_works() {
+        let mut canvas = Canvas::new();
+        canvas.set_cell_colors(&["#fff", "#eee"]).set_boundary_color("black");
+        let points = [[0.0, 0.0], [1.0, 0.0], [0.0, 1.0], [1.0, 1.0]];
+        let cells = vec![vec![0, 1, 2], vec![1, 3, 2]];
+        canvas.draw_mesh(&points, &cells, Some(&[1, 2]), true, true).unwrap();
+        let b: &str = "dat=[\n\
+                      \x20\x20\x20\x20[pth.Path.MOVETO,(0,0)],[pth.Path.LINETO,(1,0)],[pth.Path.LINETO,(0,1)],[pth.Path.LINETO,(0,0)],[pth.Path.CLOSEPOLY,(None,None)],\n\
+                      ]\n\
+                      cmd,pts=zip(*dat)\n\
+                      h=pth.Path(pts,cmd)\n\
+                      p=pat.PathPatch(h,edgecolor='#427ce5',facecolor='#eee')\n\
+                      plt.gca().add_patch(p)\n\
+                      dat=[\n\
+                      \x20\x20\x20\x20[pth.Path.MOVETO,(1,0)],[pth.Path.LINETO,(1,1)],[pth.Path.LINETO,(0,1)],[pth.Path.LINETO,(1,0)],[pth.Path.CLOSEPOLY,(None,None)],\n\
+                      ]\n\
+                      cmd,pts=zip(*dat)\n\
+                      h=pth.Path(pts,cmd)\n\
+                      p=pat.PathPatch(h,edgecolor='#427ce5',facecolor='#fff')\n\
+                      plt.gca().add_patch(p)\n\
+                      dat=[\n\
+                      \x20\x20\x20\x20[pth.Path.MOVETO,(0,0)],[pth.Path.LINETO,(1,0)],\n\
+                      \x20\x20\x20\x20[pth.Path.MOVETO,(0,1)],[pth.Path.LINETO,(0,0)],\n\
+                      \x20\x20\x20\x20[pth.Path.MOVETO,(1,0)],[pth.Path.LINETO,(1,1)],\n\
+                      \x20\x20\x20\x20[pth.Path.MOVETO,(1,1)],[pth.Path.LINETO,(0,1)],\n\
+                      ]\n\
+                      cmd,pts=zip(*dat)\n\
+                      h=pth.Path(pts,cmd)\n\
+                      p=pat.PathPatch(h,fill=False,edgecolor='black',linewidth=2)\n\
+                      plt.gca().add_patch(p)\n\
+                      plt.text(0,0,'0',color='#a81414',fontsize=8,rotation=45)\n\
+                      plt.text(1,0,'1',color='#a81414',fontsize=8,rotation=45)\n\
+                      plt.text(0,1,'2',color='#a81414',fontsize=8,rotation=45)\n\
+                      plt.text(1,1,'3',color='#a81414',fontsize=8,rotation=45)\n\
+                      plt.text(0.3333333333333333,0.3333333333333333,'0',color='#343434',ha='center',va='center',fontsize=10)\n\
+                      plt.text(0.6666666666666666,0.6666666666666666,'1',color='#343434',ha='center',va='center',fontsize=10)\n\
+                      plt.axis([-0.1,1.1,-0.1,1.1])\n";
+        assert_eq!(canvas.buffer, b);
+        assert_eq!(canvas.get_primitives().len(), 1);
+    }
+
+    #[test]
+    fn mesh_quadratic_works() {
+        let mut canvas = Canvas::new();
+        canvas.set_face_color("#ccc");
+        // tri6 with a curved edge between 1 and 2 (passing through 4)
+        let points = [[0.0, 0.0], [2.0, 0.0], [0.0, 2.0], [1.0, 0.0], [1.5, 1.5], [0.0, 1.0]];
+        canvas
+            .draw_mesh(&points, &[vec![0, 1, 2, 3, 4, 5]], None, false, false)
+            .unwrap();
+        let b: &str = "dat=[\n\
+                      \x20\x20\x20\x20[pth.Path.MOVETO,(0,0)],[pth.Path.CURVE3,(1,0)],[pth.Path.CURVE3,(2,0)],[pth.Path.CURVE3,(2,2)],[pth.Path.CURVE3,(0,2)],[pth.Path.CURVE3,(0,1)],[pth.Path.CURVE3,(0,0)],[pth.Path.CLOSEPOLY,(None,None)],\n\
+                      ]\n\
+                      cmd,pts=zip(*dat)\n\
+                      h=pth.Path(pts,cmd)\n\
+                      p=pat.PathPatch(h,edgecolor='#427ce5',facecolor='#ccc')\n\
+                      plt.gca().add_patch(p)\n\
+                      plt.axis([-0.2,2.2,-0.2,2.2])\n";
+        assert_eq!(canvas.buffer, b);
+    }
+
+    #[test]
+    fn mesh_3d_works() {
+        let mut canvas = Canvas::new();
+        canvas.set_boundary_color("red").set_boundary_line_width(0.0);
+        let points = [[0.0, 0.0, 0.0], [1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]];
+        canvas
+            .draw_mesh(&points, &[vec![0, 1, 2, 3]], None, false, true)
+            .unwrap();
+        let b: &str = "maybe_create_ax3d()\n\
+                       AX3D.plot([0,1],[0,0],[0,0],color='#427ce5')\n\
+                       AX3D.plot([1,0],[0,1],[0,0],color='#427ce5')\n\
+                       AX3D.plot([0,0],[1,0],[0,0],color='#427ce5')\n\
+                       AX3D.plot([0,0],[0,0],[0,1],color='#427ce5')\n\
+                       AX3D.plot([1,0],[0,0],[0,1],color='#427ce5')\n\
+                       AX3D.plot([0,0],[1,0],[0,1],color='#427ce5')\n\
+                       AX3D.plot([0,0],[0,1],[0,0],color='red')\n\
+                       AX3D.plot([0,1],[1,0],[0,0],color='red')\n\
+                       AX3D.plot([1,0],[0,0],[0,0],color='red')\n\
+                       AX3D.plot([1,0],[0,0],[0,1],color='red')\n\
+                       AX3D.plot([0,0],[0,0],[1,0],color='red')\n\
+                       AX3D.plot([0,0],[0,1],[1,0],color='red')\n\
+                       AX3D.text(0.25,0.25,0.25,'0',color='#343434',ha='center',va='center',fontsize=10)\n\
+                       AX3D.set_xlim3d(-0.1,1.1)\n\
+                       AX3D.set_ylim3d(-0.1,1.1)\n\
+                       AX3D.set_zlim3d(-0.1,1.1)\n";
+        assert_eq!(canvas.buffer, b);
+    }
 }
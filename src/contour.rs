@@ -1,4 +1,8 @@
-use super::{matrix_to_array, vector_to_array, vector_to_strings, AsMatrix, GraphMaker};
+use super::{
+    matrix_to_array, matrix_to_f64, matrix_to_list, vector_to_array, vector_to_f64, vector_to_strings, AsMatrix,
+    AsVector, Error, GraphMaker, Primitive, Script,
+};
+use num_traits::AsPrimitive;
 use std::fmt::Write;
 
 /// Generates a contour plot
@@ -6,10 +10,10 @@ use std::fmt::Write;
 /// # Example
 ///
 /// ```
-/// use plotpy::{Contour, Plot};
+/// use plotpy::{Contour, Error, Plot};
 /// use russell_lab::generate3d;
 ///
-/// fn main() -> Result<(), &'static str> {
+/// fn main() -> Result<(), Error> {
 ///     // generate (x,y,z) matrices
 ///     let n = 21;
 ///     let (x, y, z) = generate3d(-2.0, 2.0, -2.0, 2.0, n, n, |x, y| x * x - y * y);
@@ -39,58 +43,57 @@ use std::fmt::Write;
 ///
 /// ![doc_contour.svg](https://raw.githubusercontent.com/cpmech/plotpy/main/figures/doc_contour.svg)
 ///
+/// # Unstructured meshes
+///
+/// The values given at the nodes of a triangle mesh (e.g., finite element results) are drawn by
+/// [Contour::draw_triangles] (with `tricontourf` and `tricontour`) or by [Contour::draw_tripcolor]
+/// (with `tripcolor`). The same options for levels, colormap, and colorbar apply.
+///
 /// See also integration tests in the [tests directory](https://github.com/cpmech/plotpy/tree/main/tests)
 ///
 /// Output from some integration tests:
 ///
 /// ![integ_contour.svg](https://raw.githubusercontent.com/cpmech/plotpy/main/figures/integ_contour.svg)
 pub struct Contour {
-    colors: Vec<String>,         // Colors to be used instead of colormap
-    levels: Vec<f64>,            // Pre-defined levels
-    colormap_index: usize,       // Colormap index
-    colormap_name: String,       // Colormap name
-    no_lines: bool,              // Skip drawing a lines contour
-    no_labels: bool,             // Skip adding labels to the lines contour
-    no_inline_labels: bool,      // Do not draw labels inline
-    no_colorbar: bool,           // Skip drawing a colorbar
-    colorbar_label: String,      // Colorbar label
-    number_format_cb: String,    // Number format for the labels in lines contour
-    line_color: String,          // Line color for the lines contour
-    line_style: String,          // Line style for the lines contour
-    line_width: f64,             // Line width for the lines contour
-    fontsize_labels: f64,        // Font size for labels
-    with_selected: bool,         // Draw a line contour with a selected level
-    selected_level: f64,         // Selected level (e.g., 0.0)
-    selected_line_color: String, // Color to mark the selected level
-    selected_line_style: String, // Line style for the selected level
-    selected_line_width: f64,    // Line width for the selected level
-    buffer: String,              // buffer
+    style: ContourStyle,        // Style
+    buffer: String,             // buffer
+    primitives: Vec<Primitive>, // primitives
+}
+
+/// Holds the style of a contour (see [Contour])
+///
+/// The style is recorded by the [Primitive] drawn by [Contour] and may be modified afterwards.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ContourStyle {
+    pub colors: Vec<String>,         // Colors to be used instead of colormap
+    pub levels: Vec<f64>,            // Pre-defined levels
+    pub colormap_index: usize,       // Colormap index
+    pub colormap_name: String,       // Colormap name
+    pub no_lines: bool,              // Skip drawing a lines contour
+    pub no_labels: bool,             // Skip adding labels to the lines contour
+    pub no_inline_labels: bool,      // Do not draw labels inline
+    pub no_colorbar: bool,           // Skip drawing a colorbar
+    pub colorbar_label: String,      // Colorbar label
+    pub number_format_cb: String,    // Number format for the labels in lines contour
+    pub line_color: String,          // Line color for the lines contour
+    pub line_style: String,          // Line style for the lines contour
+    pub line_width: f64,             // Line width for the lines contour
+    pub fontsize_labels: f64,        // Font size for labels
+    pub with_selected: bool,         // Draw a line contour with a selected level
+    pub selected_level: f64,         // Selected level (e.g., 0.0)
+    pub selected_line_color: String, // Color to mark the selected level
+    pub selected_line_style: String, // Line style for the selected level
+    pub selected_line_width: f64,    // Line width for the selected level
+    pub flat_shading: bool,          // Uses flat instead of Gouraud shading (tripcolor)
 }
 
 impl Contour {
     /// Creates a new Contour object
     pub fn new() -> Self {
         Contour {
-            colors: Vec::new(),
-            levels: Vec::new(),
-            colormap_index: 0,
-            colormap_name: String::new(),
-            no_lines: false,
-            no_labels: false,
-            no_inline_labels: false,
-            no_colorbar: false,
-            colorbar_label: String::new(),
-            number_format_cb: String::new(),
-            line_color: "black".to_string(),
-            line_style: String::new(),
-            line_width: 0.0,
-            fontsize_labels: 0.0,
-            with_selected: false,
-            selected_level: 0.0,
-            selected_line_color: "yellow".to_string(),
-            selected_line_style: "-".to_string(),
-            selected_line_width: 2.0,
+            style: ContourStyle::default(),
             buffer: String::new(),
+            primitives: Vec::new(),
         }
     }
 
@@ -118,51 +121,101 @@ impl Contour {
     pub fn draw<'a, T, U>(&mut self, x: &'a T, y: &'a T, z: &'a T)
     where
         T: AsMatrix<'a, U>,
-        U: 'a + std::fmt::Display,
+        U: 'a + AsPrimitive<f64>,
     {
-        matrix_to_array(&mut self.buffer, "x", x);
-        matrix_to_array(&mut self.buffer, "y", y);
-        matrix_to_array(&mut self.buffer, "z", z);
-        if self.colors.len() > 0 {
-            vector_to_strings(&mut self.buffer, "colors", &self.colors);
-        }
-        if self.levels.len() > 0 {
-            vector_to_array(&mut self.buffer, "levels", &self.levels);
-        }
-        let opt = self.options_filled();
-        write!(&mut self.buffer, "cf=plt.contourf(x,y,z{})\n", &opt).unwrap();
-        if !self.no_lines {
-            let opt_line = self.options_line();
-            write!(&mut self.buffer, "cl=plt.contour(x,y,z{})\n", &opt_line).unwrap();
-            if !self.no_labels {
-                let opt_label = self.options_label();
-                write!(&mut self.buffer, "plt.clabel(cl{})\n", &opt_label).unwrap();
-            }
-        }
-        if !self.no_colorbar {
-            let opt_colorbar = self.options_colorbar();
-            write!(&mut self.buffer, "cb=plt.colorbar(cf{})\n", &opt_colorbar).unwrap();
-            if self.colorbar_label != "" {
-                write!(&mut self.buffer, "cb.ax.set_ylabel(r'{}')\n", self.colorbar_label).unwrap();
-            }
-        }
-        if self.with_selected {
-            let opt_selected = self.options_selected();
-            write!(&mut self.buffer, "plt.contour(x,y,z{})\n", &opt_selected).unwrap();
-        }
+        let (x, y, z) = (matrix_to_f64(x), matrix_to_f64(y), matrix_to_f64(z));
+        let style = self.style.clone();
+        self.push(Primitive::Contour { x, y, z, style });
+    }
+
+    /// Draws a fancy contour on an unstructured triangle mesh
+    ///
+    /// The filled contour, lines contour, labels, colorbar, and selected level are drawn as in [Contour::draw].
+    ///
+    /// # Input
+    ///
+    /// * `x` -- x-coordinates of the nodes
+    /// * `y` -- y-coordinates of the nodes
+    /// * `z` -- values at the nodes
+    /// * `triangles` -- the (0-based) indices of the three nodes of each triangle
+    ///
+    /// # Notes
+    ///
+    /// * The type `U` of the input arrays must be a number.
+    /// * Quadratic triangles (tri6) may be drawn by passing their three corner nodes.
+    pub fn draw_triangles<'a, T, U>(
+        &mut self,
+        x: &'a T,
+        y: &'a T,
+        z: &'a T,
+        triangles: &[[usize; 3]],
+    ) -> Result<(), Error>
+    where
+        T: AsVector<'a, U>,
+        U: 'a + AsPrimitive<f64>,
+    {
+        let (x, y, z) = (vector_to_f64(x), vector_to_f64(y), vector_to_f64(z));
+        let triangles = triangles_to_vec(&x, &y, &z, triangles)?;
+        let style = self.style.clone();
+        self.push(Primitive::TriContour {
+            x,
+            y,
+            z,
+            triangles,
+            style,
+        });
+        Ok(())
+    }
+
+    /// Draws the values at the nodes of an unstructured triangle mesh as smoothly colored triangles
+    ///
+    /// The colormap and colorbar options apply (the levels and lines do not).
+    ///
+    /// # Input
+    ///
+    /// * `x` -- x-coordinates of the nodes
+    /// * `y` -- y-coordinates of the nodes
+    /// * `z` -- values at the nodes
+    /// * `triangles` -- the (0-based) indices of the three nodes of each triangle
+    ///
+    /// # Notes
+    ///
+    /// * The type `U` of the input arrays must be a number.
+    pub fn draw_tripcolor<'a, T, U>(
+        &mut self,
+        x: &'a T,
+        y: &'a T,
+        z: &'a T,
+        triangles: &[[usize; 3]],
+    ) -> Result<(), Error>
+    where
+        T: AsVector<'a, U>,
+        U: 'a + AsPrimitive<f64>,
+    {
+        let (x, y, z) = (vector_to_f64(x), vector_to_f64(y), vector_to_f64(z));
+        let triangles = triangles_to_vec(&x, &y, &z, triangles)?;
+        let style = self.style.clone();
+        self.push(Primitive::TriColor {
+            x,
+            y,
+            z,
+            triangles,
+            style,
+        });
+        Ok(())
     }
 
     /// Sets the colors to be used instead of a pre-defined colormap
     ///
     /// Will use `colormap_index` instead if its empty.
     pub fn set_colors(&mut self, colors: &[&str]) -> &mut Self {
-        self.colors = colors.iter().map(|color| color.to_string()).collect();
+        self.style.colors = colors.iter().map(|color| color.to_string()).collect();
         self
     }
 
     /// Sets pre-defined levels, otherwise automatically calculate levels
     pub fn set_levels(&mut self, levels: &[f64]) -> &mut Self {
-        self.levels = levels.to_vec();
+        self.style.levels = levels.to_vec();
         self
     }
 
@@ -179,9 +232,9 @@ impl Contour {
     /// * 6 -- Greys
     /// * `>`6 -- starts over from 0
     pub fn set_colormap_index(&mut self, index: usize) -> &mut Self {
-        self.colors = Vec::new();
-        self.colormap_index = index;
-        self.colormap_name = String::new();
+        self.style.colors = Vec::new();
+        self.style.colormap_index = index;
+        self.style.colormap_name = String::new();
         self
     }
 
@@ -193,49 +246,49 @@ impl Contour {
     ///
     /// Will use `colormap_index` instead if `colormap_name` is empty.
     pub fn set_colormap_name(&mut self, name: &str) -> &mut Self {
-        self.colormap_name = String::from(name);
+        self.style.colormap_name = String::from(name);
         self
     }
 
     /// Sets option to skip drawing a lines contour on top of the filled contour
     pub fn set_no_lines(&mut self, flag: bool) -> &mut Self {
-        self.no_lines = flag;
+        self.style.no_lines = flag;
         self
     }
 
     /// Sets option to skip adding labels to the lines contour (if enabled)
     pub fn set_no_labels(&mut self, flag: bool) -> &mut Self {
-        self.no_labels = flag;
+        self.style.no_labels = flag;
         self
     }
 
     /// Sets option to skip drawing labels inline with the contour lines (if enabled)
     pub fn set_no_inline_labels(&mut self, flag: bool) -> &mut Self {
-        self.no_inline_labels = flag;
+        self.style.no_inline_labels = flag;
         self
     }
 
     /// Sets option to skip drawing a colorbar
     pub fn set_no_colorbar(&mut self, flag: bool) -> &mut Self {
-        self.no_colorbar = flag;
+        self.style.no_colorbar = flag;
         self
     }
 
     /// Sets the colorbar label
     pub fn set_colorbar_label(&mut self, label: &str) -> &mut Self {
-        self.colorbar_label = String::from(label);
+        self.style.colorbar_label = String::from(label);
         self
     }
 
     /// Sets the number format for the labels in the colorbar (cb)
     pub fn set_number_format_cb(&mut self, format: &str) -> &mut Self {
-        self.number_format_cb = String::from(format);
+        self.style.number_format_cb = String::from(format);
         self
     }
 
     /// Sets the line color for the lines contour (default is black)
     pub fn set_line_color(&mut self, color: &str) -> &mut Self {
-        self.line_color = String::from(color);
+        self.style.line_color = String::from(color);
         self
     }
 
@@ -245,19 +298,19 @@ impl Contour {
     ///
     /// * "`-`", "`:`", "`--`", "`-.`"
     pub fn set_line_style(&mut self, style: &str) -> &mut Self {
-        self.line_style = String::from(style);
+        self.style.line_style = String::from(style);
         self
     }
 
     /// Sets the line width for the lines contour
     pub fn set_line_width(&mut self, width: f64) -> &mut Self {
-        self.line_width = width;
+        self.style.line_width = width;
         self
     }
 
     /// Sets the font size for labels
     pub fn set_fontsize_labels(&mut self, fontsize: f64) -> &mut Self {
-        self.fontsize_labels = fontsize;
+        self.style.fontsize_labels = fontsize;
         self
     }
 
@@ -265,14 +318,14 @@ impl Contour {
     ///
     /// Will draw the selected level (e.g., 0.0) on top of everything
     pub fn set_selected_level(&mut self, level: f64, enabled: bool) -> &mut Self {
-        self.selected_level = level;
-        self.with_selected = enabled;
+        self.style.selected_level = level;
+        self.style.with_selected = enabled;
         self
     }
 
     /// Sets the color to mark the selected level
     pub fn set_selected_line_color(&mut self, color: &str) -> &mut Self {
-        self.selected_line_color = String::from(color);
+        self.style.selected_line_color = String::from(color);
         self
     }
 
@@ -282,18 +335,61 @@ impl Contour {
     ///
     /// * "`-`", "`:`", "`--`", "`-.`"
     pub fn set_selected_line_style(&mut self, style: &str) -> &mut Self {
-        self.selected_line_style = String::from(style);
+        self.style.selected_line_style = String::from(style);
         self
     }
 
     /// Sets the line width for the selected level
     pub fn set_selected_line_width(&mut self, width: f64) -> &mut Self {
-        self.selected_line_width = width;
+        self.style.selected_line_width = width;
+        self
+    }
+
+    /// Sets option to use flat instead of Gouraud shading (tripcolor)
+    ///
+    /// With flat shading, each triangle is colored by the average of the values at its nodes.
+    pub fn set_flat_shading(&mut self, flag: bool) -> &mut Self {
+        self.style.flat_shading = flag;
         self
     }
 
+    /// Writes the primitive to the buffer and records it
+    fn push(&mut self, primitive: Primitive) {
+        primitive.write_python(&mut self.buffer);
+        self.primitives.push(primitive);
+    }
+}
+
+impl Default for ContourStyle {
+    fn default() -> Self {
+        ContourStyle {
+            colors: Vec::new(),
+            levels: Vec::new(),
+            colormap_index: 0,
+            colormap_name: String::new(),
+            no_lines: false,
+            no_labels: false,
+            no_inline_labels: false,
+            no_colorbar: false,
+            colorbar_label: String::new(),
+            number_format_cb: String::new(),
+            line_color: "black".to_string(),
+            line_style: String::new(),
+            line_width: 0.0,
+            fontsize_labels: 0.0,
+            with_selected: false,
+            selected_level: 0.0,
+            selected_line_color: "yellow".to_string(),
+            selected_line_style: "-".to_string(),
+            selected_line_width: 2.0,
+            flat_shading: false,
+        }
+    }
+}
+
+impl ContourStyle {
     /// Returns options for filled contour
-    fn options_filled(&self) -> String {
+    pub(crate) fn options_filled(&self) -> String {
         let mut opt = String::new();
         if self.colors.len() > 0 {
             write!(&mut opt, ",colors=colors",).unwrap();
@@ -310,8 +406,24 @@ impl Contour {
         opt
     }
 
+    /// Returns options for colored triangles (tripcolor)
+    pub(crate) fn options_tripcolor(&self) -> String {
+        let mut opt = String::new();
+        if self.colormap_name != "" {
+            write!(&mut opt, ",cmap=plt.get_cmap('{}')", self.colormap_name).unwrap();
+        } else {
+            write!(&mut opt, ",cmap=get_colormap({})", self.colormap_index).unwrap();
+        }
+        if self.flat_shading {
+            write!(&mut opt, ",shading='flat'").unwrap();
+        } else {
+            write!(&mut opt, ",shading='gouraud'").unwrap();
+        }
+        opt
+    }
+
     /// Returns options for line contour
-    fn options_line(&self) -> String {
+    pub(crate) fn options_line(&self) -> String {
         let mut opt = String::new();
         if self.line_color != "" {
             write!(&mut opt, ",colors=['{}']", self.line_color).unwrap();
@@ -329,7 +441,7 @@ impl Contour {
     }
 
     /// Returns options for labels
-    fn options_label(&self) -> String {
+    pub(crate) fn options_label(&self) -> String {
         let mut opt = String::new();
         if self.no_inline_labels {
             write!(&mut opt, ",inline=False").unwrap();
@@ -343,7 +455,7 @@ impl Contour {
     }
 
     /// Returns options for colorbar
-    fn options_colorbar(&self) -> String {
+    pub(crate) fn options_colorbar(&self) -> String {
         let mut opt = String::new();
         if self.number_format_cb != "" {
             write!(&mut opt, ",format='{}'", self.number_format_cb).unwrap();
@@ -352,7 +464,7 @@ impl Contour {
     }
 
     /// Returns options for selected line contour
-    fn options_selected(&self) -> String {
+    pub(crate) fn options_selected(&self) -> String {
         let mut opt = String::new();
         if self.selected_line_color != "" {
             write!(&mut opt, ",colors=['{}']", self.selected_line_color).unwrap();
@@ -368,12 +480,138 @@ impl Contour {
     }
 }
 
+/// Checks the triangles of an unstructured mesh and converts them to a nested Vec
+pub(crate) fn triangles_to_vec(
+    x: &[f64],
+    y: &[f64],
+    z: &[f64],
+    triangles: &[[usize; 3]],
+) -> Result<Vec<Vec<usize>>, Error> {
+    if y.len() != x.len() {
+        return Err(Error::InvalidInput {
+            argument: "y",
+            message: "y.len() must be equal to x.len()",
+        });
+    }
+    if z.len() != x.len() {
+        return Err(Error::InvalidInput {
+            argument: "z",
+            message: "z.len() must be equal to x.len()",
+        });
+    }
+    if triangles.len() == 0 {
+        return Err(Error::InvalidInput {
+            argument: "triangles",
+            message: "there must be at least one triangle",
+        });
+    }
+    if triangles.iter().flatten().any(|p| *p >= x.len()) {
+        return Err(Error::InvalidInput {
+            argument: "triangles",
+            message: "the indices of nodes must be smaller than x.len()",
+        });
+    }
+    Ok(triangles.iter().map(|t| t.to_vec()).collect())
+}
+
+/// Writes the Python commands of a contour (see [Primitive::Contour])
+pub(crate) fn write_contour(
+    buf: &mut Script,
+    x: &Vec<Vec<f64>>,
+    y: &Vec<Vec<f64>>,
+    z: &Vec<Vec<f64>>,
+    style: &ContourStyle,
+) {
+    matrix_to_array(buf, "x", x);
+    matrix_to_array(buf, "y", y);
+    matrix_to_array(buf, "z", z);
+    write_contour_commands(buf, "", "x,y,z", style);
+}
+
+/// Writes the Python commands of a contour on a triangle mesh (see [Primitive::TriContour])
+pub(crate) fn write_tri_contour(
+    buf: &mut Script,
+    x: &[f64],
+    y: &[f64],
+    z: &[f64],
+    triangles: &Vec<Vec<usize>>,
+    style: &ContourStyle,
+) {
+    vector_to_array(buf, "x", &x);
+    vector_to_array(buf, "y", &y);
+    vector_to_array(buf, "z", &z);
+    matrix_to_list(buf, "triangles", triangles);
+    write_contour_commands(buf, "tri", "x,y,triangles,z", style);
+}
+
+/// Writes the Python commands of colored triangles (see [Primitive::TriColor])
+pub(crate) fn write_tri_color(
+    buf: &mut Script,
+    x: &[f64],
+    y: &[f64],
+    z: &[f64],
+    triangles: &Vec<Vec<usize>>,
+    style: &ContourStyle,
+) {
+    vector_to_array(buf, "x", &x);
+    vector_to_array(buf, "y", &y);
+    vector_to_array(buf, "z", &z);
+    matrix_to_list(buf, "triangles", triangles);
+    let opt = style.options_tripcolor();
+    write!(buf, "cf=plt.tripcolor(x,y,triangles,z{})\n", &opt).unwrap();
+    if !style.no_colorbar {
+        let opt_colorbar = style.options_colorbar();
+        write!(buf, "cb=plt.colorbar(cf{})\n", &opt_colorbar).unwrap();
+        if style.colorbar_label != "" {
+            write!(buf, "cb.ax.set_ylabel(r'{}')\n", style.colorbar_label).unwrap();
+        }
+    }
+}
+
+/// Writes the filled contour, lines contour, labels, colorbar, and selected level
+///
+/// The `prefix` selects the structured ("") or triangle mesh ("tri") functions and
+/// `args` holds the names of the (already written) arrays.
+fn write_contour_commands(buf: &mut Script, prefix: &str, args: &str, style: &ContourStyle) {
+    if style.colors.len() > 0 {
+        vector_to_strings(buf, "colors", &style.colors);
+    }
+    if style.levels.len() > 0 {
+        vector_to_array(buf, "levels", &style.levels);
+    }
+    let opt = style.options_filled();
+    write!(buf, "cf=plt.{}contourf({}{})\n", prefix, args, &opt).unwrap();
+    if !style.no_lines {
+        let opt_line = style.options_line();
+        write!(buf, "cl=plt.{}contour({}{})\n", prefix, args, &opt_line).unwrap();
+        if !style.no_labels {
+            let opt_label = style.options_label();
+            write!(buf, "plt.clabel(cl{})\n", &opt_label).unwrap();
+        }
+    }
+    if !style.no_colorbar {
+        let opt_colorbar = style.options_colorbar();
+        write!(buf, "cb=plt.colorbar(cf{})\n", &opt_colorbar).unwrap();
+        if style.colorbar_label != "" {
+            write!(buf, "cb.ax.set_ylabel(r'{}')\n", style.colorbar_label).unwrap();
+        }
+    }
+    if style.with_selected {
+        let opt_selected = style.options_selected();
+        write!(buf, "plt.{}contour({}{})\n", prefix, args, &opt_selected).unwrap();
+    }
+}
+
 impl GraphMaker for Contour {
     fn get_buffer<'a>(&'a self) -> &'a String {
         &self.buffer
     }
     fn clear_buffer(&mut self) {
         self.buffer.clear();
+        self.primitives.clear();
+    }
+    fn get_primitives(&self) -> &[Primitive] {
+        &self.primitives
     }
 }
 
@@ -382,32 +620,34 @@ impl GraphMaker for Contour {
 #[cfg(test)]
 mod tests {
     use super::Contour;
-    use crate::GraphMaker;
+    use crate::{Error, GraphMaker, Primitive};
     use russell_lab::Matrix;
 
     #[test]
     fn new_works() {
         let contour = Contour::new();
-        assert_eq!(contour.colors.len(), 0);
-        assert_eq!(contour.levels.len(), 0);
-        assert_eq!(contour.colormap_index, 0);
-        assert_eq!(contour.colormap_name.len(), 0);
-        assert_eq!(contour.no_lines, false);
-        assert_eq!(contour.no_labels, false);
-        assert_eq!(contour.no_inline_labels, false);
-        assert_eq!(contour.no_colorbar, false);
-        assert_eq!(contour.colorbar_label.len(), 0);
-        assert_eq!(contour.number_format_cb.len(), 0);
-        assert_eq!(contour.line_color, "black".to_string());
-        assert_eq!(contour.line_style.len(), 0);
-        assert_eq!(contour.line_width, 0.0);
-        assert_eq!(contour.fontsize_labels, 0.0);
-        assert_eq!(contour.with_selected, false);
-        assert_eq!(contour.selected_level, 0.0);
-        assert_eq!(contour.selected_line_color, "yellow".to_string());
-        assert_eq!(contour.selected_line_style, "-".to_string());
-        assert_eq!(contour.selected_line_width, 2.0);
+        assert_eq!(contour.style.colors.len(), 0);
+        assert_eq!(contour.style.levels.len(), 0);
+        assert_eq!(contour.style.colormap_index, 0);
+        assert_eq!(contour.style.colormap_name.len(), 0);
+        assert_eq!(contour.style.no_lines, false);
+        assert_eq!(contour.style.no_labels, false);
+        assert_eq!(contour.style.no_inline_labels, false);
+        assert_eq!(contour.style.no_colorbar, false);
+        assert_eq!(contour.style.colorbar_label.len(), 0);
+        assert_eq!(contour.style.number_format_cb.len(), 0);
+        assert_eq!(contour.style.line_color, "black".to_string());
+        assert_eq!(contour.style.line_style.len(), 0);
+        assert_eq!(contour.style.line_width, 0.0);
+        assert_eq!(contour.style.fontsize_labels, 0.0);
+        assert_eq!(contour.style.with_selected, false);
+        assert_eq!(contour.style.selected_level, 0.0);
+        assert_eq!(contour.style.selected_line_color, "yellow".to_string());
+        assert_eq!(contour.style.selected_line_style, "-".to_string());
+        assert_eq!(contour.style.selected_line_width, 2.0);
+        assert_eq!(contour.style.flat_shading, false);
         assert_eq!(contour.buffer.len(), 0);
+        assert_eq!(contour.primitives.len(), 0);
     }
 
     #[test]
@@ -416,14 +656,14 @@ mod tests {
         contour
             .set_colors(&vec!["#f00", "#0f0", "#00f"])
             .set_levels(&vec![0.25, 0.5, 1.0]);
-        let opt = contour.options_filled();
+        let opt = contour.style.options_filled();
         assert_eq!(
             opt,
             ",colors=colors\
              ,levels=levels"
         );
         contour.set_colormap_index(4);
-        let opt = contour.options_filled();
+        let opt = contour.style.options_filled();
         assert_eq!(
             opt,
             ",cmap=get_colormap(4)\
@@ -439,7 +679,7 @@ mod tests {
             .set_line_color("red")
             .set_line_style(":")
             .set_line_width(3.0);
-        let opt = contour.options_line();
+        let opt = contour.style.options_line();
         assert_eq!(
             opt,
             ",colors=['red']\
@@ -453,14 +693,14 @@ mod tests {
     fn options_label_works() {
         let mut contour = Contour::new();
         contour.set_no_inline_labels(false).set_fontsize_labels(5.0);
-        let opt = contour.options_label();
+        let opt = contour.style.options_label();
         assert_eq!(
             opt,
             ",inline=True\
              ,fontsize=5"
         );
         contour.set_no_inline_labels(true);
-        let opt = contour.options_label();
+        let opt = contour.style.options_label();
         assert_eq!(
             opt,
             ",inline=False\
@@ -472,7 +712,7 @@ mod tests {
     fn options_colorbar_works() {
         let mut contour = Contour::new();
         contour.set_number_format_cb("%.4f");
-        let opt = contour.options_colorbar();
+        let opt = contour.style.options_colorbar();
         assert_eq!(opt, ",format='%.4f'");
     }
 
@@ -484,7 +724,7 @@ mod tests {
             .set_selected_line_color("blue")
             .set_selected_line_style("--")
             .set_selected_line_width(2.5);
-        let opt = contour.options_selected();
+        let opt = contour.style.options_selected();
         assert_eq!(
             opt,
             ",colors=['blue']\
@@ -518,8 +758,107 @@ mod tests {
                        cb.ax.set_ylabel(r'temperature')\n\
                        plt.contour(x,y,z,colors=['yellow'],levels=[0],linestyles=['-'],linewidths=[2])\n";
         assert_eq!(contour.buffer, b);
+        assert_eq!(contour.get_primitives().len(), 1);
         contour.clear_buffer();
         assert_eq!(contour.buffer, "");
+        assert_eq!(contour.primitives.len(), 0);
+    }
+
+    #[test]
+    fn options_tripcolor_works() {
+        let mut contour = Contour::new();
+        contour.set_colormap_index(4);
+        assert_eq!(
+            contour.style.options_tripcolor(),
+            ",cmap=get_colormap(4),shading='gouraud'"
+        );
+        contour.set_colormap_name("turbo").set_flat_shading(true);
+        assert_eq!(
+            contour.style.options_tripcolor(),
+            ",cmap=plt.get_cmap('turbo'),shading='flat'"
+        );
+    }
+
+    #[test]
+    fn draw_triangles_works() {
+        let mut contour = Contour::new();
+        contour.set_no_labels(true).set_selected_level(0.5, true);
+        let x = [0.0, 1.0, 1.0, 0.0];
+        let y = [0.0, 0.0, 1.0, 1.0];
+        let z = [0.0, 1.0, 2.0, 1.0];
+        contour.draw_triangles(&x, &y, &z, &[[0, 1, 2], [0, 2, 3]]).unwrap();
+        let b: &str = "x=np.array([0,1,1,0,],dtype=float)\n\
+                       y=np.array([0,0,1,1,],dtype=float)\n\
+                       z=np.array([0,1,2,1,],dtype=float)\n\
+                       triangles=[[0,1,2,],[0,2,3,],]\n\
+                       cf=plt.tricontourf(x,y,triangles,z,cmap=get_colormap(0))\n\
+                       cl=plt.tricontour(x,y,triangles,z,colors=['black'])\n\
+                       cb=plt.colorbar(cf)\n\
+                       plt.tricontour(x,y,triangles,z,colors=['yellow'],levels=[0.5],linestyles=['-'],linewidths=[2])\n";
+        assert_eq!(contour.buffer, b);
+        assert_eq!(
+            contour.get_primitives(),
+            &[Primitive::TriContour {
+                x: x.to_vec(),
+                y: y.to_vec(),
+                z: z.to_vec(),
+                triangles: vec![vec![0, 1, 2], vec![0, 2, 3]],
+                style: contour.style.clone(),
+            }]
+        );
+    }
+
+    #[test]
+    fn draw_tripcolor_works() {
+        let mut contour = Contour::new();
+        contour.set_colorbar_label("u");
+        let x = vec![0.0, 1.0, 0.0];
+        let y = vec![0.0, 0.0, 1.0];
+        let z = vec![1.0, 2.0, 3.0];
+        contour.draw_tripcolor(&x, &y, &z, &[[0, 1, 2]]).unwrap();
+        let b: &str = "x=np.array([0,1,0,],dtype=float)\n\
+                       y=np.array([0,0,1,],dtype=float)\n\
+                       z=np.array([1,2,3,],dtype=float)\n\
+                       triangles=[[0,1,2,],]\n\
+                       cf=plt.tripcolor(x,y,triangles,z,cmap=get_colormap(0),shading='gouraud')\n\
+                       cb=plt.colorbar(cf)\n\
+                       cb.ax.set_ylabel(r'u')\n";
+        assert_eq!(contour.buffer, b);
+    }
+
+    #[test]
+    fn draw_triangles_captures_errors() {
+        let mut contour = Contour::new();
+        let x = vec![0.0, 1.0, 0.0];
+        assert_eq!(
+            contour.draw_triangles(&x, &vec![0.0, 0.0], &x, &[[0, 1, 2]]).err(),
+            Some(Error::InvalidInput {
+                argument: "y",
+                message: "y.len() must be equal to x.len()",
+            })
+        );
+        assert_eq!(
+            contour.draw_tripcolor(&x, &x, &vec![0.0], &[[0, 1, 2]]).err(),
+            Some(Error::InvalidInput {
+                argument: "z",
+                message: "z.len() must be equal to x.len()",
+            })
+        );
+        assert_eq!(
+            contour.draw_triangles(&x, &x, &x, &[]).err(),
+            Some(Error::InvalidInput {
+                argument: "triangles",
+                message: "there must be at least one triangle",
+            })
+        );
+        assert_eq!(
+            contour.draw_triangles(&x, &x, &x, &[[0, 1, 3]]).err(),
+            Some(Error::InvalidInput {
+                argument: "triangles",
+                message: "the indices of nodes must be smaller than x.len()",
+            })
+        );
+        assert_eq!(contour.primitives.len(), 0);
     }
 
     #[test]
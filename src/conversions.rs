@@ -1,4 +1,5 @@
-use super::{AsMatrix, AsVector};
+use super::{AsMatrix, AsVector, Script};
+use num_traits::AsPrimitive;
 use std::fmt::Write;
 
 /// Converts vector to a Python list of numbers
@@ -26,13 +27,18 @@ where
 }
 
 /// Converts vector to a 1D NumPy array
-pub(crate) fn vector_to_array<'a, T, U>(buf: &mut String, name: &str, vector: &'a T)
+///
+/// The array is loaded from a .npy file instead if it is large enough (see [Script::write_npy_array]).
+pub(crate) fn vector_to_array<'a, T, U>(buf: &mut Script, name: &str, vector: &'a T)
 where
     T: AsVector<'a, U>,
-    U: 'a + std::fmt::Display,
+    U: 'a + std::fmt::Display + AsPrimitive<f64>,
 {
-    write!(buf, "{}=np.array([", name).unwrap();
     let m = vector.vec_size();
+    if buf.write_npy_array(name, &[m], || vector_to_f64(vector)) {
+        return;
+    }
+    write!(buf, "{}=np.array([", name).unwrap();
     for i in 0..m {
         write!(buf, "{},", vector.vec_at(i)).unwrap();
     }
@@ -56,13 +62,18 @@ where
 }
 
 /// Converts a matrix to a 2D NumPy array
-pub(crate) fn matrix_to_array<'a, T, U>(buf: &mut String, name: &str, matrix: &'a T)
+///
+/// The array is loaded from a .npy file instead if it is large enough (see [Script::write_npy_array]).
+pub(crate) fn matrix_to_array<'a, T, U>(buf: &mut Script, name: &str, matrix: &'a T)
 where
     T: AsMatrix<'a, U>,
-    U: 'a + std::fmt::Display,
+    U: 'a + std::fmt::Display + AsPrimitive<f64>,
 {
-    write!(buf, "{}=np.array([", name).unwrap();
     let (m, n) = matrix.size();
+    if buf.write_npy_array(name, &[m, n], || matrix_to_f64(matrix).concat()) {
+        return;
+    }
+    write!(buf, "{}=np.array([", name).unwrap();
     for i in 0..m {
         write!(buf, "[").unwrap();
         for j in 0..n {
@@ -73,11 +84,44 @@ where
     write!(buf, "],dtype=float)\n").unwrap();
 }
 
+/// Converts a number to f64 (as the `as` operator does)
+pub(crate) fn to_f64<T>(value: T) -> f64
+where
+    T: AsPrimitive<f64>,
+{
+    value.as_()
+}
+
+/// Converts vector to a Vec of f64
+pub(crate) fn vector_to_f64<'a, T, U>(vector: &'a T) -> Vec<f64>
+where
+    T: AsVector<'a, U>,
+    U: 'a + AsPrimitive<f64>,
+{
+    (0..vector.vec_size()).map(|i| to_f64(vector.vec_at(i))).collect()
+}
+
+/// Converts matrix to a nested Vec of f64
+pub(crate) fn matrix_to_f64<'a, T, U>(matrix: &'a T) -> Vec<Vec<f64>>
+where
+    T: AsMatrix<'a, U>,
+    U: 'a + AsPrimitive<f64>,
+{
+    let (m, n) = matrix.size();
+    (0..m)
+        .map(|i| (0..n).map(|j| to_f64(matrix.at(i, j))).collect())
+        .collect()
+}
+
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
 #[cfg(test)]
 mod tests {
-    use super::{matrix_to_array, matrix_to_list, vector_to_array, vector_to_numbers, vector_to_strings};
+    use super::{
+        matrix_to_array, matrix_to_f64, matrix_to_list, to_f64, vector_to_array, vector_to_f64, vector_to_numbers,
+        vector_to_strings,
+    };
+    use crate::Script;
 
     #[test]
     fn vector_to_numbers_works() {
@@ -119,9 +163,10 @@ mod tests {
         let x: Vec<f64> = vec![0.1, 0.2, 0.3];
         let y: [f64; 3] = [1.0, 2.0, 3.0];
         let z: &[f64] = &[10.0, 20.0, 30.0];
-        vector_to_array(&mut buf, "x", &x);
-        vector_to_array(&mut buf, "y", &y);
-        vector_to_array(&mut buf, "z", &z);
+        let mut script = Script::new(&mut buf, None);
+        vector_to_array(&mut script, "x", &x);
+        vector_to_array(&mut script, "y", &y);
+        vector_to_array(&mut script, "z", &z);
         assert_eq!(
             buf,
             "x=np.array([0.1,0.2,0.3,],dtype=float)\n\
@@ -144,9 +189,10 @@ mod tests {
         let a: Vec<Vec<f64>> = vec![vec![1.0, 2.0, 3.0], vec![4.0, 5.0, 6.0], vec![7.0, 8.0, 9.0]];
         let b: [[f64; 3]; 3] = [[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]];
         let c: &[&[f64]] = &[&[1.0, 2.0, 3.0], &[4.0, 5.0, 6.0], &[7.0, 8.0, 9.0]];
-        matrix_to_array(&mut buf, "a", &a);
-        matrix_to_array(&mut buf, "b", &b);
-        matrix_to_array(&mut buf, "c", &c);
+        let mut script = Script::new(&mut buf, None);
+        matrix_to_array(&mut script, "a", &a);
+        matrix_to_array(&mut script, "b", &b);
+        matrix_to_array(&mut script, "c", &c);
         assert_eq!(
             buf,
             "a=np.array([[1,2,3,],[4,5,6,],[7,8,9,],],dtype=float)\n\
@@ -154,4 +200,21 @@ mod tests {
              c=np.array([[1,2,3,],[4,5,6,],[7,8,9,],],dtype=float)\n"
         );
     }
+
+    #[test]
+    fn to_f64_works() {
+        assert_eq!(to_f64(1), 1.0);
+        assert_eq!(to_f64(0.5_f32), 0.5);
+        assert_eq!(to_f64(-2.5), -2.5);
+        assert_eq!(to_f64(9007199254740993_u64), 9007199254740992.0);
+        assert_eq!(to_f64(usize::MAX), 18446744073709551615.0);
+    }
+
+    #[test]
+    fn vector_and_matrix_to_f64_work() {
+        let x: &[i32] = &[1, 2, 3];
+        assert_eq!(vector_to_f64(&x), &[1.0, 2.0, 3.0]);
+        let a = [[1, 2], [3, 4]];
+        assert_eq!(matrix_to_f64(&a), &[[1.0, 2.0], [3.0, 4.0]]);
+    }
 }
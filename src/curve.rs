@@ -1,8 +1,9 @@
-use super::{vector_to_array, AsVector, GraphMaker};
+use super::{matrix_to_array, to_f64, vector_to_array, vector_to_f64, AsVector, Error, GraphMaker, Primitive, Script};
+use num_traits::AsPrimitive;
 use std::fmt::Write;
 
 /// Holds either the second point coordinates of a ray or the slope of the ray
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq)]
 pub enum RayEndpoint {
     /// Coordinates of the second point
     Coords(f64, f64),
@@ -29,10 +30,10 @@ pub enum RayEndpoint {
 /// ## Using methods to set the points
 ///
 /// ```
-/// use plotpy::{Curve, Plot, StrError};
+/// use plotpy::{Curve, Error, Plot};
 /// use std::f64::consts::PI;
 ///
-/// fn main() -> Result<(), StrError> {
+/// fn main() -> Result<(), Error> {
 ///     // configure curve
 ///     let mut curve = Curve::new();
 ///     curve.set_line_width(2.0);
@@ -66,10 +67,10 @@ pub enum RayEndpoint {
 /// ## Using Vector with point data
 ///
 /// ```
-/// use plotpy::{Curve, Plot, StrError};
+/// use plotpy::{Curve, Error, Plot};
 /// use russell_lab::Vector;
 ///
-/// fn main() -> Result<(), StrError> {
+/// fn main() -> Result<(), Error> {
 ///     // generate (x,y) points
 ///     let x = Vector::linspace(-1.0, 1.0, 21)?;
 ///     let y = x.get_mapped(|v| 1.0 / (1.0 + f64::exp(-5.0 * v)));
@@ -112,40 +113,50 @@ pub enum RayEndpoint {
 ///
 /// ![integ_curve_3d.svg](https://raw.githubusercontent.com/cpmech/plotpy/main/figures/integ_curve_3d.svg)
 pub struct Curve {
-    label: String,             // Name of this curve in the legend
-    line_alpha: f64,           // Opacity of lines (0, 1]. A<1e-14 => A=1.0
-    line_color: String,        // Color of lines
-    line_style: String,        // Style of lines
-    line_width: f64,           // Width of lines
-    marker_color: String,      // Color of markers
-    marker_every: usize,       // Increment of data points to use when drawing markers
-    marker_void: bool,         // Draw a void marker (draw edge only)
-    marker_line_color: String, // Edge color of markers
-    marker_line_width: f64,    // Edge width of markers
-    marker_size: f64,          // Size of markers
-    marker_style: String,      // Style of markers, e.g., "`o`", "`+`"
-    stop_clip: bool,           // Stop clipping features within margins
-    buffer: String,            // buffer
+    style: CurveStyle,          // Style of this curve
+    points: Vec<[f64; 2]>,      // Points added by points_add
+    points_3d: Vec<[f64; 3]>,   // Points added by points_3d_add
+    buffer: String,             // buffer
+    primitives: Vec<Primitive>, // primitives
+}
+
+/// Holds the style of a curve (see [Curve])
+///
+/// The style is recorded by the [Primitive]s drawn by [Curve] and may be modified afterwards.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct CurveStyle {
+    pub label: String,             // Name of this curve in the legend
+    pub line_alpha: f64,           // Opacity of lines (0, 1]. A<1e-14 => A=1.0
+    pub line_color: String,        // Color of lines
+    pub line_style: String,        // Style of lines
+    pub line_width: f64,           // Width of lines
+    pub step: String,              // Draws steps instead of straight lines: "pre", "post", or "mid"
+    pub marker_color: String,      // Color of markers
+    pub marker_every: usize,       // Increment of data points to use when drawing markers
+    pub marker_void: bool,         // Draw a void marker (draw edge only)
+    pub marker_line_color: String, // Edge color of markers
+    pub marker_line_width: f64,    // Edge width of markers
+    pub marker_size: f64,          // Size of markers
+    pub marker_style: String,      // Style of markers, e.g., "`o`", "`+`"
+    pub stop_clip: bool,           // Stop clipping features within margins
+    pub error_capsize: f64,        // Length of the caps of the error bars
+    pub error_color: String,       // Color of the error bars (the color of lines if empty)
+    pub error_line_width: f64,     // Width of the lines of the error bars
+    pub band_alpha: f64,           // Opacity of the band (0, 1]. A<1e-14 => A=0.3
+    pub baseline: f64,             // Baseline of stems and stairs
+    pub stem_base_color: String,   // Color of the baseline of stems ("black" if empty)
+    pub stairs_fill: bool,         // Fills the area between stairs and the baseline
 }
 
 impl Curve {
     /// Creates new Curve object
     pub fn new() -> Self {
         Curve {
-            label: String::new(),
-            line_alpha: 0.0,
-            line_color: String::new(),
-            line_style: String::new(),
-            line_width: 0.0,
-            marker_color: String::new(),
-            marker_every: 0,
-            marker_void: false,
-            marker_line_color: String::new(),
-            marker_line_width: 0.0,
-            marker_size: 0.0,
-            marker_style: String::new(),
-            stop_clip: false,
+            style: CurveStyle::default(),
+            points: Vec::new(),
+            points_3d: Vec::new(),
             buffer: String::new(),
+            primitives: Vec::new(),
         }
     }
 
@@ -154,9 +165,9 @@ impl Curve {
     /// # Warning
     ///
     /// This function must be followed by [Curve::points_add] and [Curve::points_end],
-    /// otherwise Python/Matplotlib will fail.
+    /// otherwise the points are not drawn.
     pub fn points_begin(&mut self) -> &mut Self {
-        write!(&mut self.buffer, "xy=np.array([").unwrap();
+        self.points.clear();
         self
     }
 
@@ -165,12 +176,12 @@ impl Curve {
     /// # Warning
     ///
     /// This function must be called after [Curve::points_begin] and must be followed by [Curve::points_end],
-    /// otherwise Python/Matplotlib will fail.
+    /// otherwise the points are not drawn.
     pub fn points_add<T>(&mut self, x: T, y: T) -> &mut Self
     where
-        T: std::fmt::Display,
+        T: AsPrimitive<f64>,
     {
-        write!(&mut self.buffer, "[{},{}],", x, y).unwrap();
+        self.points.push([to_f64(x), to_f64(y)]);
         self
     }
 
@@ -179,10 +190,11 @@ impl Curve {
     /// # Warning
     ///
     /// This function must be called after [Curve::points_begin] and [Curve::points_add],
-    /// otherwise Python/Matplotlib will fail.
+    /// otherwise the points are not drawn.
     pub fn points_end(&mut self) -> &mut Self {
-        let opt = self.options();
-        write!(&mut self.buffer, "])\nplt.plot(xy[:,0],xy[:,1]{})\n", &opt).unwrap();
+        let points = std::mem::take(&mut self.points);
+        let style = self.style.clone();
+        self.push(Primitive::CurvePoints { points, style });
         self
     }
 
@@ -191,9 +203,9 @@ impl Curve {
     /// # Warning
     ///
     /// This function must be followed by [Curve::points_3d_add] and [Curve::points_3d_end],
-    /// otherwise Python/Matplotlib will fail
+    /// otherwise the points are not drawn.
     pub fn points_3d_begin(&mut self) -> &mut Self {
-        write!(&mut self.buffer, "maybe_create_ax3d()\nxyz=np.array([").unwrap();
+        self.points_3d.clear();
         self
     }
 
@@ -202,12 +214,12 @@ impl Curve {
     /// # Warning
     ///
     /// This function must be called after [Curve::points_3d_begin] and must be followed by [Curve::points_3d_end],
-    /// otherwise Python/Matplotlib will fail.
+    /// otherwise the points are not drawn.
     pub fn points_3d_add<T>(&mut self, x: T, y: T, z: T) -> &mut Self
     where
-        T: std::fmt::Display,
+        T: AsPrimitive<f64>,
     {
-        write!(&mut self.buffer, "[{},{},{}],", x, y, z).unwrap();
+        self.points_3d.push([to_f64(x), to_f64(y), to_f64(z)]);
         self
     }
 
@@ -216,10 +228,11 @@ impl Curve {
     /// # Warning
     ///
     /// This function must be called after [Curve::points_3d_begin] and [Curve::points_3d_add],
-    /// otherwise Python/Matplotlib will fail.
+    /// otherwise the points are not drawn.
     pub fn points_3d_end(&mut self) -> &mut Self {
-        let opt = self.options();
-        write!(&mut self.buffer, "])\nAX3D.plot(xyz[:,0],xyz[:,1],xyz[:,2]{})\n", &opt).unwrap();
+        let points = std::mem::take(&mut self.points_3d);
+        let style = self.style.clone();
+        self.push(Primitive::CurvePoints3d { points, style });
         self
     }
 
@@ -237,12 +250,11 @@ impl Curve {
     pub fn draw<'a, T, U>(&mut self, x: &'a T, y: &'a T)
     where
         T: AsVector<'a, U>,
-        U: 'a + std::fmt::Display,
+        U: 'a + AsPrimitive<f64>,
     {
-        vector_to_array(&mut self.buffer, "x", x);
-        vector_to_array(&mut self.buffer, "y", y);
-        let opt = self.options();
-        write!(&mut self.buffer, "plt.plot(x,y{})\n", &opt).unwrap();
+        let (x, y) = (vector_to_f64(x), vector_to_f64(y));
+        let style = self.style.clone();
+        self.push(Primitive::Curve { x, y, style });
     }
 
     /// Draws curve in 3D plot
@@ -260,31 +272,207 @@ impl Curve {
     pub fn draw_3d<'a, T, U>(&mut self, x: &'a T, y: &'a T, z: &'a T)
     where
         T: AsVector<'a, U>,
-        U: 'a + std::fmt::Display,
+        U: 'a + AsPrimitive<f64>,
+    {
+        let (x, y, z) = (vector_to_f64(x), vector_to_f64(y), vector_to_f64(z));
+        let style = self.style.clone();
+        self.push(Primitive::Curve3d { x, y, z, style });
+    }
+
+    /// Draws curve with error bars
+    ///
+    /// # Input
+    ///
+    /// * `x` - abscissa values
+    /// * `y` - ordinate values
+    /// * `x_err` - errors along x: none (empty slice), symmetric (`&[&err]`), or asymmetric (`&[&lower, &upper]`)
+    /// * `y_err` - errors along y: none (empty slice), symmetric (`&[&err]`), or asymmetric (`&[&lower, &upper]`)
+    ///
+    /// # Notes
+    ///
+    /// * The type `U` of the input arrays must be a number.
+    /// * The errors are positive distances from the points (not absolute coordinates).
+    /// * The error bars share the color and label of the curve; thus the legend shows one entry.
+    ///
+    pub fn draw_with_error_bars<'a, T, U>(
+        &mut self,
+        x: &'a T,
+        y: &'a T,
+        x_err: &[&'a T],
+        y_err: &[&'a T],
+    ) -> Result<(), Error>
+    where
+        T: AsVector<'a, U>,
+        U: 'a + AsPrimitive<f64>,
+    {
+        let (x, y) = (vector_to_f64(x), vector_to_f64(y));
+        if y.len() != x.len() {
+            return Err(Error::InvalidInput {
+                argument: "y",
+                message: "y.len() must be equal to x.len()",
+            });
+        }
+        let x_err = errors_to_f64(x_err, x.len(), "x_err")?;
+        let y_err = errors_to_f64(y_err, x.len(), "y_err")?;
+        let style = self.style.clone();
+        self.push(Primitive::CurveErrorBars {
+            x,
+            y,
+            x_err,
+            y_err,
+            style,
+        });
+        Ok(())
+    }
+
+    /// Draws curve with a shaded band (e.g., a confidence interval)
+    ///
+    /// # Input
+    ///
+    /// * `x` - abscissa values
+    /// * `y` - ordinate values
+    /// * `lower` - ordinate values of the lower boundary of the band
+    /// * `upper` - ordinate values of the upper boundary of the band
+    ///
+    /// # Notes
+    ///
+    /// * The type `U` of the input arrays must be a number.
+    /// * The band shares the color of the curve and has no entry in the legend.
+    ///
+    pub fn draw_with_band<'a, T, U>(&mut self, x: &'a T, y: &'a T, lower: &'a T, upper: &'a T) -> Result<(), Error>
+    where
+        T: AsVector<'a, U>,
+        U: 'a + AsPrimitive<f64>,
+    {
+        let (x, y) = (vector_to_f64(x), vector_to_f64(y));
+        let (lower, upper) = (vector_to_f64(lower), vector_to_f64(upper));
+        if y.len() != x.len() {
+            return Err(Error::InvalidInput {
+                argument: "y",
+                message: "y.len() must be equal to x.len()",
+            });
+        }
+        if lower.len() != x.len() || upper.len() != x.len() {
+            return Err(Error::InvalidInput {
+                argument: "lower, upper",
+                message: "the boundaries of the band must have the same length as x",
+            });
+        }
+        let style = self.style.clone();
+        self.push(Primitive::CurveBand {
+            x,
+            y,
+            lower,
+            upper,
+            style,
+        });
+        Ok(())
+    }
+
+    /// Draws stem plot (markers on vertical lines from the baseline)
+    ///
+    /// # Input
+    ///
+    /// * `x` - abscissa values
+    /// * `y` - ordinate values (tips of the stems)
+    ///
+    /// # Notes
+    ///
+    /// * The type `U` of the input arrays must be a number.
+    /// * The stems use the line setters and the tips use the marker setters (circles if the marker style is empty).
+    /// * See [Curve::set_baseline] and [Curve::set_stem_base_color].
+    ///
+    pub fn draw_stem<'a, T, U>(&mut self, x: &'a T, y: &'a T) -> Result<(), Error>
+    where
+        T: AsVector<'a, U>,
+        U: 'a + AsPrimitive<f64>,
     {
-        vector_to_array(&mut self.buffer, "x", x);
-        vector_to_array(&mut self.buffer, "y", y);
-        vector_to_array(&mut self.buffer, "z", z);
-        let opt = self.options();
-        write!(&mut self.buffer, "maybe_create_ax3d()\n").unwrap();
-        write!(&mut self.buffer, "AX3D.plot(x,y,z{})\n", &opt).unwrap();
+        let (x, y) = (vector_to_f64(x), vector_to_f64(y));
+        if x.len() == 0 {
+            return Err(Error::InvalidInput {
+                argument: "x",
+                message: "x must not be empty",
+            });
+        }
+        if y.len() != x.len() {
+            return Err(Error::InvalidInput {
+                argument: "y",
+                message: "y.len() must be equal to x.len()",
+            });
+        }
+        let style = self.style.clone();
+        self.push(Primitive::CurveStem { x, y, style });
+        Ok(())
+    }
+
+    /// Draws stairs (a piecewise-constant function) given the edges of the bins and the values
+    ///
+    /// # Input
+    ///
+    /// * `edges` - edges of the bins (one more than the number of values)
+    /// * `values` - values in each bin
+    ///
+    /// # Notes
+    ///
+    /// * The type `U` of the input arrays must be a number.
+    /// * The markers are not drawn. See [Curve::set_baseline] and [Curve::set_stairs_fill].
+    ///
+    pub fn draw_stairs<'a, T, U>(&mut self, edges: &'a T, values: &'a T) -> Result<(), Error>
+    where
+        T: AsVector<'a, U>,
+        U: 'a + AsPrimitive<f64>,
+    {
+        let (edges, values) = (vector_to_f64(edges), vector_to_f64(values));
+        if edges.len() != values.len() + 1 {
+            return Err(Error::InvalidInput {
+                argument: "edges",
+                message: "edges.len() must be equal to values.len() + 1",
+            });
+        }
+        let style = self.style.clone();
+        self.push(Primitive::CurveStairs { edges, values, style });
+        Ok(())
+    }
+
+    /// Sets the opacity of the band drawn by [Curve::draw_with_band] (0, 1]. A<1e-14 => A=0.3
+    pub fn set_band_alpha(&mut self, alpha: f64) -> &mut Self {
+        self.style.band_alpha = alpha;
+        self
+    }
+
+    /// Sets the length of the caps of the error bars
+    pub fn set_error_capsize(&mut self, capsize: f64) -> &mut Self {
+        self.style.error_capsize = capsize;
+        self
+    }
+
+    /// Sets the color of the error bars (the color of lines if empty)
+    pub fn set_error_color(&mut self, color: &str) -> &mut Self {
+        self.style.error_color = String::from(color);
+        self
+    }
+
+    /// Sets the width of the lines of the error bars
+    pub fn set_error_line_width(&mut self, width: f64) -> &mut Self {
+        self.style.error_line_width = width;
+        self
     }
 
     /// Sets the name of this curve in the legend
     pub fn set_label(&mut self, label: &str) -> &mut Self {
-        self.label = String::from(label);
+        self.style.label = String::from(label);
         self
     }
 
     /// Sets the opacity of lines (0, 1]. A<1e-14 => A=1.0
     pub fn set_line_alpha(&mut self, alpha: f64) -> &mut Self {
-        self.line_alpha = alpha;
+        self.style.line_alpha = alpha;
         self
     }
 
     /// Sets the color of lines
     pub fn set_line_color(&mut self, color: &str) -> &mut Self {
-        self.line_color = String::from(color);
+        self.style.line_color = String::from(color);
         self
     }
 
@@ -293,23 +481,13 @@ impl Curve {
     /// * For horizontal rays, only `ya` is used
     /// * For vertical rays, only `xa` is used
     pub fn draw_ray(&mut self, xa: f64, ya: f64, endpoint: RayEndpoint) {
-        let opt = self.options();
-        match endpoint {
-            RayEndpoint::Coords(xb, yb) => write!(
-                &mut self.buffer,
-                "plt.axline(({},{}),({},{}){})\n",
-                xa, ya, xb, yb, &opt
-            )
-            .unwrap(),
-            RayEndpoint::Slope(m) => write!(
-                &mut self.buffer,
-                "plt.axline(({},{}),None,slope={}{})\n",
-                xa, ya, m, &opt
-            )
-            .unwrap(),
-            RayEndpoint::Horizontal => write!(&mut self.buffer, "plt.axhline({}{})\n", ya, &opt).unwrap(),
-            RayEndpoint::Vertical => write!(&mut self.buffer, "plt.axvline({}{})\n", xa, &opt).unwrap(),
-        }
+        let style = self.style.clone();
+        self.push(Primitive::Ray {
+            xa,
+            ya,
+            endpoint,
+            style,
+        });
     }
 
     /// Sets the style of lines
@@ -319,49 +497,84 @@ impl Curve {
     /// * "`-`", `:`", "`--`", "`-.`", or "`None`"
     /// * As defined in <https://matplotlib.org/stable/gallery/lines_bars_and_markers/linestyles.html>
     pub fn set_line_style(&mut self, style: &str) -> &mut Self {
-        self.line_style = String::from(style);
+        self.style.line_style = String::from(style);
         self
     }
 
     /// Sets the width of lines
     pub fn set_line_width(&mut self, width: f64) -> &mut Self {
-        self.line_width = width;
+        self.style.line_width = width;
+        self
+    }
+
+    /// Sets the option to draw steps instead of straight lines
+    ///
+    /// Options:
+    ///
+    /// * "`pre`" -- the value y\[i\] extends to the left of x\[i\] (until x\[i-1\])
+    /// * "`post`" -- the value y\[i\] extends to the right of x\[i\] (until x\[i+1\])
+    /// * "`mid`" -- the steps occur halfway between the x values
+    /// * "" (empty) -- draws straight lines (default)
+    ///
+    /// Other values are ignored.
+    pub fn set_step(&mut self, step: &str) -> &mut Self {
+        if matches!(step, "" | "pre" | "post" | "mid") {
+            self.style.step = String::from(step);
+        }
+        self
+    }
+
+    /// Sets the baseline of stems and stairs (see [Curve::draw_stem] and [Curve::draw_stairs])
+    pub fn set_baseline(&mut self, baseline: f64) -> &mut Self {
+        self.style.baseline = baseline;
+        self
+    }
+
+    /// Sets the color of the baseline of stems ("black" if empty; use "none" to hide it)
+    pub fn set_stem_base_color(&mut self, color: &str) -> &mut Self {
+        self.style.stem_base_color = String::from(color);
+        self
+    }
+
+    /// Sets the option to fill the area between stairs and the baseline
+    pub fn set_stairs_fill(&mut self, flag: bool) -> &mut Self {
+        self.style.stairs_fill = flag;
         self
     }
 
     /// Sets the color of markers
     pub fn set_marker_color(&mut self, color: &str) -> &mut Self {
-        self.marker_color = String::from(color);
+        self.style.marker_color = String::from(color);
         self
     }
 
     /// Sets the increment of data points to use when drawing markers
     pub fn set_marker_every(&mut self, every: usize) -> &mut Self {
-        self.marker_every = every;
+        self.style.marker_every = every;
         self
     }
 
     /// Sets the option to draw a void marker (draw edge only)
     pub fn set_marker_void(&mut self, flag: bool) -> &mut Self {
-        self.marker_void = flag;
+        self.style.marker_void = flag;
         self
     }
 
     /// Sets the edge color of markers
     pub fn set_marker_line_color(&mut self, color: &str) -> &mut Self {
-        self.marker_line_color = String::from(color);
+        self.style.marker_line_color = String::from(color);
         self
     }
 
     /// Sets the edge width of markers
     pub fn set_marker_line_width(&mut self, width: f64) -> &mut Self {
-        self.marker_line_width = width;
+        self.style.marker_line_width = width;
         self
     }
 
     /// Sets the size of markers
     pub fn set_marker_size(&mut self, size: f64) -> &mut Self {
-        self.marker_size = size;
+        self.style.marker_size = size;
         self
     }
 
@@ -372,18 +585,26 @@ impl Curve {
     /// * "`o`", "`+`"
     /// * As defined in <https://matplotlib.org/stable/api/markers_api.html>
     pub fn set_marker_style(&mut self, style: &str) -> &mut Self {
-        self.marker_style = String::from(style);
+        self.style.marker_style = String::from(style);
         self
     }
 
     /// Sets the flag to stop clipping features within margins
     pub fn set_stop_clip(&mut self, flag: bool) -> &mut Self {
-        self.stop_clip = flag;
+        self.style.stop_clip = flag;
         self
     }
 
+    /// Writes the primitive to the buffer and records it
+    fn push(&mut self, primitive: Primitive) {
+        primitive.write_python(&mut self.buffer);
+        self.primitives.push(primitive);
+    }
+}
+
+impl CurveStyle {
     /// Returns options for curve
-    fn options(&self) -> String {
+    pub(crate) fn options(&self) -> String {
         // fix color if marker is void
         let line_color = if self.marker_void && self.line_color == "" {
             "red"
@@ -412,6 +633,9 @@ impl Curve {
         if self.line_width > 0.0 {
             write!(&mut opt, ",linewidth={}", self.line_width).unwrap();
         }
+        if matches!(self.step.as_str(), "pre" | "post" | "mid") {
+            write!(&mut opt, ",drawstyle='steps-{}'", self.step).unwrap();
+        }
 
         // markers
         if !self.marker_void && self.marker_color != "" {
@@ -443,6 +667,229 @@ impl Curve {
 
         opt
     }
+
+    /// Returns options for error bars
+    pub(crate) fn options_error_bars(&self) -> String {
+        let mut opt = String::new();
+        if self.error_capsize > 0.0 {
+            write!(&mut opt, ",capsize={}", self.error_capsize).unwrap();
+        }
+        if self.error_color != "" {
+            write!(&mut opt, ",ecolor='{}'", self.error_color).unwrap();
+        }
+        if self.error_line_width > 0.0 {
+            write!(&mut opt, ",elinewidth={}", self.error_line_width).unwrap();
+        }
+        opt
+    }
+
+    /// Returns options for the markers at the tips of stems
+    pub(crate) fn options_stem_markers(&self) -> String {
+        let mut style = self.clone();
+        style.line_style = "None".to_string();
+        style.step = String::new();
+        if style.marker_style == "" {
+            style.marker_style = "o".to_string();
+        }
+        style.options()
+    }
+
+    /// Returns options for the vertical lines of stems
+    pub(crate) fn options_stem_lines(&self) -> String {
+        let mut opt = String::from(",colors=l.get_color(),zorder=1");
+        if self.line_alpha > 0.0 {
+            write!(&mut opt, ",alpha={}", self.line_alpha).unwrap();
+        }
+        if self.line_style != "" {
+            write!(&mut opt, ",linestyles='{}'", self.line_style).unwrap();
+        }
+        if self.line_width > 0.0 {
+            write!(&mut opt, ",linewidths={}", self.line_width).unwrap();
+        }
+        opt
+    }
+
+    /// Returns options for stairs
+    pub(crate) fn options_stairs(&self) -> String {
+        let mut opt = String::new();
+        if self.label != "" {
+            write!(&mut opt, ",label='{}'", self.label).unwrap();
+        }
+        if self.line_alpha > 0.0 {
+            write!(&mut opt, ",alpha={}", self.line_alpha).unwrap();
+        }
+        if self.line_color != "" {
+            write!(&mut opt, ",color='{}'", self.line_color).unwrap();
+        }
+        if self.line_style != "" {
+            write!(&mut opt, ",linestyle='{}'", self.line_style).unwrap();
+        }
+        if self.line_width > 0.0 {
+            write!(&mut opt, ",linewidth={}", self.line_width).unwrap();
+        }
+        if self.baseline != 0.0 {
+            write!(&mut opt, ",baseline={}", self.baseline).unwrap();
+        }
+        if self.stairs_fill {
+            write!(&mut opt, ",fill=True").unwrap();
+        }
+        if self.stop_clip {
+            write!(&mut opt, ",clip_on=False").unwrap();
+        }
+        opt
+    }
+
+    /// Returns options for band
+    pub(crate) fn options_band(&self) -> String {
+        let alpha = if self.band_alpha < 1e-14 { 0.3 } else { self.band_alpha };
+        format!(",color=l.get_color(),alpha={},linewidth=0", alpha)
+    }
+}
+
+/// Converts the errors of a curve (zero, one, or two arrays) to a nested Vec of f64
+fn errors_to_f64<'a, T, U>(errors: &[&'a T], npoint: usize, argument: &'static str) -> Result<Vec<Vec<f64>>, Error>
+where
+    T: AsVector<'a, U>,
+    U: 'a + AsPrimitive<f64>,
+{
+    if errors.len() > 2 {
+        return Err(Error::InvalidInput {
+            argument,
+            message: "the errors must be given by zero, one, or two arrays",
+        });
+    }
+    let errors: Vec<Vec<f64>> = errors.iter().map(|e| vector_to_f64(*e)).collect();
+    if errors.iter().any(|e| e.len() != npoint) {
+        return Err(Error::InvalidInput {
+            argument,
+            message: "the arrays of errors must have the same length as x",
+        });
+    }
+    Ok(errors)
+}
+
+/// Writes the Python commands of a curve (see [Primitive::Curve])
+pub(crate) fn write_curve(buf: &mut Script, x: &[f64], y: &[f64], style: &CurveStyle) {
+    vector_to_array(buf, "x", &x);
+    vector_to_array(buf, "y", &y);
+    write!(buf, "plt.plot(x,y{})\n", style.options()).unwrap();
+}
+
+/// Writes the Python commands of a curve with error bars (see [Primitive::CurveErrorBars])
+pub(crate) fn write_curve_error_bars(
+    buf: &mut Script,
+    x: &[f64],
+    y: &[f64],
+    x_err: &Vec<Vec<f64>>,
+    y_err: &Vec<Vec<f64>>,
+    style: &CurveStyle,
+) {
+    vector_to_array(buf, "x", &x);
+    vector_to_array(buf, "y", &y);
+    let mut opt = String::new();
+    for (name, err) in [("xerr", x_err), ("yerr", y_err)] {
+        match err.len() {
+            0 => continue,
+            1 => vector_to_array(buf, name, &err[0]),
+            _ => matrix_to_array(buf, name, err),
+        }
+        write!(&mut opt, ",{}={}", name, name).unwrap();
+    }
+    write!(
+        buf,
+        "plt.errorbar(x,y{}{}{})\n",
+        opt,
+        style.options_error_bars(),
+        style.options()
+    )
+    .unwrap();
+}
+
+/// Writes the Python commands of a curve with a shaded band (see [Primitive::CurveBand])
+pub(crate) fn write_curve_band(
+    buf: &mut Script,
+    x: &[f64],
+    y: &[f64],
+    lower: &[f64],
+    upper: &[f64],
+    style: &CurveStyle,
+) {
+    vector_to_array(buf, "x", &x);
+    vector_to_array(buf, "y", &y);
+    vector_to_array(buf, "lower", &lower);
+    vector_to_array(buf, "upper", &upper);
+    write!(buf, "l,=plt.plot(x,y{})\n", style.options()).unwrap();
+    write!(buf, "plt.fill_between(x,lower,upper{})\n", style.options_band()).unwrap();
+}
+
+/// Writes the Python commands of a stem plot (see [Primitive::CurveStem])
+pub(crate) fn write_curve_stem(buf: &mut Script, x: &[f64], y: &[f64], style: &CurveStyle) {
+    vector_to_array(buf, "x", &x);
+    vector_to_array(buf, "y", &y);
+    let base_color = if style.stem_base_color == "" {
+        "black"
+    } else {
+        &style.stem_base_color
+    };
+    write!(buf, "l,=plt.plot(x,y{})\n", style.options_stem_markers()).unwrap();
+    write!(
+        buf,
+        "plt.vlines(x,{},y{})\n",
+        style.baseline,
+        style.options_stem_lines()
+    )
+    .unwrap();
+    write!(
+        buf,
+        "plt.hlines({},np.min(x),np.max(x),colors='{}',zorder=1)\n",
+        style.baseline, base_color
+    )
+    .unwrap();
+}
+
+/// Writes the Python commands of stairs (see [Primitive::CurveStairs])
+pub(crate) fn write_curve_stairs(buf: &mut Script, edges: &[f64], values: &[f64], style: &CurveStyle) {
+    vector_to_array(buf, "edges", &edges);
+    vector_to_array(buf, "values", &values);
+    write!(buf, "plt.stairs(values,edges{})\n", style.options_stairs()).unwrap();
+}
+
+/// Writes the Python commands of a 3D curve (see [Primitive::Curve3d])
+pub(crate) fn write_curve_3d(buf: &mut Script, x: &[f64], y: &[f64], z: &[f64], style: &CurveStyle) {
+    vector_to_array(buf, "x", &x);
+    vector_to_array(buf, "y", &y);
+    vector_to_array(buf, "z", &z);
+    write!(buf, "maybe_create_ax3d()\n").unwrap();
+    write!(buf, "AX3D.plot(x,y,z{})\n", style.options()).unwrap();
+}
+
+/// Writes the Python commands of a curve given by a list of points (see [Primitive::CurvePoints])
+pub(crate) fn write_curve_points(buf: &mut String, points: &[[f64; 2]], style: &CurveStyle) {
+    write!(buf, "xy=np.array([").unwrap();
+    for [x, y] in points {
+        write!(buf, "[{},{}],", x, y).unwrap();
+    }
+    write!(buf, "])\nplt.plot(xy[:,0],xy[:,1]{})\n", style.options()).unwrap();
+}
+
+/// Writes the Python commands of a 3D curve given by a list of points (see [Primitive::CurvePoints3d])
+pub(crate) fn write_curve_points_3d(buf: &mut String, points: &[[f64; 3]], style: &CurveStyle) {
+    write!(buf, "maybe_create_ax3d()\nxyz=np.array([").unwrap();
+    for [x, y, z] in points {
+        write!(buf, "[{},{},{}],", x, y, z).unwrap();
+    }
+    write!(buf, "])\nAX3D.plot(xyz[:,0],xyz[:,1],xyz[:,2]{})\n", style.options()).unwrap();
+}
+
+/// Writes the Python commands of a ray (see [Primitive::Ray])
+pub(crate) fn write_ray(buf: &mut String, xa: f64, ya: f64, endpoint: &RayEndpoint, style: &CurveStyle) {
+    let opt = style.options();
+    match endpoint {
+        RayEndpoint::Coords(xb, yb) => write!(buf, "plt.axline(({},{}),({},{}){})\n", xa, ya, xb, yb, &opt).unwrap(),
+        RayEndpoint::Slope(m) => write!(buf, "plt.axline(({},{}),None,slope={}{})\n", xa, ya, m, &opt).unwrap(),
+        RayEndpoint::Horizontal => write!(buf, "plt.axhline({}{})\n", ya, &opt).unwrap(),
+        RayEndpoint::Vertical => write!(buf, "plt.axvline({}{})\n", xa, &opt).unwrap(),
+    }
 }
 
 impl GraphMaker for Curve {
@@ -451,6 +898,10 @@ impl GraphMaker for Curve {
     }
     fn clear_buffer(&mut self) {
         self.buffer.clear();
+        self.primitives.clear();
+    }
+    fn get_primitives(&self) -> &[Primitive] {
+        &self.primitives
     }
 }
 
@@ -459,25 +910,34 @@ impl GraphMaker for Curve {
 #[cfg(test)]
 mod tests {
     use super::{Curve, RayEndpoint};
-    use crate::GraphMaker;
+    use crate::{Error, GraphMaker, Primitive};
     use russell_lab::Vector;
 
     #[test]
     fn new_works() {
         let curve = Curve::new();
-        assert_eq!(curve.label.len(), 0);
-        assert_eq!(curve.line_alpha, 0.0);
-        assert_eq!(curve.line_color.len(), 0);
-        assert_eq!(curve.line_style.len(), 0);
-        assert_eq!(curve.line_width, 0.0);
-        assert_eq!(curve.marker_color.len(), 0);
-        assert_eq!(curve.marker_every, 0);
-        assert_eq!(curve.marker_void, false);
-        assert_eq!(curve.marker_line_color.len(), 0);
-        assert_eq!(curve.marker_line_width, 0.0);
-        assert_eq!(curve.marker_size, 0.0);
-        assert_eq!(curve.marker_style.len(), 0);
+        assert_eq!(curve.style.label.len(), 0);
+        assert_eq!(curve.style.line_alpha, 0.0);
+        assert_eq!(curve.style.line_color.len(), 0);
+        assert_eq!(curve.style.line_style.len(), 0);
+        assert_eq!(curve.style.line_width, 0.0);
+        assert_eq!(curve.style.step.len(), 0);
+        assert_eq!(curve.style.marker_color.len(), 0);
+        assert_eq!(curve.style.marker_every, 0);
+        assert_eq!(curve.style.marker_void, false);
+        assert_eq!(curve.style.marker_line_color.len(), 0);
+        assert_eq!(curve.style.marker_line_width, 0.0);
+        assert_eq!(curve.style.marker_size, 0.0);
+        assert_eq!(curve.style.marker_style.len(), 0);
+        assert_eq!(curve.style.error_capsize, 0.0);
+        assert_eq!(curve.style.error_color.len(), 0);
+        assert_eq!(curve.style.error_line_width, 0.0);
+        assert_eq!(curve.style.band_alpha, 0.0);
+        assert_eq!(curve.style.baseline, 0.0);
+        assert_eq!(curve.style.stem_base_color.len(), 0);
+        assert_eq!(curve.style.stairs_fill, false);
         assert_eq!(curve.buffer.len(), 0);
+        assert_eq!(curve.primitives.len(), 0);
     }
 
     #[test]
@@ -497,7 +957,7 @@ mod tests {
             .set_marker_size(8.0)
             .set_marker_style("o")
             .set_stop_clip(true);
-        let options = curve.options();
+        let options = curve.style.options();
         assert_eq!(
             options,
             ",label='my-curve'\
@@ -515,6 +975,67 @@ mod tests {
         );
     }
 
+    #[test]
+    fn options_step_works() {
+        let mut curve = Curve::new();
+        curve.set_line_color("red").set_step("post");
+        assert_eq!(curve.style.options(), ",color='red',drawstyle='steps-post'");
+        curve.set_step("default");
+        assert_eq!(curve.style.step, "post");
+        curve.style.step = "default".to_string();
+        assert_eq!(curve.style.options(), ",color='red'");
+        curve.set_step("");
+        assert_eq!(curve.style.options(), ",color='red'");
+    }
+
+    #[test]
+    fn options_stem_and_stairs_work() {
+        let mut curve = Curve::new();
+        assert_eq!(curve.style.options_stem_markers(), ",linestyle='None',marker='o'");
+        assert_eq!(curve.style.options_stem_lines(), ",colors=l.get_color(),zorder=1");
+        assert_eq!(curve.style.options_stairs(), "");
+        curve
+            .set_label("signal")
+            .set_line_alpha(0.5)
+            .set_line_color("blue")
+            .set_line_style("--")
+            .set_line_width(2.0)
+            .set_step("mid")
+            .set_marker_style("s")
+            .set_baseline(-1.0)
+            .set_stairs_fill(true)
+            .set_stop_clip(true);
+        assert_eq!(
+            curve.style.options_stem_markers(),
+            ",label='signal',alpha=0.5,color='blue',linestyle='None',linewidth=2,marker='s',clip_on=False"
+        );
+        assert_eq!(
+            curve.style.options_stem_lines(),
+            ",colors=l.get_color(),zorder=1,alpha=0.5,linestyles='--',linewidths=2"
+        );
+        assert_eq!(
+            curve.style.options_stairs(),
+            ",label='signal',alpha=0.5,color='blue',linestyle='--',linewidth=2,baseline=-1,fill=True,clip_on=False"
+        );
+    }
+
+    #[test]
+    fn options_error_bars_and_band_work() {
+        let mut curve = Curve::new();
+        assert_eq!(curve.style.options_error_bars(), "");
+        assert_eq!(curve.style.options_band(), ",color=l.get_color(),alpha=0.3,linewidth=0");
+        curve
+            .set_error_capsize(4.0)
+            .set_error_color("gray")
+            .set_error_line_width(0.5)
+            .set_band_alpha(0.5);
+        assert_eq!(
+            curve.style.options_error_bars(),
+            ",capsize=4,ecolor='gray',elinewidth=0.5"
+        );
+        assert_eq!(curve.style.options_band(), ",color=l.get_color(),alpha=0.5,linewidth=0");
+    }
+
     #[test]
     fn points_methods_work() {
         let mut curve = Curve::new();
@@ -549,8 +1070,17 @@ mod tests {
                        y=np.array([1,4,9,16,25,],dtype=float)\n\
                        plt.plot(x,y,label='the-curve')\n";
         assert_eq!(curve.buffer, b);
+        assert_eq!(
+            curve.get_primitives(),
+            &[Primitive::Curve {
+                x: x.to_vec(),
+                y: y.to_vec(),
+                style: curve.style.clone(),
+            }]
+        );
         curve.clear_buffer();
         assert_eq!(curve.buffer, "");
+        assert_eq!(curve.primitives.len(), 0);
     }
 
     #[test]
@@ -566,6 +1096,163 @@ mod tests {
         assert_eq!(curve.buffer, b);
     }
 
+    #[test]
+    fn draw_with_error_bars_works() {
+        let x = &[1.0, 2.0, 3.0];
+        let y = &[1.0, 4.0, 9.0];
+        let e = &[0.5, 0.5, 1.0];
+        let mut curve = Curve::new();
+        curve.set_label("data").set_error_capsize(3.0);
+        curve.draw_with_error_bars(x, y, &[], &[e]).unwrap();
+        curve.draw_with_error_bars(x, y, &[e], &[e, e]).unwrap();
+        let b: &str = "x=np.array([1,2,3,],dtype=float)\n\
+                       y=np.array([1,4,9,],dtype=float)\n\
+                       yerr=np.array([0.5,0.5,1,],dtype=float)\n\
+                       plt.errorbar(x,y,yerr=yerr,capsize=3,label='data')\n\
+                       x=np.array([1,2,3,],dtype=float)\n\
+                       y=np.array([1,4,9,],dtype=float)\n\
+                       xerr=np.array([0.5,0.5,1,],dtype=float)\n\
+                       yerr=np.array([[0.5,0.5,1,],[0.5,0.5,1,],],dtype=float)\n\
+                       plt.errorbar(x,y,xerr=xerr,yerr=yerr,capsize=3,label='data')\n";
+        assert_eq!(curve.buffer, b);
+        assert_eq!(
+            curve.get_primitives()[0],
+            Primitive::CurveErrorBars {
+                x: x.to_vec(),
+                y: y.to_vec(),
+                x_err: Vec::new(),
+                y_err: vec![e.to_vec()],
+                style: curve.style.clone(),
+            }
+        );
+    }
+
+    #[test]
+    fn draw_with_error_bars_captures_errors() {
+        let x = &[1.0, 2.0, 3.0];
+        let mut curve = Curve::new();
+        assert_eq!(
+            curve.draw_with_error_bars(x, x, &[x, x, x], &[]).err(),
+            Some(Error::InvalidInput {
+                argument: "x_err",
+                message: "the errors must be given by zero, one, or two arrays",
+            })
+        );
+        assert_eq!(
+            curve.draw_with_error_bars(&vec![1.0, 2.0], &vec![1.0], &[], &[]).err(),
+            Some(Error::InvalidInput {
+                argument: "y",
+                message: "y.len() must be equal to x.len()",
+            })
+        );
+        assert_eq!(
+            curve
+                .draw_with_error_bars(&vec![1.0, 2.0], &vec![1.0, 2.0], &[], &[&vec![1.0]])
+                .err(),
+            Some(Error::InvalidInput {
+                argument: "y_err",
+                message: "the arrays of errors must have the same length as x",
+            })
+        );
+        assert_eq!(curve.primitives.len(), 0);
+    }
+
+    #[test]
+    fn draw_with_band_works() {
+        let x = &[1.0, 2.0, 3.0];
+        let y = &[1.0, 4.0, 9.0];
+        let lower = &[0.5, 3.0, 8.0];
+        let upper = &[1.5, 5.0, 10.0];
+        let mut curve = Curve::new();
+        curve.set_label("mean").set_line_color("red").set_band_alpha(0.2);
+        curve.draw_with_band(x, y, lower, upper).unwrap();
+        let b: &str = "x=np.array([1,2,3,],dtype=float)\n\
+                       y=np.array([1,4,9,],dtype=float)\n\
+                       lower=np.array([0.5,3,8,],dtype=float)\n\
+                       upper=np.array([1.5,5,10,],dtype=float)\n\
+                       l,=plt.plot(x,y,label='mean',color='red')\n\
+                       plt.fill_between(x,lower,upper,color=l.get_color(),alpha=0.2,linewidth=0)\n";
+        assert_eq!(curve.buffer, b);
+        assert_eq!(
+            curve
+                .draw_with_band(&vec![1.0, 2.0], &vec![1.0, 2.0], &vec![1.0], &vec![1.0, 2.0])
+                .err(),
+            Some(Error::InvalidInput {
+                argument: "lower, upper",
+                message: "the boundaries of the band must have the same length as x",
+            })
+        );
+        assert_eq!(
+            curve
+                .draw_with_band(&vec![1.0, 2.0], &vec![1.0], &vec![1.0, 2.0], &vec![1.0, 2.0])
+                .err(),
+            Some(Error::InvalidInput {
+                argument: "y",
+                message: "y.len() must be equal to x.len()",
+            })
+        );
+        assert_eq!(curve.primitives.len(), 1);
+    }
+
+    #[test]
+    fn draw_stem_works() {
+        let x = &[1.0, 2.0, 3.0];
+        let y = &[1.0, -2.0, 3.0];
+        let mut curve = Curve::new();
+        curve.set_stem_base_color("gray");
+        curve.draw_stem(x, y).unwrap();
+        let b: &str = "x=np.array([1,2,3,],dtype=float)\n\
+                       y=np.array([1,-2,3,],dtype=float)\n\
+                       l,=plt.plot(x,y,linestyle='None',marker='o')\n\
+                       plt.vlines(x,0,y,colors=l.get_color(),zorder=1)\n\
+                       plt.hlines(0,np.min(x),np.max(x),colors='gray',zorder=1)\n";
+        assert_eq!(curve.buffer, b);
+        assert_eq!(
+            curve.get_primitives(),
+            &[Primitive::CurveStem {
+                x: x.to_vec(),
+                y: y.to_vec(),
+                style: curve.style.clone(),
+            }]
+        );
+        assert_eq!(
+            curve.draw_stem(&Vec::<f64>::new(), &Vec::new()).err(),
+            Some(Error::InvalidInput {
+                argument: "x",
+                message: "x must not be empty",
+            })
+        );
+        assert_eq!(
+            curve.draw_stem(&vec![1.0, 2.0], &vec![1.0]).err(),
+            Some(Error::InvalidInput {
+                argument: "y",
+                message: "y.len() must be equal to x.len()",
+            })
+        );
+        assert_eq!(curve.primitives.len(), 1);
+    }
+
+    #[test]
+    fn draw_stairs_works() {
+        let edges = &vec![0.0, 1.0, 2.0, 3.0];
+        let values = &vec![2.0, 1.0, 3.0];
+        let mut curve = Curve::new();
+        curve.set_line_color("red");
+        curve.draw_stairs(edges, values).unwrap();
+        let b: &str = "edges=np.array([0,1,2,3,],dtype=float)\n\
+                       values=np.array([2,1,3,],dtype=float)\n\
+                       plt.stairs(values,edges,color='red')\n";
+        assert_eq!(curve.buffer, b);
+        assert_eq!(
+            curve.draw_stairs(&vec![0.0, 1.0], &vec![1.0, 2.0]).err(),
+            Some(Error::InvalidInput {
+                argument: "edges",
+                message: "edges.len() must be equal to values.len() + 1",
+            })
+        );
+        assert_eq!(curve.primitives.len(), 1);
+    }
+
     #[test]
     fn draw_3d_works() {
         let x = &[1.0, 2.0, 3.0, 4.0, 5.0];
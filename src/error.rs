@@ -0,0 +1,231 @@
+use std::fmt;
+use std::io;
+use std::path::PathBuf;
+use std::time::Duration;
+
+/// Defines the error type returned by all fallible functions
+///
+/// # Example
+///
+/// ```
+/// use plotpy::{Error, Plot};
+///
+/// let mut plot = Plot::new();
+/// plot.set_subplot(1, 1, 0); // wrong index (should be 1-based)
+/// match plot.save("/tmp/plotpy/doc_tests/doc_error.svg") {
+///     Err(Error::PythonFailed { exception, line, .. }) => {
+///         println!("python raised {:?} at line {:?}", exception, line);
+///     }
+///     Err(e) => println!("{}", e),
+///     Ok(()) => (),
+/// }
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Error {
+    /// The Python interpreter could not be found (holds the name of the interpreter)
+    PythonNotFound(String),
+
+    /// An input/output operation failed
+    Io {
+        /// Description of the failed operation, e.g., "cannot create file"
+        action: &'static str,
+
+        /// Path of the file (or directory) involved in the operation
+        path: PathBuf,
+
+        /// Kind of the underlying input/output error
+        kind: io::ErrorKind,
+    },
+
+    /// An argument given to a function is invalid
+    InvalidInput {
+        /// Name of the offending argument
+        argument: &'static str,
+
+        /// Explanation of what is wrong with the argument
+        message: &'static str,
+    },
+
+    /// Python was executed but failed (or printed something)
+    PythonFailed {
+        /// Captured output of the Python script (stdout followed by stderr)
+        output: String,
+
+        /// Type of the Python exception, if any (e.g., "ValueError")
+        exception: Option<String>,
+
+        /// Line number of the generated script where the exception was raised, if any
+        line: Option<usize>,
+    },
+
+    /// Python did not finish in time and was killed (see [crate::Plot::set_timeout])
+    PythonTimeout {
+        /// The maximum time given to Python
+        timeout: Duration,
+
+        /// Captured output of the Python script until it was killed (stdout followed by stderr)
+        output: String,
+    },
+
+    /// Any other error described by a message (e.g., errors from other libraries)
+    Message(&'static str),
+}
+
+impl Error {
+    /// Creates a new `PythonFailed` error by parsing the Python traceback, if any
+    ///
+    /// # Input
+    ///
+    /// * `output` -- the captured output of the Python script
+    /// * `script` -- the script name as shown in the traceback (e.g., the `.py` path or `<stdin>`)
+    pub(crate) fn python_failed(output: String, script: &str) -> Self {
+        // exception type from the last line, e.g., "ValueError: num must be an integer"
+        let exception = output.lines().rev().find(|l| l.trim().len() > 0).and_then(|last| {
+            let name = last.split(':').next().unwrap_or("").trim_end();
+            let mut chars = name.chars();
+            let first_ok = chars.next().is_some_and(|c| c.is_ascii_alphabetic() || c == '_');
+            let rest_ok = chars.all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '.');
+            if !last.starts_with(' ') && first_ok && rest_ok {
+                Some(name.to_string())
+            } else {
+                None
+            }
+        });
+
+        // innermost line of the script in the traceback, e.g., 'File "a.py", line 80, in <module>'
+        let pattern = format!("File \"{}\", line ", script);
+        let line = output
+            .lines()
+            .filter_map(|l| l.trim_start().strip_prefix(&pattern))
+            .filter_map(|l| l.split(|c: char| !c.is_ascii_digit()).next()?.parse().ok())
+            .next_back();
+
+        Error::PythonFailed {
+            output,
+            exception,
+            line,
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::PythonNotFound(program) => write!(f, "cannot find the Python interpreter `{}`", program),
+            Error::Io { action, path, kind } => {
+                write!(f, "{} `{}`: {}", action, path.display(), io::Error::from(*kind))
+            }
+            Error::InvalidInput { argument, message } => write!(f, "invalid input `{}`: {}", argument, message),
+            Error::PythonFailed {
+                output,
+                exception,
+                line,
+            } => {
+                write!(f, "python3 failed")?;
+                if let Some(exception) = exception {
+                    write!(f, " with {}", exception)?;
+                }
+                if let Some(line) = line {
+                    write!(f, " at line {} of the script", line)?;
+                }
+                write!(f, ":\n{}", output)
+            }
+            Error::PythonTimeout { timeout, output } => {
+                write!(f, "python3 was killed after {:?}", timeout)?;
+                if output.len() > 0 {
+                    write!(f, ":\n{}", output)?;
+                }
+                Ok(())
+            }
+            Error::Message(message) => write!(f, "{}", message),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<&'static str> for Error {
+    fn from(message: &'static str) -> Self {
+        Error::Message(message)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::Error;
+    use std::io;
+    use std::path::PathBuf;
+    use std::time::Duration;
+
+    #[test]
+    fn python_failed_works() {
+        let output = "Traceback (most recent call last):\n  \
+                      File \"/tmp/a.py\", line 65, in <module>\n    \
+                      plt.subplot(1,1,0)\n  \
+                      File \"/usr/lib/python3/pyplot.py\", line 1323, in subplot\n    \
+                      key = SubplotSpec._from_subplot_args(fig, args)\n\
+                      ValueError: num must be 1 <= num <= 1, not 0\n";
+        let err = Error::python_failed(output.to_string(), "/tmp/a.py");
+        assert_eq!(
+            err,
+            Error::PythonFailed {
+                output: output.to_string(),
+                exception: Some("ValueError".to_string()),
+                line: Some(65),
+            }
+        );
+        assert_eq!(
+            format!("{}", err),
+            format!("python3 failed with ValueError at line 65 of the script:\n{}", output)
+        );
+    }
+
+    #[test]
+    fn python_failed_handles_plain_output() {
+        let err = Error::python_failed("VERSION of MATPLOTLIB = 3.1.2\n".to_string(), "/tmp/a.py");
+        assert_eq!(
+            err,
+            Error::PythonFailed {
+                output: "VERSION of MATPLOTLIB = 3.1.2\n".to_string(),
+                exception: None,
+                line: None,
+            }
+        );
+        assert_eq!(format!("{}", err), "python3 failed:\nVERSION of MATPLOTLIB = 3.1.2\n");
+    }
+
+    #[test]
+    fn display_works() {
+        let err = Error::PythonNotFound("python3".to_string());
+        assert_eq!(format!("{}", err), "cannot find the Python interpreter `python3`");
+        let err = Error::Io {
+            action: "cannot create file",
+            path: PathBuf::from("/tmp/a.py"),
+            kind: io::ErrorKind::PermissionDenied,
+        };
+        assert_eq!(format!("{}", err), "cannot create file `/tmp/a.py`: permission denied");
+        let err = Error::InvalidInput {
+            argument: "ndiv",
+            message: "len(ndiv) == ndim must be 2 or 3",
+        };
+        assert_eq!(
+            format!("{}", err),
+            "invalid input `ndiv`: len(ndiv) == ndim must be 2 or 3"
+        );
+        let err = Error::PythonTimeout {
+            timeout: Duration::from_millis(1500),
+            output: String::new(),
+        };
+        assert_eq!(format!("{}", err), "python3 was killed after 1.5s");
+        let err = Error::PythonTimeout {
+            timeout: Duration::from_secs(2),
+            output: "rendering\n".to_string(),
+        };
+        assert_eq!(format!("{}", err), "python3 was killed after 2s:\nrendering\n");
+        let err = Error::from("some message");
+        assert_eq!(err, Error::Message("some message"));
+        assert_eq!(format!("{}", err), "some message");
+    }
+}
@@ -1,25 +1,139 @@
-use super::{StrError, PYTHON_HEADER};
+use super::{Error, PYTHON_HEADER};
+use std::env;
+use std::fmt;
 use std::fs;
 use std::fs::File;
-use std::io::Write;
-use std::path::Path;
-use std::process::Command;
+use std::io;
+use std::io::{BufRead, BufReader, Read, Write};
+use std::path::{Path, PathBuf};
+use std::process::{Child, Command, Stdio};
+use std::sync::mpsc::{self, RecvTimeoutError, Sender};
+use std::sync::Arc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// Name of the script shown in Python tracebacks when the commands are piped via stdin
+pub(crate) const STDIN: &str = "<stdin>";
+
+/// Name of the environment variable that selects the Python interpreter process-wide
+pub(crate) const PLOTPY_PYTHON: &str = "PLOTPY_PYTHON";
+
+/// Default Python interpreter
+const DEFAULT_PYTHON: &str = "python3";
+
+/// Holds the function that receives the output of the Python process line by line
+#[derive(Clone)]
+pub(crate) struct OutputCallback(pub(crate) Arc<dyn Fn(&str) + Send + Sync>);
+
+impl fmt::Debug for OutputCallback {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "OutputCallback")
+    }
+}
+
+impl PartialEq for OutputCallback {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl Eq for OutputCallback {}
+
+/// Holds the configuration of the Python interpreter used to run the scripts
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub(crate) struct Interpreter {
+    pub(crate) program: Option<String>, // interpreter; if None, use PLOTPY_PYTHON or python3
+    pub(crate) envs: Vec<(String, String)>, // extra environment variables
+    pub(crate) working_dir: Option<PathBuf>, // working directory of the Python process
+    pub(crate) timeout: Option<Duration>, // maximum time to wait for the process; if None, wait forever
+    pub(crate) callback: Option<OutputCallback>, // receives the output of the process line by line
+}
+
+impl Interpreter {
+    /// Returns the name (or path) of the Python interpreter
+    ///
+    /// The precedence is: the configured program, then the `PLOTPY_PYTHON` environment variable, then `python3`.
+    pub(crate) fn program(&self) -> String {
+        match &self.program {
+            Some(program) => program.clone(),
+            None => match env::var(PLOTPY_PYTHON) {
+                Ok(program) if program.trim().len() > 0 => program.trim().to_string(),
+                _ => DEFAULT_PYTHON.to_string(),
+            },
+        }
+    }
+
+    /// Returns a new command to run the interpreter with the extra environment variables and working directory
+    ///
+    /// If there is a callback, Python is run in unbuffered mode to stream its output.
+    pub(crate) fn command(&self) -> Command {
+        let mut command = Command::new(self.program());
+        if self.callback.is_some() {
+            command.env("PYTHONUNBUFFERED", "1"); // send the output as soon as it is printed
+        }
+        command.envs(self.envs.iter().map(|(k, v)| (k, v)));
+        if let Some(dir) = &self.working_dir {
+            command.current_dir(dir);
+        }
+        command
+    }
+}
 
 /// Writes a python file and call python3 on it
 ///
 /// # Arguments
 ///
 /// * `python_commands` - Python commands to be written to file
-/// * `output_dir` - Output directory to be created
-/// * `filename_py` - Filename with extension .py
+/// * `path` - Path of the .py file (the directory will be created)
+/// * `interpreter` - Configuration of the Python interpreter
+///
+/// # Note
+///
+/// The contents of [PYTHON_HEADER] are added at the beginning of the file.
+pub(crate) fn call_python3(python_commands: &String, path: &Path, interpreter: &Interpreter) -> Result<String, Error> {
+    // write file
+    write_python3(python_commands, path)?;
+
+    // execute file
+    let child = interpreter
+        .command()
+        .arg(path)
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|e| spawn_error(&interpreter.program(), e))?;
+    let (stdout, stderr, _) = wait_streaming(child, interpreter, true, path)?;
+
+    // results
+    let out = String::from_utf8_lossy(&stdout);
+    let err = String::from_utf8_lossy(&stderr);
+    let mut results = String::new();
+    if out.len() > 0 {
+        results.push_str(&out);
+    }
+    if err.len() > 0 {
+        results.push_str(&err)
+    }
+
+    // done
+    Ok(results)
+}
+
+/// Writes a python file with the header and commands (the directory is created if needed)
+///
+/// # Arguments
+///
+/// * `python_commands` - Python commands to be written to file
+/// * `path` - Path of the .py file
 ///
 /// # Note
 ///
 /// The contents of [PYTHON_HEADER] are added at the beginning of the file.
-pub(crate) fn call_python3(python_commands: &String, path: &Path) -> Result<String, StrError> {
+pub(crate) fn write_python3(python_commands: &str, path: &Path) -> Result<(), Error> {
     // create directory
     if let Some(p) = path.parent() {
-        fs::create_dir_all(p).map_err(|_| "cannot create directory")?;
+        fs::create_dir_all(p).map_err(|e| io_error("cannot create directory", p, e))?;
     }
 
     // combine header with commands
@@ -28,40 +142,373 @@ pub(crate) fn call_python3(python_commands: &String, path: &Path) -> Result<Stri
     contents.push_str(python_commands);
 
     // write file
-    let mut file = File::create(path).map_err(|_| "cannot create file")?;
-    file.write_all(contents.as_bytes()).map_err(|_| "cannot write file")?;
+    let mut file = File::create(path).map_err(|e| io_error("cannot create file", path, e))?;
+    file.write_all(contents.as_bytes())
+        .map_err(|e| io_error("cannot write file", path, e))?;
 
     // force sync
-    file.sync_all().map_err(|_| "cannot sync file")?;
+    file.sync_all().map_err(|e| io_error("cannot sync file", path, e))?;
+    Ok(())
+}
 
-    // execute file
-    let output = Command::new("python3")
-        .arg(path)
-        .output()
-        .map_err(|_| "cannot run python3")?;
+/// Pipes python commands to python3 via stdin and returns the captured output
+///
+/// # Arguments
+///
+/// * `python_commands` - Python commands to be sent to python3
+/// * `interpreter` - Configuration of the Python interpreter
+///
+/// # Output
+///
+/// Returns `(stdout, stderr, success)`, where `stdout` holds the raw bytes written by the script
+/// and `success` indicates whether python3 exited with a zero status.
+///
+/// # Note
+///
+/// The contents of [PYTHON_HEADER] are sent before the commands. No file is written.
+pub(crate) fn call_python3_piped(
+    python_commands: &str,
+    interpreter: &Interpreter,
+) -> Result<(Vec<u8>, String, bool), Error> {
+    // combine header with commands
+    let mut contents = String::new();
+    contents.push_str(PYTHON_HEADER);
+    contents.push_str(python_commands);
+
+    // spawn process
+    let mut child = interpreter
+        .command()
+        .arg("-")
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|e| spawn_error(&interpreter.program(), e))?;
+
+    // write commands in a separate thread to avoid blocking on full pipes
+    let mut stdin = child.stdin.take().ok_or("cannot open stdin of python3")?;
+    let writer = thread::spawn(move || stdin.write_all(contents.as_bytes()));
+
+    // wait for results
+    let (stdout, stderr, success) = wait_streaming(child, interpreter, false, Path::new(STDIN))?;
+    writer
+        .join()
+        .map_err(|_| "cannot join stdin writer")?
+        .map_err(|e| io_error("cannot write to python3", Path::new(STDIN), e))?;
 
     // results
-    let out = String::from_utf8(output.stdout).unwrap();
-    let err = String::from_utf8(output.stderr).unwrap();
-    let mut results = String::new();
-    if out.len() > 0 {
-        results.push_str(&out);
-    }
-    if err.len() > 0 {
-        results.push_str(&err)
+    let mut err = String::from_utf8_lossy(&stderr).to_string();
+    if !success && err.len() == 0 {
+        err.push_str("python3 exited with non-zero status\n");
     }
 
     // done
-    Ok(results)
+    Ok((stdout, err, success))
+}
+
+/// Waits for a process with piped stdout and stderr while sending its output to the callback, if any
+///
+/// # Arguments
+///
+/// * `child` - The running process
+/// * `interpreter` - Configuration of the Python interpreter with the timeout and the callback
+/// * `text_stdout` - Whether stdout holds text (sent to the callback) or binary data (not sent)
+/// * `path` - Path of the script (used in error messages)
+///
+/// # Output
+///
+/// Returns `(stdout, stderr, success)`. If the timeout is exceeded, the process is killed
+/// and [Error::PythonTimeout] is returned with the output captured so far.
+fn wait_streaming(
+    mut child: Child,
+    interpreter: &Interpreter,
+    text_stdout: bool,
+    path: &Path,
+) -> Result<(Vec<u8>, Vec<u8>, bool), Error> {
+    // read both streams in separate threads to avoid blocking on full pipes
+    let (sender, receiver) = mpsc::channel();
+    let stdout = child.stdout.take().ok_or("cannot open stdout of python3")?;
+    let stderr = child.stderr.take().ok_or("cannot open stderr of python3")?;
+    read_stream(stdout, 0, text_stdout, sender.clone());
+    read_stream(stderr, 1, true, sender);
+
+    // collect the output until both streams are closed
+    let deadline = interpreter.timeout.map(|t| Instant::now() + t);
+    let mut data = [Vec::new(), Vec::new()];
+    loop {
+        let received = match deadline {
+            Some(deadline) => receiver.recv_timeout(deadline.saturating_duration_since(Instant::now())),
+            None => receiver.recv().map_err(|_| RecvTimeoutError::Disconnected),
+        };
+        match received {
+            Ok((index, bytes)) => {
+                if let Some(callback) = &interpreter.callback {
+                    if index == 1 || text_stdout {
+                        let line = String::from_utf8_lossy(&bytes);
+                        (callback.0)(line.trim_end_matches(['\n', '\r']));
+                    }
+                }
+                data[index].extend(bytes);
+            }
+            Err(RecvTimeoutError::Disconnected) => break,
+            Err(RecvTimeoutError::Timeout) => return Err(kill(child, interpreter, data)),
+        }
+    }
+
+    // wait for the process to finish
+    let status = loop {
+        match child.try_wait() {
+            Ok(Some(status)) => break status,
+            Ok(None) => match deadline {
+                Some(deadline) if Instant::now() >= deadline => return Err(kill(child, interpreter, data)),
+                Some(_) => thread::sleep(Duration::from_millis(10)),
+                None => break child.wait().map_err(|e| io_error("cannot wait for python3", path, e))?,
+            },
+            Err(e) => return Err(io_error("cannot wait for python3", path, e)),
+        }
+    };
+    let [stdout, stderr] = data;
+    Ok((stdout, stderr, status.success()))
+}
+
+/// Reads a stream in a separate thread and sends the data (line by line if text) to the receiver
+fn read_stream<R>(stream: R, index: usize, text: bool, sender: Sender<(usize, Vec<u8>)>)
+where
+    R: Read + Send + 'static,
+{
+    thread::spawn(move || {
+        let mut reader = BufReader::new(stream);
+        loop {
+            let mut bytes = Vec::new();
+            let n = if text {
+                reader.read_until(b'\n', &mut bytes)
+            } else {
+                reader.read_to_end(&mut bytes)
+            };
+            match n {
+                Ok(n) if n > 0 => {
+                    if sender.send((index, bytes)).is_err() {
+                        break;
+                    }
+                }
+                _ => break,
+            }
+        }
+    });
+}
+
+/// Kills a process that has exceeded the timeout and returns the corresponding error
+fn kill(mut child: Child, interpreter: &Interpreter, data: [Vec<u8>; 2]) -> Error {
+    child.kill().unwrap_or(());
+    child.wait().unwrap_or_default();
+    let mut output = String::from_utf8_lossy(&data[0]).to_string();
+    output.push_str(&String::from_utf8_lossy(&data[1]));
+    Error::PythonTimeout {
+        timeout: interpreter.timeout.unwrap_or_default(),
+        output,
+    }
+}
+
+/// Writes the large NumPy arrays of the lowered primitives to binary .npy files
+///
+/// The files are written next to the figure as `<stem>_data<k>.npy` (see [crate::Plot::set_npy_threshold]).
+pub(crate) struct NpyFiles {
+    stem: PathBuf,        // the .npy files are named `<stem>_data<k>.npy`
+    threshold: usize,     // minimum number of values of an array to be written to a .npy file
+    count: usize,         // number of .npy files written so far
+    error: Option<Error>, // first error, if any
+}
+
+impl NpyFiles {
+    /// Creates a new sink of .npy files
+    ///
+    /// # Arguments
+    ///
+    /// * `fig_path` - Path of the figure; the .npy files are written next to it as `<stem>_data<k>.npy`
+    /// * `threshold` - Minimum number of values of an array to be written to a .npy file
+    pub(crate) fn new(fig_path: &Path, threshold: usize) -> Self {
+        NpyFiles {
+            stem: fig_path.with_extension(""),
+            threshold,
+            count: 0,
+            error: None,
+        }
+    }
+
+    /// Writes an array to a .npy file and the command to load it
+    ///
+    /// # Arguments
+    ///
+    /// * `buf` - Buffer receiving the command `name=np.load('<stem>_data<k>.npy')`
+    /// * `name` - Name of the Python variable
+    /// * `shape` - Shape of the array
+    /// * `values` - Function returning the values of the array (row-major)
+    ///
+    /// # Output
+    ///
+    /// Returns false if the array must be written to the script instead, i.e., if the array
+    /// is smaller than the threshold or if an error occurred (see [NpyFiles::finish]).
+    pub(crate) fn write_array<F>(&mut self, buf: &mut String, name: &str, shape: &[usize], values: F) -> bool
+    where
+        F: FnOnce() -> Vec<f64>,
+    {
+        if self.error.is_some() || shape.iter().product::<usize>() < self.threshold {
+            return false;
+        }
+        let mut npy_path = self.stem.clone().into_os_string();
+        npy_path.push(format!("_data{}.npy", self.count));
+        let npy_path = PathBuf::from(npy_path);
+        match write_npy(&npy_path, shape, &values()) {
+            Ok(()) => {
+                buf.push_str(&format!(
+                    "{}=np.load({})\n",
+                    name,
+                    python_string(&npy_path.to_string_lossy())
+                ));
+                self.count += 1;
+                true
+            }
+            Err(err) => {
+                self.error = Some(err);
+                false
+            }
+        }
+    }
+
+    /// Returns the first error that occurred while writing the .npy files, if any
+    pub(crate) fn finish(self) -> Result<(), Error> {
+        match self.error {
+            Some(err) => Err(err),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Writes an array of f64 to a .npy file (version 1.0, little-endian, C order)
+pub(crate) fn write_npy(path: &Path, shape: &[usize], values: &[f64]) -> Result<(), Error> {
+    let dims: Vec<String> = shape.iter().map(|d| d.to_string()).collect();
+    let dims = if dims.len() == 1 {
+        format!("{},", dims[0])
+    } else {
+        dims.join(", ")
+    };
+    let mut header = format!("{{'descr': '<f8', 'fortran_order': False, 'shape': ({}), }}", dims);
+    while (10 + header.len() + 1) % 64 != 0 {
+        header.push(' ');
+    }
+    header.push('\n');
+    let mut bytes = Vec::with_capacity(10 + header.len() + 8 * values.len());
+    bytes.extend_from_slice(b"\x93NUMPY\x01\x00");
+    bytes.extend_from_slice(&(header.len() as u16).to_le_bytes());
+    bytes.extend_from_slice(header.as_bytes());
+    for v in values {
+        bytes.extend_from_slice(&v.to_le_bytes());
+    }
+    fs::write(path, bytes).map_err(|e| io_error("cannot write file", path, e))
+}
+
+/// Returns a Python string literal with the quotes and backslashes escaped
+fn python_string(text: &str) -> String {
+    let mut literal = String::with_capacity(text.len() + 2);
+    literal.push('\'');
+    for c in text.chars() {
+        match c {
+            '\\' | '\'' => {
+                literal.push('\\');
+                literal.push(c);
+            }
+            '\n' => literal.push_str("\\n"),
+            '\r' => literal.push_str("\\r"),
+            _ => literal.push(c),
+        }
+    }
+    literal.push('\'');
+    literal
+}
+
+/// Computes the 64-bit FNV-1a hash of the concatenated strings
+///
+/// The hash does not depend on the platform or the version of Rust; thus, it may be saved to files.
+pub(crate) fn fnv1a_hash(strings: &[&str]) -> u64 {
+    const OFFSET: u64 = 0xcbf29ce484222325;
+    const PRIME: u64 = 0x100000001b3;
+    let mut hash = OFFSET;
+    for byte in strings.iter().flat_map(|s| s.bytes()) {
+        hash ^= byte as u64;
+        hash = hash.wrapping_mul(PRIME);
+    }
+    hash
+}
+
+/// Runs the interpreter to find the versions of Python, Matplotlib, and NumPy
+///
+/// # Output
+///
+/// Returns `(python, matplotlib, numpy)`
+///
+/// # Note
+///
+/// The [PYTHON_HEADER] is not used here; thus, a missing module yields [Error::PythonFailed]
+/// with `exception` equal to `ModuleNotFoundError`.
+pub(crate) fn python_versions(interpreter: &Interpreter) -> Result<(String, String, String), Error> {
+    let script = "import sys\n\
+                  print(sys.version.split()[0])\n\
+                  import matplotlib\n\
+                  print(matplotlib.__version__)\n\
+                  import numpy\n\
+                  print(numpy.__version__)\n";
+    let output = interpreter
+        .command()
+        .arg("-c")
+        .arg(script)
+        .output()
+        .map_err(|e| spawn_error(&interpreter.program(), e))?;
+    let out = String::from_utf8_lossy(&output.stdout);
+    let versions: Vec<&str> = out.lines().map(|l| l.trim()).collect();
+    if !output.status.success() || versions.len() != 3 {
+        let mut results = out.to_string();
+        results.push_str(&String::from_utf8_lossy(&output.stderr));
+        return Err(Error::python_failed(results, "<string>"));
+    }
+    Ok((
+        versions[0].to_string(),
+        versions[1].to_string(),
+        versions[2].to_string(),
+    ))
+}
+
+/// Converts an IO error into Error with the path of the file
+pub(crate) fn io_error(action: &'static str, path: &Path, err: io::Error) -> Error {
+    Error::Io {
+        action,
+        path: path.to_path_buf(),
+        kind: err.kind(),
+    }
+}
+
+/// Converts an error from spawning a process into Error
+pub(crate) fn spawn_error(program: &str, err: io::Error) -> Error {
+    if err.kind() == io::ErrorKind::NotFound {
+        Error::PythonNotFound(program.to_string())
+    } else {
+        io_error("cannot run", Path::new(program), err)
+    }
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
 #[cfg(test)]
 mod tests {
-    use super::{call_python3, PYTHON_HEADER};
+    use super::{
+        call_python3, call_python3_piped, fnv1a_hash, io_error, python_string, python_versions, spawn_error,
+        Interpreter, NpyFiles, OutputCallback, PYTHON_HEADER,
+    };
+    use crate::Error;
     use std::fs;
-    use std::path::Path;
+    use std::io;
+    use std::path::{Path, PathBuf};
+    use std::sync::{Arc, Mutex};
+    use std::time::{Duration, Instant};
 
     const OUT_DIR: &str = "/tmp/plotpy/unit_tests";
 
@@ -69,7 +516,7 @@ mod tests {
     fn call_python3_works() {
         let commands = "print(\"Python says: Hello World!\")".to_string();
         let path = Path::new("call_python3_works.py");
-        let output = call_python3(&commands, &path).unwrap();
+        let output = call_python3(&commands, &path, &Interpreter::default()).unwrap();
         let data = fs::read_to_string(&path).map_err(|_| "cannot read test file").unwrap();
         let mut correct = String::from(PYTHON_HEADER);
         correct.push_str(&commands);
@@ -81,7 +528,7 @@ mod tests {
     fn call_python3_create_dir_works() {
         let commands = "print(\"Python says: Hello World!\")".to_string();
         let path = Path::new(OUT_DIR).join("call_python3_works.py");
-        let output = call_python3(&commands, &path).unwrap();
+        let output = call_python3(&commands, &path, &Interpreter::default()).unwrap();
         let data = fs::read_to_string(&path).map_err(|_| "cannot read test file").unwrap();
         let mut correct = String::from(PYTHON_HEADER);
         correct.push_str(&commands);
@@ -94,7 +541,7 @@ mod tests {
         let path = Path::new(OUT_DIR).join("call_python3_twice_works.py");
         // first
         let commands_first = "print(\"Python says: Hello World!\")".to_string();
-        let output_first = call_python3(&commands_first, &path).unwrap();
+        let output_first = call_python3(&commands_first, &path, &Interpreter::default()).unwrap();
         let data_first = fs::read_to_string(&path).map_err(|_| "cannot read test file").unwrap();
         let mut correct_first = String::from(PYTHON_HEADER);
         correct_first.push_str(&commands_first);
@@ -102,11 +549,243 @@ mod tests {
         assert_eq!(output_first, "Python says: Hello World!\n");
         // second
         let commands_second = "print(\"Python says: Hello World! again\")".to_string();
-        let output_second = call_python3(&commands_second, &path).unwrap();
+        let output_second = call_python3(&commands_second, &path, &Interpreter::default()).unwrap();
         let data_second = fs::read_to_string(&path).map_err(|_| "cannot read test file").unwrap();
         let mut correct_second = String::from(PYTHON_HEADER);
         correct_second.push_str(&commands_second);
         assert_eq!(data_second, correct_second);
         assert_eq!(output_second, "Python says: Hello World! again\n");
     }
+
+    #[test]
+    fn call_python3_piped_works() {
+        let commands = "import sys\nsys.stdout.buffer.write(b'\\x00\\x01binary')\n";
+        let (out, err, success) = call_python3_piped(commands, &Interpreter::default()).unwrap();
+        assert_eq!(err, "");
+        assert_eq!(out, b"\x00\x01binary");
+        assert!(success);
+    }
+
+    #[test]
+    fn call_python3_piped_captures_errors() {
+        let commands = "raise ValueError('oops')\n";
+        let (out, err, success) = call_python3_piped(commands, &Interpreter::default()).unwrap();
+        assert_eq!(out.len(), 0);
+        assert!(err.contains("ValueError: oops"));
+        assert!(!success);
+    }
+
+    #[test]
+    fn timeout_kills_python() {
+        let interpreter = Interpreter {
+            timeout: Some(Duration::from_millis(500)),
+            ..Default::default()
+        };
+        let path = Path::new(OUT_DIR).join("timeout_kills_python.py");
+        let commands = "import sys, time\nprint('started')\nsys.stdout.flush()\ntime.sleep(30)\n".to_string();
+        let start = Instant::now();
+        let res = call_python3(&commands, &path, &interpreter);
+        assert!(start.elapsed() < Duration::from_secs(20));
+        assert_eq!(
+            res.err(),
+            Some(Error::PythonTimeout {
+                timeout: Duration::from_millis(500),
+                output: "started\n".to_string(),
+            })
+        );
+        let res = call_python3_piped("import time\ntime.sleep(30)\n", &interpreter);
+        assert!(matches!(res, Err(Error::PythonTimeout { .. })));
+        let res = call_python3(&"print('fast')\n".to_string(), &path, &interpreter);
+        assert_eq!(res, Ok("fast\n".to_string()));
+    }
+
+    #[test]
+    fn callback_receives_output() {
+        let lines = Arc::new(Mutex::new(Vec::new()));
+        let copy = lines.clone();
+        let interpreter = Interpreter {
+            callback: Some(OutputCallback(Arc::new(move |line: &str| {
+                copy.lock().unwrap().push(line.to_string())
+            }))),
+            ..Default::default()
+        };
+        let path = Path::new(OUT_DIR).join("callback_receives_output.py");
+        let commands = "import sys\nprint('step 1')\nprint('step 2')\nsys.stderr.write('warning\\n')\n".to_string();
+        let output = call_python3(&commands, &path, &interpreter).unwrap();
+        assert_eq!(output, "step 1\nstep 2\nwarning\n");
+        let mut received = lines.lock().unwrap().clone();
+        received.sort();
+        assert_eq!(received, &["step 1", "step 2", "warning"]);
+
+        // stdout holds binary data in piped mode
+        lines.lock().unwrap().clear();
+        let commands = "import sys\nsys.stdout.write('data')\nsys.stderr.write('warning\\n')\n";
+        let (out, err, _) = call_python3_piped(commands, &interpreter).unwrap();
+        assert_eq!(out, b"data");
+        assert_eq!(err, "warning\n");
+        assert_eq!(*lines.lock().unwrap(), &["warning"]);
+    }
+
+    #[test]
+    fn interpreter_works() {
+        let default = Interpreter::default();
+        assert!(default.program().len() > 0);
+        let interpreter = Interpreter {
+            program: Some("/opt/venv/bin/python".to_string()),
+            envs: vec![("MPLBACKEND".to_string(), "Agg".to_string())],
+            working_dir: Some(PathBuf::from("/tmp")),
+            ..Default::default()
+        };
+        assert_eq!(interpreter.program(), "/opt/venv/bin/python");
+        let command = interpreter.command();
+        assert_eq!(command.get_program(), "/opt/venv/bin/python");
+        assert_eq!(command.get_current_dir(), Some(Path::new("/tmp")));
+        let envs: Vec<_> = command.get_envs().collect();
+        assert_eq!(envs.len(), 1);
+        assert_eq!(envs[0].0, "MPLBACKEND");
+    }
+
+    #[test]
+    fn interpreter_configuration_is_used() {
+        let interpreter = Interpreter {
+            program: None,
+            envs: vec![("PLOTPY_TEST_VARIABLE".to_string(), "hello".to_string())],
+            working_dir: Some(PathBuf::from("/tmp")),
+            ..Default::default()
+        };
+        let commands = "import os\nprint(os.environ['PLOTPY_TEST_VARIABLE'], os.getcwd())\n";
+        let (out, err, _) = call_python3_piped(commands, &interpreter).unwrap();
+        assert_eq!(err, "");
+        assert_eq!(String::from_utf8(out).unwrap(), "hello /tmp\n");
+    }
+
+    #[test]
+    fn missing_interpreter_is_reported() {
+        let interpreter = Interpreter {
+            program: Some("python_does_not_exist".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(
+            call_python3_piped("print(1)", &interpreter).err(),
+            Some(Error::PythonNotFound("python_does_not_exist".to_string()))
+        );
+        assert_eq!(
+            python_versions(&interpreter).err(),
+            Some(Error::PythonNotFound("python_does_not_exist".to_string()))
+        );
+    }
+
+    #[test]
+    fn python_versions_works() {
+        let (python, matplotlib, numpy) = python_versions(&Interpreter::default()).unwrap();
+        assert!(python.starts_with('3'));
+        assert!(matplotlib.len() > 0);
+        assert!(numpy.len() > 0);
+    }
+
+    #[test]
+    fn fnv1a_hash_works() {
+        assert_eq!(fnv1a_hash(&[]), 0xcbf29ce484222325);
+        assert_eq!(fnv1a_hash(&["a"]), 0xaf63dc4c8601ec8c);
+        assert_eq!(fnv1a_hash(&["foobar"]), 0x85944171f73967e8);
+        assert_eq!(fnv1a_hash(&["foo", "bar"]), fnv1a_hash(&["foobar"]));
+    }
+
+    #[test]
+    fn python_string_works() {
+        assert_eq!(python_string("/tmp/a.npy"), "'/tmp/a.npy'");
+        assert_eq!(python_string("it's"), "'it\\'s'");
+        assert_eq!(python_string("C:\\a\nb"), "'C:\\\\a\\nb'");
+    }
+
+    #[test]
+    fn npy_files_write_array_works() {
+        let x = [0.1, 1.0 / 3.0, f64::MAX, f64::MIN_POSITIVE, -0.0, 1e-300];
+        let fig_path = Path::new(OUT_DIR).join("npy_files_write_array_it's.svg");
+        fs::create_dir_all(OUT_DIR).unwrap();
+        let mut npy = NpyFiles::new(&fig_path, 3);
+        let mut res = String::new();
+        res.push_str("plt.figure()\n");
+        assert_eq!(npy.write_array(&mut res, "x", &[6], || x.to_vec()), true);
+        assert_eq!(npy.write_array(&mut res, "y", &[2], || vec![1.0, 2.0]), false);
+        assert_eq!(
+            npy.write_array(&mut res, "z", &[2, 3], || vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0]),
+            true
+        );
+        npy.finish().unwrap();
+        let npy_0 = Path::new(OUT_DIR).join("npy_files_write_array_it's_data0.npy");
+        let npy_1 = Path::new(OUT_DIR).join("npy_files_write_array_it's_data1.npy");
+        assert_eq!(
+            res,
+            format!(
+                "plt.figure()\n\
+                 x=np.load('{}')\n\
+                 z=np.load('{}')\n",
+                npy_0.to_string_lossy().replace("'", "\\'"),
+                npy_1.to_string_lossy().replace("'", "\\'")
+            )
+        );
+
+        // check the 1D file (exact round-trip)
+        let bytes = fs::read(&npy_0).unwrap();
+        assert_eq!(&bytes[..8], b"\x93NUMPY\x01\x00");
+        let header_len = u16::from_le_bytes([bytes[8], bytes[9]]) as usize;
+        assert_eq!((10 + header_len) % 64, 0);
+        let header = String::from_utf8_lossy(&bytes[10..10 + header_len]);
+        assert_eq!(
+            header.trim_end(),
+            "{'descr': '<f8', 'fortran_order': False, 'shape': (6,), }"
+        );
+        let values: Vec<u64> = bytes[10 + header_len..]
+            .chunks(8)
+            .map(|c| f64::from_le_bytes(c.try_into().unwrap()).to_bits())
+            .collect();
+        assert_eq!(values, x.iter().map(|v| v.to_bits()).collect::<Vec<_>>());
+
+        // check the 2D file
+        let bytes = fs::read(&npy_1).unwrap();
+        let header_len = u16::from_le_bytes([bytes[8], bytes[9]]) as usize;
+        let header = String::from_utf8_lossy(&bytes[10..10 + header_len]);
+        assert!(header.contains("'shape': (2, 3)"));
+        assert_eq!(bytes.len(), 10 + header_len + 6 * 8);
+    }
+
+    #[test]
+    fn npy_files_capture_errors() {
+        let fig_path = Path::new("/dev/null/plotpy/npy_files_capture_errors.svg");
+        let mut npy = NpyFiles::new(fig_path, 1);
+        let mut buf = String::new();
+        assert_eq!(npy.write_array(&mut buf, "x", &[1], || vec![1.0]), false);
+        assert_eq!(npy.write_array(&mut buf, "y", &[1], || vec![1.0]), false);
+        assert_eq!(buf, "");
+        assert!(matches!(npy.finish(), Err(Error::Io { .. })));
+    }
+
+    #[test]
+    fn errors_are_converted() {
+        let err = io_error(
+            "cannot create file",
+            Path::new("/tmp/a.py"),
+            io::ErrorKind::NotFound.into(),
+        );
+        assert_eq!(
+            err,
+            Error::Io {
+                action: "cannot create file",
+                path: Path::new("/tmp/a.py").to_path_buf(),
+                kind: io::ErrorKind::NotFound,
+            }
+        );
+        let err = spawn_error("python9", io::ErrorKind::NotFound.into());
+        assert_eq!(err, Error::PythonNotFound("python9".to_string()));
+        let err = spawn_error("python9", io::ErrorKind::PermissionDenied.into());
+        assert_eq!(
+            err,
+            Error::Io {
+                action: "cannot run",
+                path: Path::new("python9").to_path_buf(),
+                kind: io::ErrorKind::PermissionDenied,
+            }
+        );
+    }
 }
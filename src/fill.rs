@@ -0,0 +1,535 @@
+use super::{
+    matrix_to_array, matrix_to_f64, vector_to_array, vector_to_f64, vector_to_strings, AsMatrix, AsVector, Error,
+    GraphMaker, Primitive, Script,
+};
+use num_traits::AsPrimitive;
+use std::fmt::Write;
+
+/// Generates filled areas between two curves (or between a curve and a constant value)
+///
+/// # Notes
+///
+/// * This struct corresponds to the **fill_between**, **fill_betweenx**, and **stackplot** functions of Matplotlib.
+/// * The areas may be restricted to the regions selected by a mask (see [Fill::draw_where]).
+///
+/// # Example
+///
+/// ```
+/// use plotpy::{Error, Fill, Plot};
+///
+/// fn main() -> Result<(), Error> {
+///     // data
+///     let x: Vec<f64> = (0..61).map(|i| (i as f64) / 10.0).collect();
+///     let y1: Vec<f64> = x.iter().map(|x| f64::sin(*x)).collect();
+///     let y2: Vec<f64> = x.iter().map(|x| 0.5 * f64::cos(*x)).collect();
+///     let mask: Vec<bool> = y1.iter().zip(&y2).map(|(a, b)| a > b).collect();
+///
+///     // area between the curves where y1 > y2
+///     let mut fill_above = Fill::new();
+///     fill_above
+///         .set_face_color("#9de19a")
+///         .set_label("y1 > y2")
+///         .set_interpolate(true);
+///     fill_above.draw_where(&x, &y1, &y2, &mask)?;
+///
+///     // area between the curves where y1 <= y2
+///     let inverse: Vec<bool> = mask.iter().map(|m| !m).collect();
+///     let mut fill_below = Fill::new();
+///     fill_below
+///         .set_face_color("none")
+///         .set_edge_color("#98a7f2")
+///         .set_hatch("//")
+///         .set_label("y1 <= y2")
+///         .set_interpolate(true);
+///     fill_below.draw_where(&x, &y1, &y2, &inverse)?;
+///
+///     // add fills to plot
+///     let mut plot = Plot::new();
+///     plot.add(&fill_above).add(&fill_below).grid_labels_legend("x", "y");
+///
+///     // save figure
+///     plot.save("/tmp/plotpy/doc_tests/doc_fill.svg")?;
+///     Ok(())
+/// }
+/// ```
+///
+/// See also integration test in the **tests** directory.
+pub struct Fill {
+    style: FillStyle,           // Style
+    buffer: String,             // buffer
+    primitives: Vec<Primitive>, // primitives
+}
+
+/// Holds the style of a filled area (see [Fill])
+///
+/// The style is recorded by the [Primitive] drawn by [Fill] and may be modified afterwards.
+#[derive(Clone, Debug, PartialEq)]
+pub struct FillStyle {
+    pub face_color: String,  // Color of the area
+    pub edge_color: String,  // Color of the boundary of the area
+    pub line_width: f64,     // Width of the boundary of the area
+    pub alpha: f64,          // Opacity of the area (0, 1]. A<1e-14 => A=1.0
+    pub hatch: String,       // Hatch pattern, e.g., "//", "xx"
+    pub label: String,       // Name of the area in the legend
+    pub interpolate: bool,   // Computes the intersection points when using a mask
+    pub horizontal: bool,    // Fills between two vertical curves (fill_betweenx)
+    pub colors: Vec<String>, // Colors of each series of a stacked area plot
+    pub labels: Vec<String>, // Labels of each series of a stacked area plot
+    pub baseline: String,    // Baseline of a stacked area plot, e.g., "zero", "sym", "wiggle"
+}
+
+impl Fill {
+    /// Creates a new Fill object
+    pub fn new() -> Self {
+        Fill {
+            style: FillStyle::default(),
+            buffer: String::new(),
+            primitives: Vec::new(),
+        }
+    }
+
+    /// Draws the area between two curves
+    ///
+    /// # Input
+    ///
+    /// * `x` - abscissa values (or ordinate values if horizontal)
+    /// * `y1` - values of the first curve
+    /// * `y2` - values of the second curve
+    ///
+    /// # Notes
+    ///
+    /// * The type `U` of the input arrays must be a number.
+    ///
+    pub fn draw<'a, T, U>(&mut self, x: &'a T, y1: &'a T, y2: &'a T)
+    where
+        T: AsVector<'a, U>,
+        U: 'a + AsPrimitive<f64>,
+    {
+        let (x, y1, y2) = (vector_to_f64(x), vector_to_f64(y1), vector_to_f64(y2));
+        let style = self.style.clone();
+        self.push(Primitive::Fill {
+            x,
+            y1,
+            y2,
+            mask: Vec::new(),
+            style,
+        });
+    }
+
+    /// Draws the area between a curve and a constant value (e.g., zero)
+    ///
+    /// # Input
+    ///
+    /// * `x` - abscissa values (or ordinate values if horizontal)
+    /// * `y` - values of the curve
+    /// * `value` - the constant value (baseline)
+    ///
+    /// # Notes
+    ///
+    /// * The type `U` of the input arrays must be a number.
+    ///
+    pub fn draw_to_value<'a, T, U>(&mut self, x: &'a T, y: &'a T, value: f64)
+    where
+        T: AsVector<'a, U>,
+        U: 'a + AsPrimitive<f64>,
+    {
+        let (x, y1) = (vector_to_f64(x), vector_to_f64(y));
+        let y2 = vec![value; x.len()];
+        let style = self.style.clone();
+        self.push(Primitive::Fill {
+            x,
+            y1,
+            y2,
+            mask: Vec::new(),
+            style,
+        });
+    }
+
+    /// Draws the area between two curves only where the mask is true
+    ///
+    /// # Input
+    ///
+    /// * `x` - abscissa values (or ordinate values if horizontal)
+    /// * `y1` - values of the first curve
+    /// * `y2` - values of the second curve
+    /// * `mask` - selects the regions to be filled; must have the same length as x
+    ///
+    /// # Notes
+    ///
+    /// * The type `U` of the input arrays must be a number.
+    /// * Use [Fill::set_interpolate] to fill up to the intersection of the curves.
+    ///
+    pub fn draw_where<'a, T, U>(&mut self, x: &'a T, y1: &'a T, y2: &'a T, mask: &[bool]) -> Result<(), Error>
+    where
+        T: AsVector<'a, U>,
+        U: 'a + AsPrimitive<f64>,
+    {
+        let (x, y1, y2) = (vector_to_f64(x), vector_to_f64(y1), vector_to_f64(y2));
+        if mask.len() != x.len() {
+            return Err(Error::InvalidInput {
+                argument: "mask",
+                message: "mask.len() must be equal to x.len()",
+            });
+        }
+        let style = self.style.clone();
+        self.push(Primitive::Fill {
+            x,
+            y1,
+            y2,
+            mask: mask.to_vec(),
+            style,
+        });
+        Ok(())
+    }
+
+    /// Draws a stacked area plot
+    ///
+    /// # Input
+    ///
+    /// * `x` - abscissa values
+    /// * `y` - matrix with one series per row; each row has the same length as x
+    ///
+    /// # Notes
+    ///
+    /// * The type `U` of the input arrays must be a number.
+    /// * The colors, labels, and baseline are given by [Fill::set_colors], [Fill::set_labels],
+    ///   and [Fill::set_baseline]; the horizontal and mask options are not available.
+    ///
+    pub fn draw_stacked<'a, T, M, U>(&mut self, x: &'a T, y: &'a M)
+    where
+        T: AsVector<'a, U>,
+        M: AsMatrix<'a, U>,
+        U: 'a + AsPrimitive<f64>,
+    {
+        let (x, y) = (vector_to_f64(x), matrix_to_f64(y));
+        let style = self.style.clone();
+        self.push(Primitive::StackedFill { x, y, style });
+    }
+
+    /// Sets the color of the area
+    pub fn set_face_color(&mut self, color: &str) -> &mut Self {
+        self.style.face_color = String::from(color);
+        self
+    }
+
+    /// Sets the color of the boundary of the area
+    pub fn set_edge_color(&mut self, color: &str) -> &mut Self {
+        self.style.edge_color = String::from(color);
+        self
+    }
+
+    /// Sets the width of the boundary of the area
+    pub fn set_line_width(&mut self, width: f64) -> &mut Self {
+        self.style.line_width = width;
+        self
+    }
+
+    /// Sets the opacity of the area (0, 1]. A<1e-14 => A=1.0
+    pub fn set_alpha(&mut self, alpha: f64) -> &mut Self {
+        self.style.alpha = alpha;
+        self
+    }
+
+    /// Sets the hatch pattern
+    ///
+    /// Options:
+    ///
+    /// * "`/`", "`\\`", "`|`", "`-`", "`+`", "`x`", "`o`", "`O`", "`.`", "`*`"
+    /// * Repeating a character increases the density, e.g., "`//`"
+    /// * As defined in <https://matplotlib.org/stable/gallery/shapes_and_collections/hatch_style_reference.html>
+    pub fn set_hatch(&mut self, hatch: &str) -> &mut Self {
+        self.style.hatch = String::from(hatch);
+        self
+    }
+
+    /// Sets the name of the area in the legend
+    pub fn set_label(&mut self, label: &str) -> &mut Self {
+        self.style.label = String::from(label);
+        self
+    }
+
+    /// Sets the option to compute the intersection points of the curves when using a mask
+    pub fn set_interpolate(&mut self, flag: bool) -> &mut Self {
+        self.style.interpolate = flag;
+        self
+    }
+
+    /// Sets the option to fill between two vertical curves (x as a function of y)
+    pub fn set_horizontal(&mut self, flag: bool) -> &mut Self {
+        self.style.horizontal = flag;
+        self
+    }
+
+    /// Sets the colors of each series of a stacked area plot
+    pub fn set_colors(&mut self, colors: &[&str]) -> &mut Self {
+        self.style.colors = colors.iter().map(|color| color.to_string()).collect();
+        self
+    }
+
+    /// Sets the labels of each series of a stacked area plot
+    pub fn set_labels(&mut self, labels: &[&str]) -> &mut Self {
+        self.style.labels = labels.iter().map(|label| label.to_string()).collect();
+        self
+    }
+
+    /// Sets the baseline of a stacked area plot
+    ///
+    /// Options:
+    ///
+    /// * "zero", "sym", "wiggle", "weighted_wiggle"
+    /// * As defined in <https://matplotlib.org/stable/api/_as_gen/matplotlib.pyplot.stackplot.html>
+    pub fn set_baseline(&mut self, baseline: &str) -> &mut Self {
+        self.style.baseline = String::from(baseline);
+        self
+    }
+
+    /// Writes the primitive to the buffer and records it
+    fn push(&mut self, primitive: Primitive) {
+        primitive.write_python(&mut self.buffer);
+        self.primitives.push(primitive);
+    }
+}
+
+impl Default for FillStyle {
+    fn default() -> Self {
+        FillStyle {
+            face_color: String::new(),
+            edge_color: String::new(),
+            line_width: 0.0,
+            alpha: 0.0,
+            hatch: String::new(),
+            label: String::new(),
+            interpolate: false,
+            horizontal: false,
+            colors: Vec::new(),
+            labels: Vec::new(),
+            baseline: String::new(),
+        }
+    }
+}
+
+impl FillStyle {
+    /// Returns options for fill_between
+    pub(crate) fn options(&self) -> String {
+        let mut opt = String::new();
+        if self.face_color != "" {
+            write!(&mut opt, ",facecolor='{}'", self.face_color).unwrap();
+        }
+        if self.label != "" {
+            write!(&mut opt, ",label=r'{}'", self.label).unwrap();
+        }
+        if self.interpolate {
+            write!(&mut opt, ",interpolate=True").unwrap();
+        }
+        opt.push_str(&self.options_shared());
+        opt
+    }
+
+    /// Returns options for stackplot
+    pub(crate) fn options_stacked(&self) -> String {
+        let mut opt = String::new();
+        if self.colors.len() > 0 {
+            write!(&mut opt, ",colors=colors").unwrap();
+        }
+        if self.labels.len() > 0 {
+            write!(&mut opt, ",labels=labels").unwrap();
+        }
+        if self.baseline != "" {
+            write!(&mut opt, ",baseline='{}'", self.baseline).unwrap();
+        }
+        opt.push_str(&self.options_shared());
+        opt
+    }
+
+    /// Returns the options shared by fill_between and stackplot
+    fn options_shared(&self) -> String {
+        let mut opt = String::new();
+        if self.edge_color != "" {
+            write!(&mut opt, ",edgecolor='{}'", self.edge_color).unwrap();
+        }
+        if self.line_width > 0.0 {
+            write!(&mut opt, ",linewidth={}", self.line_width).unwrap();
+        }
+        if self.alpha > 0.0 {
+            write!(&mut opt, ",alpha={}", self.alpha).unwrap();
+        }
+        if self.hatch != "" {
+            write!(&mut opt, ",hatch='{}'", self.hatch).unwrap();
+        }
+        opt
+    }
+}
+
+/// Writes the Python commands of a filled area (see [Primitive::Fill])
+pub(crate) fn write_fill(buf: &mut Script, x: &[f64], y1: &[f64], y2: &[f64], mask: &[bool], style: &FillStyle) {
+    vector_to_array(buf, "x", &x);
+    vector_to_array(buf, "y1", &y1);
+    vector_to_array(buf, "y2", &y2);
+    let mut opt_where = String::new();
+    if mask.len() > 0 {
+        write!(buf, "where=np.array([").unwrap();
+        for m in mask {
+            write!(buf, "{},", if *m { "True" } else { "False" }).unwrap();
+        }
+        write!(buf, "])\n").unwrap();
+        opt_where.push_str(",where=where");
+    }
+    let function = if style.horizontal {
+        "fill_betweenx"
+    } else {
+        "fill_between"
+    };
+    write!(buf, "plt.{}(x,y1,y2{}{})\n", function, opt_where, style.options()).unwrap();
+}
+
+/// Writes the Python commands of a stacked area plot (see [Primitive::StackedFill])
+pub(crate) fn write_stacked_fill(buf: &mut Script, x: &[f64], y: &Vec<Vec<f64>>, style: &FillStyle) {
+    vector_to_array(buf, "x", &x);
+    matrix_to_array(buf, "y", y);
+    if style.colors.len() > 0 {
+        vector_to_strings(buf, "colors", style.colors.as_slice());
+    }
+    if style.labels.len() > 0 {
+        vector_to_strings(buf, "labels", style.labels.as_slice());
+    }
+    write!(buf, "plt.stackplot(x,y{})\n", style.options_stacked()).unwrap();
+}
+
+impl GraphMaker for Fill {
+    fn get_buffer<'a>(&'a self) -> &'a String {
+        &self.buffer
+    }
+    fn clear_buffer(&mut self) {
+        self.buffer.clear();
+        self.primitives.clear();
+    }
+    fn get_primitives(&self) -> &[Primitive] {
+        &self.primitives
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::Fill;
+    use crate::{Error, GraphMaker, Primitive};
+
+    #[test]
+    fn new_works() {
+        let fill = Fill::new();
+        assert_eq!(fill.style.face_color.len(), 0);
+        assert_eq!(fill.style.edge_color.len(), 0);
+        assert_eq!(fill.style.line_width, 0.0);
+        assert_eq!(fill.style.alpha, 0.0);
+        assert_eq!(fill.style.hatch.len(), 0);
+        assert_eq!(fill.style.label.len(), 0);
+        assert_eq!(fill.style.interpolate, false);
+        assert_eq!(fill.style.horizontal, false);
+        assert_eq!(fill.style.colors.len(), 0);
+        assert_eq!(fill.style.labels.len(), 0);
+        assert_eq!(fill.style.baseline.len(), 0);
+        assert_eq!(fill.buffer.len(), 0);
+        assert_eq!(fill.primitives.len(), 0);
+    }
+
+    #[test]
+    fn options_works() {
+        let mut fill = Fill::new();
+        fill.set_face_color("red")
+            .set_edge_color("black")
+            .set_line_width(2.0)
+            .set_alpha(0.5)
+            .set_hatch("//")
+            .set_label("area")
+            .set_interpolate(true)
+            .set_colors(&["red", "green"])
+            .set_labels(&["a", "b"])
+            .set_baseline("sym");
+        assert_eq!(
+            fill.style.options(),
+            ",facecolor='red'\
+             ,label=r'area'\
+             ,interpolate=True\
+             ,edgecolor='black'\
+             ,linewidth=2\
+             ,alpha=0.5\
+             ,hatch='//'"
+        );
+        assert_eq!(
+            fill.style.options_stacked(),
+            ",colors=colors\
+             ,labels=labels\
+             ,baseline='sym'\
+             ,edgecolor='black'\
+             ,linewidth=2\
+             ,alpha=0.5\
+             ,hatch='//'"
+        );
+    }
+
+    #[test]
+    fn draw_works() {
+        let mut fill = Fill::new();
+        fill.draw(&[1.0, 2.0], &[3.0, 4.0], &[0.0, 1.0]);
+        fill.set_horizontal(true).draw_to_value(&[1.0, 2.0], &[3.0, 4.0], 0.5);
+        let b: &str = "x=np.array([1,2,],dtype=float)\n\
+                       y1=np.array([3,4,],dtype=float)\n\
+                       y2=np.array([0,1,],dtype=float)\n\
+                       plt.fill_between(x,y1,y2)\n\
+                       x=np.array([1,2,],dtype=float)\n\
+                       y1=np.array([3,4,],dtype=float)\n\
+                       y2=np.array([0.5,0.5,],dtype=float)\n\
+                       plt.fill_betweenx(x,y1,y2)\n";
+        assert_eq!(fill.buffer, b);
+        assert_eq!(fill.get_primitives().len(), 2);
+        fill.clear_buffer();
+        assert_eq!(fill.buffer, "");
+        assert_eq!(fill.primitives.len(), 0);
+    }
+
+    #[test]
+    fn draw_where_works() {
+        let mut fill = Fill::new();
+        fill.set_interpolate(true);
+        fill.draw_where(&[1, 2, 3], &[3, 4, 5], &[4, 4, 4], &[false, true, true])
+            .unwrap();
+        let b: &str = "x=np.array([1,2,3,],dtype=float)\n\
+                       y1=np.array([3,4,5,],dtype=float)\n\
+                       y2=np.array([4,4,4,],dtype=float)\n\
+                       where=np.array([False,True,True,])\n\
+                       plt.fill_between(x,y1,y2,where=where,interpolate=True)\n";
+        assert_eq!(fill.buffer, b);
+        assert_eq!(
+            fill.get_primitives(),
+            &[Primitive::Fill {
+                x: vec![1.0, 2.0, 3.0],
+                y1: vec![3.0, 4.0, 5.0],
+                y2: vec![4.0, 4.0, 4.0],
+                mask: vec![false, true, true],
+                style: fill.style.clone(),
+            }]
+        );
+        assert_eq!(
+            fill.draw_where(&[1, 2, 3], &[3, 4, 5], &[4, 4, 4], &[true]).err(),
+            Some(Error::InvalidInput {
+                argument: "mask",
+                message: "mask.len() must be equal to x.len()",
+            })
+        );
+        assert_eq!(fill.primitives.len(), 1);
+    }
+
+    #[test]
+    fn draw_stacked_works() {
+        let mut fill = Fill::new();
+        fill.set_colors(&["red", "green"]).set_labels(&["a", "b"]);
+        fill.draw_stacked(&[1.0, 2.0], &[[1.0, 2.0], [3.0, 4.0]]);
+        let b: &str = "x=np.array([1,2,],dtype=float)\n\
+                       y=np.array([[1,2,],[3,4,],],dtype=float)\n\
+                       colors=['red','green',]\n\
+                       labels=['a','b',]\n\
+                       plt.stackplot(x,y,colors=colors,labels=labels)\n";
+        assert_eq!(fill.buffer, b);
+    }
+}
@@ -1,4 +1,5 @@
-use super::{matrix_to_list, vector_to_strings, GraphMaker};
+use super::{matrix_to_list, to_f64, vector_to_strings, GraphMaker, Primitive};
+use num_traits::AsPrimitive;
 use std::fmt::Write;
 
 /// Generates a Histogram plot
@@ -6,9 +7,9 @@ use std::fmt::Write;
 /// # Example
 ///
 /// ```
-/// use plotpy::{Histogram, Plot, StrError};
+/// use plotpy::{Error, Histogram, Plot};
 ///
-/// fn main() -> Result<(), StrError> {
+/// fn main() -> Result<(), Error> {
 ///     // set values
 ///     let values = vec![
 ///         vec![1, 1, 1, 2, 2, 2, 2, 2, 3, 3, 4, 5, 6], // first series
@@ -47,26 +48,31 @@ use std::fmt::Write;
 ///
 /// ![integ_histogram_1.svg](https://raw.githubusercontent.com/cpmech/plotpy/main/figures/integ_histogram_1.svg)
 pub struct Histogram {
-    colors: Vec<String>, // Colors for each bar
-    line_width: f64,     // Line width
-    style: String,       // Type of histogram; e.g. "bar"
-    stacked: bool,       // Draws stacked histogram
-    no_fill: bool,       // Skip filling bars
-    number_bins: usize,  // Number of bins
-    buffer: String,      // buffer
+    style: HistogramStyle,      // Style
+    buffer: String,             // buffer
+    primitives: Vec<Primitive>, // primitives
+}
+
+/// Holds the style of a histogram (see [Histogram])
+///
+/// The style is recorded by the [Primitive] drawn by [Histogram] and may be modified afterwards.
+#[derive(Clone, Debug, PartialEq)]
+pub struct HistogramStyle {
+    pub colors: Vec<String>, // Colors for each bar
+    pub line_width: f64,     // Line width
+    pub style: String,       // Type of histogram; e.g. "bar"
+    pub stacked: bool,       // Draws stacked histogram
+    pub no_fill: bool,       // Skip filling bars
+    pub number_bins: usize,  // Number of bins
 }
 
 impl Histogram {
     /// Creates a new Histogram object
     pub fn new() -> Self {
         Histogram {
-            colors: Vec::new(),
-            line_width: 0.0,
-            style: String::new(),
-            stacked: false,
-            no_fill: false,
-            number_bins: 0,
+            style: HistogramStyle::default(),
             buffer: String::new(),
+            primitives: Vec::new(),
         }
     }
 
@@ -82,29 +88,29 @@ impl Histogram {
     /// * The type `T` must be a number.
     /// * The type `U` must be a String or &str.
     ///
-    pub fn draw<T, U>(&mut self, values: &Vec<Vec<T>>, labels: &[U])
+    pub fn draw<T, U>(&mut self, values: &[Vec<T>], labels: &[U])
     where
-        T: std::fmt::Display,
+        T: AsPrimitive<f64>,
         U: std::fmt::Display,
     {
-        let opt = self.options();
-        matrix_to_list(&mut self.buffer, "values", values);
-        vector_to_strings(&mut self.buffer, "labels", labels);
-        if self.colors.len() > 0 {
-            vector_to_strings(&mut self.buffer, "colors", self.colors.as_slice());
-        }
-        write!(&mut self.buffer, "plt.hist(values,label=labels{})\n", &opt).unwrap();
+        let values = values
+            .iter()
+            .map(|row| row.iter().map(|v| to_f64(*v)).collect())
+            .collect();
+        let labels = labels.iter().map(|label| label.to_string()).collect();
+        let style = self.style.clone();
+        self.push(Primitive::Histogram { values, labels, style });
     }
 
     /// Sets the colors for each bar
     pub fn set_colors(&mut self, colors: &[&str]) -> &mut Self {
-        self.colors = colors.iter().map(|color| color.to_string()).collect();
+        self.style.colors = colors.iter().map(|color| color.to_string()).collect();
         self
     }
 
     /// Sets the width of the lines
     pub fn set_line_width(&mut self, width: f64) -> &mut Self {
-        self.line_width = width;
+        self.style.line_width = width;
         self
     }
 
@@ -118,30 +124,51 @@ impl Histogram {
     /// * `stepfilled` generates a lineplot that is by default filled.
     /// * As defined in <https://matplotlib.org/stable/api/_as_gen/matplotlib.pyplot.hist.html>
     pub fn set_style(&mut self, style: &str) -> &mut Self {
-        self.style = String::from(style);
+        self.style.style = String::from(style);
         self
     }
 
     /// Sets option to draw stacked histogram
     pub fn set_stacked(&mut self, flag: bool) -> &mut Self {
-        self.stacked = flag;
+        self.style.stacked = flag;
         self
     }
 
     /// Sets option to skip filling bars
     pub fn set_no_fill(&mut self, flag: bool) -> &mut Self {
-        self.no_fill = flag;
+        self.style.no_fill = flag;
         self
     }
 
     /// Sets the number of bins
     pub fn set_number_bins(&mut self, bins: usize) -> &mut Self {
-        self.number_bins = bins;
+        self.style.number_bins = bins;
         self
     }
 
+    /// Writes the primitive to the buffer and records it
+    fn push(&mut self, primitive: Primitive) {
+        primitive.write_python(&mut self.buffer);
+        self.primitives.push(primitive);
+    }
+}
+
+impl Default for HistogramStyle {
+    fn default() -> Self {
+        HistogramStyle {
+            colors: Vec::new(),
+            line_width: 0.0,
+            style: String::new(),
+            stacked: false,
+            no_fill: false,
+            number_bins: 0,
+        }
+    }
+}
+
+impl HistogramStyle {
     /// Returns options for histogram
-    fn options(&self) -> String {
+    pub(crate) fn options(&self) -> String {
         let mut opt = String::new();
         if self.colors.len() > 0 {
             write!(&mut opt, ",color=colors").unwrap();
@@ -165,12 +192,27 @@ impl Histogram {
     }
 }
 
+/// Writes the Python commands of a histogram (see [Primitive::Histogram])
+pub(crate) fn write_histogram(buf: &mut String, values: &Vec<Vec<f64>>, labels: &[String], style: &HistogramStyle) {
+    let opt = style.options();
+    matrix_to_list(buf, "values", values);
+    vector_to_strings(buf, "labels", labels);
+    if style.colors.len() > 0 {
+        vector_to_strings(buf, "colors", style.colors.as_slice());
+    }
+    write!(buf, "plt.hist(values,label=labels{})\n", &opt).unwrap();
+}
+
 impl GraphMaker for Histogram {
     fn get_buffer<'a>(&'a self) -> &'a String {
         &self.buffer
     }
     fn clear_buffer(&mut self) {
         self.buffer.clear();
+        self.primitives.clear();
+    }
+    fn get_primitives(&self) -> &[Primitive] {
+        &self.primitives
     }
 }
 
@@ -184,13 +226,14 @@ mod tests {
     #[test]
     fn new_works() {
         let histogram = Histogram::new();
-        assert_eq!(histogram.colors.len(), 0);
-        assert_eq!(histogram.line_width, 0.0);
-        assert_eq!(histogram.style.len(), 0);
-        assert_eq!(histogram.stacked, false);
-        assert_eq!(histogram.no_fill, false);
-        assert_eq!(histogram.number_bins, 0);
+        assert_eq!(histogram.style.colors.len(), 0);
+        assert_eq!(histogram.style.line_width, 0.0);
+        assert_eq!(histogram.style.style.len(), 0);
+        assert_eq!(histogram.style.stacked, false);
+        assert_eq!(histogram.style.no_fill, false);
+        assert_eq!(histogram.style.number_bins, 0);
         assert_eq!(histogram.buffer.len(), 0);
+        assert_eq!(histogram.primitives.len(), 0);
     }
 
     #[test]
@@ -203,7 +246,7 @@ mod tests {
             .set_stacked(true)
             .set_no_fill(true)
             .set_number_bins(8);
-        let opt = histogram.options();
+        let opt = histogram.style.options();
         assert_eq!(
             opt,
             ",color=colors\
@@ -227,7 +270,9 @@ mod tests {
                        colors=['red','green',]\n\
                        plt.hist(values,label=labels,color=colors)\n";
         assert_eq!(histogram.buffer, b);
+        assert_eq!(histogram.get_primitives().len(), 1);
         histogram.clear_buffer();
         assert_eq!(histogram.buffer, "");
+        assert_eq!(histogram.primitives.len(), 0);
     }
 }
@@ -0,0 +1,391 @@
+use super::{vector_to_array, vector_to_f64, AsVector, Error, GraphMaker, Primitive, Script};
+use num_traits::AsPrimitive;
+use std::fmt::Write;
+
+/// Generates a 2D histogram (with rectangular or hexagonal bins) showing the density of (x,y) samples
+///
+/// # Notes
+///
+/// * This struct corresponds to the **hist2d** and **hexbin** functions of Matplotlib.
+/// * The colors represent the number of samples (counts) in each bin.
+///
+/// # Example
+///
+/// ```
+/// use plotpy::{Error, Histogram2d, Plot};
+///
+/// fn main() -> Result<(), Error> {
+///     // correlated samples (deterministic pseudo-random sequence)
+///     let n = 2000;
+///     let mut x = Vec::with_capacity(n);
+///     let mut y = Vec::with_capacity(n);
+///     for i in 0..n {
+///         let a = f64::sin(i as f64 * 12.9898) * 43758.5453;
+///         let b = f64::sin(i as f64 * 78.233) * 12345.6789;
+///         let (u, v) = (a - a.floor() - 0.5, b - b.floor() - 0.5);
+///         x.push(u + v);
+///         y.push(u - 0.5 * v);
+///     }
+///
+///     // configure and draw histogram
+///     let mut histogram = Histogram2d::new();
+///     histogram
+///         .set_number_bins(30, 30)
+///         .set_colormap_name("viridis")
+///         .set_min_count(1)
+///         .set_colorbar_label("counts");
+///     histogram.draw(&x, &y)?;
+///
+///     // add histogram to plot
+///     let mut plot = Plot::new();
+///     plot.add(&histogram).set_labels("x", "y");
+///
+///     // save figure
+///     plot.save("/tmp/plotpy/doc_tests/doc_histogram_2d.svg")?;
+///     Ok(())
+/// }
+/// ```
+///
+/// See also integration test in the **tests** directory.
+pub struct Histogram2d {
+    style: Histogram2dStyle,    // Style
+    buffer: String,             // buffer
+    primitives: Vec<Primitive>, // primitives
+}
+
+/// Holds the style of a 2D histogram (see [Histogram2d])
+///
+/// The style is recorded by the [Primitive] drawn by [Histogram2d] and may be modified afterwards.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Histogram2dStyle {
+    pub number_bins_x: usize,     // Number of bins along x (Matplotlib's default if zero)
+    pub number_bins_y: usize,     // Number of bins along y (Matplotlib's default if zero)
+    pub colormap_index: usize,    // Colormap index
+    pub colormap_name: String,    // Colormap name
+    pub log_scale: bool,          // Uses a logarithmic color scale for the counts
+    pub min_count: usize,         // Minimum count of a bin to be drawn (all bins if zero)
+    pub no_colorbar: bool,        // Skip drawing a colorbar
+    pub colorbar_label: String,   // Colorbar label
+    pub number_format_cb: String, // Number format for labels in colorbar
+}
+
+impl Histogram2d {
+    /// Creates a new Histogram2d object
+    pub fn new() -> Self {
+        Histogram2d {
+            style: Histogram2dStyle::default(),
+            buffer: String::new(),
+            primitives: Vec::new(),
+        }
+    }
+
+    /// Draws 2D histogram with rectangular bins
+    ///
+    /// # Input
+    ///
+    /// * `x` -- x coordinates of the samples
+    /// * `y` -- y coordinates of the samples
+    ///
+    /// # Notes
+    ///
+    /// * The type `U` of the input arrays must be a number.
+    ///
+    pub fn draw<'a, T, U>(&mut self, x: &'a T, y: &'a T) -> Result<(), Error>
+    where
+        T: AsVector<'a, U>,
+        U: 'a + AsPrimitive<f64>,
+    {
+        let (x, y) = samples_to_f64(x, y)?;
+        let style = self.style.clone();
+        self.push(Primitive::Histogram2d { x, y, style });
+        Ok(())
+    }
+
+    /// Draws 2D histogram with hexagonal bins
+    ///
+    /// # Input
+    ///
+    /// * `x` -- x coordinates of the samples
+    /// * `y` -- y coordinates of the samples
+    ///
+    /// # Notes
+    ///
+    /// * The type `U` of the input arrays must be a number.
+    /// * The number of bins corresponds to the number of hexagons along x and y (gridsize).
+    ///
+    pub fn draw_hexbin<'a, T, U>(&mut self, x: &'a T, y: &'a T) -> Result<(), Error>
+    where
+        T: AsVector<'a, U>,
+        U: 'a + AsPrimitive<f64>,
+    {
+        let (x, y) = samples_to_f64(x, y)?;
+        let style = self.style.clone();
+        self.push(Primitive::Hexbin { x, y, style });
+        Ok(())
+    }
+
+    /// Sets the number of bins along x and y
+    ///
+    /// Matplotlib's default is used if any number is zero.
+    pub fn set_number_bins(&mut self, nx: usize, ny: usize) -> &mut Self {
+        self.style.number_bins_x = nx;
+        self.style.number_bins_y = ny;
+        self
+    }
+
+    /// Sets the colormap index
+    ///
+    /// Options:
+    ///
+    /// * 0 -- bwr
+    /// * 1 -- RdBu
+    /// * 2 -- hsv
+    /// * 3 -- jet
+    /// * 4 -- terrain
+    /// * 5 -- pink
+    /// * 6 -- Greys
+    /// * `>`6 -- starts over from 0
+    pub fn set_colormap_index(&mut self, index: usize) -> &mut Self {
+        self.style.colormap_index = index;
+        self.style.colormap_name = String::new();
+        self
+    }
+
+    /// Sets the colormap name
+    ///
+    /// Colormap names:
+    ///
+    /// * see <https://matplotlib.org/stable/tutorials/colors/colormaps.html>
+    ///
+    /// Will use `colormap_index` instead if `colormap_name` is empty.
+    pub fn set_colormap_name(&mut self, name: &str) -> &mut Self {
+        self.style.colormap_name = String::from(name);
+        self
+    }
+
+    /// Sets option to use a logarithmic color scale for the counts
+    pub fn set_log_scale(&mut self, flag: bool) -> &mut Self {
+        self.style.log_scale = flag;
+        self
+    }
+
+    /// Sets the minimum count of a bin to be drawn
+    ///
+    /// Bins with fewer samples are left blank. All bins are drawn if zero.
+    pub fn set_min_count(&mut self, count: usize) -> &mut Self {
+        self.style.min_count = count;
+        self
+    }
+
+    /// Sets option to skip drawing a colorbar
+    pub fn set_no_colorbar(&mut self, flag: bool) -> &mut Self {
+        self.style.no_colorbar = flag;
+        self
+    }
+
+    /// Sets the colorbar label
+    pub fn set_colorbar_label(&mut self, label: &str) -> &mut Self {
+        self.style.colorbar_label = String::from(label);
+        self
+    }
+
+    /// Sets the number format for the labels in the colorbar (cb)
+    pub fn set_number_format_cb(&mut self, format: &str) -> &mut Self {
+        self.style.number_format_cb = String::from(format);
+        self
+    }
+
+    /// Writes the primitive to the buffer and records it
+    fn push(&mut self, primitive: Primitive) {
+        primitive.write_python(&mut self.buffer);
+        self.primitives.push(primitive);
+    }
+}
+
+impl Histogram2dStyle {
+    /// Returns options for hist2d (hexbin = false) or hexbin (hexbin = true)
+    pub(crate) fn options(&self, hexbin: bool) -> String {
+        let mut opt = String::new();
+        if self.number_bins_x > 0 && self.number_bins_y > 0 {
+            if hexbin {
+                write!(&mut opt, ",gridsize=({},{})", self.number_bins_x, self.number_bins_y).unwrap();
+            } else {
+                write!(&mut opt, ",bins=[{},{}]", self.number_bins_x, self.number_bins_y).unwrap();
+            }
+        }
+        if self.colormap_name != "" {
+            write!(&mut opt, ",cmap=plt.get_cmap('{}')", self.colormap_name).unwrap();
+        } else {
+            write!(&mut opt, ",cmap=get_colormap({})", self.colormap_index).unwrap();
+        }
+        if self.log_scale {
+            write!(&mut opt, ",norm=mcl.LogNorm()").unwrap();
+        }
+        if self.min_count > 0 {
+            if hexbin {
+                write!(&mut opt, ",mincnt={}", self.min_count).unwrap();
+            } else {
+                write!(&mut opt, ",cmin={}", self.min_count).unwrap();
+            }
+        }
+        opt
+    }
+
+    /// Returns options for colorbar
+    pub(crate) fn options_colorbar(&self) -> String {
+        let mut opt = String::new();
+        if self.number_format_cb != "" {
+            write!(&mut opt, ",format='{}'", self.number_format_cb).unwrap();
+        }
+        opt
+    }
+}
+
+/// Converts the coordinates of the samples to Vec of f64 and checks their lengths
+fn samples_to_f64<'a, T, U>(x: &'a T, y: &'a T) -> Result<(Vec<f64>, Vec<f64>), Error>
+where
+    T: AsVector<'a, U>,
+    U: 'a + AsPrimitive<f64>,
+{
+    let (x, y) = (vector_to_f64(x), vector_to_f64(y));
+    if y.len() != x.len() {
+        return Err(Error::InvalidInput {
+            argument: "y",
+            message: "y.len() must be equal to x.len()",
+        });
+    }
+    Ok((x, y))
+}
+
+/// Writes the Python commands of a 2D histogram (see [Primitive::Histogram2d] and [Primitive::Hexbin])
+pub(crate) fn write_histogram_2d(buf: &mut Script, x: &[f64], y: &[f64], hexbin: bool, style: &Histogram2dStyle) {
+    if style.log_scale {
+        write!(buf, "import matplotlib.colors as mcl\n").unwrap();
+    }
+    vector_to_array(buf, "x", &x);
+    vector_to_array(buf, "y", &y);
+    let opt = style.options(hexbin);
+    if hexbin {
+        write!(buf, "h=plt.hexbin(x,y{})\n", &opt).unwrap();
+    } else {
+        write!(buf, "h=plt.hist2d(x,y{})[3]\n", &opt).unwrap();
+    }
+    if !style.no_colorbar {
+        write!(buf, "cb=plt.colorbar(h{})\n", style.options_colorbar()).unwrap();
+        if style.colorbar_label != "" {
+            write!(buf, "cb.ax.set_ylabel(r'{}')\n", style.colorbar_label).unwrap();
+        }
+    }
+}
+
+impl GraphMaker for Histogram2d {
+    fn get_buffer<'a>(&'a self) -> &'a String {
+        &self.buffer
+    }
+    fn clear_buffer(&mut self) {
+        self.buffer.clear();
+        self.primitives.clear();
+    }
+    fn get_primitives(&self) -> &[Primitive] {
+        &self.primitives
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::Histogram2d;
+    use crate::{Error, GraphMaker, Primitive};
+
+    #[test]
+    fn new_works() {
+        let histogram = Histogram2d::new();
+        assert_eq!(histogram.style.number_bins_x, 0);
+        assert_eq!(histogram.style.number_bins_y, 0);
+        assert_eq!(histogram.style.colormap_index, 0);
+        assert_eq!(histogram.style.colormap_name.len(), 0);
+        assert_eq!(histogram.style.log_scale, false);
+        assert_eq!(histogram.style.min_count, 0);
+        assert_eq!(histogram.style.no_colorbar, false);
+        assert_eq!(histogram.style.colorbar_label.len(), 0);
+        assert_eq!(histogram.style.number_format_cb.len(), 0);
+        assert_eq!(histogram.buffer.len(), 0);
+        assert_eq!(histogram.primitives.len(), 0);
+    }
+
+    #[test]
+    fn options_works() {
+        let mut histogram = Histogram2d::new();
+        assert_eq!(histogram.style.options(false), ",cmap=get_colormap(0)");
+        assert_eq!(histogram.style.options_colorbar(), "");
+        histogram
+            .set_number_bins(20, 10)
+            .set_colormap_index(3)
+            .set_log_scale(true)
+            .set_min_count(2)
+            .set_number_format_cb("%.0f");
+        assert_eq!(
+            histogram.style.options(false),
+            ",bins=[20,10],cmap=get_colormap(3),norm=mcl.LogNorm(),cmin=2"
+        );
+        histogram.set_colormap_name("viridis");
+        assert_eq!(
+            histogram.style.options(true),
+            ",gridsize=(20,10),cmap=plt.get_cmap('viridis'),norm=mcl.LogNorm(),mincnt=2"
+        );
+        assert_eq!(histogram.style.options_colorbar(), ",format='%.0f'");
+    }
+
+    #[test]
+    fn draw_works() {
+        let x = &[1.0, 2.0, 3.0];
+        let y = &[4.0, 5.0, 6.0];
+        let mut histogram = Histogram2d::new();
+        histogram.set_colorbar_label("counts");
+        histogram.draw(x, y).unwrap();
+        let b: &str = "x=np.array([1,2,3,],dtype=float)\n\
+                       y=np.array([4,5,6,],dtype=float)\n\
+                       h=plt.hist2d(x,y,cmap=get_colormap(0))[3]\n\
+                       cb=plt.colorbar(h)\n\
+                       cb.ax.set_ylabel(r'counts')\n";
+        assert_eq!(histogram.buffer, b);
+        assert_eq!(
+            histogram.get_primitives(),
+            &[Primitive::Histogram2d {
+                x: x.to_vec(),
+                y: y.to_vec(),
+                style: histogram.style.clone(),
+            }]
+        );
+        histogram.clear_buffer();
+        assert_eq!(histogram.buffer, "");
+        assert_eq!(histogram.primitives.len(), 0);
+    }
+
+    #[test]
+    fn draw_hexbin_works() {
+        let x = &[1.0, 2.0, 3.0];
+        let y = &[4.0, 5.0, 6.0];
+        let mut histogram = Histogram2d::new();
+        histogram.set_log_scale(true).set_no_colorbar(true);
+        histogram.draw_hexbin(x, y).unwrap();
+        let b: &str = "import matplotlib.colors as mcl\n\
+                       x=np.array([1,2,3,],dtype=float)\n\
+                       y=np.array([4,5,6,],dtype=float)\n\
+                       h=plt.hexbin(x,y,cmap=get_colormap(0),norm=mcl.LogNorm())\n";
+        assert_eq!(histogram.buffer, b);
+    }
+
+    #[test]
+    fn draw_captures_errors() {
+        let mut histogram = Histogram2d::new();
+        let error = Error::InvalidInput {
+            argument: "y",
+            message: "y.len() must be equal to x.len()",
+        };
+        assert_eq!(histogram.draw(&vec![1.0, 2.0], &vec![1.0]).err(), Some(error.clone()));
+        assert_eq!(histogram.draw_hexbin(&vec![1.0], &vec![]).err(), Some(error));
+        assert_eq!(histogram.primitives.len(), 0);
+    }
+}
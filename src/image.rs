@@ -0,0 +1,552 @@
+use super::{
+    matrix_to_array, matrix_to_f64, vector_to_array, vector_to_f64, AsMatrix, AsVector, GraphMaker, Primitive, Script,
+};
+use num_traits::AsPrimitive;
+use std::fmt::Write;
+
+/// Generates an image (aka heatmap) representing the values of a matrix
+///
+/// # Notes
+///
+/// * This struct corresponds to the **imshow** and **pcolormesh** functions of Matplotlib.
+/// * Unlike [crate::Contour], the values are not interpolated and the coordinates are not required;
+///   thus, each cell of the image corresponds to one entry of the matrix.
+///
+/// # Example
+///
+/// ```
+/// use plotpy::{Error, Image, Plot};
+///
+/// fn main() -> Result<(), Error> {
+///     // correlation matrix
+///     let data = [
+///         [1.0, 0.8, -0.3, 0.1],
+///         [0.8, 1.0, -0.5, 0.2],
+///         [-0.3, -0.5, 1.0, -0.7],
+///         [0.1, 0.2, -0.7, 1.0],
+///     ];
+///
+///     // configure and draw image
+///     let mut image = Image::new();
+///     image
+///         .set_colormap_name("RdBu")
+///         .set_norm("diverging")
+///         .set_colorbar_label("correlation")
+///         .set_with_annotations(true)
+///         .set_number_format_annotations(".1f");
+///     image.draw(&data);
+///
+///     // add image to plot
+///     let mut plot = Plot::new();
+///     plot.add(&image).set_title("correlation matrix");
+///
+///     // save figure
+///     plot.save("/tmp/plotpy/doc_tests/doc_image.svg")?;
+///     Ok(())
+/// }
+/// ```
+///
+/// See also integration test in the **tests** directory.
+pub struct Image {
+    style: ImageStyle,          // Style
+    buffer: String,             // buffer
+    primitives: Vec<Primitive>, // primitives
+}
+
+/// Holds the style of an image (see [Image])
+///
+/// The style is recorded by the [Primitive] drawn by [Image] and may be modified afterwards.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ImageStyle {
+    pub colormap_index: usize,             // Colormap index
+    pub colormap_name: String,             // Colormap name
+    pub norm: String,                      // Normalization of values: "linear", "log", or "diverging"
+    pub extent: Vec<f64>,                  // Bounding box in data coordinates: [left, right, bottom, top]
+    pub origin: String,                    // Places the first row at the "upper" or "lower" side
+    pub interpolation: String,             // Interpolation used by imshow, e.g., "nearest"
+    pub no_colorbar: bool,                 // Skip drawing a colorbar
+    pub colorbar_label: String,            // Colorbar label
+    pub number_format_cb: String,          // Number format for labels in colorbar
+    pub with_annotations: bool,            // Writes the values in the cells
+    pub number_format_annotations: String, // Python format spec of the values in the cells, e.g., ".2f"
+    pub annotations_color: String,         // Color of the values in the cells (black or white if empty)
+    pub annotations_fontsize: f64,         // Fontsize of the values in the cells
+}
+
+impl Image {
+    /// Creates a new Image object
+    pub fn new() -> Self {
+        Image {
+            style: ImageStyle::default(),
+            buffer: String::new(),
+            primitives: Vec::new(),
+        }
+    }
+
+    /// Draws image with imshow (uniform cells)
+    ///
+    /// # Input
+    ///
+    /// * `data` -- matrix with the values; the first row is shown at the top (unless the origin is "lower")
+    ///
+    /// # Notes
+    ///
+    /// * The type `U` of the input matrix must be a number.
+    /// * The cell (i,j) is centered at (j,i) unless the extent is given.
+    ///
+    pub fn draw<'a, T, U>(&mut self, data: &'a T)
+    where
+        T: AsMatrix<'a, U>,
+        U: 'a + AsPrimitive<f64>,
+    {
+        let data = matrix_to_f64(data);
+        let style = self.style.clone();
+        self.push(Primitive::Image {
+            x: Vec::new(),
+            y: Vec::new(),
+            data,
+            style,
+        });
+    }
+
+    /// Draws image with pcolormesh (non-uniform cells)
+    ///
+    /// # Input
+    ///
+    /// * `x` -- the x coordinates of the edges (ncol + 1 values) or centers (ncol values) of the cells
+    /// * `y` -- the y coordinates of the edges (nrow + 1 values) or centers (nrow values) of the cells
+    /// * `data` -- matrix with the values (nrow × ncol); the first row is shown at the bottom
+    ///
+    /// # Notes
+    ///
+    /// * The type `U` of the input arrays must be a number.
+    /// * The extent, origin, and interpolation options are not used by pcolormesh.
+    ///
+    pub fn draw_mesh<'a, T, M, U>(&mut self, x: &'a T, y: &'a T, data: &'a M)
+    where
+        T: AsVector<'a, U>,
+        M: AsMatrix<'a, U>,
+        U: 'a + AsPrimitive<f64>,
+    {
+        let (x, y, data) = (vector_to_f64(x), vector_to_f64(y), matrix_to_f64(data));
+        let style = self.style.clone();
+        self.push(Primitive::Image { x, y, data, style });
+    }
+
+    /// Sets the colormap index
+    ///
+    /// Options:
+    ///
+    /// * 0 -- bwr
+    /// * 1 -- RdBu
+    /// * 2 -- hsv
+    /// * 3 -- jet
+    /// * 4 -- terrain
+    /// * 5 -- pink
+    /// * 6 -- Greys
+    /// * `>`6 -- starts over from 0
+    pub fn set_colormap_index(&mut self, index: usize) -> &mut Self {
+        self.style.colormap_index = index;
+        self.style.colormap_name = String::new();
+        self
+    }
+
+    /// Sets the colormap name
+    ///
+    /// Colormap names:
+    ///
+    /// * see <https://matplotlib.org/stable/tutorials/colors/colormaps.html>
+    ///
+    /// Will use `colormap_index` instead if `colormap_name` is empty.
+    pub fn set_colormap_name(&mut self, name: &str) -> &mut Self {
+        self.style.colormap_name = String::from(name);
+        self
+    }
+
+    /// Sets the normalization of values (mapping to colors)
+    ///
+    /// Options:
+    ///
+    /// * "linear" -- (default) linear mapping from the minimum to the maximum value
+    /// * "log" -- logarithmic mapping (the values must be positive)
+    /// * "diverging" -- linear mapping centered at zero (e.g., for correlations)
+    pub fn set_norm(&mut self, norm: &str) -> &mut Self {
+        self.style.norm = String::from(norm);
+        self
+    }
+
+    /// Sets the bounding box in data coordinates
+    ///
+    /// Example: `[0.0, 1.0, 0.0, 1.0]` = `[left, right, bottom, top]`
+    pub fn set_extent(&mut self, extent: &[f64]) -> &mut Self {
+        self.style.extent = extent.to_vec();
+        self
+    }
+
+    /// Sets the side where the first row is placed
+    ///
+    /// Options:
+    ///
+    /// * "upper" -- (default) the first row is at the top, as in a matrix
+    /// * "lower" -- the first row is at the bottom, as in a graph
+    pub fn set_origin(&mut self, origin: &str) -> &mut Self {
+        self.style.origin = String::from(origin);
+        self
+    }
+
+    /// Sets the interpolation used by imshow
+    ///
+    /// Options:
+    ///
+    /// * "nearest", "bilinear", "bicubic", ...
+    /// * As defined in <https://matplotlib.org/stable/gallery/images_contours_and_fields/interpolation_methods.html>
+    pub fn set_interpolation(&mut self, interpolation: &str) -> &mut Self {
+        self.style.interpolation = String::from(interpolation);
+        self
+    }
+
+    /// Sets option to skip drawing a colorbar
+    pub fn set_no_colorbar(&mut self, flag: bool) -> &mut Self {
+        self.style.no_colorbar = flag;
+        self
+    }
+
+    /// Sets the colorbar label
+    pub fn set_colorbar_label(&mut self, label: &str) -> &mut Self {
+        self.style.colorbar_label = String::from(label);
+        self
+    }
+
+    /// Sets the number format for the labels in the colorbar (cb)
+    pub fn set_number_format_cb(&mut self, format: &str) -> &mut Self {
+        self.style.number_format_cb = String::from(format);
+        self
+    }
+
+    /// Sets option to write the values in the cells
+    pub fn set_with_annotations(&mut self, flag: bool) -> &mut Self {
+        self.style.with_annotations = flag;
+        self
+    }
+
+    /// Sets the number format of the values in the cells
+    ///
+    /// Example: `".2f"` (see <https://docs.python.org/3/library/string.html#formatspec>)
+    pub fn set_number_format_annotations(&mut self, format: &str) -> &mut Self {
+        self.style.number_format_annotations = String::from(format);
+        self
+    }
+
+    /// Sets the color of the values in the cells
+    ///
+    /// If empty, black or white is selected according to the color of each cell.
+    pub fn set_annotations_color(&mut self, color: &str) -> &mut Self {
+        self.style.annotations_color = String::from(color);
+        self
+    }
+
+    /// Sets the fontsize of the values in the cells
+    pub fn set_annotations_fontsize(&mut self, fontsize: f64) -> &mut Self {
+        self.style.annotations_fontsize = fontsize;
+        self
+    }
+
+    /// Writes the primitive to the buffer and records it
+    fn push(&mut self, primitive: Primitive) {
+        primitive.write_python(&mut self.buffer);
+        self.primitives.push(primitive);
+    }
+}
+
+impl Default for ImageStyle {
+    fn default() -> Self {
+        ImageStyle {
+            colormap_index: 0,
+            colormap_name: String::new(),
+            norm: String::new(),
+            extent: Vec::new(),
+            origin: String::new(),
+            interpolation: String::new(),
+            no_colorbar: false,
+            colorbar_label: String::new(),
+            number_format_cb: String::new(),
+            with_annotations: false,
+            number_format_annotations: String::new(),
+            annotations_color: String::new(),
+            annotations_fontsize: 0.0,
+        }
+    }
+}
+
+impl ImageStyle {
+    /// Returns options for imshow (with_mesh = false) or pcolormesh (with_mesh = true)
+    pub(crate) fn options(&self, with_mesh: bool) -> String {
+        let mut opt = String::new();
+        if self.colormap_name != "" {
+            write!(&mut opt, ",cmap=plt.get_cmap('{}')", self.colormap_name).unwrap();
+        } else {
+            write!(&mut opt, ",cmap=get_colormap({})", self.colormap_index).unwrap();
+        }
+        match self.norm.as_str() {
+            "log" => write!(&mut opt, ",norm=mcl.LogNorm()").unwrap(),
+            "diverging" => write!(&mut opt, ",norm=mcl.CenteredNorm()").unwrap(),
+            _ => (),
+        }
+        if with_mesh {
+            write!(&mut opt, ",shading='auto'").unwrap();
+            return opt;
+        }
+        if self.extent.len() == 4 {
+            write!(
+                &mut opt,
+                ",extent=[{},{},{},{}]",
+                self.extent[0], self.extent[1], self.extent[2], self.extent[3]
+            )
+            .unwrap();
+        }
+        if self.origin != "" {
+            write!(&mut opt, ",origin='{}'", self.origin).unwrap();
+        }
+        if self.interpolation != "" {
+            write!(&mut opt, ",interpolation='{}'", self.interpolation).unwrap();
+        }
+        opt
+    }
+
+    /// Returns options for colorbar
+    pub(crate) fn options_colorbar(&self) -> String {
+        let mut opt = String::new();
+        if self.number_format_cb != "" {
+            write!(&mut opt, ",format='{}'", self.number_format_cb).unwrap();
+        }
+        opt
+    }
+
+    /// Returns options for the text of the annotations
+    pub(crate) fn options_annotations(&self) -> String {
+        let mut opt = String::new();
+        if self.annotations_color != "" {
+            write!(&mut opt, ",color='{}'", self.annotations_color).unwrap();
+        } else {
+            write!(&mut opt, ",color=contrast_color(im,data[i,j])").unwrap();
+        }
+        if self.annotations_fontsize > 0.0 {
+            write!(&mut opt, ",fontsize={}", self.annotations_fontsize).unwrap();
+        }
+        opt
+    }
+}
+
+/// Writes the Python commands of an image (see [Primitive::Image])
+pub(crate) fn write_image(buf: &mut Script, x: &[f64], y: &[f64], data: &Vec<Vec<f64>>, style: &ImageStyle) {
+    let with_mesh = x.len() > 0 || y.len() > 0;
+    if style.norm == "log" || style.norm == "diverging" {
+        write!(buf, "import matplotlib.colors as mcl\n").unwrap();
+    }
+    matrix_to_array(buf, "data", data);
+    if with_mesh {
+        vector_to_array(buf, "x", &x);
+        vector_to_array(buf, "y", &y);
+        write!(buf, "im=plt.pcolormesh(x,y,data{})\n", style.options(true)).unwrap();
+    } else {
+        write!(buf, "im=plt.imshow(data{})\n", style.options(false)).unwrap();
+    }
+    if !style.no_colorbar {
+        write!(buf, "cb=plt.colorbar(im{})\n", style.options_colorbar()).unwrap();
+        if style.colorbar_label != "" {
+            write!(buf, "cb.ax.set_ylabel(r'{}')\n", style.colorbar_label).unwrap();
+        }
+    }
+    if style.with_annotations {
+        // coordinates of the centers of the cells
+        let (nrow, ncol) = (data.len(), data.first().map_or(0, |row| row.len()));
+        if with_mesh {
+            write!(
+                buf,
+                "xc=x if len(x)=={} else (x[:-1]+x[1:])/2\n\
+                 yc=y if len(y)=={} else (y[:-1]+y[1:])/2\n",
+                ncol, nrow
+            )
+            .unwrap();
+        } else if style.origin == "lower" {
+            write!(
+                buf,
+                "l,r,b,t=im.get_extent()\n\
+                 xc=l+(np.arange({})+0.5)*(r-l)/{}\n\
+                 yc=b+(np.arange({})+0.5)*(t-b)/{}\n",
+                ncol, ncol, nrow, nrow
+            )
+            .unwrap();
+        } else {
+            write!(
+                buf,
+                "l,r,b,t=im.get_extent()\n\
+                 xc=l+(np.arange({})+0.5)*(r-l)/{}\n\
+                 yc=t+(np.arange({})+0.5)*(b-t)/{}\n",
+                ncol, ncol, nrow, nrow
+            )
+            .unwrap();
+        }
+
+        // text of the annotations
+        if style.annotations_color == "" {
+            write!(
+                buf,
+                "def contrast_color(im,v):\n\
+                 \x20\x20\x20\x20c=im.cmap(im.norm(v))\n\
+                 \x20\x20\x20\x20return 'black' if 0.299*c[0]+0.587*c[1]+0.114*c[2]>0.5 else 'white'\n"
+            )
+            .unwrap();
+        }
+        write!(
+            buf,
+            "for i in range({}):\n\
+             \x20\x20\x20\x20for j in range({}):\n\
+             \x20\x20\x20\x20\x20\x20\x20\x20plt.text(xc[j],yc[i],format(data[i,j],'{}'),ha='center',va='center'{})\n",
+            nrow,
+            ncol,
+            style.number_format_annotations,
+            style.options_annotations()
+        )
+        .unwrap();
+    }
+}
+
+impl GraphMaker for Image {
+    fn get_buffer<'a>(&'a self) -> &'a String {
+        &self.buffer
+    }
+    fn clear_buffer(&mut self) {
+        self.buffer.clear();
+        self.primitives.clear();
+    }
+    fn get_primitives(&self) -> &[Primitive] {
+        &self.primitives
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::Image;
+    use crate::{GraphMaker, Primitive};
+
+    #[test]
+    fn new_works() {
+        let image = Image::new();
+        assert_eq!(image.style.colormap_index, 0);
+        assert_eq!(image.style.colormap_name.len(), 0);
+        assert_eq!(image.style.norm.len(), 0);
+        assert_eq!(image.style.extent.len(), 0);
+        assert_eq!(image.style.origin.len(), 0);
+        assert_eq!(image.style.interpolation.len(), 0);
+        assert_eq!(image.style.no_colorbar, false);
+        assert_eq!(image.style.colorbar_label.len(), 0);
+        assert_eq!(image.style.number_format_cb.len(), 0);
+        assert_eq!(image.style.with_annotations, false);
+        assert_eq!(image.style.number_format_annotations.len(), 0);
+        assert_eq!(image.style.annotations_color.len(), 0);
+        assert_eq!(image.style.annotations_fontsize, 0.0);
+        assert_eq!(image.buffer.len(), 0);
+        assert_eq!(image.primitives.len(), 0);
+    }
+
+    #[test]
+    fn options_works() {
+        let mut image = Image::new();
+        image
+            .set_colormap_index(4)
+            .set_norm("log")
+            .set_extent(&[0.0, 1.0, 2.0, 3.0])
+            .set_origin("lower")
+            .set_interpolation("nearest")
+            .set_number_format_cb("%.1f")
+            .set_annotations_fontsize(8.0);
+        assert_eq!(
+            image.style.options(false),
+            ",cmap=get_colormap(4)\
+             ,norm=mcl.LogNorm()\
+             ,extent=[0,1,2,3]\
+             ,origin='lower'\
+             ,interpolation='nearest'"
+        );
+        image.set_colormap_name("viridis").set_norm("diverging");
+        assert_eq!(
+            image.style.options(true),
+            ",cmap=plt.get_cmap('viridis'),norm=mcl.CenteredNorm(),shading='auto'"
+        );
+        assert_eq!(image.style.options_colorbar(), ",format='%.1f'");
+        assert_eq!(
+            image.style.options_annotations(),
+            ",color=contrast_color(im,data[i,j]),fontsize=8"
+        );
+        image.set_annotations_color("red");
+        assert_eq!(image.style.options_annotations(), ",color='red',fontsize=8");
+    }
+
+    #[test]
+    fn draw_works() {
+        let mut image = Image::new();
+        image.set_colorbar_label("value");
+        image.draw(&[[1, 2], [3, 4]]);
+        let b: &str = "data=np.array([[1,2,],[3,4,],],dtype=float)\n\
+                       im=plt.imshow(data,cmap=get_colormap(0))\n\
+                       cb=plt.colorbar(im)\n\
+                       cb.ax.set_ylabel(r'value')\n";
+        assert_eq!(image.buffer, b);
+        assert_eq!(
+            image.get_primitives(),
+            &[Primitive::Image {
+                x: Vec::new(),
+                y: Vec::new(),
+                data: vec![vec![1.0, 2.0], vec![3.0, 4.0]],
+                style: image.style.clone(),
+            }]
+        );
+        image.clear_buffer();
+        assert_eq!(image.buffer, "");
+        assert_eq!(image.primitives.len(), 0);
+    }
+
+    #[test]
+    fn draw_with_annotations_works() {
+        let mut image = Image::new();
+        image
+            .set_no_colorbar(true)
+            .set_with_annotations(true)
+            .set_number_format_annotations(".1f")
+            .set_annotations_color("white");
+        image.draw(&[[1, 2, 3]]);
+        let b: &str = "data=np.array([[1,2,3,],],dtype=float)\n\
+                       im=plt.imshow(data,cmap=get_colormap(0))\n\
+                       l,r,b,t=im.get_extent()\n\
+                       xc=l+(np.arange(3)+0.5)*(r-l)/3\n\
+                       yc=t+(np.arange(1)+0.5)*(b-t)/1\n\
+                       for i in range(1):\n\
+                       \x20\x20\x20\x20for j in range(3):\n\
+                       \x20\x20\x20\x20\x20\x20\x20\x20plt.text(xc[j],yc[i],format(data[i,j],'.1f'),ha='center',va='center',color='white')\n";
+        assert_eq!(image.buffer, b);
+    }
+
+    #[test]
+    fn draw_mesh_works() {
+        let mut image = Image::new();
+        image.set_no_colorbar(true).set_norm("log").set_with_annotations(true);
+        image.draw_mesh(&vec![0.0, 1.0, 3.0], &vec![0.0, 2.0], &[[1.0, 2.0]]);
+        let b: &str = "import matplotlib.colors as mcl\n\
+                       data=np.array([[1,2,],],dtype=float)\n\
+                       x=np.array([0,1,3,],dtype=float)\n\
+                       y=np.array([0,2,],dtype=float)\n\
+                       im=plt.pcolormesh(x,y,data,cmap=get_colormap(0),norm=mcl.LogNorm(),shading='auto')\n\
+                       xc=x if len(x)==2 else (x[:-1]+x[1:])/2\n\
+                       yc=y if len(y)==1 else (y[:-1]+y[1:])/2\n\
+                       def contrast_color(im,v):\n\
+                       \x20\x20\x20\x20c=im.cmap(im.norm(v))\n\
+                       \x20\x20\x20\x20return 'black' if 0.299*c[0]+0.587*c[1]+0.114*c[2]>0.5 else 'white'\n\
+                       for i in range(1):\n\
+                       \x20\x20\x20\x20for j in range(2):\n\
+                       \x20\x20\x20\x20\x20\x20\x20\x20plt.text(xc[j],yc[i],format(data[i,j],''),ha='center',va='center',color=contrast_color(im,data[i,j]))\n";
+        assert_eq!(image.buffer, b);
+    }
+}
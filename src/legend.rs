@@ -1,4 +1,4 @@
-use super::{vector_to_numbers, GraphMaker};
+use super::{vector_to_numbers, GraphMaker, Primitive};
 use std::fmt::Write;
 
 /// Generates a Legend
@@ -6,10 +6,10 @@ use std::fmt::Write;
 /// # Example
 ///
 /// ```
-/// use plotpy::{Curve, Legend, Plot, StrError};
+/// use plotpy::{Curve, Error, Legend, Plot};
 /// use russell_lab::Vector;
 ///
-/// fn main() -> Result<(), StrError> {
+/// fn main() -> Result<(), Error> {
 ///     // generate (x,y) points
 ///     let x  = Vector::linspace(0.0, 5.0, 6)?;
 ///     let y1 = x.get_mapped(|v| 0.5 * v);
@@ -67,61 +67,57 @@ use std::fmt::Write;
 ///
 /// See also integration tests in the [tests directory](https://github.com/cpmech/plotpy/tree/main/tests)
 pub struct Legend {
-    fontsize: f64,      // Fontsize
-    handle_len: f64,    // Length of legend's indicator line
-    num_col: usize,     // Number of columns
-    location: String,   // Location, e.g., "best", "right", "center left"
-    outside: bool,      // Put legend outside plot area
-    show_frame: bool,   // Show frame around legend
-    x_coords: Vec<f64>, // Normalized coordinates to put legend outside
-    buffer: String,     // buffer
+    style: LegendStyle,         // Style of this legend
+    buffer: String,             // buffer
+    primitives: Vec<Primitive>, // primitives
+}
+
+/// Holds the style of a legend (see [Legend])
+///
+/// The style is recorded by the [Primitive] drawn by [Legend] and may be modified afterwards.
+#[derive(Clone, Debug, PartialEq)]
+pub struct LegendStyle {
+    pub fontsize: f64,      // Fontsize
+    pub handle_len: f64,    // Length of legend's indicator line
+    pub num_col: usize,     // Number of columns
+    pub location: String,   // Location, e.g., "best", "right", "center left"
+    pub outside: bool,      // Put legend outside plot area
+    pub show_frame: bool,   // Show frame around legend
+    pub x_coords: Vec<f64>, // Normalized coordinates to put legend outside
 }
 
 impl Legend {
     /// Creates a new Legend object
     pub fn new() -> Self {
         Legend {
-            fontsize: 0.0,
-            handle_len: 3.0,
-            num_col: 1,
-            location: "best".to_string(),
-            outside: false,
-            show_frame: true,
-            x_coords: vec![0.0, 1.02, 1.0, 0.102],
+            style: LegendStyle::default(),
             buffer: String::new(),
+            primitives: Vec::new(),
         }
     }
 
     /// Draws legend
     pub fn draw(&mut self) {
-        let opt = self.options();
-        if self.outside {
-            vector_to_numbers(&mut self.buffer, "coo", self.x_coords.as_slice());
-        }
-        write!(&mut self.buffer, "h,l=plt.gca().get_legend_handles_labels()\n").unwrap();
-        write!(&mut self.buffer, "if len(h)>0 and len(l)>0:\n").unwrap();
-        write!(&mut self.buffer, "    leg=plt.legend({})\n", &opt).unwrap();
-        write!(&mut self.buffer, "    add_to_ea(leg)\n").unwrap();
-        if !self.show_frame {
-            write!(&mut self.buffer, "    leg.get_frame().set_linewidth(0.0)\n").unwrap();
-        }
+        let primitive = Primitive::Legend(self.style.clone());
+        primitive.write_python(&mut self.buffer);
+        self.primitives.push(primitive);
     }
 
     /// Sets the fontsize
     pub fn set_fontsize(&mut self, fontsize: f64) -> &mut Self {
-        self.fontsize = fontsize;
+        self.style.fontsize = fontsize;
         self
     }
 
     /// Sets the length of legend's indicator line
     pub fn set_handle_len(&mut self, length: f64) -> &mut Self {
-        self.handle_len = length;
+        self.style.handle_len = length;
         self
     }
 
     /// Sets the number of columns
     pub fn set_num_col(&mut self, num_columns: usize) -> &mut Self {
-        self.num_col = num_columns;
+        self.style.num_col = num_columns;
         self
     }
 
@@ -132,19 +128,19 @@ impl Legend {
     /// * "best", "right", "center left"
     /// * Note: Only used if outside == false
     pub fn set_location(&mut self, location: &str) -> &mut Self {
-        self.location = String::from(location);
+        self.style.location = String::from(location);
         self
     }
 
     /// Sets option to put legend outside of plot area
     pub fn set_outside(&mut self, flag: bool) -> &mut Self {
-        self.outside = flag;
+        self.style.outside = flag;
         self
     }
 
     /// Sets option to show frame around legend
     pub fn set_show_frame(&mut self, flag: bool) -> &mut Self {
-        self.show_frame = flag;
+        self.style.show_frame = flag;
         self
     }
 
@@ -152,12 +148,28 @@ impl Legend {
     ///
     /// Example: `[0.0, 1.02, 1.0, 0.102]`
     pub fn set_x_coords(&mut self, coords: &[f64]) -> &mut Self {
-        self.x_coords = coords.to_vec();
+        self.style.x_coords = coords.to_vec();
         self
     }
+}
+
+impl Default for LegendStyle {
+    fn default() -> Self {
+        LegendStyle {
+            fontsize: 0.0,
+            handle_len: 3.0,
+            num_col: 1,
+            location: "best".to_string(),
+            outside: false,
+            show_frame: true,
+            x_coords: vec![0.0, 1.02, 1.0, 0.102],
+        }
+    }
+}
 
+impl LegendStyle {
     /// Returns options for legend
-    fn options(&self) -> String {
+    pub(crate) fn options(&self) -> String {
         let mut opt = String::new();
         let mut comma = "";
         if self.handle_len > 0.0 {
@@ -188,12 +200,31 @@ impl Legend {
     }
 }
 
+/// Writes the Python commands of a legend (see [Primitive::Legend])
+pub(crate) fn write_legend(buf: &mut String, style: &LegendStyle) {
+    let opt = style.options();
+    if style.outside {
+        vector_to_numbers(buf, "coo", style.x_coords.as_slice());
+    }
+    write!(buf, "h,l=plt.gca().get_legend_handles_labels()\n").unwrap();
+    write!(buf, "if len(h)>0 and len(l)>0:\n").unwrap();
+    write!(buf, "    leg=plt.legend({})\n", &opt).unwrap();
+    write!(buf, "    add_to_ea(leg)\n").unwrap();
+    if !style.show_frame {
+        write!(buf, "    leg.get_frame().set_linewidth(0.0)\n").unwrap();
+    }
+}
+
 impl GraphMaker for Legend {
     fn get_buffer<'a>(&'a self) -> &'a String {
         &self.buffer
     }
     fn clear_buffer(&mut self) {
         self.buffer.clear();
+        self.primitives.clear();
+    }
+    fn get_primitives(&self) -> &[Primitive] {
+        &self.primitives
     }
 }
 
@@ -202,26 +233,27 @@ impl GraphMaker for Legend {
 #[cfg(test)]
 mod tests {
     use super::Legend;
-    use crate::GraphMaker;
+    use crate::{GraphMaker, Primitive};
 
     #[test]
     fn new_works() {
         let legend = Legend::new();
-        assert_eq!(legend.fontsize, 0.0);
-        assert_eq!(legend.handle_len, 3.0);
-        assert_eq!(legend.num_col, 1);
-        assert_eq!(legend.location, "best".to_string());
-        assert_eq!(legend.outside, false);
-        assert_eq!(legend.show_frame, true);
-        assert_eq!(legend.x_coords, vec![0.0, 1.02, 1.0, 0.102]);
+        assert_eq!(legend.style.fontsize, 0.0);
+        assert_eq!(legend.style.handle_len, 3.0);
+        assert_eq!(legend.style.num_col, 1);
+        assert_eq!(legend.style.location, "best".to_string());
+        assert_eq!(legend.style.outside, false);
+        assert_eq!(legend.style.show_frame, true);
+        assert_eq!(legend.style.x_coords, vec![0.0, 1.02, 1.0, 0.102]);
         assert_eq!(legend.buffer.len(), 0);
+        assert_eq!(legend.primitives.len(), 0);
     }
 
     #[test]
     fn options_works() {
         let mut legend = Legend::new();
         legend.set_handle_len(6.0);
-        let opt = legend.options();
+        let opt = legend.style.options();
         assert_eq!(opt, "handlelength=6,ncol=1,loc='best'");
     }
 
@@ -234,7 +266,9 @@ mod tests {
                        \x20\x20\x20\x20leg=plt.legend(handlelength=3,ncol=1,loc='best')\n\
                        \x20\x20\x20\x20add_to_ea(leg)\n";
         assert_eq!(legend.buffer, b);
+        assert_eq!(legend.get_primitives(), &[Primitive::Legend(legend.style.clone())]);
         legend.clear_buffer();
         assert_eq!(legend.buffer, "");
+        assert_eq!(legend.primitives.len(), 0);
     }
 }
@@ -20,10 +20,29 @@
 //!
 //! When calling [Plot::save] or [Plot::save_and_show], if an error occurs, we generate a log
 //! file in the same output directory with the same filename as the figure (and python script),
-//! but with the `.log` extension.
+//! but with the `.log` extension. The returned [Error::PythonFailed] also carries the captured
+//! output (e.g., the Python traceback), the exception type, and the line number in the script.
 //!
-//! The typical use of this library is by allocating structures such as [Canvas], [Curve], [Contour],
-//! [Histogram], [Surface], [Text] (and more) and then passing them to [Plot] for the generation
+//! Alternatively, [Plot::render_to_bytes] pipes the script into python3 and returns the figure
+//! in memory (e.g., as SVG or PNG bytes) without writing any file.
+//!
+//! The Python interpreter may be selected process-wide via the `PLOTPY_PYTHON` environment variable
+//! (e.g., `PLOTPY_PYTHON=/opt/venv/bin/python`) or per figure via [Plot::set_python]. See also
+//! [Plot::set_python_env], [Plot::set_python_working_dir], [Plot::set_timeout],
+//! [Plot::set_output_callback], and [Plot::check_python].
+//!
+//! To save many figures, a [PythonWorker] keeps a single Python process alive and avoids
+//! importing numpy and matplotlib for every figure. Moreover, [render_all] saves a collection
+//! of figures concurrently using a pool of workers.
+//!
+//! An [Animation] saves a sequence of frames as a GIF or MP4 file using `matplotlib.animation`
+//! and a [PdfReport] saves several plots into a multi-page PDF file.
+//!
+//! With the `svg` feature, `Plot::render_svg` and `Plot::save_svg` draw a 2D subset of the
+//! features (curves, canvas shapes, text, and legends) directly to SVG without calling Python.
+//!
+//! The typical use of this library is by allocating structures such as [Barplot], [Canvas], [Curve],
+//! [Contour], [Histogram], [Surface], [Text], [VectorField] (and more) and then passing them to [Plot] for the generation
 //! of the files mentioned above. The [Plot::save_and_show] function may also be used to immediately
 //! see the plot or drawing on the screen.
 //!
@@ -33,13 +52,17 @@
 //! to the [Plot] and then call [Plot::save]. The `draw` method of each object must be called
 //! before adding to `Plot`.
 //!
+//! Each `draw_...` function records a typed [Primitive] (the data and a copy of the style) and the
+//! Python commands are generated from these primitives. The primitives are returned by
+//! [GraphMaker::get_primitives] and can be inspected, modified, and added back via [Plot::add_primitives].
+//!
 //! # Example
 //!
 //! ```
-//! use plotpy::{Plot, StrError, Surface};
+//! use plotpy::{Error, Plot, Surface};
 //! use russell_lab::generate3d;
 //!
-//! fn main() -> Result<(), StrError> {
+//! fn main() -> Result<(), Error> {
 //!     let mut surface = Surface::new();
 //!     surface
 //!         .set_with_wireframe(true)
@@ -67,40 +90,71 @@
 //!
 //! ![example_main.svg](https://raw.githubusercontent.com/cpmech/plotpy/main/figures/example_main.svg)
 
-/// Defines a type alias for the error type as a static string
-pub type StrError = &'static str;
-
 // modules ////////////////////////////////////////
+mod animation;
 mod as_matrix;
 mod as_vector;
+mod barplot;
+mod batch;
+mod boxplot;
 mod canvas;
 mod constants;
 mod contour;
 mod conversions;
 mod curve;
+mod error;
 mod fileio;
+mod fill;
 mod histogram;
+mod histogram_2d;
+mod image;
 mod legend;
+mod pdf_report;
+mod pie;
 mod plot;
+mod python_worker;
+mod scatter;
+mod scene;
 mod slope_icon;
+mod sparsity_pattern;
 mod surface;
 mod surface_geometry;
+#[cfg(feature = "svg")]
+mod svg;
 mod text;
+mod vector_field;
+pub use crate::animation::*;
 pub use crate::as_matrix::*;
 pub use crate::as_vector::*;
+pub use crate::barplot::*;
+pub use crate::batch::*;
+pub use crate::boxplot::*;
 pub use crate::canvas::*;
 pub use crate::constants::*;
 pub use crate::contour::*;
 use crate::conversions::*;
 pub use crate::curve::*;
+pub use crate::error::*;
 use crate::fileio::*;
+pub use crate::fill::*;
 pub use crate::histogram::*;
+pub use crate::histogram_2d::*;
+pub use crate::image::*;
 pub use crate::legend::*;
+pub use crate::pdf_report::*;
+pub use crate::pie::*;
 pub use crate::plot::*;
+pub use crate::python_worker::*;
+pub use crate::scatter::*;
+pub use crate::scene::*;
 pub use crate::slope_icon::*;
+pub use crate::sparsity_pattern::*;
 pub use crate::surface::*;
 pub use crate::surface_geometry::*;
+#[cfg(feature = "svg")]
+use crate::svg::*;
 pub use crate::text::*;
+pub use crate::vector_field::*;
 
 // run code from README file
 #[cfg(doctest)]
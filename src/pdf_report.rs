@@ -0,0 +1,271 @@
+use super::{call_python3, Error, Plot};
+use std::ffi::OsStr;
+use std::fmt::Write;
+use std::path::{Path, PathBuf};
+
+/// Generates a multi-page PDF file with one figure per page
+///
+/// Each page is generated by a [Plot] and saved by `matplotlib.backends.backend_pdf.PdfPages`.
+/// The figure size of each page is given by the Plot (see [Plot::set_figure_size_inches]).
+///
+/// # Note
+///
+/// The python interpreter and the error options are taken from the Plot of the first page.
+///
+/// # Example
+///
+/// ```
+/// use plotpy::{Curve, Error, PdfReport, Plot};
+///
+/// fn main() -> Result<(), Error> {
+///     // first page
+///     let mut curve = Curve::new();
+///     curve.draw(&[0.0, 1.0, 2.0], &[0.0, 1.0, 4.0]);
+///     let mut plot1 = Plot::new();
+///     plot1.add(&curve).grid_and_labels("x", "y");
+///
+///     // second page
+///     let mut plot2 = Plot::new();
+///     plot2.add(&curve).set_figure_size_inches(8.0, 3.0);
+///
+///     // save report
+///     let mut report = PdfReport::new();
+///     report
+///         .set_title("Test report")
+///         .set_author("plotpy")
+///         .add_page(&plot1, "First page")
+///         .add_page(&plot2, "");
+///     report.save("/tmp/plotpy/doc_tests/doc_pdf_report.pdf")?;
+///     Ok(())
+/// }
+/// ```
+pub struct PdfReport<'a> {
+    title: String,                  // title of the document
+    author: String,                 // author of the document
+    subject: String,                // subject of the document
+    pages: Vec<(&'a Plot, String)>, // plot and title of each page
+}
+
+impl<'a> PdfReport<'a> {
+    /// Creates a new PdfReport object
+    pub fn new() -> Self {
+        PdfReport {
+            title: String::new(),
+            author: String::new(),
+            subject: String::new(),
+            pages: Vec::new(),
+        }
+    }
+
+    /// Adds a page with the figure generated by a Plot
+    ///
+    /// # Input
+    ///
+    /// * `plot` -- the plot of this page
+    /// * `title` -- the title of this page (written above the figure); may be empty
+    pub fn add_page(&mut self, plot: &'a Plot, title: &str) -> &mut Self {
+        self.pages.push((plot, title.to_string()));
+        self
+    }
+
+    /// Sets the title of the document (metadata)
+    pub fn set_title(&mut self, title: &str) -> &mut Self {
+        self.title = String::from(title);
+        self
+    }
+
+    /// Sets the author of the document (metadata)
+    pub fn set_author(&mut self, author: &str) -> &mut Self {
+        self.author = String::from(author);
+        self
+    }
+
+    /// Sets the subject of the document (metadata)
+    pub fn set_subject(&mut self, subject: &str) -> &mut Self {
+        self.subject = String::from(subject);
+        self
+    }
+
+    /// Returns the number of pages
+    pub fn num_pages(&self) -> usize {
+        self.pages.len()
+    }
+
+    /// Calls python3 and saves the python script and PDF file
+    ///
+    /// # Input
+    ///
+    /// * `figure_path` -- may be a String, &str, or Path with the `.pdf` extension
+    ///
+    /// # Note
+    ///
+    /// As in [Plot::save], the python script is saved with the `.py` extension and, if an
+    /// error occurs, the log file is saved with the `.log` extension.
+    pub fn save<S>(&self, figure_path: &S) -> Result<(), Error>
+    where
+        S: AsRef<OsStr> + ?Sized,
+    {
+        let first = match self.pages.first() {
+            Some((plot, _)) => plot,
+            None => {
+                return Err(Error::InvalidInput {
+                    argument: "pages",
+                    message: "the report must have at least one page",
+                })
+            }
+        };
+        let fig_path = first.figure_path(figure_path)?;
+        let commands = self.script(&fig_path)?;
+        let py_path = fig_path.with_extension("py");
+        let output = call_python3(&commands, &py_path, first.interpreter())?;
+        first.check_output(output, &fig_path, &py_path)
+    }
+
+    /// Returns the python commands (without header) to save the PDF file
+    fn script(&self, fig_path: &Path) -> Result<String, Error> {
+        // check extension
+        let extension = fig_path.extension().and_then(|e| e.to_str()).unwrap_or("");
+        if extension.to_lowercase() != "pdf" {
+            return Err(Error::InvalidInput {
+                argument: "figure_path",
+                message: "the extension must be pdf",
+            });
+        }
+
+        // metadata
+        let mut buffer = String::new();
+        write!(
+            &mut buffer,
+            "from matplotlib.backends.backend_pdf import PdfPages\n\
+             fn=r'{}'\n\
+             PDF=PdfPages(fn,metadata={{",
+            fig_path.to_string_lossy()
+        )
+        .unwrap();
+        for (key, value) in [
+            ("Title", &self.title),
+            ("Author", &self.author),
+            ("Subject", &self.subject),
+        ] {
+            if value != "" {
+                write!(&mut buffer, "'{}':r'{}',", key, value).unwrap();
+            }
+        }
+        write!(&mut buffer, "}})\n").unwrap();
+
+        // pages
+        for (i, (plot, title)) in self.pages.iter().enumerate() {
+            let commands = plot.commands(&page_path(fig_path, i), plot.npy_threshold())?;
+            write!(
+                &mut buffer,
+                "plt.figure()\n\
+                 AX3D=None\n\
+                 EXTRA_ARTISTS.clear()\n\
+                 {}",
+                commands
+            )
+            .unwrap();
+            if title != "" {
+                write!(&mut buffer, "st=plt.suptitle(r'{}')\nadd_to_ea(st)\n", title).unwrap();
+            }
+            write!(
+                &mut buffer,
+                "PDF.savefig(bbox_inches='tight',bbox_extra_artists=EXTRA_ARTISTS)\n\
+                 plt.close()\n"
+            )
+            .unwrap();
+        }
+        write!(&mut buffer, "PDF.close()\n").unwrap();
+        Ok(buffer)
+    }
+}
+
+/// Returns the path used to name the .npy files of a page
+fn page_path(fig_path: &Path, index: usize) -> PathBuf {
+    let mut path = fig_path.with_extension("").into_os_string();
+    path.push(format!("_page{}", index));
+    PathBuf::from(path)
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{page_path, PdfReport};
+    use crate::{Error, Plot};
+    use std::path::Path;
+
+    #[test]
+    fn new_works() {
+        let report = PdfReport::new();
+        assert_eq!(report.title, "");
+        assert_eq!(report.author, "");
+        assert_eq!(report.subject, "");
+        assert_eq!(report.num_pages(), 0);
+    }
+
+    #[test]
+    fn script_works() {
+        let mut plot1 = Plot::new();
+        plot1.set_label_x("x");
+        let mut plot2 = Plot::new();
+        plot2.set_figure_size_inches(8.0, 3.0);
+        let mut report = PdfReport::new();
+        report
+            .set_title("Report")
+            .set_subject("Tests")
+            .add_page(&plot1, "first")
+            .add_page(&plot2, "");
+        assert_eq!(report.num_pages(), 2);
+        let script = report.script(Path::new("/tmp/plotpy/report.pdf")).unwrap();
+        let correct: &str = "from matplotlib.backends.backend_pdf import PdfPages\n\
+                             fn=r'/tmp/plotpy/report.pdf'\n\
+                             PDF=PdfPages(fn,metadata={'Title':r'Report','Subject':r'Tests',})\n\
+                             plt.figure()\n\
+                             AX3D=None\n\
+                             EXTRA_ARTISTS.clear()\n\
+                             plt.xlabel(r'x')\n\
+                             st=plt.suptitle(r'first')\n\
+                             add_to_ea(st)\n\
+                             PDF.savefig(bbox_inches='tight',bbox_extra_artists=EXTRA_ARTISTS)\n\
+                             plt.close()\n\
+                             plt.figure()\n\
+                             AX3D=None\n\
+                             EXTRA_ARTISTS.clear()\n\
+                             plt.gcf().set_size_inches(8,3)\n\
+                             PDF.savefig(bbox_inches='tight',bbox_extra_artists=EXTRA_ARTISTS)\n\
+                             plt.close()\n\
+                             PDF.close()\n";
+        assert_eq!(script, correct);
+    }
+
+    #[test]
+    fn save_captures_errors() {
+        let report = PdfReport::new();
+        assert_eq!(
+            report.save("/tmp/plotpy/unit_tests/report.pdf").err(),
+            Some(Error::InvalidInput {
+                argument: "pages",
+                message: "the report must have at least one page",
+            })
+        );
+        let plot = Plot::new();
+        let mut report = PdfReport::new();
+        report.add_page(&plot, "");
+        assert_eq!(
+            report.save("/tmp/plotpy/unit_tests/report.svg").err(),
+            Some(Error::InvalidInput {
+                argument: "figure_path",
+                message: "the extension must be pdf",
+            })
+        );
+    }
+
+    #[test]
+    fn page_path_works() {
+        assert_eq!(
+            page_path(Path::new("/tmp/plotpy/report.pdf"), 2),
+            Path::new("/tmp/plotpy/report_page2")
+        );
+    }
+}
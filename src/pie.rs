@@ -0,0 +1,361 @@
+use super::{
+    vector_to_array, vector_to_f64, vector_to_numbers, vector_to_strings, AsVector, GraphMaker, Primitive, Script,
+};
+use num_traits::AsPrimitive;
+use std::fmt::Write;
+
+/// Generates a pie (or donut) chart
+///
+/// # Example
+///
+/// ```
+/// use plotpy::{Error, Pie, Plot};
+///
+/// fn main() -> Result<(), Error> {
+///     // set values and labels (e.g., memory usage)
+///     let values = [45.0, 25.0, 20.0, 10.0];
+///     let labels = ["solver", "assembly", "output", "other"];
+///
+///     // configure and draw pie
+///     let mut pie = Pie::new();
+///     pie.set_colors(&["#9de19a", "#e7eca3", "#98a7f2", "#f2a798"])
+///         .set_explode(&[0.05, 0.0, 0.0, 0.0])
+///         .set_percent_format("%.1f%%")
+///         .set_hole(0.4)
+///         .set_start_angle(90.0);
+///     pie.draw(&values, &labels);
+///
+///     // add pie to plot
+///     let mut plot = Plot::new();
+///     plot.add(&pie).set_equal_axes(true).set_hide_axes(true);
+///
+///     // save figure
+///     plot.save("/tmp/plotpy/doc_tests/doc_pie.svg")?;
+///     Ok(())
+/// }
+/// ```
+///
+/// See also integration test in the **tests** directory.
+pub struct Pie {
+    style: PieStyle,            // Style
+    buffer: String,             // buffer
+    primitives: Vec<Primitive>, // primitives
+}
+
+/// Holds the style of a pie chart (see [Pie])
+///
+/// The style is recorded by the [Primitive] drawn by [Pie] and may be modified afterwards.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PieStyle {
+    pub colors: Vec<String>,    // Colors of the wedges
+    pub explode: Vec<f64>,      // Offsets of the wedges as a fraction of the radius
+    pub percent_format: String, // Format of the percentage labels; e.g. "%.1f%%"
+    pub percent_distance: f64,  // Distance of the percentage labels from the center (relative to the radius)
+    pub hole: f64,              // Radius of the hole (donut) relative to the radius of the pie
+    pub start_angle: f64,       // Angle (degrees) of the start of the first wedge from the x-axis
+    pub clockwise: bool,        // Draws the wedges clockwise
+    pub edge_color: String,     // Color of the edges of the wedges
+    pub line_width: f64,        // Line width of the edges of the wedges
+    pub with_legend: bool,      // Shows the labels in a legend instead of next to the wedges
+}
+
+impl Pie {
+    /// Creates a new Pie object
+    pub fn new() -> Self {
+        Pie {
+            style: PieStyle::default(),
+            buffer: String::new(),
+            primitives: Vec::new(),
+        }
+    }
+
+    /// Draws pie chart
+    ///
+    /// # Input
+    ///
+    /// * `values` -- values of the wedges (normalized by their sum)
+    /// * `labels` -- labels of the wedges (may be empty)
+    ///
+    /// # Notes
+    ///
+    /// * The type `U` of the input vector must be a number.
+    /// * The type `V` must be a String or &str.
+    ///
+    pub fn draw<'a, T, U, V>(&mut self, values: &'a T, labels: &[V])
+    where
+        T: AsVector<'a, U>,
+        U: 'a + AsPrimitive<f64>,
+        V: std::fmt::Display,
+    {
+        let values = vector_to_f64(values);
+        let labels = labels.iter().map(|label| label.to_string()).collect();
+        let style = self.style.clone();
+        self.push(Primitive::Pie { values, labels, style });
+    }
+
+    /// Sets the colors of the wedges
+    pub fn set_colors(&mut self, colors: &[&str]) -> &mut Self {
+        self.style.colors = colors.iter().map(|color| color.to_string()).collect();
+        self
+    }
+
+    /// Sets the offsets of the wedges as a fraction of the radius (one value per wedge)
+    pub fn set_explode(&mut self, offsets: &[f64]) -> &mut Self {
+        self.style.explode = offsets.to_vec();
+        self
+    }
+
+    /// Sets the format of the percentage labels
+    ///
+    /// Example: "%.1f%%". The percentages are not shown if the format is empty.
+    pub fn set_percent_format(&mut self, format: &str) -> &mut Self {
+        self.style.percent_format = String::from(format);
+        self
+    }
+
+    /// Sets the distance of the percentage labels from the center (relative to the radius)
+    ///
+    /// If zero, Matplotlib uses 0.6; or the middle of the ring if there is a hole.
+    pub fn set_percent_distance(&mut self, distance: f64) -> &mut Self {
+        self.style.percent_distance = distance;
+        self
+    }
+
+    /// Sets the radius of the hole relative to the radius of the pie (donut chart)
+    ///
+    /// Must be in [0, 1). There is no hole if zero.
+    pub fn set_hole(&mut self, radius: f64) -> &mut Self {
+        self.style.hole = radius;
+        self
+    }
+
+    /// Sets the angle (degrees) of the start of the first wedge, counterclockwise from the x-axis
+    pub fn set_start_angle(&mut self, angle: f64) -> &mut Self {
+        self.style.start_angle = angle;
+        self
+    }
+
+    /// Sets option to draw the wedges clockwise
+    pub fn set_clockwise(&mut self, flag: bool) -> &mut Self {
+        self.style.clockwise = flag;
+        self
+    }
+
+    /// Sets the color of the edges of the wedges
+    pub fn set_edge_color(&mut self, color: &str) -> &mut Self {
+        self.style.edge_color = String::from(color);
+        self
+    }
+
+    /// Sets the line width of the edges of the wedges
+    pub fn set_line_width(&mut self, width: f64) -> &mut Self {
+        self.style.line_width = width;
+        self
+    }
+
+    /// Sets option to show the labels in a legend (to the right of the pie) instead of next to the wedges
+    pub fn set_with_legend(&mut self, flag: bool) -> &mut Self {
+        self.style.with_legend = flag;
+        self
+    }
+
+    /// Writes the primitive to the buffer and records it
+    fn push(&mut self, primitive: Primitive) {
+        primitive.write_python(&mut self.buffer);
+        self.primitives.push(primitive);
+    }
+}
+
+impl Default for PieStyle {
+    fn default() -> Self {
+        PieStyle {
+            colors: Vec::new(),
+            explode: Vec::new(),
+            percent_format: String::new(),
+            percent_distance: 0.0,
+            hole: 0.0,
+            start_angle: 0.0,
+            clockwise: false,
+            edge_color: String::new(),
+            line_width: 0.0,
+            with_legend: false,
+        }
+    }
+}
+
+impl PieStyle {
+    /// Returns options for pie
+    pub(crate) fn options(&self, with_labels: bool) -> String {
+        let mut opt = String::new();
+        if with_labels {
+            write!(&mut opt, ",labels=labels").unwrap();
+            if self.with_legend {
+                write!(&mut opt, ",labeldistance=None").unwrap();
+            }
+        }
+        if self.colors.len() > 0 {
+            write!(&mut opt, ",colors=colors").unwrap();
+        }
+        if self.explode.len() > 0 {
+            write!(&mut opt, ",explode=explode").unwrap();
+        }
+        if self.percent_format != "" {
+            write!(&mut opt, ",autopct='{}'", self.percent_format).unwrap();
+            if self.percent_distance > 0.0 {
+                write!(&mut opt, ",pctdistance={}", self.percent_distance).unwrap();
+            } else if self.hole > 0.0 {
+                write!(&mut opt, ",pctdistance=(1+{})/2", self.hole).unwrap();
+            }
+        }
+        if self.start_angle != 0.0 {
+            write!(&mut opt, ",startangle={}", self.start_angle).unwrap();
+        }
+        if self.clockwise {
+            write!(&mut opt, ",counterclock=False").unwrap();
+        }
+        let mut props = Vec::new();
+        if self.hole > 0.0 {
+            props.push(format!("'width':1-{}", self.hole));
+        }
+        if self.edge_color != "" {
+            props.push(format!("'edgecolor':'{}'", self.edge_color));
+        }
+        if self.line_width > 0.0 {
+            props.push(format!("'linewidth':{}", self.line_width));
+        }
+        if props.len() > 0 {
+            write!(&mut opt, ",wedgeprops={{{}}}", props.join(",")).unwrap();
+        }
+        opt
+    }
+}
+
+/// Writes the Python commands of a pie chart (see [Primitive::Pie])
+pub(crate) fn write_pie(buf: &mut Script, values: &[f64], labels: &[String], style: &PieStyle) {
+    vector_to_array(buf, "values", &values);
+    let with_labels = labels.len() > 0;
+    if with_labels {
+        vector_to_strings(buf, "labels", labels);
+    }
+    if style.colors.len() > 0 {
+        vector_to_strings(buf, "colors", style.colors.as_slice());
+    }
+    if style.explode.len() > 0 {
+        vector_to_numbers(buf, "explode", style.explode.as_slice());
+    }
+    let opt = style.options(with_labels);
+    write!(buf, "wedges=plt.pie(values{})[0]\n", &opt).unwrap();
+    if with_labels && style.with_legend {
+        write!(
+            buf,
+            "leg=plt.legend(wedges,labels,loc='center left',bbox_to_anchor=(1,0.5))\n\
+             add_to_ea(leg)\n"
+        )
+        .unwrap();
+    }
+}
+
+impl GraphMaker for Pie {
+    fn get_buffer<'a>(&'a self) -> &'a String {
+        &self.buffer
+    }
+    fn clear_buffer(&mut self) {
+        self.buffer.clear();
+        self.primitives.clear();
+    }
+    fn get_primitives(&self) -> &[Primitive] {
+        &self.primitives
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::Pie;
+    use crate::{GraphMaker, Primitive};
+
+    #[test]
+    fn new_works() {
+        let pie = Pie::new();
+        assert_eq!(pie.style.colors.len(), 0);
+        assert_eq!(pie.style.explode.len(), 0);
+        assert_eq!(pie.style.percent_format.len(), 0);
+        assert_eq!(pie.style.percent_distance, 0.0);
+        assert_eq!(pie.style.hole, 0.0);
+        assert_eq!(pie.style.start_angle, 0.0);
+        assert_eq!(pie.style.clockwise, false);
+        assert_eq!(pie.style.edge_color.len(), 0);
+        assert_eq!(pie.style.line_width, 0.0);
+        assert_eq!(pie.style.with_legend, false);
+        assert_eq!(pie.buffer.len(), 0);
+        assert_eq!(pie.primitives.len(), 0);
+    }
+
+    #[test]
+    fn options_works() {
+        let mut pie = Pie::new();
+        assert_eq!(pie.style.options(false), "");
+        pie.set_colors(&["red", "blue"])
+            .set_explode(&[0.1, 0.0])
+            .set_percent_format("%.1f%%")
+            .set_hole(0.5)
+            .set_start_angle(90.0)
+            .set_clockwise(true)
+            .set_edge_color("white")
+            .set_line_width(2.0)
+            .set_with_legend(true);
+        assert_eq!(
+            pie.style.options(true),
+            ",labels=labels,labeldistance=None\
+             ,colors=colors\
+             ,explode=explode\
+             ,autopct='%.1f%%',pctdistance=(1+0.5)/2\
+             ,startangle=90\
+             ,counterclock=False\
+             ,wedgeprops={'width':1-0.5,'edgecolor':'white','linewidth':2}"
+        );
+        pie.set_percent_distance(0.8).set_with_legend(false);
+        assert_eq!(
+            pie.style.options(true),
+            ",labels=labels\
+             ,colors=colors\
+             ,explode=explode\
+             ,autopct='%.1f%%',pctdistance=0.8\
+             ,startangle=90\
+             ,counterclock=False\
+             ,wedgeprops={'width':1-0.5,'edgecolor':'white','linewidth':2}"
+        );
+    }
+
+    #[test]
+    fn draw_works() {
+        let mut pie = Pie::new();
+        pie.set_explode(&[0.1, 0.0, 0.0]).set_with_legend(true);
+        pie.draw(&[3, 2, 1], &["a", "b", "c"]);
+        let b: &str = "values=np.array([3,2,1,],dtype=float)\n\
+                       labels=['a','b','c',]\n\
+                       explode=[0.1,0,0,]\n\
+                       wedges=plt.pie(values,labels=labels,labeldistance=None,explode=explode)[0]\n\
+                       leg=plt.legend(wedges,labels,loc='center left',bbox_to_anchor=(1,0.5))\n\
+                       add_to_ea(leg)\n";
+        assert_eq!(pie.buffer, b);
+        assert_eq!(
+            pie.get_primitives(),
+            &[Primitive::Pie {
+                values: vec![3.0, 2.0, 1.0],
+                labels: vec!["a".to_string(), "b".to_string(), "c".to_string()],
+                style: pie.style.clone(),
+            }]
+        );
+        pie.clear_buffer();
+        assert_eq!(pie.buffer, "");
+        assert_eq!(pie.primitives.len(), 0);
+        let labels: [&str; 0] = [];
+        pie.draw(&vec![1.5, 2.5], &labels);
+        let b: &str = "values=np.array([1.5,2.5,],dtype=float)\n\
+                       explode=[0.1,0,0,]\n\
+                       wedges=plt.pie(values,explode=explode)[0]\n";
+        assert_eq!(pie.buffer, b);
+    }
+}
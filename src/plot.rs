@@ -1,9 +1,24 @@
-use super::{call_python3, Legend, StrError};
+#[cfg(feature = "svg")]
+use super::render_svg;
+use super::Primitive;
+use super::{
+    call_python3, call_python3_piped, fnv1a_hash, io_error, python_versions, write_python3, Error, Interpreter, Legend,
+    NpyFiles, OutputCallback, PYTHON_HEADER, STDIN,
+};
+use std::env;
 use std::ffi::OsStr;
 use std::fmt::Write;
-use std::fs::File;
+use std::fs::{self, File};
 use std::io::Write as IoWrite;
-use std::path::Path;
+use std::ops::Range;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Holds the figure formats accepted by [Plot::render_to_bytes]
+const FIGURE_FORMATS: [&str; 14] = [
+    "eps", "jpeg", "jpg", "pdf", "pgf", "png", "ps", "raw", "rgba", "svg", "svgz", "tif", "tiff", "webp",
+];
 
 /// Defines the trait used by Plot to add graph entities
 pub trait GraphMaker {
@@ -12,6 +27,31 @@ pub trait GraphMaker {
 
     /// Clear the text buffer with Python commands
     fn clear_buffer(&mut self);
+
+    /// Returns the primitives recorded by the draw functions (see [Primitive])
+    ///
+    /// If not empty, the text buffer must hold the Python commands written by these
+    /// primitives (see [Primitive::write_python]) because they may be lowered again
+    /// (e.g., to save the large arrays to .npy files; see [Plot::set_npy_threshold]).
+    fn get_primitives(&self) -> &[Primitive] {
+        &[]
+    }
+}
+
+/// Holds the versions of Python and the required modules found by [Plot::check_python]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PythonInfo {
+    /// Name (or path) of the Python interpreter
+    pub interpreter: String,
+
+    /// Version of Python, e.g., "3.11.4"
+    pub python: String,
+
+    /// Version of Matplotlib, e.g., "3.7.2"
+    pub matplotlib: String,
+
+    /// Version of NumPy, e.g., "1.25.1"
+    pub numpy: String,
 }
 
 /// Driver structure that calls Python
@@ -19,10 +59,10 @@ pub trait GraphMaker {
 /// # Example
 ///
 /// ```
-/// use plotpy::{Curve, Plot, StrError};
+/// use plotpy::{Curve, Error, Plot};
 /// use russell_lab::Vector;
 ///
-/// fn main() -> Result<(), StrError> {
+/// fn main() -> Result<(), Error> {
 ///     // generate (x,y) points
 ///     let n = 11;
 ///     let x = Vector::linspace(-1.0, 1.0, n)?;
@@ -84,9 +124,22 @@ pub trait GraphMaker {
 /// ![doc_plot.svg](https://raw.githubusercontent.com/cpmech/plotpy/main/figures/doc_plot.svg)
 ///
 /// See also integration tests in the [tests directory](https://github.com/cpmech/plotpy/tree/main/tests)
+///
+/// # Python interpreter
+///
+/// By default, `python3` is called. Another interpreter may be selected process-wide
+/// via the `PLOTPY_PYTHON` environment variable (e.g., `PLOTPY_PYTHON=/opt/venv/bin/python`)
+/// or per Plot via [Plot::set_python]; the latter takes precedence.
 pub struct Plot {
-    show_errors: bool, // show python errors, if any
-    buffer: String,    // buffer
+    show_errors: bool,    // show python errors, if any
+    python: Interpreter,  // python interpreter configuration
+    npy_threshold: usize, // arrays with this number of values (or more) go to .npy files (0 = disabled)
+    script_only: bool,    // only write the python script (do not call python)
+    cache: bool,          // skip calling python if the figure is up to date
+    buffer: String,       // buffer
+
+    scene: Vec<Primitive>, // primitives (for the SVG renderer and the .npy files)
+    lowered: Vec<(Range<usize>, Range<usize>)>, // ranges of the buffer written by ranges of the scene
 }
 
 impl Plot {
@@ -94,13 +147,54 @@ impl Plot {
     pub fn new() -> Self {
         Plot {
             show_errors: false,
+            python: Interpreter::default(),
+            npy_threshold: 0,
+            script_only: false,
+            cache: false,
             buffer: String::new(),
+            scene: Vec::new(),
+            lowered: Vec::new(),
         }
     }
 
     /// Adds new graph entity
     pub fn add(&mut self, graph: &dyn GraphMaker) -> &mut Self {
+        let start = self.buffer.len();
         self.buffer.push_str(graph.get_buffer());
+        self.record(start, graph.get_primitives());
+        self
+    }
+
+    /// Adds primitives (e.g., recorded by a graph entity and then modified)
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use plotpy::{Curve, GraphMaker, Plot, Primitive};
+    ///
+    /// let mut curve = Curve::new();
+    /// curve.draw(&[0.0, 1.0, 2.0], &[0.0, 1.0, 4.0]);
+    ///
+    /// // restyle all curves
+    /// let primitives: Vec<_> = curve
+    ///     .get_primitives()
+    ///     .iter()
+    ///     .cloned()
+    ///     .map(|mut primitive| {
+    ///         if let Primitive::Curve { style, .. } = &mut primitive {
+    ///             style.line_style = "--".to_string();
+    ///         }
+    ///         primitive
+    ///     })
+    ///     .collect();
+    ///
+    /// let mut plot = Plot::new();
+    /// plot.add_primitives(&primitives);
+    /// ```
+    pub fn add_primitives(&mut self, primitives: &[Primitive]) -> &mut Self {
+        for primitive in primitives {
+            self.push(primitive.clone());
+        }
         self
     }
 
@@ -113,7 +207,9 @@ impl Plot {
     /// # Note
     ///
     /// Call `set_show_errors` to configure how the errors (if any) are printed.
-    pub fn save<S>(&self, figure_path: &S) -> Result<(), StrError>
+    /// If python3 fails, [Error::PythonFailed] holds the captured output, the exception type,
+    /// and the line number of the python script (if available).
+    pub fn save<S>(&self, figure_path: &S) -> Result<(), Error>
     where
         S: AsRef<OsStr> + ?Sized,
     {
@@ -129,22 +225,154 @@ impl Plot {
     /// # Note
     ///
     /// Call `set_show_errors` to configure how the errors (if any) are printed.
-    pub fn save_and_show<S>(&self, figure_path: &S) -> Result<(), StrError>
+    pub fn save_and_show<S>(&self, figure_path: &S) -> Result<(), Error>
     where
         S: AsRef<OsStr> + ?Sized,
     {
         self.run(figure_path, true)
     }
 
+    /// Calls python3 and returns the figure as bytes (no files are written)
+    ///
+    /// # Input
+    ///
+    /// * `format` -- the figure format supported by Matplotlib: "eps", "jpeg", "jpg", "pdf", "pgf",
+    ///   "png", "ps", "raw", "rgba", "svg", "svgz", "tif", "tiff", or "webp"
+    ///
+    /// # Note
+    ///
+    /// The python script is piped into python3 and the figure is read from its standard output.
+    /// The call succeeds if python3 exits successfully and writes the figure; thus, warnings
+    /// printed by the script (e.g., deprecation warnings) are ignored.
+    /// Call `set_show_errors` to configure how the errors (if any) are printed.
+    pub fn render_to_bytes(&self, format: &str) -> Result<Vec<u8>, Error> {
+        // check format
+        if !FIGURE_FORMATS.contains(&format) {
+            return Err(Error::InvalidInput {
+                argument: "format",
+                message: "the figure format is not supported",
+            });
+        }
+
+        // update commands
+        let commands = format!(
+            "import io,sys\n\
+             stdout=sys.stdout.buffer\n\
+             sys.stdout=sys.stderr\n\
+             {}\n\
+             buf=io.BytesIO()\n\
+             plt.savefig(buf,format='{}',bbox_inches='tight',bbox_extra_artists=EXTRA_ARTISTS)\n\
+             stdout.write(buf.getvalue())\n\
+             stdout.flush()\n",
+            self.buffer, format
+        );
+
+        // call python
+        let (figure, mut output, success) = call_python3_piped(&commands, &self.python)?;
+
+        // handle error
+        if !success || figure.len() == 0 {
+            if output == "" {
+                output.push_str("python3 did not write the figure\n");
+            }
+            if self.show_errors {
+                println!("{}", output);
+            }
+            return Err(Error::python_failed(output, STDIN));
+        }
+        Ok(figure)
+    }
+
+    /// Renders the figure as SVG without calling Python (requires the `svg` feature)
+    ///
+    /// # Note
+    ///
+    /// The native renderer supports the 2D subset: lines and markers of [crate::Curve];
+    /// arcs, circles, arrows, polycurves, polylines, and grids of [crate::Canvas]; [crate::Text];
+    /// [crate::Legend]; and the title, labels, limits, ticks, grid, and equal axes of Plot.
+    /// Other commands (e.g., subplots, log scales, 3D features, and `extra`) are ignored.
+    /// The text width is estimated; thus, the layout differs slightly from Matplotlib.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "svg")]
+    /// # {
+    /// use plotpy::{Curve, Plot};
+    ///
+    /// let mut curve = Curve::new();
+    /// curve.set_label("parabola").set_marker_style("o");
+    /// curve.draw(&[0.0, 1.0, 2.0, 3.0], &[0.0, 1.0, 4.0, 9.0]);
+    ///
+    /// let mut plot = Plot::new();
+    /// plot.add(&curve).grid_labels_legend("x", "y");
+    /// let svg = plot.render_svg();
+    /// assert!(svg.contains("parabola"));
+    /// # }
+    /// ```
+    #[cfg(feature = "svg")]
+    pub fn render_svg(&self) -> String {
+        render_svg(&self.scene)
+    }
+
+    /// Saves the figure as SVG without calling Python (requires the `svg` feature)
+    ///
+    /// # Input
+    ///
+    /// * `figure_path` -- may be a String, &str, or Path
+    ///
+    /// See [Plot::render_svg] for the supported features.
+    #[cfg(feature = "svg")]
+    pub fn save_svg<S>(&self, figure_path: &S) -> Result<(), Error>
+    where
+        S: AsRef<OsStr> + ?Sized,
+    {
+        let path = Path::new(figure_path);
+        if let Some(p) = path.parent() {
+            fs::create_dir_all(p).map_err(|e| io_error("cannot create directory", p, e))?;
+        }
+        fs::write(path, self.render_svg()).map_err(|e| io_error("cannot write file", path, e))
+    }
+
+    /// Calls the Python interpreter and returns the versions of Python, Matplotlib, and NumPy
+    ///
+    /// # Note
+    ///
+    /// This function is useful to check the environment at startup. If Matplotlib or NumPy
+    /// cannot be imported, [Error::PythonFailed] is returned with `ModuleNotFoundError`.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use plotpy::{Error, Plot};
+    ///
+    /// fn main() -> Result<(), Error> {
+    ///     let mut plot = Plot::new();
+    ///     plot.set_python("/opt/venv/bin/python");
+    ///     let info = plot.check_python()?;
+    ///     println!("matplotlib {} and numpy {}", info.matplotlib, info.numpy);
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn check_python(&self) -> Result<PythonInfo, Error> {
+        let (python, matplotlib, numpy) = python_versions(&self.python)?;
+        Ok(PythonInfo {
+            interpreter: self.python.program(),
+            python,
+            matplotlib,
+            numpy,
+        })
+    }
+
     /// Clears the current axes
     pub fn clear_current_axes(&mut self) -> &mut Self {
-        self.buffer.push_str("plt.gca().cla()\n");
+        self.push(Primitive::ClearAxes);
         self
     }
 
     /// Clears current figure
     pub fn clear_current_figure(&mut self) -> &mut Self {
-        self.buffer.push_str("plt.clf()\n");
+        self.push(Primitive::ClearFigure);
         self
     }
 
@@ -157,29 +385,17 @@ impl Plot {
 
     /// Adds grid and labels
     pub fn grid_and_labels(&mut self, xlabel: &str, ylabel: &str) -> &mut Self {
-        write!(
-            &mut self.buffer,
-            "plt.gca().set_axisbelow(True)\n\
-             plt.grid(linestyle='--',color='grey',zorder=-1000)\n\
-             plt.xlabel(r'{}')\n\
-             plt.ylabel(r'{}')\n",
-            xlabel, ylabel
-        )
-        .unwrap();
+        self.push(Primitive::Grid);
+        self.push(Primitive::LabelX(xlabel.to_string()));
+        self.push(Primitive::LabelY(ylabel.to_string()));
         self
     }
 
     /// Adds grid, labels, and legend
     pub fn grid_labels_legend(&mut self, xlabel: &str, ylabel: &str) -> &mut Self {
-        write!(
-            &mut self.buffer,
-            "plt.gca().set_axisbelow(True)\n\
-             plt.grid(linestyle='--',color='grey',zorder=-1000)\n\
-             plt.xlabel(r'{}')\n\
-             plt.ylabel(r'{}')\n",
-            xlabel, ylabel
-        )
-        .unwrap();
+        self.push(Primitive::Grid);
+        self.push(Primitive::LabelX(xlabel.to_string()));
+        self.push(Primitive::LabelY(ylabel.to_string()));
         self.legend()
     }
 
@@ -189,6 +405,122 @@ impl Plot {
         self
     }
 
+    /// Sets the Python interpreter (name or path), e.g., "python3.11" or "/opt/venv/bin/python"
+    ///
+    /// # Note
+    ///
+    /// This option takes precedence over the `PLOTPY_PYTHON` environment variable.
+    pub fn set_python(&mut self, program: &str) -> &mut Self {
+        self.python.program = Some(program.to_string());
+        self
+    }
+
+    /// Sets an extra environment variable for the Python process (e.g., "MPLBACKEND")
+    pub fn set_python_env(&mut self, key: &str, value: &str) -> &mut Self {
+        self.python.envs.retain(|(k, _)| k != key);
+        self.python.envs.push((key.to_string(), value.to_string()));
+        self
+    }
+
+    /// Sets the working directory of the Python process
+    ///
+    /// # Note
+    ///
+    /// Relative figure paths given to `save` are still relative to the current directory of the Rust process.
+    pub fn set_python_working_dir<P>(&mut self, dir: &P) -> &mut Self
+    where
+        P: AsRef<Path> + ?Sized,
+    {
+        self.python.working_dir = Some(dir.as_ref().to_path_buf());
+        self
+    }
+
+    /// Sets the maximum time to wait for the Python process
+    ///
+    /// # Input
+    ///
+    /// * `timeout` -- if Python does not finish in time, the process is killed and
+    ///   [Error::PythonTimeout] is returned. Use `Duration::ZERO` to wait forever (default).
+    ///
+    /// # Note
+    ///
+    /// This option is useful to avoid blocking forever, e.g., if matplotlib waits for an
+    /// interactive backend in [Plot::save_and_show].
+    pub fn set_timeout(&mut self, timeout: Duration) -> &mut Self {
+        self.python.timeout = if timeout.is_zero() { None } else { Some(timeout) };
+        self
+    }
+
+    /// Sets a function to receive the output of the Python process line by line (e.g., to show progress)
+    ///
+    /// # Note
+    ///
+    /// The lines are given without the trailing newline as soon as Python prints them (stdout and stderr).
+    /// The output is still captured and returned in [Error::PythonFailed], if any. With
+    /// [Plot::render_to_bytes], only stderr is given to the function because stdout holds the figure.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use plotpy::Plot;
+    ///
+    /// let mut plot = Plot::new();
+    /// plot.set_output_callback(|line| println!("python: {}", line));
+    /// ```
+    pub fn set_output_callback<F>(&mut self, callback: F) -> &mut Self
+    where
+        F: Fn(&str) + Send + Sync + 'static,
+    {
+        self.python.callback = Some(OutputCallback(Arc::new(callback)));
+        self
+    }
+
+    /// Sets the number of values above which arrays are saved to binary .npy files
+    ///
+    /// # Input
+    ///
+    /// * `threshold` -- arrays (e.g., the x, y, z data of Curve, Contour, or Surface) with
+    ///   `threshold` values or more are saved to `.npy` files next to the figure and loaded by
+    ///   the python script with `np.load`. Use 0 to disable (default), i.e., always write the
+    ///   numbers into the script.
+    ///
+    /// # Note
+    ///
+    /// The files are named after the figure as `<figure stem>_data<k>.npy`. This option speeds up
+    /// the generation of figures with large datasets (e.g., a 1000×1000 Surface) and does not affect
+    /// the values, which are written from the f64 data recorded by the draw functions (see [Primitive]).
+    /// The commands given to [Plot::extra] are not changed. This option is ignored by [Plot::render_to_bytes].
+    pub fn set_npy_threshold(&mut self, threshold: usize) -> &mut Self {
+        self.npy_threshold = threshold;
+        self
+    }
+
+    /// Sets the option to only write the python script (python is not called and no figure is saved)
+    ///
+    /// # Note
+    ///
+    /// This option is useful to generate the scripts and run them later (e.g., on another machine).
+    /// It is used by [Plot::save], [Plot::save_and_show], and [crate::PythonWorker::save].
+    pub fn set_script_only(&mut self, flag: bool) -> &mut Self {
+        self.script_only = flag;
+        self
+    }
+
+    /// Sets the option to skip calling python if the figure is up to date
+    ///
+    /// # Note
+    ///
+    /// When this option is enabled, a hash of the full python script (header, commands, and
+    /// figure path) is saved to a file with the `.hash` extension next to the figure after python
+    /// succeeds. The next call to [Plot::save] (or [crate::PythonWorker::save]) with the same script
+    /// does nothing if the figure and the hash file exist. The data in `.npy` files (see
+    /// [Plot::set_npy_threshold]) is also accounted for because the hash is computed from the
+    /// commands with all the values written in full. This option is ignored by [Plot::save_and_show].
+    pub fn set_cache(&mut self, flag: bool) -> &mut Self {
+        self.cache = flag;
+        self
+    }
+
     /// Configures subplots
     ///
     /// # Input
@@ -269,7 +601,7 @@ impl Plot {
 
     /// Adds a title to the plot or sub-plot
     pub fn set_title(&mut self, title: &str) -> &mut Self {
-        write!(&mut self.buffer, "plt.title(r'{}')\n", title).unwrap();
+        self.push(Primitive::Title(title.to_string()));
         self
     }
 
@@ -304,32 +636,26 @@ impl Plot {
 
     /// Sets same scale for both axes
     pub fn set_equal_axes(&mut self, equal: bool) -> &mut Self {
-        if equal {
-            self.buffer.push_str("set_equal_axes()\n");
-        } else {
-            self.buffer.push_str("plt.gca().axes.set_aspect('auto')\n");
-        }
+        self.push(Primitive::EqualAxes(equal));
         self
     }
 
     /// Sets the figure size in inches
     pub fn set_figure_size_inches(&mut self, width: f64, height: f64) -> &mut Self {
-        write!(&mut self.buffer, "plt.gcf().set_size_inches({},{})\n", width, height).unwrap();
+        self.push(Primitive::FigureSize(width, height));
         self
     }
 
     /// Sets the figure size in points
-    #[rustfmt::skip]
     pub fn set_figure_size_points(&mut self, width: f64, height: f64) -> &mut Self {
         const FACTOR: f64 = 72.27;
-        write!(&mut self.buffer, "plt.gcf().set_size_inches({},{})\n", width / FACTOR, height / FACTOR).unwrap();
+        self.push(Primitive::FigureSize(width / FACTOR, height / FACTOR));
         self
     }
 
     /// Set option to hide axes
     pub fn set_hide_axes(&mut self, hide: bool) -> &mut Self {
-        let option = if hide { "off" } else { "on" };
-        write!(&mut self.buffer, "plt.axis('{}')\n", option).unwrap();
+        self.push(Primitive::HideAxes(hide));
         self
     }
 
@@ -349,114 +675,101 @@ impl Plot {
 
     /// Sets axes limits
     pub fn set_range(&mut self, xmin: f64, xmax: f64, ymin: f64, ymax: f64) -> &mut Self {
-        write!(&mut self.buffer, "plt.axis([{},{},{},{}])\n", xmin, xmax, ymin, ymax).unwrap();
+        self.push(Primitive::Range {
+            xmin: Some(xmin),
+            xmax: Some(xmax),
+            ymin: Some(ymin),
+            ymax: Some(ymax),
+        });
         self
     }
 
     /// Sets axes limits from vector
     pub fn set_range_from_vec(&mut self, limits: &[f64]) -> &mut Self {
-        write!(
-            &mut self.buffer,
-            "plt.axis([{},{},{},{}])\n",
-            limits[0], limits[1], limits[2], limits[3]
-        )
-        .unwrap();
+        self.push(Primitive::Range {
+            xmin: Some(limits[0]),
+            xmax: Some(limits[1]),
+            ymin: Some(limits[2]),
+            ymax: Some(limits[3]),
+        });
         self
     }
 
     /// Sets minimum x
     pub fn set_xmin(&mut self, xmin: f64) -> &mut Self {
-        write!(
-            &mut self.buffer,
-            "plt.axis([{},plt.axis()[1],plt.axis()[2],plt.axis()[3]])\n",
-            xmin
-        )
-        .unwrap();
+        self.push(Primitive::Range {
+            xmin: Some(xmin),
+            xmax: None,
+            ymin: None,
+            ymax: None,
+        });
         self
     }
 
     /// Sets maximum x
     pub fn set_xmax(&mut self, xmax: f64) -> &mut Self {
-        write!(
-            &mut self.buffer,
-            "plt.axis([plt.axis()[0],{},plt.axis()[2],plt.axis()[3]])\n",
-            xmax
-        )
-        .unwrap();
+        self.push(Primitive::Range {
+            xmin: None,
+            xmax: Some(xmax),
+            ymin: None,
+            ymax: None,
+        });
         self
     }
 
     /// Sets minimum y
     pub fn set_ymin(&mut self, ymin: f64) -> &mut Self {
-        write!(
-            &mut self.buffer,
-            "plt.axis([plt.axis()[0],plt.axis()[1],{},plt.axis()[3]])\n",
-            ymin
-        )
-        .unwrap();
+        self.push(Primitive::Range {
+            xmin: None,
+            xmax: None,
+            ymin: Some(ymin),
+            ymax: None,
+        });
         self
     }
 
     /// Sets maximum y
     pub fn set_ymax(&mut self, ymax: f64) -> &mut Self {
-        write!(
-            &mut self.buffer,
-            "plt.axis([plt.axis()[0],plt.axis()[1],plt.axis()[2],{}])\n",
-            ymax
-        )
-        .unwrap();
+        self.push(Primitive::Range {
+            xmin: None,
+            xmax: None,
+            ymin: None,
+            ymax: Some(ymax),
+        });
         self
     }
 
     /// Sets x-range (i.e. limits)
     pub fn set_xrange(&mut self, xmin: f64, xmax: f64) -> &mut Self {
-        write!(
-            &mut self.buffer,
-            "plt.axis([{},{},plt.axis()[2],plt.axis()[3]])\n",
-            xmin, xmax
-        )
-        .unwrap();
+        self.push(Primitive::Range {
+            xmin: Some(xmin),
+            xmax: Some(xmax),
+            ymin: None,
+            ymax: None,
+        });
         self
     }
 
     /// Sets y-range (i.e. limits)
     pub fn set_yrange(&mut self, ymin: f64, ymax: f64) -> &mut Self {
-        write!(
-            &mut self.buffer,
-            "plt.axis([plt.axis()[0],plt.axis()[1],{},{}])\n",
-            ymin, ymax
-        )
-        .unwrap();
+        self.push(Primitive::Range {
+            xmin: None,
+            xmax: None,
+            ymin: Some(ymin),
+            ymax: Some(ymax),
+        });
         self
     }
 
     /// Sets number of ticks along x
     pub fn set_num_ticks_x(&mut self, num: usize) -> &mut Self {
-        if num == 0 {
-            self.buffer.push_str("plt.gca().get_xaxis().set_ticks([])\n");
-        } else {
-            write!(
-                &mut self.buffer,
-                "plt.gca().get_xaxis().set_major_locator(tck.MaxNLocator({}))\n",
-                num
-            )
-            .unwrap();
-        }
+        self.push(Primitive::NumTicksX(num));
         self
     }
 
     /// Sets number of ticks along y
     pub fn set_num_ticks_y(&mut self, num: usize) -> &mut Self {
-        if num == 0 {
-            self.buffer.push_str("plt.gca().get_yaxis().set_ticks([])\n");
-        } else {
-            write!(
-                &mut self.buffer,
-                "plt.gca().get_yaxis().set_major_locator(tck.MaxNLocator({}))\n",
-                num
-            )
-            .unwrap();
-        }
+        self.push(Primitive::NumTicksY(num));
         self
     }
 
@@ -518,6 +831,29 @@ impl Plot {
         self
     }
 
+    /// Writes the primitive to the buffer and records it
+    fn push(&mut self, primitive: Primitive) {
+        let start = self.buffer.len();
+        primitive.write_python(&mut self.buffer);
+        self.record(start, std::slice::from_ref(&primitive));
+    }
+
+    /// Records the primitives that wrote the buffer from `start` to the end
+    fn record(&mut self, start: usize, primitives: &[Primitive]) {
+        if primitives.len() == 0 {
+            return;
+        }
+        let (end, first) = (self.buffer.len(), self.scene.len());
+        self.scene.extend_from_slice(primitives);
+        match self.lowered.last_mut() {
+            Some((text, scene)) if text.end == start && scene.end == first => {
+                text.end = end;
+                scene.end = self.scene.len();
+            }
+            _ => self.lowered.push((start..end, first..self.scene.len())),
+        }
+    }
+
     /// Writes the function multiple_of_pi_formatter to buffer
     #[inline]
     fn write_multiple_of_pi_formatter(&mut self) {
@@ -621,24 +957,20 @@ impl Plot {
 
     /// Sets the label for the x-axis
     pub fn set_label_x(&mut self, label: &str) -> &mut Self {
-        write!(&mut self.buffer, "plt.xlabel(r'{}')\n", label).unwrap();
+        self.push(Primitive::LabelX(label.to_string()));
         self
     }
 
     /// Sets the label for the y-axis
     pub fn set_label_y(&mut self, label: &str) -> &mut Self {
-        write!(&mut self.buffer, "plt.ylabel(r'{}')\n", label).unwrap();
+        self.push(Primitive::LabelY(label.to_string()));
         self
     }
 
     /// Sets the labels of x and y axis
     pub fn set_labels(&mut self, xlabel: &str, ylabel: &str) -> &mut Self {
-        write!(
-            &mut self.buffer,
-            "plt.xlabel(r'{}')\nplt.ylabel(r'{}')\n",
-            xlabel, ylabel
-        )
-        .unwrap();
+        self.push(Primitive::LabelX(xlabel.to_string()));
+        self.push(Primitive::LabelY(ylabel.to_string()));
         self
     }
 
@@ -707,39 +1039,137 @@ impl Plot {
     }
 
     /// Run python
-    fn run<S>(&self, figure_path: &S, show: bool) -> Result<(), StrError>
+    fn run<S>(&self, figure_path: &S, show: bool) -> Result<(), Error>
     where
         S: AsRef<OsStr> + ?Sized,
     {
+        // check cache
+        let fig_path = self.figure_path(figure_path)?;
+        if !show && self.is_up_to_date(&fig_path) {
+            return Ok(());
+        }
+
         // update commands
-        let fig_path = Path::new(figure_path);
+        let commands = self.script(&fig_path, show)?;
+        let py_path = fig_path.with_extension("py");
+        if self.script_only {
+            return write_python3(&commands, &py_path);
+        }
+
+        // call python
+        let output = call_python3(&commands, &py_path, &self.python)?;
+        self.check_output(output, &fig_path, &py_path)?;
+        self.update_cache(&fig_path)
+    }
+
+    /// Returns the python commands (without header) to save the figure
+    ///
+    /// Writes the .npy files, if needed (see [Plot::set_npy_threshold]).
+    pub(crate) fn script(&self, fig_path: &Path, show: bool) -> Result<String, Error> {
         let txt = if show {
             "plt.savefig(fn,bbox_inches='tight',bbox_extra_artists=EXTRA_ARTISTS)\nplt.show()\n"
         } else {
             "plt.savefig(fn,bbox_inches='tight',bbox_extra_artists=EXTRA_ARTISTS)\n"
         };
-        let commands = format!("{}\nfn=r'{}'\n{}", self.buffer, fig_path.to_string_lossy(), txt);
+        let buffer = self.commands(fig_path, self.npy_threshold)?;
+        Ok(format!("{}\nfn=r'{}'\n{}", buffer, fig_path.to_string_lossy(), txt))
+    }
 
-        // call python
-        let mut path = Path::new(figure_path).to_path_buf();
-        path.set_extension("py");
-        let output = call_python3(&commands, &path)?;
+    /// Returns the python commands with the large arrays saved to .npy files (see [Plot::set_npy_threshold])
+    ///
+    /// The primitives are lowered again with the arrays of `npy_threshold` values or more written
+    /// to .npy files named after `fig_path`. The other commands are copied from the buffer.
+    pub(crate) fn commands(&self, fig_path: &Path, npy_threshold: usize) -> Result<String, Error> {
+        if npy_threshold == 0 || self.lowered.len() == 0 {
+            return Ok(self.buffer.clone());
+        }
+        if let Some(p) = fig_path.parent() {
+            fs::create_dir_all(p).map_err(|e| io_error("cannot create directory", p, e))?;
+        }
+        let mut npy = NpyFiles::new(fig_path, npy_threshold);
+        let mut buffer = String::new();
+        let mut start = 0;
+        for (text, scene) in &self.lowered {
+            buffer.push_str(&self.buffer[start..text.start]);
+            for primitive in &self.scene[scene.clone()] {
+                primitive.write_python_npy(&mut buffer, &mut npy);
+            }
+            start = text.end;
+        }
+        buffer.push_str(&self.buffer[start..]);
+        npy.finish()?;
+        Ok(buffer)
+    }
+
+    /// Returns the number of values above which arrays are saved to .npy files (see [Plot::set_npy_threshold])
+    pub(crate) fn npy_threshold(&self) -> usize {
+        self.npy_threshold
+    }
+
+    /// Returns whether the option to only write the python script is enabled
+    pub(crate) fn script_only(&self) -> bool {
+        self.script_only
+    }
 
-        // handle error => write log file
+    /// Returns whether the figure and the hash file of the same script exist (see [Plot::set_cache])
+    pub(crate) fn is_up_to_date(&self, fig_path: &Path) -> bool {
+        if !self.cache || !fig_path.exists() {
+            return false;
+        }
+        match fs::read_to_string(fig_path.with_extension("hash")) {
+            Ok(hash) => hash.trim() == self.hash(fig_path),
+            Err(_) => false,
+        }
+    }
+
+    /// Writes the hash file of the script (see [Plot::set_cache])
+    pub(crate) fn update_cache(&self, fig_path: &Path) -> Result<(), Error> {
+        if self.cache {
+            let hash_path = fig_path.with_extension("hash");
+            fs::write(&hash_path, self.hash(fig_path)).map_err(|e| io_error("cannot write file", &hash_path, e))?;
+        }
+        Ok(())
+    }
+
+    /// Returns the hash of the full python script
+    fn hash(&self, fig_path: &Path) -> String {
+        let path = fig_path.to_string_lossy();
+        format!("{:016x}", fnv1a_hash(&[PYTHON_HEADER, &self.buffer, &path]))
+    }
+
+    /// Handles the output of python => writes the log file if there is any output
+    pub(crate) fn check_output(&self, output: String, fig_path: &Path, py_path: &Path) -> Result<(), Error> {
         if output != "" {
-            let mut log_path = Path::new(figure_path).to_path_buf();
-            log_path.set_extension("log");
-            let mut log_file = File::create(log_path).map_err(|_| "cannot create log file")?;
+            let log_path = fig_path.with_extension("log");
+            let mut log_file = File::create(&log_path).map_err(|e| io_error("cannot create log file", &log_path, e))?;
             log_file
                 .write_all(output.as_bytes())
-                .map_err(|_| "cannot write to log file")?;
+                .map_err(|e| io_error("cannot write to log file", &log_path, e))?;
             if self.show_errors {
                 println!("{}", output);
             }
-            return Err("python3 failed; please see the log file");
+            return Err(Error::python_failed(output, &py_path.to_string_lossy()));
         }
         Ok(())
     }
+
+    /// Returns the configuration of the python interpreter
+    pub(crate) fn interpreter(&self) -> &Interpreter {
+        &self.python
+    }
+
+    /// Returns the figure path, made absolute if the Python process runs in another directory
+    pub(crate) fn figure_path<S>(&self, figure_path: &S) -> Result<PathBuf, Error>
+    where
+        S: AsRef<OsStr> + ?Sized,
+    {
+        let path = Path::new(figure_path);
+        if self.python.working_dir.is_none() || path.is_absolute() {
+            return Ok(path.to_path_buf());
+        }
+        let current = env::current_dir().map_err(|e| io_error("cannot get current directory", path, e))?;
+        Ok(current.join(path))
+    }
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -747,9 +1177,11 @@ impl Plot {
 #[cfg(test)]
 mod tests {
     use super::Plot;
+    use crate::{Curve, Error, GraphMaker, PYTHON_HEADER};
     use std::fs::File;
     use std::io::{BufRead, BufReader};
-    use std::path::Path;
+    use std::path::{Path, PathBuf};
+    use std::time::Duration;
 
     const OUT_DIR: &str = "/tmp/plotpy/unit_tests";
 
@@ -790,7 +1222,187 @@ mod tests {
         plot.set_show_errors(true);
         plot.set_subplot(1, 1, WRONG);
         let path = Path::new(OUT_DIR).join("show_errors_works.svg");
-        assert_eq!(plot.save(&path).err(), Some("python3 failed; please see the log file"));
+        match plot.save(&path).err() {
+            Some(Error::PythonFailed { exception, line, .. }) => {
+                assert_eq!(exception, Some("ValueError".to_string()));
+                assert_eq!(line, Some(PYTHON_HEADER.lines().count() + 2));
+            }
+            _ => panic!("python3 should have failed"),
+        }
+    }
+
+    #[test]
+    fn python_options_work() {
+        let mut plot = Plot::new();
+        plot.set_python("python3")
+            .set_python_env("MPLBACKEND", "Agg")
+            .set_python_env("MPLBACKEND", "svg")
+            .set_python_working_dir("/tmp");
+        assert_eq!(plot.python.program, Some("python3".to_string()));
+        assert_eq!(plot.python.envs, &[("MPLBACKEND".to_string(), "svg".to_string())]);
+        assert_eq!(plot.python.working_dir, Some(PathBuf::from("/tmp")));
+        assert_eq!(plot.figure_path("/a/b.svg").unwrap(), PathBuf::from("/a/b.svg"));
+        let relative = plot.figure_path("b.svg").unwrap();
+        assert!(relative.is_absolute());
+        assert!(relative.ends_with("b.svg"));
+        assert_eq!(Plot::new().figure_path("b.svg").unwrap(), PathBuf::from("b.svg"));
+    }
+
+    #[test]
+    fn timeout_and_callback_work() {
+        let mut plot = Plot::new();
+        plot.set_timeout(Duration::from_millis(500))
+            .set_output_callback(|_| ())
+            .extra("import time\ntime.sleep(30)\n");
+        assert_eq!(plot.python.timeout, Some(Duration::from_millis(500)));
+        assert!(plot.python.callback.is_some());
+        let path = Path::new(OUT_DIR).join("timeout_and_callback_work.svg");
+        assert!(matches!(plot.save(&path), Err(Error::PythonTimeout { .. })));
+        plot.set_timeout(Duration::ZERO);
+        assert_eq!(plot.python.timeout, None);
+    }
+
+    #[test]
+    fn npy_threshold_works() {
+        let mut curve = Curve::new();
+        curve.draw(&[1.0, 2.0, 3.0], &[4.0, 5.0, 6.0]);
+        let mut plot = Plot::new();
+        plot.extra("w=np.array([1,2,3,],dtype=float)\n")
+            .add(&curve)
+            .set_title("npy")
+            .extra("z=np.array([1,2,],dtype=float)\n");
+        let fig_path = Path::new(OUT_DIR).join("npy_threshold_works.svg");
+        let script = plot.script(&fig_path, false).unwrap();
+        assert!(script.starts_with(&plot.buffer));
+        plot.set_npy_threshold(3);
+        let script = plot.script(&fig_path, false).unwrap();
+        let npy_0 = Path::new(OUT_DIR).join("npy_threshold_works_data0.npy");
+        let npy_1 = Path::new(OUT_DIR).join("npy_threshold_works_data1.npy");
+        let buffer = curve.get_buffer();
+        let correct = format!(
+            "w=np.array([1,2,3,],dtype=float)\n\
+             x=np.load('{}')\n\
+             y=np.load('{}')\n\
+             {}\
+             plt.title(r'npy')\n\
+             z=np.array([1,2,],dtype=float)\n",
+            npy_0.to_string_lossy(),
+            npy_1.to_string_lossy(),
+            &buffer[buffer.find("plt.plot").unwrap()..]
+        );
+        assert!(script.starts_with(&correct));
+        assert!(npy_0.exists());
+        assert!(npy_1.exists());
+    }
+
+    #[test]
+    fn script_only_works() {
+        let mut plot = Plot::new();
+        plot.set_python("__plotpy_missing_python__").set_script_only(true);
+        let path = Path::new(OUT_DIR).join("script_only_works.svg");
+        let py_path = path.with_extension("py");
+        std::fs::remove_file(&py_path).unwrap_or(());
+        plot.set_title("script only").save(&path).unwrap();
+        let script = std::fs::read_to_string(&py_path).unwrap();
+        assert!(script.starts_with(PYTHON_HEADER));
+        assert!(script.contains("plt.title(r'script only')\n"));
+        assert!(!path.exists());
+    }
+
+    #[test]
+    fn cache_works() {
+        let mut plot = Plot::new();
+        plot.set_title("cached");
+        let path = Path::new(OUT_DIR).join("cache_works.svg");
+        let hash_path = path.with_extension("hash");
+        std::fs::create_dir_all(OUT_DIR).unwrap();
+        std::fs::remove_file(&hash_path).unwrap_or(());
+        std::fs::write(&path, "<svg/>").unwrap();
+        assert_eq!(plot.is_up_to_date(&path), false);
+        plot.update_cache(&path).unwrap();
+        assert_eq!(hash_path.exists(), false);
+
+        // enable cache
+        plot.set_cache(true);
+        assert_eq!(plot.is_up_to_date(&path), false);
+        plot.update_cache(&path).unwrap();
+        assert_eq!(plot.is_up_to_date(&path), true);
+        assert_eq!(
+            plot.is_up_to_date(&Path::new(OUT_DIR).join("cache_works_missing.svg")),
+            false
+        );
+
+        // python is not called if the figure is up to date
+        plot.set_python("__plotpy_missing_python__");
+        assert_eq!(plot.save(&path), Ok(()));
+
+        // python is called if the script changes
+        plot.set_label_x("x");
+        assert_eq!(plot.is_up_to_date(&path), false);
+        assert_eq!(
+            plot.save(&path).err(),
+            Some(Error::PythonNotFound("__plotpy_missing_python__".to_string()))
+        );
+    }
+
+    #[test]
+    fn check_python_works() {
+        let plot = Plot::new();
+        let info = plot.check_python().unwrap();
+        assert!(info.python.starts_with('3'));
+        assert!(info.matplotlib.len() > 0);
+        assert!(info.numpy.len() > 0);
+        let mut plot = Plot::new();
+        plot.set_python("python_does_not_exist");
+        assert_eq!(
+            plot.check_python().err(),
+            Some(Error::PythonNotFound("python_does_not_exist".to_string()))
+        );
+    }
+
+    #[test]
+    fn render_to_bytes_works() {
+        let plot = Plot::new();
+        let figure = plot.render_to_bytes("svg").unwrap();
+        let svg = String::from_utf8(figure).unwrap();
+        assert!(svg.contains("<svg"));
+        assert!(svg.lines().count() > 20);
+    }
+
+    #[test]
+    fn render_to_bytes_ignores_warnings() {
+        let mut plot = Plot::new();
+        plot.extra("sys.stderr.write('DeprecationWarning: old stuff\\n')\n");
+        let figure = plot.render_to_bytes("svg").unwrap();
+        assert!(figure.len() > 0);
+    }
+
+    #[test]
+    fn render_to_bytes_rejects_wrong_format() {
+        let plot = Plot::new();
+        for format in ["", "svg')\nimport os\n#", "doc"] {
+            assert_eq!(
+                plot.render_to_bytes(format).err(),
+                Some(Error::InvalidInput {
+                    argument: "format",
+                    message: "the figure format is not supported",
+                })
+            );
+        }
+    }
+
+    #[test]
+    fn render_to_bytes_captures_errors() {
+        const WRONG: usize = 0;
+        let mut plot = Plot::new();
+        plot.set_subplot(1, 1, WRONG);
+        match plot.render_to_bytes("svg").err() {
+            Some(Error::PythonFailed { exception, line, .. }) => {
+                assert_eq!(exception, Some("ValueError".to_string()));
+                assert_eq!(line, Some(PYTHON_HEADER.lines().count() + 5));
+            }
+            _ => panic!("python3 should have failed"),
+        }
     }
 
     #[test]
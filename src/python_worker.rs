@@ -0,0 +1,317 @@
+use super::{call_python3, io_error, spawn_error, write_python3, Error, Interpreter, Plot};
+use std::ffi::OsStr;
+use std::io::{BufRead, BufReader, Read, Write};
+use std::path::Path;
+use std::process::{Child, ChildStdin, ChildStdout, Stdio};
+
+/// Python script run by the worker process
+///
+/// The worker imports numpy and matplotlib once and then waits for the paths of
+/// python scripts (one per line) on stdin. Each script is executed with fresh globals,
+/// its output is captured, and the figure state is reset afterwards. The captured
+/// output is sent back to stdout as `<number of bytes>\n<bytes>`.
+const WORKER_SCRIPT: &str = "import io, sys, traceback
+import numpy
+import matplotlib
+import matplotlib.pyplot
+RC_PARAMS = matplotlib.rcParams.copy()
+REPLY = sys.stdout.buffer
+def run(path):
+    out = io.StringIO()
+    sys.stdout, sys.stderr = out, out
+    try:
+        with open(path) as f:
+            code = compile(f.read(), path, 'exec')
+        exec(code, {'__name__': '__main__', '__file__': path})
+    except SystemExit:
+        pass
+    except BaseException as e:
+        traceback.print_exception(type(e), e, e.__traceback__.tb_next)
+    finally:
+        sys.stdout, sys.stderr = sys.__stdout__, sys.__stderr__
+        matplotlib.pyplot.close('all')
+        matplotlib.rcParams.update(RC_PARAMS)
+    return out.getvalue().encode('utf-8', 'replace')
+for line in sys.stdin:
+    data = run(line.rstrip('\\n'))
+    REPLY.write(b'%d\\n' % len(data))
+    REPLY.write(data)
+    REPLY.flush()
+";
+
+/// Holds the handles of a running worker process
+struct Process {
+    interpreter: Interpreter,       // configuration used to start the process
+    child: Child,                   // the python process
+    stdin: ChildStdin,              // receives the paths of the scripts
+    stdout: BufReader<ChildStdout>, // sends back the output of each script
+}
+
+/// Keeps a Python process alive to save many figures without restarting the interpreter
+///
+/// Every call to [Plot::save] starts a new Python process, which has to import numpy and
+/// matplotlib again. The worker, instead, starts Python once (on the first call to
+/// [PythonWorker::save]) and then feeds it the scripts of subsequent figures.
+/// The figure state (open figures and `rcParams`) is reset between jobs and the errors are
+/// reported per job, exactly as in [Plot::save].
+///
+/// If the worker cannot be used (e.g., the process has crashed), the figure is saved
+/// by the one-shot mode as in [Plot::save] and a new worker is started by the next job.
+/// The one-shot mode is also used if the plot has a timeout or an output callback
+/// (see [Plot::set_timeout] and [Plot::set_output_callback]).
+///
+/// # Example
+///
+/// ```
+/// use plotpy::{Curve, Error, Plot, PythonWorker};
+///
+/// fn main() -> Result<(), Error> {
+///     let mut worker = PythonWorker::new();
+///     for i in 0..3 {
+///         let mut curve = Curve::new();
+///         curve.draw(&[0.0, 1.0, 2.0], &[0.0, i as f64, 0.0]);
+///         let mut plot = Plot::new();
+///         plot.add(&curve);
+///         let path = format!("/tmp/plotpy/doc_tests/doc_python_worker_{}.svg", i);
+///         worker.save(&plot, &path)?;
+///     }
+///     Ok(())
+/// }
+/// ```
+pub struct PythonWorker {
+    process: Option<Process>, // the worker process, if running
+}
+
+impl PythonWorker {
+    /// Creates a new PythonWorker (the process is started by the first call to save)
+    pub fn new() -> Self {
+        PythonWorker { process: None }
+    }
+
+    /// Calls the worker and saves the python script and figure
+    ///
+    /// # Input
+    ///
+    /// * `plot` -- the figure; its interpreter configuration is used to start the worker
+    /// * `figure_path` -- may be a String, &str, or Path
+    ///
+    /// # Note
+    ///
+    /// The worker is restarted if the interpreter configuration of `plot` differs from the
+    /// one used to start the running process.
+    pub fn save<S>(&mut self, plot: &Plot, figure_path: &S) -> Result<(), Error>
+    where
+        S: AsRef<OsStr> + ?Sized,
+    {
+        // write script
+        let fig_path = plot.figure_path(figure_path)?;
+        if plot.is_up_to_date(&fig_path) {
+            return Ok(());
+        }
+        let py_path = fig_path.with_extension("py");
+        let commands = plot.script(&fig_path, false)?;
+        write_python3(&commands, &py_path)?;
+        if plot.script_only() {
+            return Ok(());
+        }
+
+        // call worker or fall back to one-shot mode
+        let output = match self.run(&py_path, plot.interpreter()) {
+            Some(output) => output,
+            None => call_python3(&commands, &py_path, plot.interpreter())?,
+        };
+        plot.check_output(output, &fig_path, &py_path)?;
+        plot.update_cache(&fig_path)
+    }
+
+    /// Returns whether the worker process is running or not
+    pub fn is_running(&mut self) -> bool {
+        match &mut self.process {
+            Some(process) => matches!(process.child.try_wait(), Ok(None)),
+            None => false,
+        }
+    }
+
+    /// Stops the worker process (a new one is started by the next call to save)
+    pub fn close(&mut self) {
+        if let Some(process) = self.process.take() {
+            let Process { mut child, stdin, .. } = process;
+            drop(stdin); // the worker stops at the end of stdin
+            if child.wait().is_err() {
+                child.kill().unwrap_or(());
+            }
+        }
+    }
+
+    /// Runs the script in the worker and returns its output, or None if the worker cannot be used
+    ///
+    /// The worker is not used if there is a timeout or an output callback (see [Plot::set_timeout]).
+    fn run(&mut self, py_path: &Path, interpreter: &Interpreter) -> Option<String> {
+        if interpreter.timeout.is_some() || interpreter.callback.is_some() {
+            return None;
+        }
+        let path = py_path.to_str().filter(|p| !p.contains('\n'))?;
+        if self.process.as_ref().is_some_and(|p| &p.interpreter != interpreter) {
+            self.close();
+        }
+        if self.process.is_none() {
+            self.process = start(interpreter).ok();
+        }
+        let process = self.process.as_mut()?;
+        match request(process, path) {
+            Ok(output) => Some(output),
+            Err(_) => {
+                process.child.kill().unwrap_or(());
+                process.child.wait().unwrap_or_default();
+                self.process = None;
+                None
+            }
+        }
+    }
+}
+
+impl Drop for PythonWorker {
+    fn drop(&mut self) {
+        self.close();
+    }
+}
+
+/// Starts the worker process
+fn start(interpreter: &Interpreter) -> Result<Process, Error> {
+    let mut child = interpreter
+        .command()
+        .arg("-u")
+        .arg("-c")
+        .arg(WORKER_SCRIPT)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null())
+        .spawn()
+        .map_err(|e| spawn_error(&interpreter.program(), e))?;
+    let stdin = child.stdin.take().ok_or("cannot open stdin of the worker")?;
+    let stdout = child.stdout.take().ok_or("cannot open stdout of the worker")?;
+    Ok(Process {
+        interpreter: interpreter.clone(),
+        child,
+        stdin,
+        stdout: BufReader::new(stdout),
+    })
+}
+
+/// Sends the path of a script to the worker and reads the output
+fn request(process: &mut Process, path: &str) -> Result<String, Error> {
+    let worker = Path::new("<worker>");
+    writeln!(process.stdin, "{}", path).map_err(|e| io_error("cannot write to", worker, e))?;
+    process
+        .stdin
+        .flush()
+        .map_err(|e| io_error("cannot write to", worker, e))?;
+    let mut header = String::new();
+    let n = process
+        .stdout
+        .read_line(&mut header)
+        .map_err(|e| io_error("cannot read from", worker, e))?;
+    if n == 0 {
+        return Err(Error::Message("the worker has stopped"));
+    }
+    let len: usize = header.trim().parse().map_err(|_| "the worker sent an invalid reply")?;
+    let mut data = vec![0; len];
+    process
+        .stdout
+        .read_exact(&mut data)
+        .map_err(|e| io_error("cannot read from", worker, e))?;
+    Ok(String::from_utf8_lossy(&data).to_string())
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::PythonWorker;
+    use crate::{Error, Plot, PYTHON_HEADER};
+    use std::fs;
+    use std::path::Path;
+
+    const OUT_DIR: &str = "/tmp/plotpy/unit_tests";
+
+    #[test]
+    fn new_works() {
+        let mut worker = PythonWorker::new();
+        assert!(worker.process.is_none());
+        assert_eq!(worker.is_running(), false);
+    }
+
+    #[test]
+    fn save_works() {
+        let mut worker = PythonWorker::new();
+        let mut plot = Plot::new();
+        plot.extra("LEAKED = True\nprint('first')\n");
+        let path = Path::new(OUT_DIR).join("python_worker_save_works_1.svg");
+        match worker.save(&plot, &path).err() {
+            Some(Error::PythonFailed { output, .. }) => assert_eq!(output, "first\n"),
+            _ => panic!("the output should have been reported"),
+        }
+        assert_eq!(worker.is_running(), true);
+        let log = fs::read_to_string(path.with_extension("log")).unwrap();
+        assert_eq!(log, "first\n");
+
+        // globals do not leak from the previous job
+        let mut plot = Plot::new();
+        plot.extra("print('second', 'LEAKED' in globals())\n");
+        let path = Path::new(OUT_DIR).join("python_worker_save_works_2.svg");
+        match worker.save(&plot, &path).err() {
+            Some(Error::PythonFailed { output, .. }) => assert_eq!(output, "second False\n"),
+            _ => panic!("the output should have been reported"),
+        }
+        let script = fs::read_to_string(path.with_extension("py")).unwrap();
+        assert!(script.starts_with(PYTHON_HEADER));
+
+        // a successful job
+        let plot = Plot::new();
+        let path = Path::new(OUT_DIR).join("python_worker_save_works_3.svg");
+        worker.save(&plot, &path).unwrap();
+        assert!(path.exists());
+        worker.close();
+        assert_eq!(worker.is_running(), false);
+    }
+
+    #[test]
+    fn save_reports_errors() {
+        const WRONG: usize = 0;
+        let mut worker = PythonWorker::new();
+        let mut plot = Plot::new();
+        plot.set_subplot(1, 1, WRONG);
+        let path = Path::new(OUT_DIR).join("python_worker_save_reports_errors.svg");
+        match worker.save(&plot, &path).err() {
+            Some(Error::PythonFailed {
+                output,
+                exception,
+                line,
+            }) => {
+                assert!(output.starts_with("Traceback"));
+                assert_eq!(exception, Some("ValueError".to_string()));
+                assert_eq!(line, Some(PYTHON_HEADER.lines().count() + 2));
+            }
+            _ => panic!("python3 should have failed"),
+        }
+        assert_eq!(worker.is_running(), true);
+    }
+
+    #[test]
+    fn save_falls_back_to_one_shot() {
+        let mut worker = PythonWorker::new();
+        let mut plot = Plot::new();
+        plot.extra("import os\nos._exit(0)\n");
+        let path = Path::new(OUT_DIR).join("python_worker_save_falls_back.svg");
+        worker.save(&plot, &path).unwrap();
+        assert_eq!(worker.is_running(), false);
+
+        let mut plot = Plot::new();
+        plot.set_python("python_does_not_exist");
+        let path = Path::new(OUT_DIR).join("python_worker_save_falls_back.svg");
+        assert_eq!(
+            worker.save(&plot, &path).err(),
+            Some(Error::PythonNotFound("python_does_not_exist".to_string()))
+        );
+    }
+}
@@ -0,0 +1,573 @@
+use super::{vector_to_array, vector_to_f64, AsVector, Error, GraphMaker, Primitive, Script};
+use num_traits::AsPrimitive;
+use std::fmt::Write;
+
+/// Generates a scatter plot with the color and size of markers mapped to data
+///
+/// # Notes
+///
+/// * This struct corresponds to the **scatter** function of Matplotlib.
+/// * The colors are mapped from scalar values by a colormap (and shown by a colorbar).
+/// * The sizes are given in points² (times the size scale; see [Scatter::set_size_scale]).
+///
+/// # Example
+///
+/// ```
+/// use plotpy::{Error, Plot, Scatter};
+///
+/// fn main() -> Result<(), Error> {
+///     // data
+///     let x = vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0];
+///     let y = vec![2.0, 4.0, 1.0, 5.0, 3.0, 6.0];
+///     let temperature = vec![10.0, 20.0, 15.0, 30.0, 25.0, 35.0];
+///     let population = vec![1.0, 5.0, 2.0, 8.0, 3.0, 10.0];
+///
+///     // configure and draw scatter
+///     let mut scatter = Scatter::new();
+///     scatter
+///         .set_colormap_name("viridis")
+///         .set_colorbar_label("temperature")
+///         .set_size_scale(30.0)
+///         .set_size_legend(3, "population")
+///         .set_edge_color("black");
+///     scatter.draw(&x, &y, Some(&temperature), Some(&population))?;
+///
+///     // add scatter to plot
+///     let mut plot = Plot::new();
+///     plot.add(&scatter).grid_and_labels("x", "y");
+///
+///     // save figure
+///     plot.save("/tmp/plotpy/doc_tests/doc_scatter.svg")?;
+///     Ok(())
+/// }
+/// ```
+///
+/// See also integration test in the **tests** directory.
+pub struct Scatter {
+    style: ScatterStyle,        // Style
+    buffer: String,             // buffer
+    primitives: Vec<Primitive>, // primitives
+}
+
+/// Holds the style of a scatter plot (see [Scatter])
+///
+/// The style is recorded by the [Primitive] drawn by [Scatter] and may be modified afterwards.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ScatterStyle {
+    pub color: String,             // Solid color of markers (when not mapping colors)
+    pub marker_style: String,      // Style of markers, e.g., "`o`", "`+`"
+    pub marker_size: f64,          // Size of markers (when not mapping sizes)
+    pub edge_color: String,        // Edge color of markers
+    pub line_width: f64,           // Edge width of markers
+    pub alpha: f64,                // Opacity of markers (0, 1]. A<1e-14 => A=1.0
+    pub label: String,             // Name of this scatter in the legend
+    pub colormap_index: usize,     // Colormap index
+    pub colormap_name: String,     // Colormap name
+    pub no_colorbar: bool,         // Skip drawing a colorbar (when mapping colors)
+    pub colorbar_label: String,    // Colorbar label
+    pub number_format_cb: String,  // Number format for labels in colorbar
+    pub size_scale: f64,           // Factor to convert the size values to points²
+    pub size_legend_num: usize,    // Number of size classes in the legend of sizes (0 => no legend)
+    pub size_legend_title: String, // Title of the legend of sizes
+}
+
+impl Scatter {
+    /// Creates a new Scatter object
+    pub fn new() -> Self {
+        Scatter {
+            style: ScatterStyle::default(),
+            buffer: String::new(),
+            primitives: Vec::new(),
+        }
+    }
+
+    /// Draws scatter plot
+    ///
+    /// # Input
+    ///
+    /// * `x` - abscissa values
+    /// * `y` - ordinate values
+    /// * `c` - (optional) values mapped to the color of markers by the colormap
+    /// * `s` - (optional) values mapped to the size of markers (times the size scale)
+    ///
+    /// # Notes
+    ///
+    /// * The type `U` of the input arrays must be a number.
+    /// * The size scale must be positive if the sizes are given (see [Scatter::set_size_scale]).
+    ///
+    pub fn draw<'a, T, U>(&mut self, x: &'a T, y: &'a T, c: Option<&'a T>, s: Option<&'a T>) -> Result<(), Error>
+    where
+        T: AsVector<'a, U>,
+        U: 'a + AsPrimitive<f64>,
+    {
+        let (x, y) = (vector_to_f64(x), vector_to_f64(y));
+        let c = c.map(|c| vector_to_f64(c)).unwrap_or_default();
+        let s = sizes_to_f64(s, self.style.size_scale)?;
+        let style = self.style.clone();
+        self.push(Primitive::Scatter {
+            x,
+            y,
+            z: Vec::new(),
+            c,
+            s,
+            style,
+        });
+        Ok(())
+    }
+
+    /// Draws scatter plot in 3D
+    ///
+    /// # Input
+    ///
+    /// * `x` - x values
+    /// * `y` - y values
+    /// * `z` - z values
+    /// * `c` - (optional) values mapped to the color of markers by the colormap
+    /// * `s` - (optional) values mapped to the size of markers (times the size scale)
+    ///
+    /// # Notes
+    ///
+    /// * The type `U` of the input arrays must be a number.
+    /// * The size scale must be positive if the sizes are given (see [Scatter::set_size_scale]).
+    ///
+    pub fn draw_3d<'a, T, U>(
+        &mut self,
+        x: &'a T,
+        y: &'a T,
+        z: &'a T,
+        c: Option<&'a T>,
+        s: Option<&'a T>,
+    ) -> Result<(), Error>
+    where
+        T: AsVector<'a, U>,
+        U: 'a + AsPrimitive<f64>,
+    {
+        let (x, y, z) = (vector_to_f64(x), vector_to_f64(y), vector_to_f64(z));
+        let c = c.map(|c| vector_to_f64(c)).unwrap_or_default();
+        let s = sizes_to_f64(s, self.style.size_scale)?;
+        let style = self.style.clone();
+        self.push(Primitive::Scatter { x, y, z, c, s, style });
+        Ok(())
+    }
+
+    /// Sets the solid color of markers (used when the colors are not mapped)
+    pub fn set_color(&mut self, color: &str) -> &mut Self {
+        self.style.color = String::from(color);
+        self
+    }
+
+    /// Sets the style of markers
+    ///
+    /// Examples:
+    ///
+    /// * "`o`", "`+`"
+    /// * As defined in <https://matplotlib.org/stable/api/markers_api.html>
+    pub fn set_marker_style(&mut self, style: &str) -> &mut Self {
+        self.style.marker_style = String::from(style);
+        self
+    }
+
+    /// Sets the size of markers in points² (used when the sizes are not mapped)
+    pub fn set_marker_size(&mut self, size: f64) -> &mut Self {
+        self.style.marker_size = size;
+        self
+    }
+
+    /// Sets the edge color of markers
+    pub fn set_edge_color(&mut self, color: &str) -> &mut Self {
+        self.style.edge_color = String::from(color);
+        self
+    }
+
+    /// Sets the edge width of markers
+    pub fn set_line_width(&mut self, width: f64) -> &mut Self {
+        self.style.line_width = width;
+        self
+    }
+
+    /// Sets the opacity of markers (0, 1]. A<1e-14 => A=1.0
+    pub fn set_alpha(&mut self, alpha: f64) -> &mut Self {
+        self.style.alpha = alpha;
+        self
+    }
+
+    /// Sets the name of this scatter in the legend
+    pub fn set_label(&mut self, label: &str) -> &mut Self {
+        self.style.label = String::from(label);
+        self
+    }
+
+    /// Sets the colormap index
+    ///
+    /// Options:
+    ///
+    /// * 0 -- bwr
+    /// * 1 -- RdBu
+    /// * 2 -- hsv
+    /// * 3 -- jet
+    /// * 4 -- terrain
+    /// * 5 -- pink
+    /// * 6 -- Greys
+    /// * `>`6 -- starts over from 0
+    pub fn set_colormap_index(&mut self, index: usize) -> &mut Self {
+        self.style.colormap_index = index;
+        self.style.colormap_name = String::new();
+        self
+    }
+
+    /// Sets the colormap name
+    ///
+    /// Colormap names:
+    ///
+    /// * see <https://matplotlib.org/stable/tutorials/colors/colormaps.html>
+    ///
+    /// Will use `colormap_index` instead if `colormap_name` is empty.
+    pub fn set_colormap_name(&mut self, name: &str) -> &mut Self {
+        self.style.colormap_name = String::from(name);
+        self
+    }
+
+    /// Sets option to skip drawing a colorbar
+    pub fn set_no_colorbar(&mut self, flag: bool) -> &mut Self {
+        self.style.no_colorbar = flag;
+        self
+    }
+
+    /// Sets the colorbar label
+    pub fn set_colorbar_label(&mut self, label: &str) -> &mut Self {
+        self.style.colorbar_label = String::from(label);
+        self
+    }
+
+    /// Sets the number format for the labels in the colorbar (cb)
+    pub fn set_number_format_cb(&mut self, format: &str) -> &mut Self {
+        self.style.number_format_cb = String::from(format);
+        self
+    }
+
+    /// Sets the factor to convert the size values to points²
+    ///
+    /// **Note:** The scale must be positive; otherwise, the draw functions return an error if the sizes are given.
+    pub fn set_size_scale(&mut self, scale: f64) -> &mut Self {
+        self.style.size_scale = scale;
+        self
+    }
+
+    /// Sets the legend of sizes (shown when the sizes are mapped)
+    ///
+    /// # Input
+    ///
+    /// * `num` -- the (approximate) number of size classes; 0 disables the legend of sizes
+    /// * `title` -- the title of the legend; may be empty
+    ///
+    /// **Note:** The legend of sizes is added to the axes as an extra artist; thus, it
+    /// is kept when a [crate::Legend] is drawn afterwards.
+    pub fn set_size_legend(&mut self, num: usize, title: &str) -> &mut Self {
+        self.style.size_legend_num = num;
+        self.style.size_legend_title = String::from(title);
+        self
+    }
+
+    /// Writes the primitive to the buffer and records it
+    fn push(&mut self, primitive: Primitive) {
+        primitive.write_python(&mut self.buffer);
+        self.primitives.push(primitive);
+    }
+}
+
+impl Default for ScatterStyle {
+    fn default() -> Self {
+        ScatterStyle {
+            color: String::new(),
+            marker_style: String::new(),
+            marker_size: 0.0,
+            edge_color: String::new(),
+            line_width: 0.0,
+            alpha: 0.0,
+            label: String::new(),
+            colormap_index: 0,
+            colormap_name: String::new(),
+            no_colorbar: false,
+            colorbar_label: String::new(),
+            number_format_cb: String::new(),
+            size_scale: 1.0,
+            size_legend_num: 0,
+            size_legend_title: String::new(),
+        }
+    }
+}
+
+impl ScatterStyle {
+    /// Returns options for scatter
+    pub(crate) fn options(&self, with_colors: bool, with_sizes: bool) -> String {
+        let mut opt = String::new();
+        if with_colors {
+            write!(&mut opt, ",c=c").unwrap();
+            if self.colormap_name != "" {
+                write!(&mut opt, ",cmap=plt.get_cmap('{}')", self.colormap_name).unwrap();
+            } else {
+                write!(&mut opt, ",cmap=get_colormap({})", self.colormap_index).unwrap();
+            }
+        } else if self.color != "" {
+            write!(&mut opt, ",color='{}'", self.color).unwrap();
+        }
+        if with_sizes {
+            write!(&mut opt, ",s={}*s", self.size_scale).unwrap();
+        } else if self.marker_size > 0.0 {
+            write!(&mut opt, ",s={}", self.marker_size).unwrap();
+        }
+        if self.marker_style != "" {
+            write!(&mut opt, ",marker='{}'", self.marker_style).unwrap();
+        }
+        if self.edge_color != "" {
+            write!(&mut opt, ",edgecolors='{}'", self.edge_color).unwrap();
+        }
+        if self.line_width > 0.0 {
+            write!(&mut opt, ",linewidths={}", self.line_width).unwrap();
+        }
+        if self.alpha > 0.0 {
+            write!(&mut opt, ",alpha={}", self.alpha).unwrap();
+        }
+        if self.label != "" {
+            write!(&mut opt, ",label=r'{}'", self.label).unwrap();
+        }
+        opt
+    }
+
+    /// Returns options for colorbar
+    pub(crate) fn options_colorbar(&self) -> String {
+        let mut opt = String::new();
+        if self.number_format_cb != "" {
+            write!(&mut opt, ",format='{}'", self.number_format_cb).unwrap();
+        }
+        opt
+    }
+
+    /// Returns options for the legend of sizes
+    pub(crate) fn options_size_legend(&self) -> String {
+        let mut opt = String::new();
+        if self.size_legend_title != "" {
+            write!(&mut opt, ",title=r'{}'", self.size_legend_title).unwrap();
+        }
+        opt
+    }
+}
+
+/// Converts the (optional) sizes to Vec of f64 and checks the size scale
+fn sizes_to_f64<'a, T, U>(s: Option<&'a T>, scale: f64) -> Result<Vec<f64>, Error>
+where
+    T: AsVector<'a, U>,
+    U: 'a + AsPrimitive<f64>,
+{
+    match s {
+        Some(_) if scale.is_nan() || scale <= 0.0 => Err(Error::InvalidInput {
+            argument: "scale",
+            message: "the size scale must be positive",
+        }),
+        Some(s) => Ok(vector_to_f64(s)),
+        None => Ok(Vec::new()),
+    }
+}
+
+/// Writes the Python commands of a scatter plot (see [Primitive::Scatter])
+pub(crate) fn write_scatter(
+    buf: &mut Script,
+    x: &[f64],
+    y: &[f64],
+    z: &[f64],
+    c: &[f64],
+    s: &[f64],
+    style: &ScatterStyle,
+) {
+    vector_to_array(buf, "x", &x);
+    vector_to_array(buf, "y", &y);
+    if z.len() > 0 {
+        vector_to_array(buf, "z", &z);
+    }
+    if c.len() > 0 {
+        vector_to_array(buf, "c", &c);
+    }
+    if s.len() > 0 {
+        vector_to_array(buf, "s", &s);
+    }
+    let opt = style.options(c.len() > 0, s.len() > 0);
+    if z.len() > 0 {
+        write!(buf, "maybe_create_ax3d()\n").unwrap();
+        write!(buf, "sc=AX3D.scatter(x,y,z{})\n", &opt).unwrap();
+    } else {
+        write!(buf, "sc=plt.scatter(x,y{})\n", &opt).unwrap();
+    }
+    if c.len() > 0 && !style.no_colorbar {
+        write!(buf, "cb=plt.colorbar(sc{})\n", style.options_colorbar()).unwrap();
+        if style.colorbar_label != "" {
+            write!(buf, "cb.ax.set_ylabel(r'{}')\n", style.colorbar_label).unwrap();
+        }
+    }
+    if s.len() > 0 && style.size_legend_num > 0 {
+        write!(
+            buf,
+            "h,l=sc.legend_elements(prop='sizes',num={},func=lambda v:v/{})\n\
+             leg=plt.legend(h,l{})\n\
+             plt.gca().add_artist(leg)\n\
+             add_to_ea(leg)\n",
+            style.size_legend_num,
+            style.size_scale,
+            style.options_size_legend()
+        )
+        .unwrap();
+    }
+}
+
+impl GraphMaker for Scatter {
+    fn get_buffer<'a>(&'a self) -> &'a String {
+        &self.buffer
+    }
+    fn clear_buffer(&mut self) {
+        self.buffer.clear();
+        self.primitives.clear();
+    }
+    fn get_primitives(&self) -> &[Primitive] {
+        &self.primitives
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::Scatter;
+    use crate::{Error, GraphMaker, Primitive};
+
+    #[test]
+    fn new_works() {
+        let scatter = Scatter::new();
+        assert_eq!(scatter.style.color.len(), 0);
+        assert_eq!(scatter.style.marker_style.len(), 0);
+        assert_eq!(scatter.style.marker_size, 0.0);
+        assert_eq!(scatter.style.edge_color.len(), 0);
+        assert_eq!(scatter.style.line_width, 0.0);
+        assert_eq!(scatter.style.alpha, 0.0);
+        assert_eq!(scatter.style.label.len(), 0);
+        assert_eq!(scatter.style.colormap_index, 0);
+        assert_eq!(scatter.style.colormap_name.len(), 0);
+        assert_eq!(scatter.style.no_colorbar, false);
+        assert_eq!(scatter.style.colorbar_label.len(), 0);
+        assert_eq!(scatter.style.number_format_cb.len(), 0);
+        assert_eq!(scatter.style.size_scale, 1.0);
+        assert_eq!(scatter.style.size_legend_num, 0);
+        assert_eq!(scatter.style.size_legend_title.len(), 0);
+        assert_eq!(scatter.buffer.len(), 0);
+        assert_eq!(scatter.primitives.len(), 0);
+    }
+
+    #[test]
+    fn options_works() {
+        let mut scatter = Scatter::new();
+        scatter
+            .set_color("red")
+            .set_marker_style("s")
+            .set_marker_size(20.0)
+            .set_edge_color("black")
+            .set_line_width(0.5)
+            .set_alpha(0.8)
+            .set_label("data")
+            .set_size_scale(10.0);
+        assert_eq!(
+            scatter.style.options(false, false),
+            ",color='red'\
+             ,s=20\
+             ,marker='s'\
+             ,edgecolors='black'\
+             ,linewidths=0.5\
+             ,alpha=0.8\
+             ,label=r'data'"
+        );
+        scatter.set_colormap_index(3);
+        assert_eq!(
+            scatter.style.options(true, true),
+            ",c=c,cmap=get_colormap(3)\
+             ,s=10*s\
+             ,marker='s'\
+             ,edgecolors='black'\
+             ,linewidths=0.5\
+             ,alpha=0.8\
+             ,label=r'data'"
+        );
+        scatter.set_colormap_name("viridis").set_number_format_cb("%.2f");
+        assert_eq!(scatter.style.options(true, false), ",c=c,cmap=plt.get_cmap('viridis'),s=20,marker='s',edgecolors='black',linewidths=0.5,alpha=0.8,label=r'data'");
+        assert_eq!(scatter.style.options_colorbar(), ",format='%.2f'");
+        scatter.set_size_legend(4, "size");
+        assert_eq!(scatter.style.options_size_legend(), ",title=r'size'");
+    }
+
+    #[test]
+    fn draw_works() {
+        let mut scatter = Scatter::new();
+        scatter.set_colorbar_label("temperature").set_size_legend(3, "");
+        scatter.draw(&[1, 2], &[3, 4], Some(&[5, 6]), Some(&[7, 8])).unwrap();
+        let b: &str = "x=np.array([1,2,],dtype=float)\n\
+                       y=np.array([3,4,],dtype=float)\n\
+                       c=np.array([5,6,],dtype=float)\n\
+                       s=np.array([7,8,],dtype=float)\n\
+                       sc=plt.scatter(x,y,c=c,cmap=get_colormap(0),s=1*s)\n\
+                       cb=plt.colorbar(sc)\n\
+                       cb.ax.set_ylabel(r'temperature')\n\
+                       h,l=sc.legend_elements(prop='sizes',num=3,func=lambda v:v/1)\n\
+                       leg=plt.legend(h,l)\n\
+                       plt.gca().add_artist(leg)\n\
+                       add_to_ea(leg)\n";
+        assert_eq!(scatter.buffer, b);
+        assert_eq!(
+            scatter.get_primitives(),
+            &[Primitive::Scatter {
+                x: vec![1.0, 2.0],
+                y: vec![3.0, 4.0],
+                z: Vec::new(),
+                c: vec![5.0, 6.0],
+                s: vec![7.0, 8.0],
+                style: scatter.style.clone(),
+            }]
+        );
+        scatter.clear_buffer();
+        assert_eq!(scatter.buffer, "");
+        assert_eq!(scatter.primitives.len(), 0);
+    }
+
+    #[test]
+    fn draw_3d_works() {
+        let mut scatter = Scatter::new();
+        scatter.set_color("red");
+        scatter.draw_3d(&[1, 2], &[3, 4], &[5, 6], None, None).unwrap();
+        let b: &str = "x=np.array([1,2,],dtype=float)\n\
+                       y=np.array([3,4,],dtype=float)\n\
+                       z=np.array([5,6,],dtype=float)\n\
+                       maybe_create_ax3d()\n\
+                       sc=AX3D.scatter(x,y,z,color='red')\n";
+        assert_eq!(scatter.buffer, b);
+    }
+
+    #[test]
+    fn draw_rejects_wrong_size_scale() {
+        let mut scatter = Scatter::new();
+        for scale in [0.0, -1.0, f64::NAN] {
+            scatter.set_size_scale(scale);
+            assert_eq!(
+                scatter.draw(&[1, 2], &[3, 4], None, Some(&[5, 6])).err(),
+                Some(Error::InvalidInput {
+                    argument: "scale",
+                    message: "the size scale must be positive",
+                })
+            );
+            assert_eq!(
+                scatter.draw_3d(&[1, 2], &[3, 4], &[5, 6], None, Some(&[7, 8])).err(),
+                Some(Error::InvalidInput {
+                    argument: "scale",
+                    message: "the size scale must be positive",
+                })
+            );
+        }
+        assert_eq!(scatter.buffer, "");
+        scatter.draw(&[1, 2], &[3, 4], None, None).unwrap();
+        assert_eq!(scatter.primitives.len(), 1);
+    }
+}
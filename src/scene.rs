@@ -0,0 +1,743 @@
+use super::{
+    write_arc, write_arrow, write_barplot, write_boxplot, write_canvas_grid, write_canvas_mesh, write_circle,
+    write_contour, write_curve, write_curve_3d, write_curve_band, write_curve_error_bars, write_curve_points,
+    write_curve_points_3d, write_curve_stairs, write_curve_stem, write_fill, write_histogram, write_histogram_2d,
+    write_image, write_legend, write_path, write_pie, write_polyline_3d, write_ray, write_scatter, write_slope_icon,
+    write_sparsity_pattern, write_stacked_fill, write_streamlines, write_surface, write_text, write_text_3d,
+    write_tri_color, write_tri_contour, write_tri_surface, write_vector_field, write_vector_field_3d, BarplotStyle,
+    BoxplotStyle, CanvasStyle, ContourStyle, CurveStyle, FillStyle, Histogram2dStyle, HistogramStyle, ImageStyle,
+    LegendStyle, PieStyle, PolyCode, RayEndpoint, ScatterStyle, SlopeIconStyle, SparsityPatternStyle, SurfaceStyle,
+    TextStyle, VectorFieldStyle,
+};
+use crate::NpyFiles;
+use std::fmt::Write;
+use std::ops::{Deref, DerefMut};
+
+/// Defines the graphical primitives recorded by the draw functions
+///
+/// Each draw function (e.g., [crate::Curve::draw] or [crate::Canvas::draw_circle]) records a primitive
+/// holding the data (in data units) and a copy of the style at the time of the call. The Python commands
+/// are then generated from the primitives (see [Primitive::write_python]). Thus, the primitives may be
+/// inspected (e.g., in unit tests), modified (e.g., to restyle a figure), or rendered by another backend.
+///
+/// The primitives of a graph entity are returned by [crate::GraphMaker::get_primitives] and may be
+/// added to a [crate::Plot] by [crate::Plot::add_primitives].
+///
+/// # Example
+///
+/// ```
+/// use plotpy::{Curve, GraphMaker, Plot, Primitive};
+///
+/// let mut curve = Curve::new();
+/// curve.set_line_color("red");
+/// curve.draw(&[0.0, 1.0, 2.0], &[0.0, 1.0, 4.0]);
+///
+/// // inspect the curve
+/// let mut primitives = curve.get_primitives().to_vec();
+/// match &mut primitives[0] {
+///     Primitive::Curve { y, style, .. } => {
+///         assert_eq!(y, &[0.0, 1.0, 4.0]);
+///         assert_eq!(style.line_color, "red");
+///         style.line_color = "blue".to_string(); // restyle
+///     }
+///     _ => panic!("a curve should have been recorded"),
+/// }
+///
+/// // add the restyled curve to a plot
+/// let mut plot = Plot::new();
+/// plot.add_primitives(&primitives);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub enum Primitive {
+    /// Curve through the points (x,y) (see [crate::Curve::draw])
+    Curve {
+        x: Vec<f64>,
+        y: Vec<f64>,
+        style: CurveStyle,
+    },
+
+    /// Curve through the points (x,y,z) in 3D (see [crate::Curve::draw_3d])
+    Curve3d {
+        x: Vec<f64>,
+        y: Vec<f64>,
+        z: Vec<f64>,
+        style: CurveStyle,
+    },
+
+    /// Curve with error bars along x and/or y (see [crate::Curve::draw_with_error_bars])
+    ///
+    /// Each error holds zero (no error bars), one (symmetric), or two (lower and upper) arrays.
+    CurveErrorBars {
+        x: Vec<f64>,
+        y: Vec<f64>,
+        x_err: Vec<Vec<f64>>,
+        y_err: Vec<Vec<f64>>,
+        style: CurveStyle,
+    },
+
+    /// Curve with a shaded band between lower and upper values (see [crate::Curve::draw_with_band])
+    CurveBand {
+        x: Vec<f64>,
+        y: Vec<f64>,
+        lower: Vec<f64>,
+        upper: Vec<f64>,
+        style: CurveStyle,
+    },
+
+    /// Stem plot with markers on vertical lines from a baseline (see [crate::Curve::draw_stem])
+    CurveStem {
+        x: Vec<f64>,
+        y: Vec<f64>,
+        style: CurveStyle,
+    },
+
+    /// Stairs given the edges of the bins and the values (see [crate::Curve::draw_stairs])
+    CurveStairs {
+        edges: Vec<f64>,
+        values: Vec<f64>,
+        style: CurveStyle,
+    },
+
+    /// Curve through a list of points (see [crate::Curve::points_add])
+    CurvePoints { points: Vec<[f64; 2]>, style: CurveStyle },
+
+    /// Curve through a list of 3D points (see [crate::Curve::points_3d_add])
+    CurvePoints3d { points: Vec<[f64; 3]>, style: CurveStyle },
+
+    /// Infinite line passing through (xa,ya) (see [crate::Curve::draw_ray])
+    Ray {
+        xa: f64,
+        ya: f64,
+        endpoint: RayEndpoint,
+        style: CurveStyle,
+    },
+
+    /// Arc with center (xc,yc), radius r, and angles in degrees (see [crate::Canvas::draw_arc])
+    Arc {
+        xc: f64,
+        yc: f64,
+        r: f64,
+        ini_angle: f64,
+        fin_angle: f64,
+        style: CanvasStyle,
+    },
+
+    /// Arrow from (xi,yi) to (xf,yf) (see [crate::Canvas::draw_arrow])
+    Arrow {
+        xi: f64,
+        yi: f64,
+        xf: f64,
+        yf: f64,
+        style: CanvasStyle,
+    },
+
+    /// Circle with center (xc,yc) and radius r (see [crate::Canvas::draw_circle])
+    Circle {
+        xc: f64,
+        yc: f64,
+        r: f64,
+        style: CanvasStyle,
+    },
+
+    /// Path with straight segments and Bezier curves; one code per point (see [crate::Canvas::draw_polycurve])
+    Path {
+        points: Vec<[f64; 2]>,
+        codes: Vec<PolyCode>,
+        closed: bool,
+        style: CanvasStyle,
+    },
+
+    /// Polyline in 3D (see [crate::Canvas::polyline_3d_add])
+    Polyline3d { points: Vec<[f64; 3]>, style: CanvasStyle },
+
+    /// Grid with optional point and cell ids in 2D or 3D (see [crate::Canvas::draw_grid])
+    CanvasGrid {
+        xmin: Vec<f64>,
+        xmax: Vec<f64>,
+        ndiv: Vec<usize>,
+        with_point_ids: bool,
+        with_cell_ids: bool,
+        style: CanvasStyle,
+    },
+
+    /// Unstructured mesh with optional point and cell ids in 2D or 3D (see [crate::Canvas::draw_mesh])
+    ///
+    /// The points have three coordinates (z is zero in 2D) and the attributes are empty if not given.
+    CanvasMesh {
+        ndim: usize,
+        points: Vec<[f64; 3]>,
+        cells: Vec<Vec<usize>>,
+        attributes: Vec<usize>,
+        with_point_ids: bool,
+        with_cell_ids: bool,
+        style: CanvasStyle,
+    },
+
+    /// Text at (x,y) (see [crate::Text::draw])
+    Text {
+        x: f64,
+        y: f64,
+        message: String,
+        style: TextStyle,
+    },
+
+    /// Text at (x,y,z) in 3D (see [crate::Text::draw_3d])
+    Text3d {
+        x: f64,
+        y: f64,
+        z: f64,
+        message: String,
+        style: TextStyle,
+    },
+
+    /// Legend with the labels of curves (see [crate::Legend::draw])
+    Legend(LegendStyle),
+
+    /// Filled contour with lines, labels, and colorbar (see [crate::Contour::draw])
+    Contour {
+        x: Vec<Vec<f64>>,
+        y: Vec<Vec<f64>>,
+        z: Vec<Vec<f64>>,
+        style: ContourStyle,
+    },
+
+    /// Surface and/or wireframe in 3D (see [crate::Surface::draw])
+    Surface {
+        x: Vec<Vec<f64>>,
+        y: Vec<Vec<f64>>,
+        z: Vec<Vec<f64>>,
+        style: SurfaceStyle,
+    },
+
+    /// Filled contour with lines, labels, and colorbar on a triangle mesh (see [crate::Contour::draw_triangles])
+    TriContour {
+        x: Vec<f64>,
+        y: Vec<f64>,
+        z: Vec<f64>,
+        triangles: Vec<Vec<usize>>,
+        style: ContourStyle,
+    },
+
+    /// Triangles colored by the values at the nodes (see [crate::Contour::draw_tripcolor])
+    TriColor {
+        x: Vec<f64>,
+        y: Vec<f64>,
+        z: Vec<f64>,
+        triangles: Vec<Vec<usize>>,
+        style: ContourStyle,
+    },
+
+    /// Surface and/or wireframe in 3D on a triangle mesh (see [crate::Surface::draw_triangles])
+    TriSurface {
+        x: Vec<f64>,
+        y: Vec<f64>,
+        z: Vec<f64>,
+        triangles: Vec<Vec<usize>>,
+        style: SurfaceStyle,
+    },
+
+    /// Arrows of a vector field in 2D (see [crate::VectorField::draw])
+    VectorField {
+        x: Vec<Vec<f64>>,
+        y: Vec<Vec<f64>>,
+        u: Vec<Vec<f64>>,
+        v: Vec<Vec<f64>>,
+        style: VectorFieldStyle,
+    },
+
+    /// Streamlines of a vector field in 2D (see [crate::VectorField::draw_streamlines])
+    Streamlines {
+        x: Vec<Vec<f64>>,
+        y: Vec<Vec<f64>>,
+        u: Vec<Vec<f64>>,
+        v: Vec<Vec<f64>>,
+        style: VectorFieldStyle,
+    },
+
+    /// Arrows of a vector field in 3D (see [crate::VectorField::draw_3d])
+    VectorField3d {
+        x: Vec<Vec<f64>>,
+        y: Vec<Vec<f64>>,
+        z: Vec<Vec<f64>>,
+        u: Vec<Vec<f64>>,
+        v: Vec<Vec<f64>>,
+        w: Vec<Vec<f64>>,
+        style: VectorFieldStyle,
+    },
+
+    /// Histogram of one or more series of values (see [crate::Histogram::draw])
+    Histogram {
+        values: Vec<Vec<f64>>,
+        labels: Vec<String>,
+        style: HistogramStyle,
+    },
+
+    /// 2D histogram with rectangular bins (see [crate::Histogram2d::draw])
+    Histogram2d {
+        x: Vec<f64>,
+        y: Vec<f64>,
+        style: Histogram2dStyle,
+    },
+
+    /// 2D histogram with hexagonal bins (see [crate::Histogram2d::draw_hexbin])
+    Hexbin {
+        x: Vec<f64>,
+        y: Vec<f64>,
+        style: Histogram2dStyle,
+    },
+
+    /// Box plot or violin plot of one or more series of samples (see [crate::Boxplot::draw])
+    Boxplot {
+        values: Vec<Vec<f64>>,
+        labels: Vec<String>,
+        style: BoxplotStyle,
+    },
+
+    /// Pie or donut chart (see [crate::Pie::draw])
+    Pie {
+        values: Vec<f64>,
+        labels: Vec<String>,
+        style: PieStyle,
+    },
+
+    /// Markers with colors and sizes mapped to data, in 2D or 3D (see [crate::Scatter::draw])
+    ///
+    /// The z, c (colors), and s (sizes) values are empty if not given.
+    Scatter {
+        x: Vec<f64>,
+        y: Vec<f64>,
+        z: Vec<f64>,
+        c: Vec<f64>,
+        s: Vec<f64>,
+        style: ScatterStyle,
+    },
+
+    /// Area between two curves, optionally restricted by a mask (see [crate::Fill::draw])
+    Fill {
+        x: Vec<f64>,
+        y1: Vec<f64>,
+        y2: Vec<f64>,
+        mask: Vec<bool>,
+        style: FillStyle,
+    },
+
+    /// Stacked areas of one or more series of values (see [crate::Fill::draw_stacked])
+    StackedFill {
+        x: Vec<f64>,
+        y: Vec<Vec<f64>>,
+        style: FillStyle,
+    },
+
+    /// Bars of one or more series of values per category (see [crate::Barplot::draw])
+    Barplot {
+        categories: Vec<String>,
+        values: Vec<Vec<f64>>,
+        errors: Vec<Vec<f64>>,
+        style: BarplotStyle,
+    },
+
+    /// Image (aka heatmap) of the values of a matrix (see [crate::Image::draw])
+    ///
+    /// The x and y coordinates (of the edges or centers of the cells) are empty if drawn by imshow.
+    Image {
+        x: Vec<f64>,
+        y: Vec<f64>,
+        data: Vec<Vec<f64>>,
+        style: ImageStyle,
+    },
+
+    /// Sparsity pattern of a matrix given by the indices of the nonzero entries (see [crate::SparsityPattern::draw_triplet])
+    ///
+    /// The values are empty if not given.
+    SparsityPattern {
+        nrow: usize,
+        ncol: usize,
+        rows: Vec<usize>,
+        cols: Vec<usize>,
+        values: Vec<f64>,
+        style: SparsityPatternStyle,
+    },
+
+    /// Icon indicating the slope of a line (see [crate::SlopeIcon::draw])
+    SlopeIcon {
+        slope: f64,
+        x_center: f64,
+        y_center: f64,
+        style: SlopeIconStyle,
+    },
+
+    /// Title of the axes
+    Title(String),
+
+    /// Label of the x-axis
+    LabelX(String),
+
+    /// Label of the y-axis
+    LabelY(String),
+
+    /// Grid lines (dashed and grey) below the other features
+    Grid,
+
+    /// Limits of the axes (None means unchanged)
+    Range {
+        xmin: Option<f64>,
+        xmax: Option<f64>,
+        ymin: Option<f64>,
+        ymax: Option<f64>,
+    },
+
+    /// Same scale for both axes
+    EqualAxes(bool),
+
+    /// Hides the frame, ticks, and labels
+    HideAxes(bool),
+
+    /// Maximum number of intervals between ticks along x (0 means no ticks)
+    NumTicksX(usize),
+
+    /// Maximum number of intervals between ticks along y (0 means no ticks)
+    NumTicksY(usize),
+
+    /// Figure size (width, height) in inches
+    FigureSize(f64, f64),
+
+    /// Clears the current axes
+    ClearAxes,
+
+    /// Clears the current figure
+    ClearFigure,
+}
+
+impl Primitive {
+    /// Writes the Python commands (lowering) of this primitive to the buffer
+    pub fn write_python(&self, buffer: &mut String) {
+        self.lower(&mut Script::new(buffer, None));
+    }
+
+    /// Writes the Python commands of this primitive with the large arrays saved to .npy files by the sink
+    pub(crate) fn write_python_npy(&self, buffer: &mut String, npy: &mut NpyFiles) {
+        self.lower(&mut Script::new(buffer, Some(npy)));
+    }
+
+    /// Writes the Python commands of this primitive to the script
+    fn lower(&self, buffer: &mut Script) {
+        match self {
+            Primitive::Curve { x, y, style } => write_curve(buffer, x, y, style),
+            Primitive::Curve3d { x, y, z, style } => write_curve_3d(buffer, x, y, z, style),
+            Primitive::CurveErrorBars {
+                x,
+                y,
+                x_err,
+                y_err,
+                style,
+            } => write_curve_error_bars(buffer, x, y, x_err, y_err, style),
+            Primitive::CurveBand {
+                x,
+                y,
+                lower,
+                upper,
+                style,
+            } => write_curve_band(buffer, x, y, lower, upper, style),
+            Primitive::CurveStem { x, y, style } => write_curve_stem(buffer, x, y, style),
+            Primitive::CurveStairs { edges, values, style } => write_curve_stairs(buffer, edges, values, style),
+            Primitive::CurvePoints { points, style } => write_curve_points(buffer, points, style),
+            Primitive::CurvePoints3d { points, style } => write_curve_points_3d(buffer, points, style),
+            Primitive::Ray {
+                xa,
+                ya,
+                endpoint,
+                style,
+            } => write_ray(buffer, *xa, *ya, endpoint, style),
+            Primitive::Arc {
+                xc,
+                yc,
+                r,
+                ini_angle,
+                fin_angle,
+                style,
+            } => write_arc(buffer, *xc, *yc, *r, *ini_angle, *fin_angle, style),
+            Primitive::Arrow { xi, yi, xf, yf, style } => write_arrow(buffer, *xi, *yi, *xf, *yf, style),
+            Primitive::Circle { xc, yc, r, style } => write_circle(buffer, *xc, *yc, *r, style),
+            Primitive::Path {
+                points,
+                codes,
+                closed,
+                style,
+            } => write_path(buffer, points, codes, *closed, style),
+            Primitive::Polyline3d { points, style } => write_polyline_3d(buffer, points, style),
+            Primitive::CanvasGrid {
+                xmin,
+                xmax,
+                ndiv,
+                with_point_ids,
+                with_cell_ids,
+                style,
+            } => write_canvas_grid(buffer, xmin, xmax, ndiv, *with_point_ids, *with_cell_ids, style),
+            Primitive::CanvasMesh {
+                ndim,
+                points,
+                cells,
+                attributes,
+                with_point_ids,
+                with_cell_ids,
+                style,
+            } => write_canvas_mesh(
+                buffer,
+                *ndim,
+                points,
+                cells,
+                attributes,
+                *with_point_ids,
+                *with_cell_ids,
+                style,
+            ),
+            Primitive::Text { x, y, message, style } => write_text(buffer, *x, *y, message, style),
+            Primitive::Text3d {
+                x,
+                y,
+                z,
+                message,
+                style,
+            } => write_text_3d(buffer, *x, *y, *z, message, style),
+            Primitive::Legend(style) => write_legend(buffer, style),
+            Primitive::Contour { x, y, z, style } => write_contour(buffer, x, y, z, style),
+            Primitive::Surface { x, y, z, style } => write_surface(buffer, x, y, z, style),
+            Primitive::TriContour {
+                x,
+                y,
+                z,
+                triangles,
+                style,
+            } => write_tri_contour(buffer, x, y, z, triangles, style),
+            Primitive::TriColor {
+                x,
+                y,
+                z,
+                triangles,
+                style,
+            } => write_tri_color(buffer, x, y, z, triangles, style),
+            Primitive::TriSurface {
+                x,
+                y,
+                z,
+                triangles,
+                style,
+            } => write_tri_surface(buffer, x, y, z, triangles, style),
+            Primitive::VectorField { x, y, u, v, style } => write_vector_field(buffer, x, y, u, v, style),
+            Primitive::Streamlines { x, y, u, v, style } => write_streamlines(buffer, x, y, u, v, style),
+            Primitive::VectorField3d {
+                x,
+                y,
+                z,
+                u,
+                v,
+                w,
+                style,
+            } => write_vector_field_3d(buffer, x, y, z, u, v, w, style),
+            Primitive::Histogram { values, labels, style } => write_histogram(buffer, values, labels, style),
+            Primitive::Histogram2d { x, y, style } => write_histogram_2d(buffer, x, y, false, style),
+            Primitive::Hexbin { x, y, style } => write_histogram_2d(buffer, x, y, true, style),
+            Primitive::Boxplot { values, labels, style } => write_boxplot(buffer, values, labels, style),
+            Primitive::Pie { values, labels, style } => write_pie(buffer, values, labels, style),
+            Primitive::Scatter { x, y, z, c, s, style } => write_scatter(buffer, x, y, z, c, s, style),
+            Primitive::Fill { x, y1, y2, mask, style } => write_fill(buffer, x, y1, y2, mask, style),
+            Primitive::StackedFill { x, y, style } => write_stacked_fill(buffer, x, y, style),
+            Primitive::Barplot {
+                categories,
+                values,
+                errors,
+                style,
+            } => write_barplot(buffer, categories, values, errors, style),
+            Primitive::Image { x, y, data, style } => write_image(buffer, x, y, data, style),
+            Primitive::SparsityPattern {
+                nrow,
+                ncol,
+                rows,
+                cols,
+                values,
+                style,
+            } => write_sparsity_pattern(buffer, *nrow, *ncol, rows, cols, values, style),
+            Primitive::SlopeIcon {
+                slope,
+                x_center,
+                y_center,
+                style,
+            } => write_slope_icon(buffer, *slope, *x_center, *y_center, style),
+            Primitive::Title(title) => write!(buffer, "plt.title(r'{}')\n", title).unwrap(),
+            Primitive::LabelX(label) => write!(buffer, "plt.xlabel(r'{}')\n", label).unwrap(),
+            Primitive::LabelY(label) => write!(buffer, "plt.ylabel(r'{}')\n", label).unwrap(),
+            Primitive::Grid => buffer.push_str(
+                "plt.gca().set_axisbelow(True)\n\
+                 plt.grid(linestyle='--',color='grey',zorder=-1000)\n",
+            ),
+            Primitive::Range { xmin, xmax, ymin, ymax } => {
+                let limit = |value: &Option<f64>, index: usize| match value {
+                    Some(v) => format!("{}", v),
+                    None => format!("plt.axis()[{}]", index),
+                };
+                write!(
+                    buffer,
+                    "plt.axis([{},{},{},{}])\n",
+                    limit(xmin, 0),
+                    limit(xmax, 1),
+                    limit(ymin, 2),
+                    limit(ymax, 3)
+                )
+                .unwrap();
+            }
+            Primitive::EqualAxes(equal) => {
+                if *equal {
+                    buffer.push_str("set_equal_axes()\n");
+                } else {
+                    buffer.push_str("plt.gca().axes.set_aspect('auto')\n");
+                }
+            }
+            Primitive::HideAxes(hide) => {
+                let option = if *hide { "off" } else { "on" };
+                write!(buffer, "plt.axis('{}')\n", option).unwrap();
+            }
+            Primitive::NumTicksX(num) => {
+                if *num == 0 {
+                    buffer.push_str("plt.gca().get_xaxis().set_ticks([])\n");
+                } else {
+                    write!(
+                        buffer,
+                        "plt.gca().get_xaxis().set_major_locator(tck.MaxNLocator({}))\n",
+                        num
+                    )
+                    .unwrap();
+                }
+            }
+            Primitive::NumTicksY(num) => {
+                if *num == 0 {
+                    buffer.push_str("plt.gca().get_yaxis().set_ticks([])\n");
+                } else {
+                    write!(
+                        buffer,
+                        "plt.gca().get_yaxis().set_major_locator(tck.MaxNLocator({}))\n",
+                        num
+                    )
+                    .unwrap();
+                }
+            }
+            Primitive::FigureSize(width, height) => {
+                write!(buffer, "plt.gcf().set_size_inches({},{})\n", width, height).unwrap()
+            }
+            Primitive::ClearAxes => buffer.push_str("plt.gca().cla()\n"),
+            Primitive::ClearFigure => buffer.push_str("plt.clf()\n"),
+        }
+    }
+}
+
+/// Receives the Python commands written while lowering the primitives
+///
+/// The script dereferences to the buffer. The large NumPy arrays are written to .npy files
+/// instead if the script has a sink for them (see [Primitive::write_python_npy]).
+pub(crate) struct Script<'a> {
+    buffer: &'a mut String,        // Python commands
+    npy: Option<&'a mut NpyFiles>, // sink of the large arrays, if any
+}
+
+impl<'a> Script<'a> {
+    /// Creates a new script writing to the buffer
+    pub(crate) fn new(buffer: &'a mut String, npy: Option<&'a mut NpyFiles>) -> Self {
+        Script { buffer, npy }
+    }
+
+    /// Writes an array to a .npy file and the command to load it, if there is a sink (see [NpyFiles::write_array])
+    ///
+    /// Returns false if the array must be written to the script instead.
+    pub(crate) fn write_npy_array<F>(&mut self, name: &str, shape: &[usize], values: F) -> bool
+    where
+        F: FnOnce() -> Vec<f64>,
+    {
+        match self.npy.as_deref_mut() {
+            Some(npy) => npy.write_array(self.buffer, name, shape, values),
+            None => false,
+        }
+    }
+}
+
+impl Deref for Script<'_> {
+    type Target = String;
+    fn deref(&self) -> &String {
+        self.buffer
+    }
+}
+
+impl DerefMut for Script<'_> {
+    fn deref_mut(&mut self) -> &mut String {
+        self.buffer
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::Primitive;
+    use crate::{CurveStyle, RayEndpoint};
+
+    #[test]
+    fn derive_works() {
+        let primitive = Primitive::Title("hello".to_string());
+        let clone = primitive.clone();
+        assert_eq!(clone, primitive);
+        assert_eq!(format!("{:?}", primitive), "Title(\"hello\")");
+    }
+
+    #[test]
+    fn write_python_works() {
+        let mut buffer = String::new();
+        let primitives = [
+            Primitive::Ray {
+                xa: 1.0,
+                ya: 2.0,
+                endpoint: RayEndpoint::Horizontal,
+                style: CurveStyle {
+                    line_color: "red".to_string(),
+                    ..Default::default()
+                },
+            },
+            Primitive::Title("title".to_string()),
+            Primitive::LabelX("x".to_string()),
+            Primitive::LabelY("y".to_string()),
+            Primitive::Grid,
+            Primitive::Range {
+                xmin: Some(-1.0),
+                xmax: None,
+                ymin: None,
+                ymax: Some(1.5),
+            },
+            Primitive::EqualAxes(true),
+            Primitive::EqualAxes(false),
+            Primitive::HideAxes(true),
+            Primitive::NumTicksX(0),
+            Primitive::NumTicksY(5),
+            Primitive::FigureSize(4.0, 3.0),
+            Primitive::ClearAxes,
+            Primitive::ClearFigure,
+        ];
+        for primitive in &primitives {
+            primitive.write_python(&mut buffer);
+        }
+        assert_eq!(
+            buffer,
+            "plt.axhline(2,color='red')\n\
+             plt.title(r'title')\n\
+             plt.xlabel(r'x')\n\
+             plt.ylabel(r'y')\n\
+             plt.gca().set_axisbelow(True)\n\
+             plt.grid(linestyle='--',color='grey',zorder=-1000)\n\
+             plt.axis([-1,plt.axis()[1],plt.axis()[2],1.5])\n\
+             set_equal_axes()\n\
+             plt.gca().axes.set_aspect('auto')\n\
+             plt.axis('off')\n\
+             plt.gca().get_xaxis().set_ticks([])\n\
+             plt.gca().get_yaxis().set_major_locator(tck.MaxNLocator(5))\n\
+             plt.gcf().set_size_inches(4,3)\n\
+             plt.gca().cla()\n\
+             plt.clf()\n"
+        );
+    }
+}
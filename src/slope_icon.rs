@@ -1,4 +1,4 @@
-use super::GraphMaker;
+use super::{GraphMaker, Primitive};
 use std::fmt::Write;
 
 /// Creates an icon to indicate the slope of lines
@@ -11,10 +11,10 @@ use std::fmt::Write;
 /// # Example
 ///
 /// ```
-/// use plotpy::{Curve, Plot, SlopeIcon, StrError};
+/// use plotpy::{Curve, Error, Plot, SlopeIcon};
 /// use russell_lab::Vector;
 ///
-/// fn main() -> Result<(), StrError> {
+/// fn main() -> Result<(), Error> {
 ///     // models
 ///     let slope = 2.0;
 ///     let (xi, xf, yi) = (0.0, 10.0, 0.0);
@@ -76,187 +76,69 @@ use std::fmt::Write;
 ///
 /// ![integ_slope_icon_logx_logy.svg](https://raw.githubusercontent.com/cpmech/plotpy/main/figures/integ_slope_icon_logx_logy.svg)
 pub struct SlopeIcon {
-    above: bool,        // draw icon above line
-    edge_color: String, // Color of icon lines
-    face_color: String, // Color of icon faces
-    line_style: String, // Style of lines
-    line_width: f64,    // Width of lines
-    length: f64,        // horizontal length of icon in Axes coords [0,1]
-    offset_v: f64,      // vertical offset in points
-    no_text: bool,      // do not draw text
-    fontsize: f64,      // text font size
-    precision: usize,   // precision of slope number in label
-    text_h: String,     // use fixed text for horizontal value
-    text_v: String,     // use fixed text for vertical (slope) value
-    text_color: String, // Color of text
-    text_offset_h: f64, // horizontal offset for text in points
-    text_offset_v: f64, // vertical offset for text in points
-    buffer: String,     // buffer
+    style: SlopeIconStyle,      // Style
+    buffer: String,             // buffer
+    primitives: Vec<Primitive>, // primitives
+}
+
+/// Holds the style of a slope icon (see [SlopeIcon])
+///
+/// The style is recorded by the [Primitive] drawn by [SlopeIcon] and may be modified afterwards.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SlopeIconStyle {
+    pub above: bool,        // draw icon above line
+    pub edge_color: String, // Color of icon lines
+    pub face_color: String, // Color of icon faces
+    pub line_style: String, // Style of lines
+    pub line_width: f64,    // Width of lines
+    pub length: f64,        // horizontal length of icon in Axes coords [0,1]
+    pub offset_v: f64,      // vertical offset in points
+    pub no_text: bool,      // do not draw text
+    pub fontsize: f64,      // text font size
+    pub precision: usize,   // precision of slope number in label
+    pub text_h: String,     // use fixed text for horizontal value
+    pub text_v: String,     // use fixed text for vertical (slope) value
+    pub text_color: String, // Color of text
+    pub text_offset_h: f64, // horizontal offset for text in points
+    pub text_offset_v: f64, // vertical offset for text in points
 }
 
 impl SlopeIcon {
     /// Creates a new SlopeIcon object
     pub fn new() -> Self {
         SlopeIcon {
-            above: false,
-            edge_color: "#000000".to_string(),
-            face_color: "#f7f7f7".to_string(),
-            line_style: String::new(),
-            line_width: 0.0,
-            length: 0.1,
-            offset_v: 5.0,
-            no_text: false,
-            fontsize: 0.0,
-            precision: 0,
-            text_h: "1".to_string(),
-            text_v: String::new(),
-            text_color: "#000000".to_string(),
-            text_offset_h: 3.0,
-            text_offset_v: 2.0,
+            style: SlopeIconStyle::default(),
             buffer: String::new(),
+            primitives: Vec::new(),
         }
     }
 
     /// Draws an icon of line slope
     pub fn draw(&mut self, slope: f64, x_center: f64, y_center: f64) {
-        // set flip flag
-        let flip = if slope < 0.0 { !self.above } else { self.above };
-
-        // compute axis (normalized) coordinates and slope
-        write!(
-            &mut self.buffer,
-            "slope,cx,cy=float({}),float({}),float({})\n\
-             if plt.gca().get_xscale() == 'log': cx=np.log10(cx)\n\
-             if plt.gca().get_yscale() == 'log': cy=np.log10(cy)\n\
-             xc,yc=data_to_axis((cx,cy))\n\
-             xa,ya=data_to_axis((cx+1.0,cy+slope))\n\
-             m,l=(ya-yc)/(xa-xc),{}\n",
+        let style = self.style.clone();
+        self.push(Primitive::SlopeIcon {
             slope,
             x_center,
             y_center,
-            self.length / 2.0,
-        )
-        .unwrap();
-
-        // set polygon
-        if flip {
-            self.buffer.push_str(
-                "dat=[[pth.Path.MOVETO,(xc-l,yc-m*l)],\
-                      [pth.Path.LINETO,(xc-l,yc+m*l)],\
-                      [pth.Path.LINETO,(xc+l,yc+m*l)],\
-                      [pth.Path.CLOSEPOLY,(None,None)]]\n",
-            );
-        } else {
-            self.buffer.push_str(
-                "dat=[[pth.Path.MOVETO,(xc-l,yc-m*l)],\
-                      [pth.Path.LINETO,(xc+l,yc-m*l)],\
-                      [pth.Path.LINETO,(xc+l,yc+m*l)],\
-                      [pth.Path.CLOSEPOLY,(None,None)]]\n",
-            );
-        }
-
-        // draw
-        let tf = self.transform(slope);
-        let opt = self.options();
-        write!(
-            &mut self.buffer,
-            "{}cmd,pts=zip(*dat)\n\
-             h=pth.Path(pts,cmd)\n\
-             p=pat.PathPatch(h{})\n\
-             plt.gca().add_patch(p)\n",
-            tf, opt,
-        )
-        .unwrap();
-
-        // skip text
-        if self.no_text {
-            return;
-        }
-
-        // coordinates for labels
-        self.buffer.push_str(
-            "xm,ym=xc-l,yc-m*l\n\
-             xp,yp=xc+l,yc+m*l\n",
-        );
-
-        // slope text
-        let mut text = String::new();
-        if self.text_v == "" {
-            if self.precision == 0 {
-                write!(&mut text, "{}", f64::abs(slope)).unwrap();
-            } else {
-                write!(&mut text, "{:.1$}", f64::abs(slope), self.precision).unwrap();
-            }
-        } else {
-            write!(&mut text, "{}", self.text_v).unwrap();
-        }
-
-        // draw labels
-        let tf_txt = self.transform_text(slope);
-        self.buffer.push_str(&tf_txt);
-        let (opt_x, opt_y) = self.options_text();
-        if flip {
-            if slope < 0.0 {
-                write!(
-                    &mut self.buffer,
-                    "plt.text(xc,yp,r'{}',ha='center',va='top'{})\n",
-                    self.text_h, opt_x
-                )
-                .unwrap();
-            } else {
-                write!(
-                    &mut self.buffer,
-                    "plt.text(xc,yp,r'{}',ha='center',va='bottom'{})\n",
-                    self.text_h, opt_x
-                )
-                .unwrap();
-            }
-            write!(
-                &mut self.buffer,
-                "plt.text(xm,yc,r'{}',ha='right',va='center'{})\n",
-                text, opt_y
-            )
-            .unwrap();
-        } else {
-            if slope < 0.0 {
-                write!(
-                    &mut self.buffer,
-                    "plt.text(xc,ym,r'{}',ha='center',va='bottom'{})\n",
-                    self.text_h, opt_x
-                )
-                .unwrap();
-            } else {
-                write!(
-                    &mut self.buffer,
-                    "plt.text(xc,ym,r'{}',ha='center',va='top'{})\n",
-                    self.text_h, opt_x
-                )
-                .unwrap();
-            }
-            write!(
-                &mut self.buffer,
-                "plt.text(xp,yc,r'{}',ha='left',va='center'{})\n",
-                text, opt_y
-            )
-            .unwrap();
-        }
+            style,
+        });
     }
 
     /// Sets option to draw icon above line
     pub fn set_above(&mut self, flag: bool) -> &mut Self {
-        self.above = flag;
+        self.style.above = flag;
         self
     }
 
     /// Sets the color of icon lines
     pub fn set_edge_color(&mut self, color: &str) -> &mut Self {
-        self.edge_color = String::from(color);
+        self.style.edge_color = String::from(color);
         self
     }
 
     /// Sets the color of icon face
     pub fn set_face_color(&mut self, color: &str) -> &mut Self {
-        self.face_color = String::from(color);
+        self.style.face_color = String::from(color);
         self
     }
 
@@ -267,78 +149,108 @@ impl SlopeIcon {
     /// * "`-`", `:`", "`--`", "`-.`", or "`None`"
     /// * As defined in <https://matplotlib.org/stable/gallery/lines_bars_and_markers/linestyles.html>
     pub fn set_line_style(&mut self, style: &str) -> &mut Self {
-        self.line_style = String::from(style);
+        self.style.line_style = String::from(style);
         self
     }
 
     /// Sets the width of lines
     pub fn set_line_width(&mut self, width: f64) -> &mut Self {
-        self.line_width = width;
+        self.style.line_width = width;
         self
     }
 
     /// Sets the (horizontal) length of the icon in Axes coordinates [0, 1]
     pub fn set_length(&mut self, value: f64) -> &mut Self {
-        self.length = value;
+        self.style.length = value;
         self
     }
 
     /// Sets the whole icon's offset in normalized axes coordinates in points
     pub fn set_offset_v(&mut self, value: f64) -> &mut Self {
-        self.offset_v = value;
+        self.style.offset_v = value;
         self
     }
 
     /// Sets option to skip drawing text
     pub fn set_no_text(&mut self, flag: bool) -> &mut Self {
-        self.no_text = flag;
+        self.style.no_text = flag;
         self
     }
 
     /// Sets the font size
     pub fn set_fontsize(&mut self, fontsize: f64) -> &mut Self {
-        self.fontsize = fontsize;
+        self.style.fontsize = fontsize;
         self
     }
 
     /// Sets the the precision of slope number in label
     pub fn set_precision(&mut self, value: usize) -> &mut Self {
-        self.precision = value;
+        self.style.precision = value;
         self
     }
 
     /// Sets text of horizontal value (== 1)
     pub fn set_text_h(&mut self, one: &str) -> &mut Self {
-        self.text_h = String::from(one);
+        self.style.text_h = String::from(one);
         self
     }
 
     /// Sets text of vertical value (slope)
     pub fn set_text_v(&mut self, slope: &str) -> &mut Self {
-        self.text_v = String::from(slope);
+        self.style.text_v = String::from(slope);
         self
     }
 
     /// Sets the color of text
     pub fn set_text_color(&mut self, color: &str) -> &mut Self {
-        self.text_color = String::from(color);
+        self.style.text_color = String::from(color);
         self
     }
 
     /// Sets the horizontal offset for the text in normalized axes coordinates in points
     pub fn set_text_offset_h(&mut self, value: f64) -> &mut Self {
-        self.text_offset_h = value;
+        self.style.text_offset_h = value;
         self
     }
 
     /// Sets the vertical offset for the text in normalized axes coordinates in points
     pub fn set_text_offset_v(&mut self, value: f64) -> &mut Self {
-        self.text_offset_v = value;
+        self.style.text_offset_v = value;
         self
     }
 
+    /// Writes the primitive to the buffer and records it
+    fn push(&mut self, primitive: Primitive) {
+        primitive.write_python(&mut self.buffer);
+        self.primitives.push(primitive);
+    }
+}
+
+impl Default for SlopeIconStyle {
+    fn default() -> Self {
+        SlopeIconStyle {
+            above: false,
+            edge_color: "#000000".to_string(),
+            face_color: "#f7f7f7".to_string(),
+            line_style: String::new(),
+            line_width: 0.0,
+            length: 0.1,
+            offset_v: 5.0,
+            no_text: false,
+            fontsize: 0.0,
+            precision: 0,
+            text_h: "1".to_string(),
+            text_v: String::new(),
+            text_color: "#000000".to_string(),
+            text_offset_h: 3.0,
+            text_offset_v: 2.0,
+        }
+    }
+}
+
+impl SlopeIconStyle {
     /// Returns the icon's (whole) coordinate transform
-    fn transform(&self, slope: f64) -> String {
+    pub(crate) fn transform(&self, slope: f64) -> String {
         let flip = if slope < 0.0 { !self.above } else { self.above };
         let mut opt = String::new();
         if self.offset_v > 0.0 {
@@ -360,7 +272,7 @@ impl SlopeIcon {
     }
 
     /// Returns the coordinate transform for text
-    fn transform_text(&self, slope: f64) -> String {
+    pub(crate) fn transform_text(&self, slope: f64) -> String {
         let flip = if slope < 0.0 { !self.above } else { self.above };
         let mut opt = String::new();
         if self.offset_v > 0.0 || self.text_offset_v > 0.0 {
@@ -398,7 +310,7 @@ impl SlopeIcon {
     }
 
     /// Returns options for slope icon
-    fn options(&self) -> String {
+    pub(crate) fn options(&self) -> String {
         let mut opt = String::from(",transform=tf");
         if self.edge_color != "" {
             write!(&mut opt, ",edgecolor='{}'", self.edge_color).unwrap();
@@ -416,7 +328,7 @@ impl SlopeIcon {
     }
 
     /// Returns options for text
-    fn options_text(&self) -> (String, String) {
+    pub(crate) fn options_text(&self) -> (String, String) {
         let mut opt_x = String::from(",transform=tfx");
         let mut opt_y = String::from(",transform=tfy");
         if self.text_color != "" {
@@ -431,12 +343,131 @@ impl SlopeIcon {
     }
 }
 
+/// Writes the Python commands of a slope icon (see [Primitive::SlopeIcon])
+pub(crate) fn write_slope_icon(buf: &mut String, slope: f64, x_center: f64, y_center: f64, style: &SlopeIconStyle) {
+    // set flip flag
+    let flip = if slope < 0.0 { !style.above } else { style.above };
+
+    // compute axis (normalized) coordinates and slope
+    write!(
+        buf,
+        "slope,cx,cy=float({}),float({}),float({})\n\
+         if plt.gca().get_xscale() == 'log': cx=np.log10(cx)\n\
+         if plt.gca().get_yscale() == 'log': cy=np.log10(cy)\n\
+         xc,yc=data_to_axis((cx,cy))\n\
+         xa,ya=data_to_axis((cx+1.0,cy+slope))\n\
+         m,l=(ya-yc)/(xa-xc),{}\n",
+        slope,
+        x_center,
+        y_center,
+        style.length / 2.0,
+    )
+    .unwrap();
+
+    // set polygon
+    if flip {
+        buf.push_str(
+            "dat=[[pth.Path.MOVETO,(xc-l,yc-m*l)],\
+                  [pth.Path.LINETO,(xc-l,yc+m*l)],\
+                  [pth.Path.LINETO,(xc+l,yc+m*l)],\
+                  [pth.Path.CLOSEPOLY,(None,None)]]\n",
+        );
+    } else {
+        buf.push_str(
+            "dat=[[pth.Path.MOVETO,(xc-l,yc-m*l)],\
+                  [pth.Path.LINETO,(xc+l,yc-m*l)],\
+                  [pth.Path.LINETO,(xc+l,yc+m*l)],\
+                  [pth.Path.CLOSEPOLY,(None,None)]]\n",
+        );
+    }
+
+    // draw
+    let tf = style.transform(slope);
+    let opt = style.options();
+    write!(
+        buf,
+        "{}cmd,pts=zip(*dat)\n\
+         h=pth.Path(pts,cmd)\n\
+         p=pat.PathPatch(h{})\n\
+         plt.gca().add_patch(p)\n",
+        tf, opt,
+    )
+    .unwrap();
+
+    // skip text
+    if style.no_text {
+        return;
+    }
+
+    // coordinates for labels
+    buf.push_str(
+        "xm,ym=xc-l,yc-m*l\n\
+         xp,yp=xc+l,yc+m*l\n",
+    );
+
+    // slope text
+    let mut text = String::new();
+    if style.text_v == "" {
+        if style.precision == 0 {
+            write!(&mut text, "{}", f64::abs(slope)).unwrap();
+        } else {
+            write!(&mut text, "{:.1$}", f64::abs(slope), style.precision).unwrap();
+        }
+    } else {
+        write!(&mut text, "{}", style.text_v).unwrap();
+    }
+
+    // draw labels
+    let tf_txt = style.transform_text(slope);
+    buf.push_str(&tf_txt);
+    let (opt_x, opt_y) = style.options_text();
+    if flip {
+        if slope < 0.0 {
+            write!(
+                buf,
+                "plt.text(xc,yp,r'{}',ha='center',va='top'{})\n",
+                style.text_h, opt_x
+            )
+            .unwrap();
+        } else {
+            write!(
+                buf,
+                "plt.text(xc,yp,r'{}',ha='center',va='bottom'{})\n",
+                style.text_h, opt_x
+            )
+            .unwrap();
+        }
+        write!(buf, "plt.text(xm,yc,r'{}',ha='right',va='center'{})\n", text, opt_y).unwrap();
+    } else {
+        if slope < 0.0 {
+            write!(
+                buf,
+                "plt.text(xc,ym,r'{}',ha='center',va='bottom'{})\n",
+                style.text_h, opt_x
+            )
+            .unwrap();
+        } else {
+            write!(
+                buf,
+                "plt.text(xc,ym,r'{}',ha='center',va='top'{})\n",
+                style.text_h, opt_x
+            )
+            .unwrap();
+        }
+        write!(buf, "plt.text(xp,yc,r'{}',ha='left',va='center'{})\n", text, opt_y).unwrap();
+    }
+}
+
 impl GraphMaker for SlopeIcon {
     fn get_buffer<'a>(&'a self) -> &'a String {
         &self.buffer
     }
     fn clear_buffer(&mut self) {
         self.buffer.clear();
+        self.primitives.clear();
+    }
+    fn get_primitives(&self) -> &[Primitive] {
+        &self.primitives
     }
 }
 
@@ -450,21 +481,22 @@ mod tests {
     #[test]
     fn new_works() {
         let icon = SlopeIcon::new();
-        assert_eq!(icon.above, false);
-        assert_eq!(icon.edge_color.len(), 7);
-        assert_eq!(icon.line_style.len(), 0);
-        assert_eq!(icon.line_width, 0.0);
-        assert_eq!(icon.length, 0.1);
-        assert_eq!(icon.offset_v, 5.0);
-        assert_eq!(icon.no_text, false);
-        assert_eq!(icon.fontsize, 0.0);
-        assert_eq!(icon.precision, 0);
-        assert_eq!(icon.text_h.len(), 1);
-        assert_eq!(icon.text_v.len(), 0);
-        assert_eq!(icon.text_color.len(), 7);
-        assert_eq!(icon.text_offset_h, 3.0);
-        assert_eq!(icon.text_offset_v, 2.0);
+        assert_eq!(icon.style.above, false);
+        assert_eq!(icon.style.edge_color.len(), 7);
+        assert_eq!(icon.style.line_style.len(), 0);
+        assert_eq!(icon.style.line_width, 0.0);
+        assert_eq!(icon.style.length, 0.1);
+        assert_eq!(icon.style.offset_v, 5.0);
+        assert_eq!(icon.style.no_text, false);
+        assert_eq!(icon.style.fontsize, 0.0);
+        assert_eq!(icon.style.precision, 0);
+        assert_eq!(icon.style.text_h.len(), 1);
+        assert_eq!(icon.style.text_v.len(), 0);
+        assert_eq!(icon.style.text_color.len(), 7);
+        assert_eq!(icon.style.text_offset_h, 3.0);
+        assert_eq!(icon.style.text_offset_v, 2.0);
         assert_eq!(icon.buffer.len(), 0);
+        assert_eq!(icon.primitives.len(), 0);
     }
 
     #[test]
@@ -473,27 +505,27 @@ mod tests {
         icon.set_offset_v(7.0);
         icon.set_above(false);
         assert_eq!(
-            icon.transform(1.0),
+            icon.style.transform(1.0),
             "tf=tra.offset_copy(plt.gca().transAxes,fig=plt.gcf(),x=0,y=-7,units='points')\n"
         );
         icon.set_above(true);
         assert_eq!(
-            icon.transform(1.0),
+            icon.style.transform(1.0),
             "tf=tra.offset_copy(plt.gca().transAxes,fig=plt.gcf(),x=0,y=7,units='points')\n"
         );
         icon.set_above(false);
         assert_eq!(
-            icon.transform(-1.0),
+            icon.style.transform(-1.0),
             "tf=tra.offset_copy(plt.gca().transAxes,fig=plt.gcf(),x=0,y=-7,units='points')\n"
         );
         icon.set_above(true);
         assert_eq!(
-            icon.transform(-1.0),
+            icon.style.transform(-1.0),
             "tf=tra.offset_copy(plt.gca().transAxes,fig=plt.gcf(),x=0,y=7,units='points')\n"
         );
         icon.set_offset_v(0.0);
         icon.set_above(false);
-        assert_eq!(icon.transform(-1.0), "tf=plt.gca().transAxes\n");
+        assert_eq!(icon.style.transform(-1.0), "tf=plt.gca().transAxes\n");
     }
 
     #[test]
@@ -505,25 +537,25 @@ mod tests {
         icon.set_text_offset_v(3.0);
         icon.set_above(false);
         assert_eq!(
-            icon.transform_text(1.0),
+            icon.style.transform_text(1.0),
             "tfx=tra.offset_copy(plt.gca().transAxes,fig=plt.gcf(),x=0,y=-10,units='points')\n\
              tfy=tra.offset_copy(plt.gca().transAxes,fig=plt.gcf(),x=1,y=-7,units='points')\n"
         );
         icon.set_above(true);
         assert_eq!(
-            icon.transform_text(1.0),
+            icon.style.transform_text(1.0),
             "tfx=tra.offset_copy(plt.gca().transAxes,fig=plt.gcf(),x=0,y=10,units='points')\n\
              tfy=tra.offset_copy(plt.gca().transAxes,fig=plt.gcf(),x=-1,y=7,units='points')\n"
         );
         icon.set_above(false);
         assert_eq!(
-            icon.transform_text(-1.0),
+            icon.style.transform_text(-1.0),
             "tfx=tra.offset_copy(plt.gca().transAxes,fig=plt.gcf(),x=0,y=-10,units='points')\n\
              tfy=tra.offset_copy(plt.gca().transAxes,fig=plt.gcf(),x=-1,y=-7,units='points')\n"
         );
         icon.set_above(true);
         assert_eq!(
-            icon.transform_text(-1.0),
+            icon.style.transform_text(-1.0),
             "tfx=tra.offset_copy(plt.gca().transAxes,fig=plt.gcf(),x=0,y=10,units='points')\n\
              tfy=tra.offset_copy(plt.gca().transAxes,fig=plt.gcf(),x=1,y=7,units='points')\n"
         );
@@ -531,25 +563,25 @@ mod tests {
         icon.set_offset_v(0.0);
         icon.set_above(false);
         assert_eq!(
-            icon.transform_text(1.0),
+            icon.style.transform_text(1.0),
             "tfx=tra.offset_copy(plt.gca().transAxes,fig=plt.gcf(),x=0,y=-3,units='points')\n\
              tfy=tra.offset_copy(plt.gca().transAxes,fig=plt.gcf(),x=1,y=-0,units='points')\n"
         );
         icon.set_above(true);
         assert_eq!(
-            icon.transform_text(1.0),
+            icon.style.transform_text(1.0),
             "tfx=tra.offset_copy(plt.gca().transAxes,fig=plt.gcf(),x=0,y=3,units='points')\n\
              tfy=tra.offset_copy(plt.gca().transAxes,fig=plt.gcf(),x=-1,y=0,units='points')\n"
         );
         icon.set_above(false);
         assert_eq!(
-            icon.transform_text(-1.0),
+            icon.style.transform_text(-1.0),
             "tfx=tra.offset_copy(plt.gca().transAxes,fig=plt.gcf(),x=0,y=-3,units='points')\n\
              tfy=tra.offset_copy(plt.gca().transAxes,fig=plt.gcf(),x=-1,y=-0,units='points')\n"
         );
         icon.set_above(true);
         assert_eq!(
-            icon.transform_text(-1.0),
+            icon.style.transform_text(-1.0),
             "tfx=tra.offset_copy(plt.gca().transAxes,fig=plt.gcf(),x=0,y=3,units='points')\n\
              tfy=tra.offset_copy(plt.gca().transAxes,fig=plt.gcf(),x=1,y=0,units='points')\n"
         );
@@ -559,23 +591,23 @@ mod tests {
         icon.set_text_offset_h(0.0);
         icon.set_above(false);
         assert_eq!(
-            icon.transform_text(1.0),
+            icon.style.transform_text(1.0),
             "tfx=plt.gca().transAxes\n\
              tfy=plt.gca().transAxes\n"
         );
         assert_eq!(
-            icon.transform_text(-1.0),
+            icon.style.transform_text(-1.0),
             "tfx=plt.gca().transAxes\n\
              tfy=plt.gca().transAxes\n"
         );
         icon.set_above(true);
         assert_eq!(
-            icon.transform_text(1.0),
+            icon.style.transform_text(1.0),
             "tfx=plt.gca().transAxes\n\
              tfy=plt.gca().transAxes\n"
         );
         assert_eq!(
-            icon.transform_text(-1.0),
+            icon.style.transform_text(-1.0),
             "tfx=plt.gca().transAxes\n\
              tfy=plt.gca().transAxes\n"
         );
@@ -588,7 +620,7 @@ mod tests {
             .set_face_color("gold")
             .set_line_style("--")
             .set_line_width(2.0);
-        let options = icon.options();
+        let options = icon.style.options();
         assert_eq!(
             options,
             ",transform=tf\
@@ -603,7 +635,7 @@ mod tests {
     fn options_text_works() {
         let mut icon = SlopeIcon::new();
         icon.set_text_color("red").set_fontsize(12.0);
-        let (opt_x, opt_y) = icon.options_text();
+        let (opt_x, opt_y) = icon.style.options_text();
         assert_eq!(
             opt_x,
             ",transform=tfx\
@@ -656,7 +688,9 @@ mod tests {
                        plt.text(xc,yp,r'one',ha='center',va='bottom',transform=tfx,color='gold',fontsize=4)\n\
                        plt.text(xm,yc,r'lambda',ha='right',va='center',transform=tfy,color='gold',fontsize=4)\n";
         assert_eq!(icon.buffer, b);
+        assert_eq!(icon.get_primitives().len(), 1);
         icon.clear_buffer();
         assert_eq!(icon.buffer, "");
+        assert_eq!(icon.primitives.len(), 0);
     }
 }
@@ -0,0 +1,650 @@
+use super::{vector_to_array, Error, GraphMaker, Primitive, Script};
+use std::collections::HashMap;
+use std::fmt::Write;
+
+/// Number of nonzero entries above which the markers (or cells) are rasterized
+const RASTERIZE_NNZ: usize = 5000;
+
+/// Generates a plot of the sparsity pattern of a matrix (aka spy plot)
+///
+/// The nonzero entries are given in triplet (COO) or compressed sparse row (CSR) format and are
+/// drawn as markers or as colored cells (optionally colored by their values).
+///
+/// # Notes
+///
+/// * The first row is shown at the top and the (0-based) indices are shown on the axes.
+/// * The title shows the dimensions of the matrix and the number of nonzero entries (nnz).
+/// * Duplicate entries (with the same row and column) are summed; thus, nnz counts the distinct entries.
+/// * The markers (or cells) are rasterized if nnz is large (or if requested).
+///
+/// # Example
+///
+/// ```
+/// use plotpy::{Error, Plot, SparsityPattern};
+///
+/// fn main() -> Result<(), Error> {
+///     // tridiagonal matrix in triplet format
+///     let n: usize = 8;
+///     let (mut rows, mut cols, mut values) = (Vec::new(), Vec::new(), Vec::new());
+///     for i in 0..n {
+///         for j in i.saturating_sub(1)..usize::min(i + 2, n) {
+///             rows.push(i);
+///             cols.push(j);
+///             values.push(if i == j { 2.0 } else { -1.0 });
+///         }
+///     }
+///
+///     // configure and draw sparsity pattern
+///     let mut spy = SparsityPattern::new();
+///     spy.set_colormap_name("coolwarm")
+///         .set_block_separators(&[4], &[4]);
+///     spy.draw_triplet(n, n, &rows, &cols, Some(&values))?;
+///
+///     // add sparsity pattern to plot
+///     let mut plot = Plot::new();
+///     plot.add(&spy);
+///
+///     // save figure
+///     plot.save("/tmp/plotpy/doc_tests/doc_sparsity_pattern.svg")?;
+///     Ok(())
+/// }
+/// ```
+///
+/// See also integration test in the **tests** directory.
+pub struct SparsityPattern {
+    style: SparsityPatternStyle, // Style
+    buffer: String,              // buffer
+    primitives: Vec<Primitive>,  // primitives
+}
+
+/// Holds the style of a sparsity pattern (see [SparsityPattern])
+///
+/// The style is recorded by the [Primitive] drawn by [SparsityPattern] and may be modified afterwards.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SparsityPatternStyle {
+    pub with_cells: bool,       // Draws colored cells instead of markers
+    pub color: String,          // Color of the entries (when not colored by values)
+    pub marker_style: String,   // Style of markers, e.g., "`s`", "`o`"
+    pub marker_size: f64,       // Size of markers in points²
+    pub colormap_index: usize,  // Colormap index
+    pub colormap_name: String,  // Colormap name
+    pub no_colorbar: bool,      // Skip drawing a colorbar (when colored by values)
+    pub colorbar_label: String, // Colorbar label
+    pub no_title: bool,         // Skip writing the dimensions and nnz in the title
+    pub row_blocks: Vec<usize>, // Rows before which a horizontal separator is drawn
+    pub col_blocks: Vec<usize>, // Columns before which a vertical separator is drawn
+    pub block_color: String,    // Color of the block separators
+    pub block_line_width: f64,  // Width of the block separators
+    pub rasterized: bool,       // Rasterizes the markers or cells (automatic if nnz is large)
+}
+
+impl SparsityPattern {
+    /// Creates a new SparsityPattern object
+    pub fn new() -> Self {
+        SparsityPattern {
+            style: SparsityPatternStyle::default(),
+            buffer: String::new(),
+            primitives: Vec::new(),
+        }
+    }
+
+    /// Draws the sparsity pattern of a matrix given in triplet (COO) format
+    ///
+    /// # Input
+    ///
+    /// * `nrow` -- number of rows of the matrix
+    /// * `ncol` -- number of columns of the matrix
+    /// * `rows` -- the (0-based) row index of each nonzero entry
+    /// * `cols` -- the (0-based) column index of each nonzero entry
+    /// * `values` -- (optional) the value of each nonzero entry, mapped to colors by the colormap
+    ///
+    /// **Note:** Duplicate entries (with the same row and column) are summed, as in the COO format of SciPy.
+    pub fn draw_triplet(
+        &mut self,
+        nrow: usize,
+        ncol: usize,
+        rows: &[usize],
+        cols: &[usize],
+        values: Option<&[f64]>,
+    ) -> Result<(), Error> {
+        if cols.len() != rows.len() {
+            return Err(Error::InvalidInput {
+                argument: "cols",
+                message: "cols.len() must be equal to rows.len()",
+            });
+        }
+        if let Some(values) = values {
+            if values.len() != rows.len() {
+                return Err(Error::InvalidInput {
+                    argument: "values",
+                    message: "values.len() must be equal to rows.len()",
+                });
+            }
+        }
+        if rows.iter().any(|i| *i >= nrow) {
+            return Err(Error::InvalidInput {
+                argument: "rows",
+                message: "row indices must be smaller than nrow",
+            });
+        }
+        if cols.iter().any(|j| *j >= ncol) {
+            return Err(Error::InvalidInput {
+                argument: "cols",
+                message: "column indices must be smaller than ncol",
+            });
+        }
+        let (rows, cols, values) = sum_duplicates(rows, cols, values);
+        let style = self.style.clone();
+        self.push(Primitive::SparsityPattern {
+            nrow,
+            ncol,
+            rows,
+            cols,
+            values,
+            style,
+        });
+        Ok(())
+    }
+
+    /// Draws the sparsity pattern of a matrix given in compressed sparse row (CSR) format
+    ///
+    /// # Input
+    ///
+    /// * `nrow` -- number of rows of the matrix
+    /// * `ncol` -- number of columns of the matrix
+    /// * `row_pointers` -- (nrow + 1) offsets of the first entry of each row in `col_indices`
+    /// * `col_indices` -- the (0-based) column index of each nonzero entry
+    /// * `values` -- (optional) the value of each nonzero entry, mapped to colors by the colormap
+    pub fn draw_csr(
+        &mut self,
+        nrow: usize,
+        ncol: usize,
+        row_pointers: &[usize],
+        col_indices: &[usize],
+        values: Option<&[f64]>,
+    ) -> Result<(), Error> {
+        if row_pointers.len() != nrow + 1 {
+            return Err(Error::InvalidInput {
+                argument: "row_pointers",
+                message: "row_pointers.len() must be equal to nrow + 1",
+            });
+        }
+        if row_pointers.windows(2).any(|w| w[0] > w[1]) || row_pointers[nrow] != col_indices.len() {
+            return Err(Error::InvalidInput {
+                argument: "row_pointers",
+                message: "row_pointers must be non-decreasing and end at col_indices.len()",
+            });
+        }
+        let mut rows = Vec::with_capacity(col_indices.len());
+        for i in 0..nrow {
+            let count = row_pointers[i + 1] - row_pointers[i];
+            rows.resize(rows.len() + count, i);
+        }
+        self.draw_triplet(nrow, ncol, &rows, col_indices, values)
+    }
+
+    /// Sets option to draw colored cells instead of markers
+    pub fn set_with_cells(&mut self, flag: bool) -> &mut Self {
+        self.style.with_cells = flag;
+        self
+    }
+
+    /// Sets the color of the entries (used when the entries are not colored by values)
+    pub fn set_color(&mut self, color: &str) -> &mut Self {
+        self.style.color = String::from(color);
+        self
+    }
+
+    /// Sets the style of markers
+    ///
+    /// Examples:
+    ///
+    /// * "`s`", "`o`", "`.`"
+    /// * As defined in <https://matplotlib.org/stable/api/markers_api.html>
+    pub fn set_marker_style(&mut self, style: &str) -> &mut Self {
+        self.style.marker_style = String::from(style);
+        self
+    }
+
+    /// Sets the size of markers in points²
+    pub fn set_marker_size(&mut self, size: f64) -> &mut Self {
+        self.style.marker_size = size;
+        self
+    }
+
+    /// Sets the colormap index
+    ///
+    /// Options:
+    ///
+    /// * 0 -- bwr
+    /// * 1 -- RdBu
+    /// * 2 -- hsv
+    /// * 3 -- jet
+    /// * 4 -- terrain
+    /// * 5 -- pink
+    /// * 6 -- Greys
+    /// * `>`6 -- starts over from 0
+    pub fn set_colormap_index(&mut self, index: usize) -> &mut Self {
+        self.style.colormap_index = index;
+        self.style.colormap_name = String::new();
+        self
+    }
+
+    /// Sets the colormap name
+    ///
+    /// Colormap names:
+    ///
+    /// * see <https://matplotlib.org/stable/tutorials/colors/colormaps.html>
+    ///
+    /// Will use `colormap_index` instead if `colormap_name` is empty.
+    pub fn set_colormap_name(&mut self, name: &str) -> &mut Self {
+        self.style.colormap_name = String::from(name);
+        self
+    }
+
+    /// Sets option to skip drawing a colorbar
+    pub fn set_no_colorbar(&mut self, flag: bool) -> &mut Self {
+        self.style.no_colorbar = flag;
+        self
+    }
+
+    /// Sets the colorbar label
+    pub fn set_colorbar_label(&mut self, label: &str) -> &mut Self {
+        self.style.colorbar_label = String::from(label);
+        self
+    }
+
+    /// Sets option to skip writing the dimensions and nnz in the title
+    pub fn set_no_title(&mut self, flag: bool) -> &mut Self {
+        self.style.no_title = flag;
+        self
+    }
+
+    /// Sets the block separators
+    ///
+    /// # Input
+    ///
+    /// * `rows` -- the rows before which a horizontal line is drawn, e.g., `[3]` separates rows 0-2 from rows 3-
+    /// * `cols` -- the columns before which a vertical line is drawn
+    pub fn set_block_separators(&mut self, rows: &[usize], cols: &[usize]) -> &mut Self {
+        self.style.row_blocks = rows.to_vec();
+        self.style.col_blocks = cols.to_vec();
+        self
+    }
+
+    /// Sets the color of the block separators
+    pub fn set_block_color(&mut self, color: &str) -> &mut Self {
+        self.style.block_color = String::from(color);
+        self
+    }
+
+    /// Sets the width of the block separators
+    pub fn set_block_line_width(&mut self, width: f64) -> &mut Self {
+        self.style.block_line_width = width;
+        self
+    }
+
+    /// Sets option to rasterize the markers (or cells)
+    ///
+    /// **Note:** The markers (or cells) are always rasterized if there are more than 5000 nonzero entries.
+    pub fn set_rasterized(&mut self, flag: bool) -> &mut Self {
+        self.style.rasterized = flag;
+        self
+    }
+
+    /// Writes the primitive to the buffer and records it
+    fn push(&mut self, primitive: Primitive) {
+        primitive.write_python(&mut self.buffer);
+        self.primitives.push(primitive);
+    }
+}
+
+impl Default for SparsityPatternStyle {
+    fn default() -> Self {
+        SparsityPatternStyle {
+            with_cells: false,
+            color: "black".to_string(),
+            marker_style: "s".to_string(),
+            marker_size: 0.0,
+            colormap_index: 0,
+            colormap_name: String::new(),
+            no_colorbar: false,
+            colorbar_label: String::new(),
+            no_title: false,
+            row_blocks: Vec::new(),
+            col_blocks: Vec::new(),
+            block_color: "gray".to_string(),
+            block_line_width: 1.0,
+            rasterized: false,
+        }
+    }
+}
+
+impl SparsityPatternStyle {
+    /// Returns options for the markers (scatter) or cells (PolyCollection)
+    pub(crate) fn options(&self, with_values: bool, nnz: usize) -> String {
+        let mut opt = String::new();
+        if with_values {
+            if self.colormap_name != "" {
+                write!(&mut opt, ",cmap=plt.get_cmap('{}')", self.colormap_name).unwrap();
+            } else {
+                write!(&mut opt, ",cmap=get_colormap({})", self.colormap_index).unwrap();
+            }
+        } else if self.with_cells {
+            write!(&mut opt, ",facecolors='{}'", self.color).unwrap();
+        } else {
+            write!(&mut opt, ",color='{}'", self.color).unwrap();
+        }
+        if !self.with_cells {
+            if self.marker_style != "" {
+                write!(&mut opt, ",marker='{}'", self.marker_style).unwrap();
+            }
+            if self.marker_size > 0.0 {
+                write!(&mut opt, ",s={}", self.marker_size).unwrap();
+            }
+        }
+        write!(&mut opt, ",linewidths=0").unwrap();
+        if self.rasterized || nnz > RASTERIZE_NNZ {
+            write!(&mut opt, ",rasterized=True").unwrap();
+        }
+        opt
+    }
+
+    /// Returns options for the block separators
+    pub(crate) fn options_blocks(&self) -> String {
+        let mut opt = String::new();
+        if self.block_color != "" {
+            write!(&mut opt, ",color='{}'", self.block_color).unwrap();
+        }
+        if self.block_line_width > 0.0 {
+            write!(&mut opt, ",linewidth={}", self.block_line_width).unwrap();
+        }
+        opt
+    }
+}
+
+/// Writes the Python commands of a sparsity pattern (see [Primitive::SparsityPattern])
+pub(crate) fn write_sparsity_pattern(
+    buf: &mut Script,
+    nrow: usize,
+    ncol: usize,
+    rows: &[usize],
+    cols: &[usize],
+    values: &[f64],
+    style: &SparsityPatternStyle,
+) {
+    let nnz = rows.len();
+    let with_values = values.len() > 0;
+    vector_to_array(buf, "i", &rows);
+    vector_to_array(buf, "j", &cols);
+    if with_values {
+        vector_to_array(buf, "v", &values);
+    }
+    let opt = style.options(with_values, nnz);
+    if style.with_cells {
+        write!(
+            buf,
+            "import matplotlib.collections as mco\n\
+             cells=np.stack([j-0.5,i-0.5,j+0.5,i-0.5,j+0.5,i+0.5,j-0.5,i+0.5],axis=1).reshape(-1,4,2)\n\
+             sp=mco.PolyCollection(cells{})\n",
+            &opt
+        )
+        .unwrap();
+        if with_values {
+            write!(buf, "sp.set_array(v)\n").unwrap();
+        }
+        write!(buf, "plt.gca().add_collection(sp)\n").unwrap();
+    } else if with_values {
+        write!(buf, "sp=plt.scatter(j,i,c=v{})\n", &opt).unwrap();
+    } else {
+        write!(buf, "sp=plt.scatter(j,i{})\n", &opt).unwrap();
+    }
+    if with_values && !style.no_colorbar {
+        write!(buf, "cb=plt.colorbar(sp)\n").unwrap();
+        if style.colorbar_label != "" {
+            write!(buf, "cb.ax.set_ylabel(r'{}')\n", style.colorbar_label).unwrap();
+        }
+    }
+    let opt_blocks = style.options_blocks();
+    for row in &style.row_blocks {
+        write!(buf, "plt.axhline({}-0.5{})\n", row, &opt_blocks).unwrap();
+    }
+    for col in &style.col_blocks {
+        write!(buf, "plt.axvline({}-0.5{})\n", col, &opt_blocks).unwrap();
+    }
+    write!(
+        buf,
+        "plt.gca().set_xlim(-0.5,{}-0.5)\n\
+         plt.gca().set_ylim({}-0.5,-0.5)\n\
+         plt.gca().set_aspect('equal')\n\
+         plt.gca().xaxis.tick_top()\n\
+         plt.gca().xaxis.set_label_position('top')\n",
+        ncol, nrow
+    )
+    .unwrap();
+    if !style.no_title {
+        write!(buf, "plt.title(r'{} × {}, nnz = {}')\n", nrow, ncol, nnz).unwrap();
+    }
+}
+
+/// Sums the duplicate entries (with the same row and column) of a matrix in triplet format
+///
+/// Returns the rows, columns, and values (empty if not given) of the distinct entries in the order
+/// in which they first appear.
+fn sum_duplicates(rows: &[usize], cols: &[usize], values: Option<&[f64]>) -> (Vec<usize>, Vec<usize>, Vec<f64>) {
+    let mut positions = HashMap::with_capacity(rows.len());
+    let (mut distinct_rows, mut distinct_cols) = (Vec::with_capacity(rows.len()), Vec::with_capacity(rows.len()));
+    let mut distinct_values = Vec::with_capacity(values.map_or(0, |v| v.len()));
+    for (k, (i, j)) in rows.iter().zip(cols).enumerate() {
+        match positions.get(&(*i, *j)) {
+            Some(p) => {
+                if let Some(values) = values {
+                    distinct_values[*p] += values[k];
+                }
+            }
+            None => {
+                positions.insert((*i, *j), distinct_rows.len());
+                distinct_rows.push(*i);
+                distinct_cols.push(*j);
+                if let Some(values) = values {
+                    distinct_values.push(values[k]);
+                }
+            }
+        }
+    }
+    (distinct_rows, distinct_cols, distinct_values)
+}
+
+impl GraphMaker for SparsityPattern {
+    fn get_buffer<'a>(&'a self) -> &'a String {
+        &self.buffer
+    }
+    fn clear_buffer(&mut self) {
+        self.buffer.clear();
+        self.primitives.clear();
+    }
+    fn get_primitives(&self) -> &[Primitive] {
+        &self.primitives
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::SparsityPattern;
+    use crate::{Error, GraphMaker, Primitive};
+
+    #[test]
+    fn new_works() {
+        let spy = SparsityPattern::new();
+        assert_eq!(spy.style.with_cells, false);
+        assert_eq!(spy.style.color, "black");
+        assert_eq!(spy.style.marker_style, "s");
+        assert_eq!(spy.style.marker_size, 0.0);
+        assert_eq!(spy.style.colormap_index, 0);
+        assert_eq!(spy.style.colormap_name.len(), 0);
+        assert_eq!(spy.style.no_colorbar, false);
+        assert_eq!(spy.style.colorbar_label.len(), 0);
+        assert_eq!(spy.style.no_title, false);
+        assert_eq!(spy.style.row_blocks.len(), 0);
+        assert_eq!(spy.style.col_blocks.len(), 0);
+        assert_eq!(spy.style.block_color, "gray");
+        assert_eq!(spy.style.block_line_width, 1.0);
+        assert_eq!(spy.style.rasterized, false);
+        assert_eq!(spy.buffer.len(), 0);
+        assert_eq!(spy.primitives.len(), 0);
+    }
+
+    #[test]
+    fn options_works() {
+        let mut spy = SparsityPattern::new();
+        spy.set_color("red").set_marker_style("o").set_marker_size(4.0);
+        assert_eq!(spy.style.options(false, 1), ",color='red',marker='o',s=4,linewidths=0");
+        assert_eq!(
+            spy.style.options(false, 5001),
+            ",color='red',marker='o',s=4,linewidths=0,rasterized=True"
+        );
+        spy.set_colormap_index(3).set_rasterized(true);
+        assert_eq!(
+            spy.style.options(true, 1),
+            ",cmap=get_colormap(3),marker='o',s=4,linewidths=0,rasterized=True"
+        );
+        spy.set_with_cells(true)
+            .set_colormap_name("viridis")
+            .set_rasterized(false);
+        assert_eq!(spy.style.options(true, 1), ",cmap=plt.get_cmap('viridis'),linewidths=0");
+        assert_eq!(
+            spy.style.options(false, 5001),
+            ",facecolors='red',linewidths=0,rasterized=True"
+        );
+        spy.set_block_color("blue").set_block_line_width(2.0);
+        assert_eq!(spy.style.options_blocks(), ",color='blue',linewidth=2");
+    }
+
+    #[test]
+    fn draw_triplet_works() {
+        let mut spy = SparsityPattern::new();
+        spy.set_block_separators(&[1], &[]);
+        spy.draw_triplet(2, 3, &[0, 1], &[2, 0], None).unwrap();
+        let b: &str = "i=np.array([0,1,],dtype=float)\n\
+                       j=np.array([2,0,],dtype=float)\n\
+                       sp=plt.scatter(j,i,color='black',marker='s',linewidths=0)\n\
+                       plt.axhline(1-0.5,color='gray',linewidth=1)\n\
+                       plt.gca().set_xlim(-0.5,3-0.5)\n\
+                       plt.gca().set_ylim(2-0.5,-0.5)\n\
+                       plt.gca().set_aspect('equal')\n\
+                       plt.gca().xaxis.tick_top()\n\
+                       plt.gca().xaxis.set_label_position('top')\n\
+                       plt.title(r'2 × 3, nnz = 2')\n";
+        assert_eq!(spy.buffer, b);
+        assert_eq!(
+            spy.get_primitives(),
+            &[Primitive::SparsityPattern {
+                nrow: 2,
+                ncol: 3,
+                rows: vec![0, 1],
+                cols: vec![2, 0],
+                values: Vec::new(),
+                style: spy.style.clone(),
+            }]
+        );
+        spy.clear_buffer();
+        assert_eq!(spy.buffer, "");
+        assert_eq!(spy.primitives.len(), 0);
+    }
+
+    #[test]
+    fn draw_csr_works() {
+        // [[1, 0, 2],
+        //  [0, 0, 0],
+        //  [0, 3, 0]]
+        let mut spy = SparsityPattern::new();
+        spy.set_with_cells(true).set_no_title(true).set_colorbar_label("value");
+        spy.draw_csr(3, 3, &[0, 2, 2, 3], &[0, 2, 1], Some(&[1.0, 2.0, 3.0]))
+            .unwrap();
+        let b: &str = "i=np.array([0,0,2,],dtype=float)\n\
+                       j=np.array([0,2,1,],dtype=float)\n\
+                       v=np.array([1,2,3,],dtype=float)\n\
+                       import matplotlib.collections as mco\n\
+                       cells=np.stack([j-0.5,i-0.5,j+0.5,i-0.5,j+0.5,i+0.5,j-0.5,i+0.5],axis=1).reshape(-1,4,2)\n\
+                       sp=mco.PolyCollection(cells,cmap=get_colormap(0),linewidths=0)\n\
+                       sp.set_array(v)\n\
+                       plt.gca().add_collection(sp)\n\
+                       cb=plt.colorbar(sp)\n\
+                       cb.ax.set_ylabel(r'value')\n\
+                       plt.gca().set_xlim(-0.5,3-0.5)\n\
+                       plt.gca().set_ylim(3-0.5,-0.5)\n\
+                       plt.gca().set_aspect('equal')\n\
+                       plt.gca().xaxis.tick_top()\n\
+                       plt.gca().xaxis.set_label_position('top')\n";
+        assert_eq!(spy.buffer, b);
+    }
+
+    #[test]
+    fn draw_triplet_sums_duplicates() {
+        let mut spy = SparsityPattern::new();
+        spy.set_with_cells(true);
+        spy.draw_triplet(2, 2, &[1, 0, 1, 1], &[0, 1, 0, 0], Some(&[1.0, 2.0, 3.0, 4.0]))
+            .unwrap();
+        assert!(spy.buffer.contains("plt.title(r'2 × 2, nnz = 2')\n"));
+        match &spy.get_primitives()[0] {
+            Primitive::SparsityPattern { rows, cols, values, .. } => {
+                assert_eq!(rows, &[1, 0]);
+                assert_eq!(cols, &[0, 1]);
+                assert_eq!(values, &[8.0, 2.0]);
+            }
+            _ => panic!("a sparsity pattern should have been recorded"),
+        }
+        spy.draw_triplet(2, 2, &[0, 0], &[1, 1], None).unwrap();
+        assert!(spy.buffer.contains("plt.title(r'2 × 2, nnz = 1')\n"));
+    }
+
+    #[test]
+    fn draw_captures_errors() {
+        let mut spy = SparsityPattern::new();
+        assert_eq!(
+            spy.draw_triplet(2, 2, &[0, 1], &[0], None).err(),
+            Some(Error::InvalidInput {
+                argument: "cols",
+                message: "cols.len() must be equal to rows.len()",
+            })
+        );
+        assert_eq!(
+            spy.draw_triplet(2, 2, &[0], &[0], Some(&[1.0, 2.0])).err(),
+            Some(Error::InvalidInput {
+                argument: "values",
+                message: "values.len() must be equal to rows.len()",
+            })
+        );
+        assert_eq!(
+            spy.draw_triplet(2, 2, &[2], &[0], None).err(),
+            Some(Error::InvalidInput {
+                argument: "rows",
+                message: "row indices must be smaller than nrow",
+            })
+        );
+        assert_eq!(
+            spy.draw_triplet(2, 2, &[0], &[2], None).err(),
+            Some(Error::InvalidInput {
+                argument: "cols",
+                message: "column indices must be smaller than ncol",
+            })
+        );
+        assert_eq!(
+            spy.draw_csr(2, 2, &[0, 1], &[0], None).err(),
+            Some(Error::InvalidInput {
+                argument: "row_pointers",
+                message: "row_pointers.len() must be equal to nrow + 1",
+            })
+        );
+        assert_eq!(
+            spy.draw_csr(2, 2, &[0, 1, 3], &[0, 1], None).err(),
+            Some(Error::InvalidInput {
+                argument: "row_pointers",
+                message: "row_pointers must be non-decreasing and end at col_indices.len()",
+            })
+        );
+        assert_eq!(spy.primitives.len(), 0);
+    }
+}
@@ -1,4 +1,8 @@
-use super::{matrix_to_array, AsMatrix, GraphMaker, StrError};
+use super::{
+    matrix_to_array, matrix_to_f64, matrix_to_list, triangles_to_vec, vector_to_array, vector_to_f64, AsMatrix,
+    AsVector, Error, GraphMaker, Primitive, Script,
+};
+use num_traits::AsPrimitive;
 use std::fmt::Write;
 
 /// Generates a 3D a surface (or wireframe, or both)
@@ -6,10 +10,10 @@ use std::fmt::Write;
 /// # Example
 ///
 /// ```
-/// use plotpy::{Plot, StrError, Surface};
+/// use plotpy::{Error, Plot, Surface};
 /// use russell_lab::generate3d;
 ///
-/// fn main() -> Result<(), StrError> {
+/// fn main() -> Result<(), Error> {
 ///     // generate (x,y,z) matrices
 ///     let n = 21;
 ///     let (x, y, z) = generate3d(-2.0, 2.0, -2.0, 2.0, n, n, |x, y| x * x - y * y);
@@ -44,42 +48,39 @@ use std::fmt::Write;
 ///
 /// ![integ_surface_wireframe.svg](https://raw.githubusercontent.com/cpmech/plotpy/main/figures/integ_surface_wireframe.svg)
 pub struct Surface {
-    row_stride: usize,        // Row stride
-    col_stride: usize,        // Column stride
-    with_surface: bool,       // Generates a surface
-    with_wireframe: bool,     // Generates a wireframe
-    colormap_index: usize,    // Colormap index
-    colormap_name: String,    // Colormap name
-    with_colormap: bool,      // Use colormap
-    with_colorbar: bool,      // Draw a colorbar
-    colorbar_label: String,   // Colorbar label
-    number_format_cb: String, // Number format for labels in colorbar
-    solid_color: String,      // Solid color of surface (when not using colormap)
-    line_color: String,       // Color of wireframe lines
-    line_style: String,       // Style of wireframe line
-    line_width: f64,          // Width of wireframe line
-    buffer: String,           // buffer
+    style: SurfaceStyle,        // Style
+    buffer: String,             // buffer
+    primitives: Vec<Primitive>, // primitives
+}
+
+/// Holds the style of a surface (see [Surface])
+///
+/// The style is recorded by the [Primitive] drawn by [Surface] and may be modified afterwards.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SurfaceStyle {
+    pub row_stride: usize,        // Row stride
+    pub col_stride: usize,        // Column stride
+    pub with_surface: bool,       // Generates a surface
+    pub with_wireframe: bool,     // Generates a wireframe
+    pub colormap_index: usize,    // Colormap index
+    pub colormap_name: String,    // Colormap name
+    pub with_colormap: bool,      // Use colormap
+    pub with_colorbar: bool,      // Draw a colorbar
+    pub colorbar_label: String,   // Colorbar label
+    pub number_format_cb: String, // Number format for labels in colorbar
+    pub solid_color: String,      // Solid color of surface (when not using colormap)
+    pub line_color: String,       // Color of wireframe lines
+    pub line_style: String,       // Style of wireframe line
+    pub line_width: f64,          // Width of wireframe line
 }
 
 impl Surface {
     /// Creates a new Surface object
     pub fn new() -> Self {
         Surface {
-            row_stride: 0,
-            col_stride: 0,
-            with_surface: true,
-            with_wireframe: false,
-            colormap_index: 0,
-            colormap_name: String::new(),
-            with_colormap: true,
-            with_colorbar: false,
-            colorbar_label: String::new(),
-            number_format_cb: String::new(),
-            solid_color: String::new(),
-            line_color: "black".to_string(),
-            line_style: String::new(),
-            line_width: 0.0,
+            style: SurfaceStyle::default(),
             buffer: String::new(),
+            primitives: Vec::new(),
         }
     }
 
@@ -105,50 +106,72 @@ impl Surface {
     pub fn draw<'a, T, U>(&mut self, x: &'a T, y: &'a T, z: &'a T)
     where
         T: AsMatrix<'a, U>,
-        U: 'a + std::fmt::Display,
+        U: 'a + AsPrimitive<f64>,
     {
-        matrix_to_array(&mut self.buffer, "x", x);
-        matrix_to_array(&mut self.buffer, "y", y);
-        matrix_to_array(&mut self.buffer, "z", z);
-        write!(&mut self.buffer, "maybe_create_ax3d()\n").unwrap();
-        if self.with_surface {
-            let opt_surface = self.options_surface();
-            write!(&mut self.buffer, "sf=AX3D.plot_surface(x,y,z{})\n", &opt_surface).unwrap();
-        }
-        if self.with_wireframe {
-            let opt_wireframe = self.options_wireframe();
-            write!(&mut self.buffer, "AX3D.plot_wireframe(x,y,z{})\n", &opt_wireframe).unwrap();
-        }
-        if self.with_colorbar {
-            let opt_colorbar = self.options_colorbar();
-            write!(&mut self.buffer, "cb=plt.colorbar(sf{})\n", &opt_colorbar).unwrap();
-            if self.colorbar_label != "" {
-                write!(&mut self.buffer, "cb.ax.set_ylabel(r'{}')\n", self.colorbar_label).unwrap();
-            }
-        }
+        let (x, y, z) = (matrix_to_f64(x), matrix_to_f64(y), matrix_to_f64(z));
+        let style = self.style.clone();
+        self.push(Primitive::Surface { x, y, z, style });
+    }
+
+    /// Draws a surface, or wireframe, or both on an unstructured triangle mesh
+    ///
+    /// The surface and wireframe options apply, except the row and column strides.
+    ///
+    /// # Input
+    ///
+    /// * `x` -- x-coordinates of the nodes
+    /// * `y` -- y-coordinates of the nodes
+    /// * `z` -- z-coordinates (or values) at the nodes
+    /// * `triangles` -- the (0-based) indices of the three nodes of each triangle
+    ///
+    /// # Notes
+    ///
+    /// * The type `U` of the input arrays must be a number.
+    pub fn draw_triangles<'a, T, U>(
+        &mut self,
+        x: &'a T,
+        y: &'a T,
+        z: &'a T,
+        triangles: &[[usize; 3]],
+    ) -> Result<(), Error>
+    where
+        T: AsVector<'a, U>,
+        U: 'a + AsPrimitive<f64>,
+    {
+        let (x, y, z) = (vector_to_f64(x), vector_to_f64(y), vector_to_f64(z));
+        let triangles = triangles_to_vec(&x, &y, &z, triangles)?;
+        let style = self.style.clone();
+        self.push(Primitive::TriSurface {
+            x,
+            y,
+            z,
+            triangles,
+            style,
+        });
+        Ok(())
     }
 
     /// Sets the row stride
     pub fn set_row_stride(&mut self, value: usize) -> &mut Self {
-        self.row_stride = value;
+        self.style.row_stride = value;
         self
     }
 
     /// Sets the column stride
     pub fn set_col_stride(&mut self, value: usize) -> &mut Self {
-        self.col_stride = value;
+        self.style.col_stride = value;
         self
     }
 
     /// Sets option to generate surface
     pub fn set_with_surface(&mut self, flag: bool) -> &mut Self {
-        self.with_surface = flag;
+        self.style.with_surface = flag;
         self
     }
 
     /// Sets option to generate wireframe
     pub fn set_with_wireframe(&mut self, flag: bool) -> &mut Self {
-        self.with_wireframe = flag;
+        self.style.with_wireframe = flag;
         self
     }
 
@@ -165,8 +188,8 @@ impl Surface {
     /// * 6 -- Greys
     /// * `>`6 -- starts over from 0
     pub fn set_colormap_index(&mut self, index: usize) -> &mut Self {
-        self.colormap_index = index;
-        self.colormap_name = String::new();
+        self.style.colormap_index = index;
+        self.style.colormap_name = String::new();
         self
     }
 
@@ -183,44 +206,44 @@ impl Surface {
     /// * `Greys`
     /// * see more here <https://matplotlib.org/stable/tutorials/colors/colormaps.html>
     pub fn set_colormap_name(&mut self, name: &str) -> &mut Self {
-        self.colormap_name = String::from(name);
+        self.style.colormap_name = String::from(name);
         self
     }
 
     /// Sets option to use a colormap
     pub fn set_with_colormap(&mut self, flag: bool) -> &mut Self {
-        self.with_colormap = flag;
+        self.style.with_colormap = flag;
         self
     }
 
     /// Sets option to draw a colorbar
     pub fn set_with_colorbar(&mut self, flag: bool) -> &mut Self {
-        self.with_colorbar = flag;
+        self.style.with_colorbar = flag;
         self
     }
 
     /// Sets the colorbar label
     pub fn set_colorbar_label(&mut self, label: &str) -> &mut Self {
-        self.colorbar_label = String::from(label);
+        self.style.colorbar_label = String::from(label);
         self
     }
 
     /// Sets the number format for the labels in the colorbar (cb)
     pub fn set_number_format_cb(&mut self, format: &str) -> &mut Self {
-        self.number_format_cb = String::from(format);
+        self.style.number_format_cb = String::from(format);
         self
     }
 
     /// Sets a solid color for the surface (disables colormap)
     pub fn set_solid_color(&mut self, color: &str) -> &mut Self {
-        self.solid_color = String::from(color);
-        self.with_colormap = false;
+        self.style.solid_color = String::from(color);
+        self.style.with_colormap = false;
         self
     }
 
     /// Sets the color of wireframe lines
     pub fn set_line_color(&mut self, color: &str) -> &mut Self {
-        self.line_color = String::from(color);
+        self.style.line_color = String::from(color);
         self
     }
 
@@ -230,18 +253,88 @@ impl Surface {
     ///
     /// * "`-`", "`:`", "`--`", "`-.`"
     pub fn set_line_style(&mut self, style: &str) -> &mut Self {
-        self.line_style = String::from(style);
+        self.style.line_style = String::from(style);
         self
     }
 
     /// Sets the width of wireframe line
     pub fn set_line_width(&mut self, width: f64) -> &mut Self {
-        self.line_width = width;
+        self.style.line_width = width;
         self
     }
 
+    /// Creates a triad aligned to an axis passing through a and b
+    pub(super) fn aligned_system(a: &[f64], b: &[f64]) -> Result<(Vec<f64>, Vec<f64>, Vec<f64>), Error> {
+        // vector aligned with the axis
+        let n = vec![b[0] - a[0], b[1] - a[1], b[2] - a[2]];
+        let n_dot_n = n[0] * n[0] + n[1] * n[1] + n[2] * n[2];
+        if n_dot_n <= f64::EPSILON {
+            return Err(Error::InvalidInput {
+                argument: "b",
+                message: "a-to-b segment is too short",
+            });
+        }
+
+        // arbitrary vector not parallel to n
+        let x = if f64::abs(n[1]) <= f64::EPSILON && f64::abs(n[2]) <= f64::EPSILON {
+            vec![n[0], n[1] + 1.0, n[2]] // parallel to x => distort along y
+        } else {
+            vec![n[0] + 1.0, n[1], n[2]] // distort along x
+        };
+
+        // orthogonal projection of x onto the axis
+        // q = x - p = x - n * (x⋅n)/(n⋅n)
+        let x_dot_n = x[0] * n[0] + x[1] * n[1] + x[2] * n[2];
+        let q = vec![
+            x[0] - n[0] * x_dot_n / n_dot_n,
+            x[1] - n[1] * x_dot_n / n_dot_n,
+            x[2] - n[2] * x_dot_n / n_dot_n,
+        ];
+
+        // local system aligned with the axis (parallel to n)
+        let norm_n = f64::sqrt(n_dot_n);
+        let norm_q = f64::sqrt(q[0] * q[0] + q[1] * q[1] + q[2] * q[2]);
+        let e0 = vec![n[0] / norm_n, n[1] / norm_n, n[2] / norm_n];
+        let e1 = vec![q[0] / norm_q, q[1] / norm_q, q[2] / norm_q];
+        let e2 = vec![
+            e0[1] * e1[2] - e0[2] * e1[1],
+            e0[2] * e1[0] - e0[0] * e1[2],
+            e0[0] * e1[1] - e0[1] * e1[0],
+        ];
+        Ok((e0, e1, e2))
+    }
+
+    /// Writes the primitive to the buffer and records it
+    fn push(&mut self, primitive: Primitive) {
+        primitive.write_python(&mut self.buffer);
+        self.primitives.push(primitive);
+    }
+}
+
+impl Default for SurfaceStyle {
+    fn default() -> Self {
+        SurfaceStyle {
+            row_stride: 0,
+            col_stride: 0,
+            with_surface: true,
+            with_wireframe: false,
+            colormap_index: 0,
+            colormap_name: String::new(),
+            with_colormap: true,
+            with_colorbar: false,
+            colorbar_label: String::new(),
+            number_format_cb: String::new(),
+            solid_color: String::new(),
+            line_color: "black".to_string(),
+            line_style: String::new(),
+            line_width: 0.0,
+        }
+    }
+}
+
+impl SurfaceStyle {
     /// Returns options for surface
-    fn options_surface(&self) -> String {
+    pub(crate) fn options_surface(&self) -> String {
         let mut opt = String::new();
         if self.row_stride > 0 {
             write!(&mut opt, ",rstride={}", self.row_stride).unwrap();
@@ -263,7 +356,7 @@ impl Surface {
     }
 
     /// Returns options for wireframe
-    fn options_wireframe(&self) -> String {
+    pub(crate) fn options_wireframe(&self) -> String {
         let mut opt = String::new();
         if self.row_stride > 0 {
             write!(&mut opt, ",rstride={}", self.row_stride).unwrap();
@@ -283,51 +376,104 @@ impl Surface {
         opt
     }
 
+    /// Returns options for surface on a triangle mesh
+    pub(crate) fn options_tri_surface(&self) -> String {
+        let mut opt = String::new();
+        if self.solid_color != "" {
+            write!(&mut opt, ",color='{}'", self.solid_color).unwrap();
+        }
+        if self.with_colormap {
+            if self.colormap_name != "" {
+                write!(&mut opt, ",cmap=plt.get_cmap('{}')", self.colormap_name).unwrap();
+            } else {
+                write!(&mut opt, ",cmap=get_colormap({})", self.colormap_index).unwrap();
+            }
+        }
+        opt
+    }
+
+    /// Returns options for wireframe on a triangle mesh
+    pub(crate) fn options_tri_wireframe(&self) -> String {
+        let mut opt = String::from(",color=(0,0,0,0)");
+        if self.line_color != "" {
+            write!(&mut opt, ",edgecolor='{}'", self.line_color).unwrap();
+        }
+        if self.line_style != "" {
+            write!(&mut opt, ",linestyle='{}'", self.line_style).unwrap();
+        }
+        if self.line_width > 0.0 {
+            write!(&mut opt, ",linewidth={}", self.line_width).unwrap();
+        }
+        opt
+    }
+
     /// Returns options for colorbar
-    fn options_colorbar(&self) -> String {
+    pub(crate) fn options_colorbar(&self) -> String {
         let mut opt = String::new();
         if self.number_format_cb != "" {
             write!(&mut opt, ",format='{}'", self.number_format_cb).unwrap();
         }
         opt
     }
+}
 
-    /// Creates a triad aligned to an axis passing through a and b
-    pub(super) fn aligned_system(a: &[f64], b: &[f64]) -> Result<(Vec<f64>, Vec<f64>, Vec<f64>), StrError> {
-        // vector aligned with the axis
-        let n = vec![b[0] - a[0], b[1] - a[1], b[2] - a[2]];
-        let n_dot_n = n[0] * n[0] + n[1] * n[1] + n[2] * n[2];
-        if n_dot_n <= f64::EPSILON {
-            return Err("a-to-b segment is too short");
+/// Writes the Python commands of a surface (see [Primitive::Surface])
+pub(crate) fn write_surface(
+    buf: &mut Script,
+    x: &Vec<Vec<f64>>,
+    y: &Vec<Vec<f64>>,
+    z: &Vec<Vec<f64>>,
+    style: &SurfaceStyle,
+) {
+    matrix_to_array(buf, "x", x);
+    matrix_to_array(buf, "y", y);
+    matrix_to_array(buf, "z", z);
+    write!(buf, "maybe_create_ax3d()\n").unwrap();
+    if style.with_surface {
+        let opt_surface = style.options_surface();
+        write!(buf, "sf=AX3D.plot_surface(x,y,z{})\n", &opt_surface).unwrap();
+    }
+    if style.with_wireframe {
+        let opt_wireframe = style.options_wireframe();
+        write!(buf, "AX3D.plot_wireframe(x,y,z{})\n", &opt_wireframe).unwrap();
+    }
+    if style.with_colorbar {
+        let opt_colorbar = style.options_colorbar();
+        write!(buf, "cb=plt.colorbar(sf{})\n", &opt_colorbar).unwrap();
+        if style.colorbar_label != "" {
+            write!(buf, "cb.ax.set_ylabel(r'{}')\n", style.colorbar_label).unwrap();
         }
+    }
+}
 
-        // arbitrary vector not parallel to n
-        let x = if f64::abs(n[1]) <= f64::EPSILON && f64::abs(n[2]) <= f64::EPSILON {
-            vec![n[0], n[1] + 1.0, n[2]] // parallel to x => distort along y
-        } else {
-            vec![n[0] + 1.0, n[1], n[2]] // distort along x
-        };
-
-        // orthogonal projection of x onto the axis
-        // q = x - p = x - n * (x⋅n)/(n⋅n)
-        let x_dot_n = x[0] * n[0] + x[1] * n[1] + x[2] * n[2];
-        let q = vec![
-            x[0] - n[0] * x_dot_n / n_dot_n,
-            x[1] - n[1] * x_dot_n / n_dot_n,
-            x[2] - n[2] * x_dot_n / n_dot_n,
-        ];
-
-        // local system aligned with the axis (parallel to n)
-        let norm_n = f64::sqrt(n_dot_n);
-        let norm_q = f64::sqrt(q[0] * q[0] + q[1] * q[1] + q[2] * q[2]);
-        let e0 = vec![n[0] / norm_n, n[1] / norm_n, n[2] / norm_n];
-        let e1 = vec![q[0] / norm_q, q[1] / norm_q, q[2] / norm_q];
-        let e2 = vec![
-            e0[1] * e1[2] - e0[2] * e1[1],
-            e0[2] * e1[0] - e0[0] * e1[2],
-            e0[0] * e1[1] - e0[1] * e1[0],
-        ];
-        Ok((e0, e1, e2))
+/// Writes the Python commands of a surface on a triangle mesh (see [Primitive::TriSurface])
+pub(crate) fn write_tri_surface(
+    buf: &mut Script,
+    x: &[f64],
+    y: &[f64],
+    z: &[f64],
+    triangles: &Vec<Vec<usize>>,
+    style: &SurfaceStyle,
+) {
+    vector_to_array(buf, "x", &x);
+    vector_to_array(buf, "y", &y);
+    vector_to_array(buf, "z", &z);
+    matrix_to_list(buf, "triangles", triangles);
+    write!(buf, "maybe_create_ax3d()\n").unwrap();
+    if style.with_surface {
+        let opt_surface = style.options_tri_surface();
+        write!(buf, "sf=AX3D.plot_trisurf(x,y,triangles,z{})\n", &opt_surface).unwrap();
+    }
+    if style.with_wireframe {
+        let opt_wireframe = style.options_tri_wireframe();
+        write!(buf, "AX3D.plot_trisurf(x,y,triangles,z{})\n", &opt_wireframe).unwrap();
+    }
+    if style.with_colorbar && style.with_surface {
+        let opt_colorbar = style.options_colorbar();
+        write!(buf, "cb=plt.colorbar(sf{})\n", &opt_colorbar).unwrap();
+        if style.colorbar_label != "" {
+            write!(buf, "cb.ax.set_ylabel(r'{}')\n", style.colorbar_label).unwrap();
+        }
     }
 }
 
@@ -337,6 +483,10 @@ impl GraphMaker for Surface {
     }
     fn clear_buffer(&mut self) {
         self.buffer.clear();
+        self.primitives.clear();
+    }
+    fn get_primitives(&self) -> &[Primitive] {
+        &self.primitives
     }
 }
 
@@ -345,54 +495,55 @@ impl GraphMaker for Surface {
 #[cfg(test)]
 mod tests {
     use super::Surface;
-    use crate::GraphMaker;
+    use crate::{Error, GraphMaker};
     use russell_chk::vec_approx_eq;
     use russell_lab::Matrix;
 
     #[test]
     fn new_works() {
         let surface = Surface::new();
-        assert_eq!(surface.row_stride, 0);
-        assert_eq!(surface.col_stride, 0);
-        assert_eq!(surface.with_surface, true);
-        assert_eq!(surface.with_wireframe, false);
-        assert_eq!(surface.colormap_index, 0);
-        assert_eq!(surface.colormap_name.len(), 0);
-        assert_eq!(surface.with_colorbar, false);
-        assert_eq!(surface.colorbar_label.len(), 0);
-        assert_eq!(surface.number_format_cb.len(), 0);
-        assert_eq!(surface.line_color, "black".to_string());
-        assert_eq!(surface.line_style.len(), 0);
-        assert_eq!(surface.line_width, 0.0);
+        assert_eq!(surface.style.row_stride, 0);
+        assert_eq!(surface.style.col_stride, 0);
+        assert_eq!(surface.style.with_surface, true);
+        assert_eq!(surface.style.with_wireframe, false);
+        assert_eq!(surface.style.colormap_index, 0);
+        assert_eq!(surface.style.colormap_name.len(), 0);
+        assert_eq!(surface.style.with_colorbar, false);
+        assert_eq!(surface.style.colorbar_label.len(), 0);
+        assert_eq!(surface.style.number_format_cb.len(), 0);
+        assert_eq!(surface.style.line_color, "black".to_string());
+        assert_eq!(surface.style.line_style.len(), 0);
+        assert_eq!(surface.style.line_width, 0.0);
         assert_eq!(surface.buffer.len(), 0);
+        assert_eq!(surface.primitives.len(), 0);
     }
 
     #[test]
     fn options_surface_works() {
         let mut surface = Surface::new();
         surface.set_row_stride(3).set_col_stride(4);
-        let opt = surface.options_surface();
+        let opt = surface.style.options_surface();
         assert_eq!(opt, ",rstride=3,cstride=4,cmap=get_colormap(0)");
 
         surface.set_colormap_name("Pastel1");
-        let opt = surface.options_surface();
+        let opt = surface.style.options_surface();
         assert_eq!(opt, ",rstride=3,cstride=4,cmap=plt.get_cmap('Pastel1')");
 
         surface.set_colormap_index(3);
-        let opt = surface.options_surface();
+        let opt = surface.style.options_surface();
         assert_eq!(opt, ",rstride=3,cstride=4,cmap=get_colormap(3)");
 
         surface.set_colormap_name("turbo");
-        let opt = surface.options_surface();
+        let opt = surface.style.options_surface();
         assert_eq!(opt, ",rstride=3,cstride=4,cmap=plt.get_cmap('turbo')");
 
         surface.set_with_colormap(false);
-        let opt = surface.options_surface();
+        let opt = surface.style.options_surface();
         assert_eq!(opt, ",rstride=3,cstride=4");
 
         surface.set_with_colormap(true).set_solid_color("blue");
-        let opt = surface.options_surface();
-        assert_eq!(surface.with_colormap, false);
+        let opt = surface.style.options_surface();
+        assert_eq!(surface.style.with_colormap, false);
         assert_eq!(opt, ",rstride=3,cstride=4,color='blue'");
     }
 
@@ -405,7 +556,7 @@ mod tests {
             .set_line_color("red")
             .set_line_style("--")
             .set_line_width(2.5);
-        let opt = surface.options_wireframe();
+        let opt = surface.style.options_wireframe();
         assert_eq!(opt, ",rstride=3,cstride=4,color='red',linestyle='--',linewidth=2.5");
     }
 
@@ -413,7 +564,7 @@ mod tests {
     fn options_colorbar_works() {
         let mut surface = Surface::new();
         surface.set_number_format_cb("%.3f");
-        let opt = surface.options_colorbar();
+        let opt = surface.style.options_colorbar();
         assert_eq!(opt, ",format='%.3f'");
     }
 
@@ -437,8 +588,10 @@ mod tests {
                        cb=plt.colorbar(sf)\n\
                        cb.ax.set_ylabel(r'temperature')\n";
         assert_eq!(surface.buffer, b);
+        assert_eq!(surface.get_primitives().len(), 1);
         surface.clear_buffer();
         assert_eq!(surface.buffer, "");
+        assert_eq!(surface.primitives.len(), 0);
     }
 
     #[test]
@@ -456,10 +609,64 @@ mod tests {
         assert_eq!(surface.buffer, b);
     }
 
+    #[test]
+    fn options_tri_surface_works() {
+        let mut surface = Surface::new();
+        surface.set_colormap_name("Pastel1");
+        assert_eq!(surface.style.options_tri_surface(), ",cmap=plt.get_cmap('Pastel1')");
+        surface
+            .set_solid_color("blue")
+            .set_line_color("red")
+            .set_line_style("--")
+            .set_line_width(2.0);
+        assert_eq!(surface.style.options_tri_surface(), ",color='blue'");
+        assert_eq!(
+            surface.style.options_tri_wireframe(),
+            ",color=(0,0,0,0),edgecolor='red',linestyle='--',linewidth=2"
+        );
+    }
+
+    #[test]
+    fn draw_triangles_works() {
+        let mut surface = Surface::new();
+        surface
+            .set_with_wireframe(true)
+            .set_with_colorbar(true)
+            .set_colorbar_label("temperature");
+        let x = [0.0, 1.0, 1.0, 0.0];
+        let y = [0.0, 0.0, 1.0, 1.0];
+        let z = [0.0, 1.0, 2.0, 1.0];
+        surface.draw_triangles(&x, &y, &z, &[[0, 1, 2], [0, 2, 3]]).unwrap();
+        let b: &str = "x=np.array([0,1,1,0,],dtype=float)\n\
+                       y=np.array([0,0,1,1,],dtype=float)\n\
+                       z=np.array([0,1,2,1,],dtype=float)\n\
+                       triangles=[[0,1,2,],[0,2,3,],]\n\
+                       maybe_create_ax3d()\n\
+                       sf=AX3D.plot_trisurf(x,y,triangles,z,cmap=get_colormap(0))\n\
+                       AX3D.plot_trisurf(x,y,triangles,z,color=(0,0,0,0),edgecolor='black')\n\
+                       cb=plt.colorbar(sf)\n\
+                       cb.ax.set_ylabel(r'temperature')\n";
+        assert_eq!(surface.buffer, b);
+        assert_eq!(surface.get_primitives().len(), 1);
+        assert_eq!(
+            surface.draw_triangles(&x, &y, &z, &[[0, 1, 4]]).err(),
+            Some(Error::InvalidInput {
+                argument: "triangles",
+                message: "the indices of nodes must be smaller than x.len()",
+            })
+        );
+    }
+
     #[test]
     fn aligned_system_fails_on_wrong_input() {
         let res = Surface::aligned_system(&[0.0, 0.0, 0.0], &[0.0, 0.0, 0.0]);
-        assert_eq!(res.err(), Some("a-to-b segment is too short"));
+        assert_eq!(
+            res.err(),
+            Some(Error::InvalidInput {
+                argument: "b",
+                message: "a-to-b segment is too short"
+            })
+        );
     }
 
     #[test]
@@ -1,4 +1,4 @@
-use crate::{StrError, Surface};
+use crate::{Error, Surface};
 use russell_lab::math::{suq_cos, suq_sin};
 use russell_lab::{generate3d, Matrix};
 use std::f64::consts::PI;
@@ -17,10 +17,10 @@ impl Surface {
     /// # Example
     ///
     /// ```
-    /// use plotpy::{Plot, StrError, Surface};
+    /// use plotpy::{Error, Plot, Surface};
     /// use std::path::Path;
     ///
-    /// fn main() -> Result<(), StrError> {
+    /// fn main() -> Result<(), Error> {
     ///     // configure and draw surface
     ///     let mut surface = Surface::new();
     ///     let a = &[0.0, 0.0, 0.0];
@@ -50,18 +50,30 @@ impl Surface {
         radius: f64,
         ndiv_axis: usize,
         ndiv_perimeter: usize,
-    ) -> Result<(), StrError> {
+    ) -> Result<(), Error> {
         if a.len() != 3 {
-            return Err("a.len() must equal to 3");
+            return Err(Error::InvalidInput {
+                argument: "a",
+                message: "a.len() must equal to 3",
+            });
         }
         if b.len() != 3 {
-            return Err("b.len() must equal to 3");
+            return Err(Error::InvalidInput {
+                argument: "b",
+                message: "b.len() must equal to 3",
+            });
         }
         if ndiv_axis < 1 {
-            return Err("ndiv_axis must be ≥ 1");
+            return Err(Error::InvalidInput {
+                argument: "ndiv_axis",
+                message: "ndiv_axis must be ≥ 1",
+            });
         }
         if ndiv_perimeter < 3 {
-            return Err("ndiv_perimeter must be ≥ 3");
+            return Err(Error::InvalidInput {
+                argument: "ndiv_perimeter",
+                message: "ndiv_perimeter must be ≥ 3",
+            });
         }
         let (e0, e1, e2) = Surface::aligned_system(a, b)?;
         let cylinder_height =
@@ -109,10 +121,10 @@ impl Surface {
     /// # Example
     ///
     /// ```
-    /// use plotpy::{Plot, StrError, Surface};
+    /// use plotpy::{Error, Plot, Surface};
     /// use std::path::Path;
     ///
-    /// fn main() -> Result<(), StrError> {
+    /// fn main() -> Result<(), Error> {
     ///     // configure and draw surface
     ///     let mut surface = Surface::new();
     ///     let p = &[0.0, 0.0, 0.0];
@@ -146,15 +158,24 @@ impl Surface {
         ymax: f64,
         nx: usize,
         ny: usize,
-    ) -> Result<(Matrix, Matrix, Matrix), StrError> {
+    ) -> Result<(Matrix, Matrix, Matrix), Error> {
         if p.len() != 3 || n.len() != 3 {
-            return Err("p.len() and n.len() must be equal to 3");
+            return Err(Error::InvalidInput {
+                argument: "p, n",
+                message: "p.len() and n.len() must be equal to 3",
+            });
         }
         if f64::abs(n[2]) < 1e-10 {
-            return Err("the z-component of the normal vector cannot be zero");
+            return Err(Error::InvalidInput {
+                argument: "n",
+                message: "the z-component of the normal vector cannot be zero",
+            });
         }
         if nx < 2 || ny < 2 {
-            return Err("nx and ny must be ≥ 2");
+            return Err(Error::InvalidInput {
+                argument: "nx, ny",
+                message: "nx and ny must be ≥ 2",
+            });
         }
         let d = -n[0] * p[0] - n[1] * p[1] - n[2] * p[2];
         let (x, y, z) = generate3d(xmin, xmax, ymin, ymax, nx + 1, ny + 1, |x, y| {
@@ -183,10 +204,10 @@ impl Surface {
     /// # Example
     ///
     /// ```
-    /// use plotpy::{Plot, StrError, Surface};
+    /// use plotpy::{Error, Plot, Surface};
     /// use std::path::Path;
     ///
-    /// fn main() -> Result<(), StrError> {
+    /// fn main() -> Result<(), Error> {
     ///     // draw hat
     ///     let mut hat = Surface::new();
     ///     let c = &[-0.5, 0.0, 0.0];
@@ -224,12 +245,18 @@ impl Surface {
         n_alpha: usize,
         n_theta: usize,
         cup: bool,
-    ) -> Result<(Matrix, Matrix, Matrix), StrError> {
+    ) -> Result<(Matrix, Matrix, Matrix), Error> {
         if c.len() != 3 {
-            return Err("c.len() must be equal to 3");
+            return Err(Error::InvalidInput {
+                argument: "c",
+                message: "c.len() must be equal to 3",
+            });
         }
         if n_alpha < 2 || n_theta < 2 {
-            return Err("n_alpha and n_theta must be ≥ 2");
+            return Err(Error::InvalidInput {
+                argument: "n_alpha, n_theta",
+                message: "n_alpha and n_theta must be ≥ 2",
+            });
         }
         let a_min = alpha_min * PI / 180.0;
         let a_max = alpha_max * PI / 180.0;
@@ -280,10 +307,10 @@ impl Surface {
     /// # Example
     ///
     /// ```
-    /// use plotpy::{Plot, StrError, Surface};
+    /// use plotpy::{Error, Plot, Surface};
     /// use std::path::Path;
     ///
-    /// fn main() -> Result<(), StrError> {
+    /// fn main() -> Result<(), Error> {
     ///     // configure and draw surface
     ///     let c = &[0.0, 0.0, 0.0];
     ///     let r = &[1.0, 1.0, 1.0];
@@ -318,15 +345,24 @@ impl Surface {
         theta_max: f64,
         n_alpha: usize,
         n_theta: usize,
-    ) -> Result<(Matrix, Matrix, Matrix), StrError> {
+    ) -> Result<(Matrix, Matrix, Matrix), Error> {
         if c.len() != 3 || r.len() != 3 || k.len() != 3 {
-            return Err("c.len(), r.len(), and k.len() must be equal to 3");
+            return Err(Error::InvalidInput {
+                argument: "c, r, k",
+                message: "c.len(), r.len(), and k.len() must be equal to 3",
+            });
         }
         if n_alpha < 2 || n_theta < 2 {
-            return Err("n_alpha and n_theta must be ≥ 2");
+            return Err(Error::InvalidInput {
+                argument: "n_alpha, n_theta",
+                message: "n_alpha and n_theta must be ≥ 2",
+            });
         }
         if k[0] < 0.0 || k[1] < 0.0 || k[2] < 0.0 {
-            return Err("exponents k must be greater than zero");
+            return Err(Error::InvalidInput {
+                argument: "k",
+                message: "exponents k must be greater than zero",
+            });
         }
         let (aa, bb, cc) = (2.0 / k[0], 2.0 / k[1], 2.0 / k[2]);
         let a_min = alpha_min * PI / 180.0;
@@ -367,10 +403,10 @@ impl Surface {
     /// # Example
     ///
     /// ```
-    /// use plotpy::{Plot, StrError, Surface};
+    /// use plotpy::{Error, Plot, Surface};
     /// use std::path::Path;
     ///
-    /// fn main() -> Result<(), StrError> {
+    /// fn main() -> Result<(), Error> {
     ///     // configure and draw surface
     ///     let mut surface = Surface::new();
     ///     let c = &[0.0, 0.0, 0.0];
@@ -398,12 +434,18 @@ impl Surface {
         r: f64,
         n_alpha: usize,
         n_theta: usize,
-    ) -> Result<(Matrix, Matrix, Matrix), StrError> {
+    ) -> Result<(Matrix, Matrix, Matrix), Error> {
         if c.len() != 3 {
-            return Err("c.len() must be equal to 3");
+            return Err(Error::InvalidInput {
+                argument: "c",
+                message: "c.len() must be equal to 3",
+            });
         }
         if n_alpha < 2 || n_theta < 2 {
-            return Err("n_alpha and n_theta must be ≥ 2");
+            return Err(Error::InvalidInput {
+                argument: "n_alpha, n_theta",
+                message: "n_alpha and n_theta must be ≥ 2",
+            });
         }
         let (alpha_min, alpha_max) = (-180.0, 180.0);
         let (theta_min, theta_max) = (-90.0, 90.0);
@@ -426,25 +468,55 @@ impl Surface {
 #[cfg(test)]
 mod tests {
     use super::Surface;
-    use crate::GraphMaker;
+    use crate::{Error, GraphMaker};
 
     #[test]
     fn draw_cylinder_fails_on_wrong_input() {
         let mut surf = Surface::new();
         let res = surf.draw_cylinder(&[0.0, 0.0], &[1.0, 1.0, 1.0], 1.0, 1, 3);
-        assert_eq!(res.err(), Some("a.len() must equal to 3"));
+        assert_eq!(
+            res.err(),
+            Some(Error::InvalidInput {
+                argument: "a",
+                message: "a.len() must equal to 3"
+            })
+        );
 
         let res = surf.draw_cylinder(&[0.0, 0.0, 0.0], &[1.0, 1.0], 1.0, 1, 3);
-        assert_eq!(res.err(), Some("b.len() must equal to 3"));
+        assert_eq!(
+            res.err(),
+            Some(Error::InvalidInput {
+                argument: "b",
+                message: "b.len() must equal to 3"
+            })
+        );
 
         let res = surf.draw_cylinder(&[0.0, 0.0, 0.0], &[1.0, 1.0, 1.0], 1.0, 0, 3);
-        assert_eq!(res.err(), Some("ndiv_axis must be ≥ 1"));
+        assert_eq!(
+            res.err(),
+            Some(Error::InvalidInput {
+                argument: "ndiv_axis",
+                message: "ndiv_axis must be ≥ 1"
+            })
+        );
 
         let res = surf.draw_cylinder(&[0.0, 0.0, 0.0], &[1.0, 1.0, 1.0], 1.0, 1, 2);
-        assert_eq!(res.err(), Some("ndiv_perimeter must be ≥ 3"));
+        assert_eq!(
+            res.err(),
+            Some(Error::InvalidInput {
+                argument: "ndiv_perimeter",
+                message: "ndiv_perimeter must be ≥ 3"
+            })
+        );
 
         let res = surf.draw_cylinder(&[0.0, 0.0, 0.0], &[0.0, 0.0, 0.0], 1.0, 1, 3);
-        assert_eq!(res.err(), Some("a-to-b segment is too short"));
+        assert_eq!(
+            res.err(),
+            Some(Error::InvalidInput {
+                argument: "b",
+                message: "a-to-b segment is too short"
+            })
+        );
     }
 
     #[test]
@@ -459,17 +531,47 @@ mod tests {
     fn draw_plane_nzz_fails_on_wrong_input() {
         let mut surf = Surface::new();
         let res = surf.draw_plane_nzz(&[0.0, 0.0], &[1.0, 1.0], 0.0, 1.0, 0.0, 1.0, 2, 2);
-        assert_eq!(res.err(), Some("p.len() and n.len() must be equal to 3"));
+        assert_eq!(
+            res.err(),
+            Some(Error::InvalidInput {
+                argument: "p, n",
+                message: "p.len() and n.len() must be equal to 3"
+            })
+        );
         let res = surf.draw_plane_nzz(&[0.0, 0.0, 0.0], &[1.0, 1.0], 0.0, 1.0, 0.0, 1.0, 2, 2);
-        assert_eq!(res.err(), Some("p.len() and n.len() must be equal to 3"));
+        assert_eq!(
+            res.err(),
+            Some(Error::InvalidInput {
+                argument: "p, n",
+                message: "p.len() and n.len() must be equal to 3"
+            })
+        );
 
         let res = surf.draw_plane_nzz(&[0.0, 0.0, 0.0], &[1.0, 1.0, 0.0], 0.0, 1.0, 0.0, 1.0, 2, 2);
-        assert_eq!(res.err(), Some("the z-component of the normal vector cannot be zero"));
+        assert_eq!(
+            res.err(),
+            Some(Error::InvalidInput {
+                argument: "n",
+                message: "the z-component of the normal vector cannot be zero"
+            })
+        );
 
         let res = surf.draw_plane_nzz(&[0.0, 0.0, 0.0], &[1.0, 1.0, 1.0], 0.0, 1.0, 0.0, 1.0, 1, 2);
-        assert_eq!(res.err(), Some("nx and ny must be ≥ 2"));
+        assert_eq!(
+            res.err(),
+            Some(Error::InvalidInput {
+                argument: "nx, ny",
+                message: "nx and ny must be ≥ 2"
+            })
+        );
         let res = surf.draw_plane_nzz(&[0.0, 0.0, 0.0], &[1.0, 1.0, 1.0], 0.0, 1.0, 0.0, 1.0, 2, 1);
-        assert_eq!(res.err(), Some("nx and ny must be ≥ 2"));
+        assert_eq!(
+            res.err(),
+            Some(Error::InvalidInput {
+                argument: "nx, ny",
+                message: "nx and ny must be ≥ 2"
+            })
+        );
     }
 
     #[test]
@@ -484,12 +586,30 @@ mod tests {
     fn draw_hemisphere_fails_on_wrong_input() {
         let mut surf = Surface::new();
         let res = surf.draw_hemisphere(&[0.0, 0.0], 1.0, 0.0, 180.0, 2, 2, false);
-        assert_eq!(res.err(), Some("c.len() must be equal to 3"));
+        assert_eq!(
+            res.err(),
+            Some(Error::InvalidInput {
+                argument: "c",
+                message: "c.len() must be equal to 3"
+            })
+        );
 
         let res = surf.draw_hemisphere(&[0.0, 0.0, 0.0], 1.0, 0.0, 180.0, 1, 2, false);
-        assert_eq!(res.err(), Some("n_alpha and n_theta must be ≥ 2"));
+        assert_eq!(
+            res.err(),
+            Some(Error::InvalidInput {
+                argument: "n_alpha, n_theta",
+                message: "n_alpha and n_theta must be ≥ 2"
+            })
+        );
         let res = surf.draw_hemisphere(&[0.0, 0.0, 0.0], 1.0, 0.0, 180.0, 2, 1, false);
-        assert_eq!(res.err(), Some("n_alpha and n_theta must be ≥ 2"));
+        assert_eq!(
+            res.err(),
+            Some(Error::InvalidInput {
+                argument: "n_alpha, n_theta",
+                message: "n_alpha and n_theta must be ≥ 2"
+            })
+        );
     }
 
     #[test]
@@ -510,24 +630,66 @@ mod tests {
 
         let mut surf = Surface::new();
         let res = surf.draw_superquadric(d2, d3, d3, 0.0, 180.0, 0.0, 180.0, 2, 2);
-        assert_eq!(res.err(), Some("c.len(), r.len(), and k.len() must be equal to 3"));
+        assert_eq!(
+            res.err(),
+            Some(Error::InvalidInput {
+                argument: "c, r, k",
+                message: "c.len(), r.len(), and k.len() must be equal to 3"
+            })
+        );
         let res = surf.draw_superquadric(d3, d2, d3, 0.0, 180.0, 0.0, 180.0, 2, 2);
-        assert_eq!(res.err(), Some("c.len(), r.len(), and k.len() must be equal to 3"));
+        assert_eq!(
+            res.err(),
+            Some(Error::InvalidInput {
+                argument: "c, r, k",
+                message: "c.len(), r.len(), and k.len() must be equal to 3"
+            })
+        );
         let res = surf.draw_superquadric(d3, d3, d2, 0.0, 180.0, 0.0, 180.0, 2, 2);
-        assert_eq!(res.err(), Some("c.len(), r.len(), and k.len() must be equal to 3"));
+        assert_eq!(
+            res.err(),
+            Some(Error::InvalidInput {
+                argument: "c, r, k",
+                message: "c.len(), r.len(), and k.len() must be equal to 3"
+            })
+        );
 
         let res = surf.draw_superquadric(d3, d3, d3, 0.0, 180.0, 0.0, 180.0, 1, 2);
-        assert_eq!(res.err(), Some("n_alpha and n_theta must be ≥ 2"));
+        assert_eq!(
+            res.err(),
+            Some(Error::InvalidInput {
+                argument: "n_alpha, n_theta",
+                message: "n_alpha and n_theta must be ≥ 2"
+            })
+        );
 
         let ka = &[-1.0, 0.0, 0.0];
         let kb = &[0.0, -1.0, 0.0];
         let kc = &[0.0, 0.0, -1.0];
         let res = surf.draw_superquadric(d3, d3, ka, 0.0, 180.0, 0.0, 180.0, 2, 2);
-        assert_eq!(res.err(), Some("exponents k must be greater than zero"));
+        assert_eq!(
+            res.err(),
+            Some(Error::InvalidInput {
+                argument: "k",
+                message: "exponents k must be greater than zero"
+            })
+        );
         let res = surf.draw_superquadric(d3, d3, kb, 0.0, 180.0, 0.0, 180.0, 2, 2);
-        assert_eq!(res.err(), Some("exponents k must be greater than zero"));
+        assert_eq!(
+            res.err(),
+            Some(Error::InvalidInput {
+                argument: "k",
+                message: "exponents k must be greater than zero"
+            })
+        );
         let res = surf.draw_superquadric(d3, d3, kc, 0.0, 180.0, 0.0, 180.0, 2, 2);
-        assert_eq!(res.err(), Some("exponents k must be greater than zero"));
+        assert_eq!(
+            res.err(),
+            Some(Error::InvalidInput {
+                argument: "k",
+                message: "exponents k must be greater than zero"
+            })
+        );
     }
 
     #[test]
@@ -552,12 +714,30 @@ mod tests {
     fn draw_sphere_fails_on_wrong_input() {
         let mut surf = Surface::new();
         let res = surf.draw_sphere(&[0.0, 0.0], 1.0, 2, 2);
-        assert_eq!(res.err(), Some("c.len() must be equal to 3"));
+        assert_eq!(
+            res.err(),
+            Some(Error::InvalidInput {
+                argument: "c",
+                message: "c.len() must be equal to 3"
+            })
+        );
 
         let res = surf.draw_sphere(&[0.0, 0.0, 0.0], 1.0, 1, 2);
-        assert_eq!(res.err(), Some("n_alpha and n_theta must be ≥ 2"));
+        assert_eq!(
+            res.err(),
+            Some(Error::InvalidInput {
+                argument: "n_alpha, n_theta",
+                message: "n_alpha and n_theta must be ≥ 2"
+            })
+        );
         let res = surf.draw_sphere(&[0.0, 0.0, 0.0], 1.0, 2, 1);
-        assert_eq!(res.err(), Some("n_alpha and n_theta must be ≥ 2"));
+        assert_eq!(
+            res.err(),
+            Some(Error::InvalidInput {
+                argument: "n_alpha, n_theta",
+                message: "n_alpha and n_theta must be ≥ 2"
+            })
+        );
     }
 
     #[test]
@@ -0,0 +1,1334 @@
+use super::{
+    grid_items, grid_limits, CanvasStyle, CurveStyle, GridItem, LegendStyle, PolyCode, Primitive, RayEndpoint,
+    TextStyle,
+};
+use std::fmt::Write;
+
+/// Default figure width in points (same as Matplotlib: 6.4 inches)
+const DEFAULT_WIDTH: f64 = 460.8;
+
+/// Default figure height in points (same as Matplotlib: 4.8 inches)
+const DEFAULT_HEIGHT: f64 = 345.6;
+
+/// Default font size in points
+const DEFAULT_FONTSIZE: f64 = 10.0;
+
+/// Default colors of curves (same as Matplotlib's "tab10" cycle)
+const COLOR_CYCLE: [&str; 10] = [
+    "#1f77b4", "#ff7f0e", "#2ca02c", "#d62728", "#9467bd", "#8c564b", "#e377c2", "#7f7f7f", "#bcbd22", "#17becf",
+];
+
+/// Length of tick marks in points
+const TICK_LEN: f64 = 3.5;
+
+/// Holds the state of the axes collected from the primitives
+struct Axes<'a> {
+    width: f64,                      // figure width in points
+    height: f64,                     // figure height in points
+    title: &'a str,                  // title
+    label_x: &'a str,                // label of x-axis
+    label_y: &'a str,                // label of y-axis
+    grid: bool,                      // draw grid lines
+    equal: bool,                     // equal scale
+    hide: bool,                      // hide frame, ticks, and labels
+    num_ticks: [Option<usize>; 2],   // maximum number of intervals (x,y)
+    range: [Option<f64>; 4],         // fixed limits
+    legend: Option<&'a LegendStyle>, // legend configuration
+    items: Vec<&'a Primitive>,       // drawable primitives
+}
+
+/// Maps data coordinates to SVG coordinates
+struct Transform {
+    limits: [f64; 4], // xmin, xmax, ymin, ymax
+    left: f64,        // left side of axes in points
+    right: f64,       // right side of axes in points
+    top: f64,         // top side of axes in points
+    bottom: f64,      // bottom side of axes in points
+}
+
+impl Transform {
+    fn x(&self, x: f64) -> f64 {
+        self.left + (x - self.limits[0]) / (self.limits[1] - self.limits[0]) * (self.right - self.left)
+    }
+    fn y(&self, y: f64) -> f64 {
+        self.bottom - (y - self.limits[2]) / (self.limits[3] - self.limits[2]) * (self.bottom - self.top)
+    }
+    fn sx(&self) -> f64 {
+        (self.right - self.left) / (self.limits[1] - self.limits[0])
+    }
+    fn sy(&self) -> f64 {
+        (self.bottom - self.top) / (self.limits[3] - self.limits[2])
+    }
+}
+
+/// Renders the primitives as an SVG document
+pub(crate) fn render_svg(scene: &[Primitive]) -> String {
+    let scene = expand(scene);
+    let axes = collect(&scene);
+    let tr = transform(&axes);
+    let mut buf = String::new();
+
+    // header
+    write!(
+        &mut buf,
+        "<?xml version=\"1.0\" encoding=\"utf-8\" standalone=\"no\"?>\n\
+         <svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{}pt\" height=\"{}pt\" viewBox=\"0 0 {} {}\" version=\"1.1\">\n\
+         <defs>\n\
+         <clipPath id=\"clip-axes\"><rect x=\"{}\" y=\"{}\" width=\"{}\" height=\"{}\"/></clipPath>\n\
+         </defs>\n\
+         <rect width=\"100%\" height=\"100%\" fill=\"#ffffff\"/>\n",
+        num(axes.width),
+        num(axes.height),
+        num(axes.width),
+        num(axes.height),
+        num(tr.left),
+        num(tr.top),
+        num(tr.right - tr.left),
+        num(tr.bottom - tr.top)
+    )
+    .unwrap();
+
+    // ticks and grid
+    let xticks = ticks(tr.limits[0], tr.limits[1], axes.num_ticks[0], tr.right - tr.left);
+    let yticks = ticks(tr.limits[2], tr.limits[3], axes.num_ticks[1], tr.bottom - tr.top);
+    if axes.grid && !axes.hide {
+        buf.push_str("<g stroke=\"#808080\" stroke-width=\"0.8\" stroke-dasharray=\"2.96,1.28\">\n");
+        for x in &xticks {
+            let sx = num(tr.x(*x));
+            write!(
+                &mut buf,
+                "<path d=\"M{} {}L{} {}\"/>\n",
+                sx,
+                num(tr.top),
+                sx,
+                num(tr.bottom)
+            )
+            .unwrap();
+        }
+        for y in &yticks {
+            let sy = num(tr.y(*y));
+            write!(
+                &mut buf,
+                "<path d=\"M{} {}L{} {}\"/>\n",
+                num(tr.left),
+                sy,
+                num(tr.right),
+                sy
+            )
+            .unwrap();
+        }
+        buf.push_str("</g>\n");
+    }
+
+    // features
+    let mut cycle = 0;
+    for item in &axes.items {
+        match item {
+            Primitive::Curve { x, y, style } => {
+                let color = curve_color(style, &mut cycle);
+                draw_curve(&mut buf, &tr, x, y, style, &color);
+            }
+            Primitive::Ray {
+                xa,
+                ya,
+                endpoint,
+                style,
+            } => {
+                let color = curve_color(style, &mut cycle);
+                let (x, y) = ray(&tr, *xa, *ya, endpoint);
+                let no_markers = CurveStyle {
+                    marker_style: String::new(),
+                    ..style.clone()
+                };
+                draw_curve(&mut buf, &tr, &x, &y, &no_markers, &color);
+            }
+            Primitive::Path {
+                points,
+                codes,
+                closed,
+                style,
+            } => {
+                let d = path_data(&tr, points, codes, *closed);
+                write!(&mut buf, "<path d=\"{}\"{}/>\n", d, patch_attributes(style, true)).unwrap();
+            }
+            Primitive::Circle { xc, yc, r, style } => {
+                write!(
+                    &mut buf,
+                    "<ellipse cx=\"{}\" cy=\"{}\" rx=\"{}\" ry=\"{}\"{}/>\n",
+                    num(tr.x(*xc)),
+                    num(tr.y(*yc)),
+                    num(r * tr.sx()),
+                    num(r * tr.sy()),
+                    patch_attributes(style, true)
+                )
+                .unwrap();
+            }
+            Primitive::Arc {
+                xc,
+                yc,
+                r,
+                ini_angle,
+                fin_angle,
+                style,
+            } => {
+                let d = arc_data(&tr, *xc, *yc, *r, *ini_angle, *fin_angle);
+                write!(&mut buf, "<path d=\"{}\"{}/>\n", d, patch_attributes(style, false)).unwrap();
+            }
+            Primitive::Arrow { xi, yi, xf, yf, style } => draw_arrow(&mut buf, &tr, (*xi, *yi), (*xf, *yf), style),
+            Primitive::Text { x, y, message, style } => {
+                draw_text(&mut buf, tr.x(*x), tr.y(*y), message, style);
+            }
+            _ => (),
+        }
+    }
+
+    // frame, ticks, and labels
+    if !axes.hide {
+        draw_frame(&mut buf, &tr, &axes, &xticks, &yticks);
+    }
+
+    // legend
+    if let Some(legend) = axes.legend {
+        draw_legend(&mut buf, &tr, &axes, legend);
+    }
+
+    // done
+    buf.push_str("</svg>\n");
+    buf
+}
+
+/// Expands the primitives that the renderer does not draw directly (e.g., grids) into simpler ones
+fn expand(scene: &[Primitive]) -> Vec<Primitive> {
+    let mut primitives = Vec::new();
+    for primitive in scene {
+        match primitive {
+            Primitive::CurvePoints { points, style } => primitives.push(Primitive::Curve {
+                x: points.iter().map(|p| p[0]).collect(),
+                y: points.iter().map(|p| p[1]).collect(),
+                style: style.clone(),
+            }),
+            Primitive::CanvasGrid {
+                xmin,
+                xmax,
+                ndiv,
+                with_point_ids,
+                with_cell_ids,
+                style,
+            } if ndiv.len() == 2 => {
+                let mut points = Vec::new();
+                let mut codes = Vec::new();
+                for item in grid_items(xmin, xmax, ndiv, *with_point_ids, *with_cell_ids) {
+                    match item {
+                        GridItem::Line(a, b) => {
+                            points.extend([[a[0], a[1]], [b[0], b[1]]]);
+                            codes.extend([PolyCode::MoveTo, PolyCode::LineTo]);
+                        }
+                        GridItem::EndLines => primitives.push(Primitive::Path {
+                            points: std::mem::take(&mut points),
+                            codes: std::mem::take(&mut codes),
+                            closed: false,
+                            style: style.clone(),
+                        }),
+                        GridItem::Text(a, message, alternative) => primitives.push(Primitive::Text {
+                            x: a[0],
+                            y: a[1],
+                            message,
+                            style: if alternative {
+                                style.alt_text.clone()
+                            } else {
+                                style.text.clone()
+                            },
+                        }),
+                    }
+                }
+                let limits = grid_limits(xmin, xmax);
+                primitives.push(Primitive::Range {
+                    xmin: Some(limits[0].0),
+                    xmax: Some(limits[0].1),
+                    ymin: Some(limits[1].0),
+                    ymax: Some(limits[1].1),
+                });
+            }
+            _ => primitives.push(primitive.clone()),
+        }
+    }
+    primitives
+}
+
+/// Collects the state of the axes from the primitives
+fn collect(scene: &[Primitive]) -> Axes<'_> {
+    let mut axes = Axes {
+        width: DEFAULT_WIDTH,
+        height: DEFAULT_HEIGHT,
+        title: "",
+        label_x: "",
+        label_y: "",
+        grid: false,
+        equal: false,
+        hide: false,
+        num_ticks: [None, None],
+        range: [None; 4],
+        legend: None,
+        items: Vec::new(),
+    };
+    for primitive in scene {
+        match primitive {
+            Primitive::Legend(style) => axes.legend = Some(style),
+            Primitive::Title(title) => axes.title = title,
+            Primitive::LabelX(label) => axes.label_x = label,
+            Primitive::LabelY(label) => axes.label_y = label,
+            Primitive::Grid => axes.grid = true,
+            Primitive::Range { xmin, xmax, ymin, ymax } => {
+                for (i, value) in [xmin, xmax, ymin, ymax].iter().enumerate() {
+                    if value.is_some() {
+                        axes.range[i] = **value;
+                    }
+                }
+            }
+            Primitive::EqualAxes(equal) => axes.equal = *equal,
+            Primitive::HideAxes(hide) => axes.hide = *hide,
+            Primitive::NumTicksX(num) => axes.num_ticks[0] = Some(*num),
+            Primitive::NumTicksY(num) => axes.num_ticks[1] = Some(*num),
+            Primitive::FigureSize(width, height) => {
+                axes.width = 72.0 * width;
+                axes.height = 72.0 * height;
+            }
+            Primitive::ClearAxes | Primitive::ClearFigure => {
+                let (width, height) = (axes.width, axes.height);
+                axes = collect(&[]);
+                axes.width = width;
+                axes.height = height;
+            }
+            _ => axes.items.push(primitive),
+        }
+    }
+    axes
+}
+
+/// Computes the limits of the axes and the transformation from data to SVG coordinates
+fn transform(axes: &Axes) -> Transform {
+    // data limits
+    let mut lim = [f64::INFINITY, f64::NEG_INFINITY, f64::INFINITY, f64::NEG_INFINITY];
+    let mut update = |x: f64, y: f64| {
+        if x.is_finite() && y.is_finite() {
+            lim = [lim[0].min(x), lim[1].max(x), lim[2].min(y), lim[3].max(y)];
+        }
+    };
+    for item in &axes.items {
+        match item {
+            Primitive::Curve { x, y, .. } => x.iter().zip(y).for_each(|(a, b)| update(*a, *b)),
+            Primitive::Path { points, .. } => points.iter().for_each(|[a, b]| update(*a, *b)),
+            Primitive::Circle { xc, yc, r, .. } | Primitive::Arc { xc, yc, r, .. } => {
+                update(xc - r, yc - r);
+                update(xc + r, yc + r);
+            }
+            Primitive::Arrow { xi, yi, xf, yf, .. } => {
+                update(*xi, *yi);
+                update(*xf, *yf);
+            }
+            _ => (),
+        }
+    }
+
+    // margins (5%) or defaults
+    let mut limits = [0.0, 1.0, 0.0, 1.0];
+    for d in 0..2 {
+        let (min, max) = (lim[2 * d], lim[2 * d + 1]);
+        if min <= max {
+            let gap = if max > min {
+                0.05 * (max - min)
+            } else {
+                f64::max(0.05 * min.abs(), 0.05)
+            };
+            limits[2 * d] = min - gap;
+            limits[2 * d + 1] = max + gap;
+        }
+    }
+    for (limit, value) in limits.iter_mut().zip(axes.range) {
+        if let Some(value) = value {
+            *limit = value;
+        }
+    }
+    for d in 0..2 {
+        if limits[2 * d + 1] <= limits[2 * d] {
+            limits[2 * d + 1] = limits[2 * d] + 1.0;
+        }
+    }
+
+    // axes box (same as Matplotlib's default subplot parameters)
+    let mut tr = Transform {
+        limits,
+        left: 0.125 * axes.width,
+        right: 0.9 * axes.width,
+        top: 0.12 * axes.height,
+        bottom: 0.89 * axes.height,
+    };
+
+    // equal scale => expand the limits
+    if axes.equal {
+        let (sx, sy) = (tr.sx(), tr.sy());
+        if sx > sy {
+            let (mid, half) = (0.5 * (limits[0] + limits[1]), 0.5 * (tr.right - tr.left) / sy);
+            tr.limits[0] = mid - half;
+            tr.limits[1] = mid + half;
+        } else {
+            let (mid, half) = (0.5 * (limits[2] + limits[3]), 0.5 * (tr.bottom - tr.top) / sx);
+            tr.limits[2] = mid - half;
+            tr.limits[3] = mid + half;
+        }
+    }
+    tr
+}
+
+/// Computes "nice" tick locations (steps of 1, 2, 2.5, 5 times a power of ten)
+fn ticks(min: f64, max: f64, num: Option<usize>, length: f64) -> Vec<f64> {
+    let nbin = match num {
+        Some(0) => return Vec::new(),
+        Some(n) => n,
+        None => ((length / 40.0) as usize).clamp(2, 9),
+    };
+    let raw = (max - min) / (nbin as f64);
+    if raw.is_nan() || raw <= 0.0 || raw.is_infinite() {
+        return Vec::new();
+    }
+    let magnitude = f64::powf(10.0, raw.log10().floor());
+    let step = [1.0, 2.0, 2.5, 5.0, 10.0]
+        .iter()
+        .map(|m| m * magnitude)
+        .find(|s| *s >= raw * (1.0 - 1e-9))
+        .unwrap_or(10.0 * magnitude);
+    let first = (min / step - 1e-9).ceil() as i64;
+    let last = (max / step + 1e-9).floor() as i64;
+    (first..=last)
+        .map(|i| {
+            let t = (i as f64) * step;
+            if t.abs() < step * 1e-9 {
+                0.0
+            } else {
+                t
+            }
+        })
+        .collect()
+}
+
+/// Formats the tick labels with the same number of decimal digits
+fn tick_labels(ticks: &[f64]) -> Vec<String> {
+    let decimals = (0..=10)
+        .find(|d| {
+            let factor = f64::powi(10.0, *d);
+            ticks.iter().all(|t| ((t * factor).round() - t * factor).abs() < 1e-6)
+        })
+        .unwrap_or(10) as usize;
+    ticks
+        .iter()
+        .map(|t| format!("{:.*}", decimals, t).replace('-', "\u{2212}"))
+        .collect()
+}
+
+/// Draws the frame, ticks, tick labels, axis labels, and title
+fn draw_frame(buf: &mut String, tr: &Transform, axes: &Axes, xticks: &[f64], yticks: &[f64]) {
+    write!(
+        buf,
+        "<rect x=\"{}\" y=\"{}\" width=\"{}\" height=\"{}\" fill=\"none\" stroke=\"#000000\" stroke-width=\"0.8\"/>\n",
+        num(tr.left),
+        num(tr.top),
+        num(tr.right - tr.left),
+        num(tr.bottom - tr.top)
+    )
+    .unwrap();
+    let font = format!(
+        "font-family=\"DejaVu Sans, sans-serif\" font-size=\"{}\" fill=\"#000000\"",
+        DEFAULT_FONTSIZE
+    );
+    buf.push_str("<g stroke=\"#000000\" stroke-width=\"0.8\">\n");
+    for x in xticks {
+        let sx = num(tr.x(*x));
+        write!(
+            buf,
+            "<path d=\"M{} {}L{} {}\"/>\n",
+            sx,
+            num(tr.bottom),
+            sx,
+            num(tr.bottom + TICK_LEN)
+        )
+        .unwrap();
+    }
+    for y in yticks {
+        let sy = num(tr.y(*y));
+        write!(
+            buf,
+            "<path d=\"M{} {}L{} {}\"/>\n",
+            num(tr.left - TICK_LEN),
+            sy,
+            num(tr.left),
+            sy
+        )
+        .unwrap();
+    }
+    buf.push_str("</g>\n");
+    let gap = TICK_LEN + 3.5;
+    for (x, label) in xticks.iter().zip(tick_labels(xticks)) {
+        write!(
+            buf,
+            "<text x=\"{}\" y=\"{}\" text-anchor=\"middle\" dominant-baseline=\"hanging\" {}>{}</text>\n",
+            num(tr.x(*x)),
+            num(tr.bottom + gap),
+            font,
+            escape(&label)
+        )
+        .unwrap();
+    }
+    let ylabels = tick_labels(yticks);
+    for (y, label) in yticks.iter().zip(&ylabels) {
+        write!(
+            buf,
+            "<text x=\"{}\" y=\"{}\" text-anchor=\"end\" dominant-baseline=\"central\" {}>{}</text>\n",
+            num(tr.left - gap),
+            num(tr.y(*y)),
+            font,
+            escape(label)
+        )
+        .unwrap();
+    }
+    if axes.label_x != "" {
+        let y = tr.bottom + gap + if xticks.is_empty() { 0.0 } else { 1.4 * DEFAULT_FONTSIZE };
+        write!(
+            buf,
+            "<text x=\"{}\" y=\"{}\" text-anchor=\"middle\" dominant-baseline=\"hanging\" {}>{}</text>\n",
+            num(0.5 * (tr.left + tr.right)),
+            num(y),
+            font,
+            escape(axes.label_x)
+        )
+        .unwrap();
+    }
+    if axes.label_y != "" {
+        let width = ylabels
+            .iter()
+            .map(|l| text_width(l, DEFAULT_FONTSIZE))
+            .fold(0.0, f64::max);
+        let x = tr.left - gap - width - 4.0 - 0.5 * DEFAULT_FONTSIZE;
+        let y = 0.5 * (tr.top + tr.bottom);
+        write!(
+            buf,
+            "<text x=\"{}\" y=\"{}\" text-anchor=\"middle\" dominant-baseline=\"central\" transform=\"rotate(-90 {} {})\" {}>{}</text>\n",
+            num(x),
+            num(y),
+            num(x),
+            num(y),
+            font,
+            escape(axes.label_y)
+        )
+        .unwrap();
+    }
+    if axes.title != "" {
+        write!(
+            buf,
+            "<text x=\"{}\" y=\"{}\" text-anchor=\"middle\" font-family=\"DejaVu Sans, sans-serif\" font-size=\"12\" fill=\"#000000\">{}</text>\n",
+            num(0.5 * (tr.left + tr.right)),
+            num(tr.top - 6.0),
+            escape(axes.title)
+        )
+        .unwrap();
+    }
+}
+
+/// Returns the color of a curve (the next color of the cycle if not set)
+fn curve_color(style: &CurveStyle, cycle: &mut usize) -> String {
+    if style.line_color != "" {
+        color(&style.line_color)
+    } else if style.marker_void {
+        "red".to_string()
+    } else {
+        let c = COLOR_CYCLE[*cycle % COLOR_CYCLE.len()].to_string();
+        *cycle += 1;
+        c
+    }
+}
+
+/// Draws the lines and markers of a curve
+fn draw_curve(buf: &mut String, tr: &Transform, x: &[f64], y: &[f64], style: &CurveStyle, line_color: &str) {
+    let clip = if style.stop_clip {
+        ""
+    } else {
+        " clip-path=\"url(#clip-axes)\""
+    };
+    let opacity = if style.line_alpha > 0.0 {
+        format!(" opacity=\"{}\"", num(style.line_alpha))
+    } else {
+        String::new()
+    };
+    write!(buf, "<g{}{}>\n", clip, opacity).unwrap();
+
+    // lines
+    let width = if style.line_width > 0.0 { style.line_width } else { 1.5 };
+    if let Some(dashes) = dash_array(&style.line_style, width) {
+        let (xs, ys) = step_vertices(x, y, &style.step);
+        let mut d = String::new();
+        let mut pen_down = false;
+        for (a, b) in xs.iter().zip(&ys) {
+            if a.is_finite() && b.is_finite() {
+                let cmd = if pen_down { "L" } else { "M" };
+                write!(&mut d, "{}{} {}", cmd, num(tr.x(*a)), num(tr.y(*b))).unwrap();
+                pen_down = true;
+            } else {
+                pen_down = false;
+            }
+        }
+        write!(
+            buf,
+            "<path d=\"{}\" fill=\"none\" stroke=\"{}\" stroke-width=\"{}\" stroke-linejoin=\"round\" stroke-linecap=\"square\"{}/>\n",
+            d,
+            line_color,
+            num(width),
+            dashes
+        )
+        .unwrap();
+    }
+
+    // markers
+    if style.marker_style != "" && style.marker_style != "None" && style.marker_style != "none" {
+        let (fill, stroke, stroke_width) = marker_colors(style, line_color);
+        let size = if style.marker_size > 0.0 {
+            style.marker_size
+        } else {
+            6.0
+        };
+        let every = usize::max(style.marker_every, 1);
+        write!(
+            buf,
+            "<g fill=\"{}\" stroke=\"{}\" stroke-width=\"{}\">\n",
+            fill,
+            stroke,
+            num(stroke_width)
+        )
+        .unwrap();
+        for i in (0..usize::min(x.len(), y.len())).step_by(every) {
+            if x[i].is_finite() && y[i].is_finite() {
+                let d = marker_path(&style.marker_style, tr.x(x[i]), tr.y(y[i]), size);
+                write!(buf, "<path d=\"{}\"/>\n", d).unwrap();
+            }
+        }
+        buf.push_str("</g>\n");
+    }
+    buf.push_str("</g>\n");
+}
+
+/// Returns the vertices of the lines drawn with steps (as Matplotlib's `drawstyle='steps-*'`)
+///
+/// The vertices are the points themselves if `step` is not "pre", "post", or "mid".
+fn step_vertices(x: &[f64], y: &[f64], step: &str) -> (Vec<f64>, Vec<f64>) {
+    let n = usize::min(x.len(), y.len());
+    let (mut xs, mut ys) = (Vec::with_capacity(2 * n), Vec::with_capacity(2 * n));
+    for i in 0..n {
+        match step {
+            // vertical segment first: (x[i-1],y[i-1]) -> (x[i-1],y[i]) -> (x[i],y[i])
+            "pre" if i > 0 => {
+                xs.extend([x[i - 1], x[i]]);
+                ys.extend([y[i], y[i]]);
+            }
+            // horizontal segment first: (x[i-1],y[i-1]) -> (x[i],y[i-1]) -> (x[i],y[i])
+            "post" if i > 0 => {
+                xs.extend([x[i], x[i]]);
+                ys.extend([y[i - 1], y[i]]);
+            }
+            // horizontal segment of y[i] from the previous midpoint to the next one
+            "mid" => {
+                let left = if i > 0 { (x[i - 1] + x[i]) / 2.0 } else { x[i] };
+                let right = if i + 1 < n { (x[i] + x[i + 1]) / 2.0 } else { x[i] };
+                xs.extend([left, right]);
+                ys.extend([y[i], y[i]]);
+            }
+            _ => {
+                xs.push(x[i]);
+                ys.push(y[i]);
+            }
+        }
+    }
+    (xs, ys)
+}
+
+/// Returns the colors (fill, stroke) and stroke width of markers
+fn marker_colors(style: &CurveStyle, line_color: &str) -> (String, String, f64) {
+    let edge = if style.marker_line_color != "" {
+        color(&style.marker_line_color)
+    } else {
+        line_color.to_string()
+    };
+    let face = if style.marker_void || is_line_marker(&style.marker_style) {
+        "none".to_string()
+    } else if style.marker_color != "" {
+        color(&style.marker_color)
+    } else {
+        line_color.to_string()
+    };
+    let width = if style.marker_line_width > 0.0 {
+        style.marker_line_width
+    } else {
+        1.0
+    };
+    (face, edge, width)
+}
+
+/// Returns whether the marker is made of lines only
+fn is_line_marker(marker: &str) -> bool {
+    matches!(marker, "+" | "x" | "|" | "_" | "1" | "2" | "3" | "4")
+}
+
+/// Returns the SVG path of a marker centered at (x,y) with size (diameter) in points
+fn marker_path(marker: &str, x: f64, y: f64, size: f64) -> String {
+    let r = 0.5 * size;
+    let polygon = |n: usize, radius: f64, rotation: f64| -> String {
+        let mut d = String::new();
+        for k in 0..n {
+            let t = rotation + 2.0 * std::f64::consts::PI * (k as f64) / (n as f64);
+            let cmd = if k == 0 { "M" } else { "L" };
+            write!(
+                &mut d,
+                "{}{} {}",
+                cmd,
+                num(x + radius * t.sin()),
+                num(y - radius * t.cos())
+            )
+            .unwrap();
+        }
+        d.push('Z');
+        d
+    };
+    let circle = |radius: f64| -> String {
+        format!(
+            "M{} {}A{} {} 0 1 0 {} {}A{} {} 0 1 0 {} {}Z",
+            num(x - radius),
+            num(y),
+            num(radius),
+            num(radius),
+            num(x + radius),
+            num(y),
+            num(radius),
+            num(radius),
+            num(x - radius),
+            num(y)
+        )
+    };
+    match marker {
+        "." => circle(0.5 * r),
+        "," => circle(0.5),
+        "s" => polygon(4, r * std::f64::consts::SQRT_2, std::f64::consts::FRAC_PI_4),
+        "D" => polygon(4, r, 0.0),
+        "d" => format!(
+            "M{} {}L{} {}L{} {}L{} {}Z",
+            num(x),
+            num(y - r),
+            num(x + 0.6 * r),
+            num(y),
+            num(x),
+            num(y + r),
+            num(x - 0.6 * r),
+            num(y)
+        ),
+        "^" => polygon(3, r, 0.0),
+        "v" => polygon(3, r, std::f64::consts::PI),
+        ">" => polygon(3, r, 0.5 * std::f64::consts::PI),
+        "<" => polygon(3, r, -0.5 * std::f64::consts::PI),
+        "p" => polygon(5, r, 0.0),
+        "h" | "H" => polygon(6, r, 0.0),
+        "8" => polygon(8, r, std::f64::consts::PI / 8.0),
+        "*" => {
+            let mut d = String::new();
+            for k in 0..10 {
+                let radius = if k % 2 == 0 { r } else { 0.381966 * r };
+                let t = std::f64::consts::PI * (k as f64) / 5.0;
+                let cmd = if k == 0 { "M" } else { "L" };
+                write!(
+                    &mut d,
+                    "{}{} {}",
+                    cmd,
+                    num(x + radius * t.sin()),
+                    num(y - radius * t.cos())
+                )
+                .unwrap();
+            }
+            d.push('Z');
+            d
+        }
+        "+" => format!(
+            "M{} {}L{} {}M{} {}L{} {}",
+            num(x - r),
+            num(y),
+            num(x + r),
+            num(y),
+            num(x),
+            num(y - r),
+            num(x),
+            num(y + r)
+        ),
+        "x" => format!(
+            "M{} {}L{} {}M{} {}L{} {}",
+            num(x - r),
+            num(y - r),
+            num(x + r),
+            num(y + r),
+            num(x - r),
+            num(y + r),
+            num(x + r),
+            num(y - r)
+        ),
+        "|" => format!("M{} {}L{} {}", num(x), num(y - r), num(x), num(y + r)),
+        "_" => format!("M{} {}L{} {}", num(x - r), num(y), num(x + r), num(y)),
+        _ => circle(r),
+    }
+}
+
+/// Returns the stroke-dasharray attribute or None if the line is not drawn
+fn dash_array(line_style: &str, width: f64) -> Option<String> {
+    let pattern: &[f64] = match line_style {
+        "" | "-" | "solid" => &[],
+        "--" | "dashed" => &[3.7, 1.6],
+        ":" | "dotted" => &[1.0, 1.65],
+        "-." | "dashdot" => &[6.4, 1.6, 1.0, 1.6],
+        _ => return None,
+    };
+    if pattern.is_empty() {
+        return Some(String::new());
+    }
+    let dashes: Vec<String> = pattern.iter().map(|p| num(p * width)).collect();
+    Some(format!(" stroke-dasharray=\"{}\"", dashes.join(",")))
+}
+
+/// Computes the two points of a ray within the axes
+fn ray(tr: &Transform, xa: f64, ya: f64, endpoint: &RayEndpoint) -> (Vec<f64>, Vec<f64>) {
+    let [xmin, xmax, ymin, ymax] = tr.limits;
+    match endpoint {
+        RayEndpoint::Horizontal => (vec![xmin, xmax], vec![ya, ya]),
+        RayEndpoint::Vertical => (vec![xa, xa], vec![ymin, ymax]),
+        RayEndpoint::Coords(xb, _) if (xb - xa).abs() < f64::EPSILON * (1.0 + xa.abs()) => {
+            (vec![xa, xa], vec![ymin, ymax])
+        }
+        RayEndpoint::Coords(xb, yb) => {
+            let m = (yb - ya) / (xb - xa);
+            (vec![xmin, xmax], vec![ya + m * (xmin - xa), ya + m * (xmax - xa)])
+        }
+        RayEndpoint::Slope(m) => (vec![xmin, xmax], vec![ya + m * (xmin - xa), ya + m * (xmax - xa)]),
+    }
+}
+
+/// Returns the SVG path data of a polycurve
+fn path_data(tr: &Transform, points: &[[f64; 2]], codes: &[PolyCode], closed: bool) -> String {
+    let mut d = String::new();
+    let p = |i: usize| format!("{} {}", num(tr.x(points[i][0])), num(tr.y(points[i][1])));
+    let mut i = 0;
+    while i < points.len() {
+        let code = if i == 0 { PolyCode::MoveTo } else { codes[i] };
+        match code {
+            PolyCode::MoveTo => write!(&mut d, "M{}", p(i)).unwrap(),
+            PolyCode::LineTo => write!(&mut d, "L{}", p(i)).unwrap(),
+            PolyCode::Curve3 if i + 1 < points.len() => {
+                write!(&mut d, "Q{} {}", p(i), p(i + 1)).unwrap();
+                i += 1;
+            }
+            PolyCode::Curve4 if i + 2 < points.len() => {
+                write!(&mut d, "C{} {} {}", p(i), p(i + 1), p(i + 2)).unwrap();
+                i += 2;
+            }
+            _ => write!(&mut d, "L{}", p(i)).unwrap(),
+        }
+        i += 1;
+    }
+    if closed {
+        d.push('Z');
+    }
+    d
+}
+
+/// Returns the SVG path data of an arc (angles in degrees, counterclockwise)
+fn arc_data(tr: &Transform, xc: f64, yc: f64, r: f64, ini_angle: f64, fin_angle: f64) -> String {
+    let mut sweep = (fin_angle - ini_angle).rem_euclid(360.0);
+    if sweep == 0.0 {
+        sweep = 360.0;
+    }
+    let (rx, ry) = (num(r * tr.sx()), num(r * tr.sy()));
+    let point = |angle: f64| {
+        let t = angle.to_radians();
+        format!("{} {}", num(tr.x(xc + r * t.cos())), num(tr.y(yc + r * t.sin())))
+    };
+    let mut d = format!("M{}", point(ini_angle));
+    if sweep > 180.0 {
+        let mid = ini_angle + 180.0;
+        write!(&mut d, "A{} {} 0 0 0 {}", rx, ry, point(mid)).unwrap();
+        write!(&mut d, "A{} {} 0 0 0 {}", rx, ry, point(ini_angle + sweep)).unwrap();
+    } else {
+        write!(&mut d, "A{} {} 0 0 0 {}", rx, ry, point(ini_angle + sweep)).unwrap();
+    }
+    d
+}
+
+/// Returns the SVG attributes of a patch
+fn patch_attributes(style: &CanvasStyle, filled: bool) -> String {
+    let face = if !filled {
+        "none".to_string()
+    } else if style.face_color != "" {
+        color(&style.face_color)
+    } else {
+        COLOR_CYCLE[0].to_string()
+    };
+    let edge = if style.edge_color != "" {
+        color(&style.edge_color)
+    } else {
+        "none".to_string()
+    };
+    let width = if style.line_width > 0.0 { style.line_width } else { 1.0 };
+    let clip = if style.stop_clip {
+        ""
+    } else {
+        " clip-path=\"url(#clip-axes)\""
+    };
+    format!(
+        " fill=\"{}\" stroke=\"{}\" stroke-width=\"{}\" stroke-linejoin=\"miter\"{}",
+        face,
+        edge,
+        num(width),
+        clip
+    )
+}
+
+/// Draws an arrow
+fn draw_arrow(buf: &mut String, tr: &Transform, a: (f64, f64), b: (f64, f64), style: &CanvasStyle) {
+    let (ax, ay, bx, by) = (tr.x(a.0), tr.y(a.1), tr.x(b.0), tr.y(b.1));
+    let len = f64::hypot(bx - ax, by - ay);
+    if len == 0.0 {
+        return;
+    }
+    let (ux, uy) = ((bx - ax) / len, (by - ay) / len);
+    let scale = if style.arrow_scale > 0.0 {
+        style.arrow_scale
+    } else {
+        1.0
+    };
+    let arrow_style = style.arrow_style.as_str();
+    let simple = matches!(arrow_style, "" | "simple" | "fancy" | "wedge");
+    let head_b = simple || arrow_style.ends_with('>');
+    let head_a = arrow_style.starts_with('<');
+    let filled = simple || arrow_style.contains('|');
+    let (head_len, head_half) = if simple {
+        (0.5 * scale, 0.25 * scale)
+    } else {
+        (0.4 * scale, 0.2 * scale)
+    };
+    let edge = if style.edge_color != "" {
+        color(&style.edge_color)
+    } else {
+        COLOR_CYCLE[0].to_string()
+    };
+    let face = if style.face_color != "" {
+        color(&style.face_color)
+    } else {
+        edge.clone()
+    };
+    let width = if style.line_width > 0.0 { style.line_width } else { 1.0 };
+    let shaft = if simple { f64::max(width, 0.2 * scale) } else { width };
+    let clip = if style.stop_clip {
+        ""
+    } else {
+        " clip-path=\"url(#clip-axes)\""
+    };
+    write!(
+        buf,
+        "<g{} stroke=\"{}\" stroke-width=\"{}\" stroke-linejoin=\"miter\">\n\
+         <path d=\"M{} {}L{} {}\" fill=\"none\" stroke-width=\"{}\"/>\n",
+        clip,
+        edge,
+        num(width),
+        num(ax),
+        num(ay),
+        num(bx),
+        num(by),
+        num(shaft)
+    )
+    .unwrap();
+    let mut head = |tip_x: f64, tip_y: f64, dx: f64, dy: f64| {
+        let (base_x, base_y) = (tip_x - dx * head_len, tip_y - dy * head_len);
+        let (nx, ny) = (-dy * head_half, dx * head_half);
+        let fill = if filled { face.as_str() } else { "none" };
+        let close = if filled { "Z" } else { "" };
+        write!(
+            buf,
+            "<path d=\"M{} {}L{} {}L{} {}{}\" fill=\"{}\"/>\n",
+            num(base_x + nx),
+            num(base_y + ny),
+            num(tip_x),
+            num(tip_y),
+            num(base_x - nx),
+            num(base_y - ny),
+            close,
+            fill
+        )
+        .unwrap();
+    };
+    if head_b {
+        head(bx, by, ux, uy);
+    }
+    if head_a {
+        head(ax, ay, -ux, -uy);
+    }
+    buf.push_str("</g>\n");
+}
+
+/// Draws a text at (x,y) given in SVG coordinates
+fn draw_text(buf: &mut String, x: f64, y: f64, message: &str, style: &TextStyle) {
+    let fontsize = if style.fontsize > 0.0 {
+        style.fontsize
+    } else {
+        DEFAULT_FONTSIZE
+    };
+    let (anchor, shift) = match style.align_horizontal.as_str() {
+        "center" => ("middle", 0.5),
+        "right" => ("end", 1.0),
+        _ => ("start", 0.0),
+    };
+    let (baseline, drop) = match style.align_vertical.as_str() {
+        "center" | "center_baseline" => ("central", 0.5),
+        "top" => ("text-before-edge", 0.0),
+        "bottom" => ("text-after-edge", 1.0),
+        _ => ("alphabetic", 0.8),
+    };
+    let rotation = if style.rotation != 0.0 {
+        format!(" transform=\"rotate({} {} {})\"", num(-style.rotation), num(x), num(y))
+    } else {
+        String::new()
+    };
+    write!(buf, "<g{}>\n", rotation).unwrap();
+    if style.bbox {
+        let (w, h) = (text_width(message, fontsize), 1.2 * fontsize);
+        let pad = 0.3 * fontsize;
+        let face = if style.bbox_facecolor != "" {
+            color(&style.bbox_facecolor)
+        } else {
+            "#ffffff".to_string()
+        };
+        let edge = if style.bbox_edgecolor != "" {
+            color(&style.bbox_edgecolor)
+        } else {
+            "#000000".to_string()
+        };
+        write!(
+            buf,
+            "<rect x=\"{}\" y=\"{}\" width=\"{}\" height=\"{}\" fill=\"{}\" stroke=\"{}\" opacity=\"{}\"/>\n",
+            num(x - shift * w - pad),
+            num(y - drop * h - pad),
+            num(w + 2.0 * pad),
+            num(h + 2.0 * pad),
+            face,
+            edge,
+            num(style.bbox_alpha)
+        )
+        .unwrap();
+    }
+    let fill = if style.color != "" {
+        color(&style.color)
+    } else {
+        "#000000".to_string()
+    };
+    write!(
+        buf,
+        "<text x=\"{}\" y=\"{}\" text-anchor=\"{}\" dominant-baseline=\"{}\" font-family=\"DejaVu Sans, sans-serif\" font-size=\"{}\" fill=\"{}\">{}</text>\n</g>\n",
+        num(x),
+        num(y),
+        anchor,
+        baseline,
+        num(fontsize),
+        fill,
+        escape(message)
+    )
+    .unwrap();
+}
+
+/// Draws the legend with the labeled curves
+fn draw_legend(buf: &mut String, tr: &Transform, axes: &Axes, legend: &LegendStyle) {
+    // entries
+    let mut cycle = 0;
+    let mut entries = Vec::new();
+    for item in &axes.items {
+        if let Primitive::Curve { style, .. } | Primitive::Ray { style, .. } = item {
+            let color = curve_color(style, &mut cycle);
+            if style.label != "" {
+                entries.push((style, color));
+            }
+        }
+    }
+    if entries.is_empty() {
+        return;
+    }
+
+    // dimensions
+    let fs = if legend.fontsize > 0.0 {
+        legend.fontsize
+    } else {
+        DEFAULT_FONTSIZE
+    };
+    let ncol = usize::max(legend.num_col, 1);
+    let nrow = entries.len().div_ceil(ncol);
+    let handle = legend.handle_len * fs;
+    let text_w = entries
+        .iter()
+        .map(|(style, _)| text_width(&style.label, fs))
+        .fold(0.0, f64::max);
+    let (pad, row_h, col_sep) = (0.4 * fs, 1.25 * fs, 2.0 * fs);
+    let col_w = handle + 0.8 * fs + text_w;
+    let width = 2.0 * pad + (ncol as f64) * col_w + ((ncol - 1) as f64) * col_sep;
+    let height = 2.0 * pad + (nrow as f64) * row_h;
+
+    // location
+    let border = 0.5 * fs;
+    let (x0, y0) = if legend.outside && legend.x_coords.len() == 4 {
+        let (aw, ah) = (tr.right - tr.left, tr.bottom - tr.top);
+        let y_bottom = tr.bottom - legend.x_coords[1] * ah;
+        (tr.left + legend.x_coords[0] * aw, y_bottom - height)
+    } else {
+        let location = legend.location.as_str();
+        let x = if location.contains("left") {
+            tr.left + border
+        } else if location == "center" || location.ends_with(" center") {
+            0.5 * (tr.left + tr.right - width)
+        } else {
+            tr.right - border - width
+        };
+        let y = if location.starts_with("lower") {
+            tr.bottom - border - height
+        } else if location.starts_with("center") || location == "right" {
+            0.5 * (tr.top + tr.bottom - height)
+        } else {
+            tr.top + border
+        };
+        (x, y)
+    };
+
+    // frame
+    if legend.show_frame {
+        write!(
+            buf,
+            "<rect x=\"{}\" y=\"{}\" width=\"{}\" height=\"{}\" rx=\"2\" fill=\"#ffffff\" fill-opacity=\"0.8\" stroke=\"#cccccc\" stroke-width=\"1\"/>\n",
+            num(x0),
+            num(y0),
+            num(width),
+            num(height)
+        )
+        .unwrap();
+    }
+
+    // entries
+    let tr_identity = Transform {
+        limits: [0.0, 1.0, 0.0, 1.0],
+        left: 0.0,
+        right: 1.0,
+        top: 0.0,
+        bottom: 1.0,
+    };
+    for (k, (style, color)) in entries.iter().enumerate() {
+        let (row, col) = (k / ncol, k % ncol);
+        let x = x0 + pad + (col as f64) * (col_w + col_sep);
+        let y = y0 + pad + (row as f64 + 0.5) * row_h;
+        let sample = CurveStyle {
+            marker_every: 0,
+            stop_clip: true,
+            ..(*style).clone()
+        };
+        let xs = [x, x + 0.5 * handle, x + handle];
+        let ys = [1.0 - y; 3];
+        draw_curve_sample(buf, &tr_identity, &xs, &ys, &sample, color);
+        let text_style = TextStyle {
+            align_vertical: "center".to_string(),
+            fontsize: fs,
+            ..Default::default()
+        };
+        draw_text(buf, x + handle + 0.8 * fs, y, &style.label, &text_style);
+    }
+}
+
+/// Draws the line of a legend entry with a single marker in the middle
+fn draw_curve_sample(buf: &mut String, tr: &Transform, x: &[f64; 3], y: &[f64; 3], style: &CurveStyle, color: &str) {
+    let lines = CurveStyle {
+        marker_style: String::new(),
+        ..style.clone()
+    };
+    draw_curve(buf, tr, &[x[0], x[2]], &[y[0], y[2]], &lines, color);
+    let marker = CurveStyle {
+        line_style: "None".to_string(),
+        ..style.clone()
+    };
+    draw_curve(buf, tr, &x[1..2], &y[1..2], &marker, color);
+}
+
+/// Estimates the width of a text in points
+fn text_width(text: &str, fontsize: f64) -> f64 {
+    0.6 * fontsize * (text.chars().count() as f64)
+}
+
+/// Converts a Matplotlib color to an SVG color
+fn color(c: &str) -> String {
+    let named = match c {
+        "b" => "#0000ff",
+        "g" => "#008000",
+        "r" => "#ff0000",
+        "c" => "#00bfbf",
+        "m" => "#bf00bf",
+        "y" => "#bfbf00",
+        "k" => "#000000",
+        "w" => "#ffffff",
+        "none" | "None" => "none",
+        "tab:blue" => COLOR_CYCLE[0],
+        "tab:orange" => COLOR_CYCLE[1],
+        "tab:green" => COLOR_CYCLE[2],
+        "tab:red" => COLOR_CYCLE[3],
+        "tab:purple" => COLOR_CYCLE[4],
+        "tab:brown" => COLOR_CYCLE[5],
+        "tab:pink" => COLOR_CYCLE[6],
+        "tab:gray" | "tab:grey" => COLOR_CYCLE[7],
+        "tab:olive" => COLOR_CYCLE[8],
+        "tab:cyan" => COLOR_CYCLE[9],
+        _ => "",
+    };
+    if named != "" {
+        return named.to_string();
+    }
+    if let Some(index) = c.strip_prefix('C').and_then(|i| i.parse::<usize>().ok()) {
+        return COLOR_CYCLE[index % COLOR_CYCLE.len()].to_string();
+    }
+    if let Ok(gray) = c.parse::<f64>() {
+        let level = (gray.clamp(0.0, 1.0) * 255.0).round() as u8;
+        return format!("#{:02x}{:02x}{:02x}", level, level, level);
+    }
+    escape(c)
+}
+
+/// Escapes the special characters of XML
+fn escape(text: &str) -> String {
+    let mut res = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => res.push_str("&amp;"),
+            '<' => res.push_str("&lt;"),
+            '>' => res.push_str("&gt;"),
+            '"' => res.push_str("&quot;"),
+            '\'' => res.push_str("&apos;"),
+            _ => res.push(c),
+        }
+    }
+    res
+}
+
+/// Formats a coordinate with at most three decimal digits
+fn num(value: f64) -> String {
+    let mut s = format!("{:.3}", value);
+    if s.contains('.') {
+        while s.ends_with('0') {
+            s.pop();
+        }
+        if s.ends_with('.') {
+            s.pop();
+        }
+    }
+    if s == "-0" {
+        s = "0".to_string();
+    }
+    s
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{color, num, render_svg, step_vertices, tick_labels, ticks};
+    use crate::{CurveStyle, Primitive};
+
+    #[test]
+    fn num_works() {
+        assert_eq!(num(1.0), "1");
+        assert_eq!(num(1.23456), "1.235");
+        assert_eq!(num(-0.0001), "0");
+        assert_eq!(num(10.5), "10.5");
+    }
+
+    #[test]
+    fn ticks_work() {
+        assert_eq!(
+            ticks(0.0, 1.0, Some(5), 100.0),
+            &[0.0, 0.2, 0.4, 0.6000000000000001, 0.8, 1.0]
+        );
+        assert_eq!(ticks(-1.0, 1.0, Some(4), 100.0), &[-1.0, -0.5, 0.0, 0.5, 1.0]);
+        assert_eq!(ticks(0.0, 1.0, Some(0), 100.0).len(), 0);
+        assert_eq!(ticks(0.0, 100.0, None, 360.0), &[0.0, 20.0, 40.0, 60.0, 80.0, 100.0]);
+        assert_eq!(
+            tick_labels(&[-1.0, -0.5, 0.0, 0.5, 1.0]),
+            &["−1.0", "−0.5", "0.0", "0.5", "1.0"]
+        );
+        assert_eq!(tick_labels(&[0.0, 20.0, 40.0]), &["0", "20", "40"]);
+    }
+
+    #[test]
+    fn color_works() {
+        assert_eq!(color("r"), "#ff0000");
+        assert_eq!(color("C1"), "#ff7f0e");
+        assert_eq!(color("tab:green"), "#2ca02c");
+        assert_eq!(color("0.5"), "#808080");
+        assert_eq!(color("#427ce5"), "#427ce5");
+        assert_eq!(color("None"), "none");
+    }
+
+    #[test]
+    fn step_vertices_work() {
+        let (x, y) = (&[1.0, 2.0, 4.0], &[10.0, 20.0, 30.0]);
+        assert_eq!(step_vertices(x, y, ""), (x.to_vec(), y.to_vec()));
+        assert_eq!(
+            step_vertices(x, y, "pre"),
+            (vec![1.0, 1.0, 2.0, 2.0, 4.0], vec![10.0, 20.0, 20.0, 30.0, 30.0])
+        );
+        assert_eq!(
+            step_vertices(x, y, "post"),
+            (vec![1.0, 2.0, 2.0, 4.0, 4.0], vec![10.0, 10.0, 20.0, 20.0, 30.0])
+        );
+        assert_eq!(
+            step_vertices(x, y, "mid"),
+            (
+                vec![1.0, 1.5, 1.5, 3.0, 3.0, 4.0],
+                vec![10.0, 10.0, 20.0, 20.0, 30.0, 30.0]
+            )
+        );
+        assert_eq!(step_vertices(&[], &[], "mid"), (Vec::new(), Vec::new()));
+    }
+
+    #[test]
+    fn render_svg_works() {
+        let scene = vec![
+            Primitive::Curve {
+                x: vec![0.0, 1.0, 2.0],
+                y: vec![0.0, 1.0, 4.0],
+                style: CurveStyle {
+                    label: "a & b".to_string(),
+                    marker_style: "o".to_string(),
+                    ..Default::default()
+                },
+            },
+            Primitive::Title("my <title>".to_string()),
+            Primitive::Grid,
+        ];
+        let svg = render_svg(&scene);
+        assert!(svg.starts_with("<?xml"));
+        assert!(svg.ends_with("</svg>\n"));
+        assert!(svg.contains("width=\"460.8pt\" height=\"345.6pt\""));
+        assert!(svg.contains("stroke=\"#1f77b4\""));
+        assert!(svg.contains("my &lt;title&gt;"));
+        assert!(svg.contains("stroke-dasharray=\"2.96,1.28\""));
+        assert_eq!(svg.matches("A3 3 0 1 0").count(), 6); // 3 markers
+        assert!(!svg.contains("a &amp; b")); // no legend
+
+        let mut scene = scene.clone();
+        scene.push(Primitive::Legend(Default::default()));
+        let svg = render_svg(&scene);
+        assert!(svg.contains("a &amp; b"));
+
+        scene.push(Primitive::ClearAxes);
+        let svg = render_svg(&scene);
+        assert!(!svg.contains("#1f77b4"));
+        assert!(!svg.contains("title"));
+    }
+}
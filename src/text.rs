@@ -1,4 +1,4 @@
-use super::GraphMaker;
+use super::{GraphMaker, Primitive};
 use std::fmt::Write;
 
 /// Creates text to be added to a plot
@@ -6,10 +6,10 @@ use std::fmt::Write;
 /// # Example
 ///
 /// ```
-/// use plotpy::{Plot, Text, StrError};
+/// use plotpy::{Error, Plot, Text};
 /// use std::path::Path;
 ///
-/// fn main() -> Result<(), StrError> {
+/// fn main() -> Result<(), Error> {
 ///     // configure text
 ///     let mut text = Text::new();
 ///     text.set_color("purple")
@@ -38,71 +38,68 @@ use std::fmt::Write;
 ///
 /// ![doc_text.svg](https://raw.githubusercontent.com/cpmech/plotpy/main/figures/doc_text.svg)
 pub struct Text {
+    style: TextStyle,           // Style of this text
+    buffer: String,             // buffer
+    primitives: Vec<Primitive>, // primitives
+}
+
+/// Holds the style of text (see [Text])
+///
+/// The style is recorded by the [Primitive]s drawn by [Text] (and by the ids of [crate::Canvas::draw_grid])
+/// and may be modified afterwards.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TextStyle {
     // text
-    color: String,            // Color
-    align_horizontal: String, // Horizontal alignment
-    align_vertical: String,   // Vertical alignment
-    fontsize: f64,            // Font size
-    rotation: f64,            // Text rotation
+    pub color: String,            // Color
+    pub align_horizontal: String, // Horizontal alignment
+    pub align_vertical: String,   // Vertical alignment
+    pub fontsize: f64,            // Font size
+    pub rotation: f64,            // Text rotation
 
     // bounding box
-    bbox: bool,             // Use bounding box
-    bbox_facecolor: String, // Facecolor of bounding box
-    bbox_edgecolor: String, // Edgecolor of bounding box
-    bbox_alpha: f64,        // Alpha of bounding box
-    bbox_style: String,     // Style of bounding box; example "round,pad=0.2"
-
-    // buffer
-    buffer: String,
+    pub bbox: bool,             // Use bounding box
+    pub bbox_facecolor: String, // Facecolor of bounding box
+    pub bbox_edgecolor: String, // Edgecolor of bounding box
+    pub bbox_alpha: f64,        // Alpha of bounding box
+    pub bbox_style: String,     // Style of bounding box; example "round,pad=0.2"
 }
 
 impl Text {
     /// Creates a new Text object
     pub fn new() -> Self {
         Text {
-            color: String::new(),
-            align_horizontal: String::new(),
-            align_vertical: String::new(),
-            fontsize: 0.0,
-            rotation: 0.0,
-            bbox: false,
-            bbox_facecolor: String::new(),
-            bbox_edgecolor: String::new(),
-            bbox_alpha: 1.0,
-            bbox_style: String::new(),
+            style: TextStyle::default(),
             buffer: String::new(),
+            primitives: Vec::new(),
         }
     }
 
     /// Draws text
     pub fn draw(&mut self, x: f64, y: f64, message: &str) {
-        let opt = self.options();
-        write!(&mut self.buffer, "t=plt.text({},{},'{}'{})\n", x, y, message, &opt).unwrap();
-        if self.bbox {
-            let opt_bbox = self.options_bbox();
-            write!(&mut self.buffer, "t.set_bbox(dict({}))\n", opt_bbox).unwrap();
-        }
+        let style = self.style.clone();
+        self.push(Primitive::Text {
+            x,
+            y,
+            message: message.to_string(),
+            style,
+        });
     }
 
     /// Draws text in 3D plot
     pub fn draw_3d(&mut self, x: f64, y: f64, z: f64, message: &str) {
-        let opt = self.options();
-        write!(
-            &mut self.buffer,
-            "maybe_create_ax3d()\n\
-             t=AX3D.text({},{},{},'{}'{})\n",
-            x, y, z, message, &opt
-        )
-        .unwrap();
-        if self.bbox {
-            let opt_bbox = self.options_bbox();
-            write!(&mut self.buffer, "t.set_bbox(dict({}))\n", opt_bbox).unwrap();
-        }
+        let style = self.style.clone();
+        self.push(Primitive::Text3d {
+            x,
+            y,
+            z,
+            message: message.to_string(),
+            style,
+        });
     }
 
     /// Sets the text color
     pub fn set_color(&mut self, color: &str) -> &mut Self {
-        self.color = String::from(color);
+        self.style.color = String::from(color);
         self
     }
 
@@ -110,7 +107,7 @@ impl Text {
     ///
     /// Options: "center", "left", "right"
     pub fn set_align_horizontal(&mut self, option: &str) -> &mut Self {
-        self.align_horizontal = String::from(option);
+        self.style.align_horizontal = String::from(option);
         self
     }
 
@@ -118,43 +115,43 @@ impl Text {
     ///
     /// Options: "center", "top", "bottom", "baseline", "center_baseline"
     pub fn set_align_vertical(&mut self, option: &str) -> &mut Self {
-        self.align_vertical = String::from(option);
+        self.style.align_vertical = String::from(option);
         self
     }
 
     /// Sets the font size
     pub fn set_fontsize(&mut self, fontsize: f64) -> &mut Self {
-        self.fontsize = fontsize;
+        self.style.fontsize = fontsize;
         self
     }
 
     /// Sets the text rotation
     pub fn set_rotation(&mut self, rotation: f64) -> &mut Self {
-        self.rotation = rotation;
+        self.style.rotation = rotation;
         self
     }
 
     /// Sets use bounding box flag
     pub fn set_bbox(&mut self, flag: bool) -> &mut Self {
-        self.bbox = flag;
+        self.style.bbox = flag;
         self
     }
 
     /// Sets facecolor of bounding box
     pub fn set_bbox_facecolor(&mut self, color: &str) -> &mut Self {
-        self.bbox_facecolor = String::from(color);
+        self.style.bbox_facecolor = String::from(color);
         self
     }
 
     /// Sets edgecolor of bounding box
     pub fn set_bbox_edgecolor(&mut self, color: &str) -> &mut Self {
-        self.bbox_edgecolor = String::from(color);
+        self.style.bbox_edgecolor = String::from(color);
         self
     }
 
     /// Sets alpha of bounding box
     pub fn set_bbox_alpha(&mut self, value: f64) -> &mut Self {
-        self.bbox_alpha = value;
+        self.style.bbox_alpha = value;
         self
     }
 
@@ -174,12 +171,37 @@ impl Text {
     ///
     /// See [Matplotlib](https://matplotlib.org/stable/api/_as_gen/matplotlib.patches.BoxStyle.html)
     pub fn set_bbox_style(&mut self, style: &str) -> &mut Self {
-        self.bbox_style = String::from(style);
+        self.style.bbox_style = String::from(style);
         self
     }
 
+    /// Writes the primitive to the buffer and records it
+    fn push(&mut self, primitive: Primitive) {
+        primitive.write_python(&mut self.buffer);
+        self.primitives.push(primitive);
+    }
+}
+
+impl Default for TextStyle {
+    fn default() -> Self {
+        TextStyle {
+            color: String::new(),
+            align_horizontal: String::new(),
+            align_vertical: String::new(),
+            fontsize: 0.0,
+            rotation: 0.0,
+            bbox: false,
+            bbox_facecolor: String::new(),
+            bbox_edgecolor: String::new(),
+            bbox_alpha: 1.0,
+            bbox_style: String::new(),
+        }
+    }
+}
+
+impl TextStyle {
     /// Returns options for text
-    fn options(&self) -> String {
+    pub(crate) fn options(&self) -> String {
         let mut opt = String::new();
         if self.color != "" {
             write!(&mut opt, ",color='{}'", self.color).unwrap();
@@ -200,7 +222,7 @@ impl Text {
     }
 
     /// Returns options for bounding box
-    fn options_bbox(&self) -> String {
+    pub(crate) fn options_bbox(&self) -> String {
         let mut opt = String::new();
         if self.bbox_facecolor != "" {
             write!(&mut opt, "facecolor='{}',", self.bbox_facecolor).unwrap();
@@ -216,12 +238,42 @@ impl Text {
     }
 }
 
+/// Writes the Python commands of text (see [Primitive::Text])
+pub(crate) fn write_text(buf: &mut String, x: f64, y: f64, message: &str, style: &TextStyle) {
+    write!(buf, "t=plt.text({},{},'{}'{})\n", x, y, message, style.options()).unwrap();
+    if style.bbox {
+        write!(buf, "t.set_bbox(dict({}))\n", style.options_bbox()).unwrap();
+    }
+}
+
+/// Writes the Python commands of text in 3D (see [Primitive::Text3d])
+pub(crate) fn write_text_3d(buf: &mut String, x: f64, y: f64, z: f64, message: &str, style: &TextStyle) {
+    write!(
+        buf,
+        "maybe_create_ax3d()\n\
+         t=AX3D.text({},{},{},'{}'{})\n",
+        x,
+        y,
+        z,
+        message,
+        style.options()
+    )
+    .unwrap();
+    if style.bbox {
+        write!(buf, "t.set_bbox(dict({}))\n", style.options_bbox()).unwrap();
+    }
+}
+
 impl GraphMaker for Text {
     fn get_buffer<'a>(&'a self) -> &'a String {
         &self.buffer
     }
     fn clear_buffer(&mut self) {
         self.buffer.clear();
+        self.primitives.clear();
+    }
+    fn get_primitives(&self) -> &[Primitive] {
+        &self.primitives
     }
 }
 
@@ -235,12 +287,14 @@ mod tests {
     #[test]
     fn new_works() {
         let text = Text::new();
-        assert_eq!(text.color.len(), 0);
-        assert_eq!(text.align_horizontal.len(), 0);
-        assert_eq!(text.align_vertical.len(), 0);
-        assert_eq!(text.fontsize, 0.0);
-        assert_eq!(text.rotation, 0.0);
+        assert_eq!(text.style.color.len(), 0);
+        assert_eq!(text.style.align_horizontal.len(), 0);
+        assert_eq!(text.style.align_vertical.len(), 0);
+        assert_eq!(text.style.fontsize, 0.0);
+        assert_eq!(text.style.rotation, 0.0);
+        assert_eq!(text.style.bbox_alpha, 1.0);
         assert_eq!(text.buffer.len(), 0);
+        assert_eq!(text.primitives.len(), 0);
     }
 
     #[test]
@@ -251,7 +305,7 @@ mod tests {
             .set_align_vertical("center")
             .set_fontsize(8.0)
             .set_rotation(45.0);
-        let opt = text.options();
+        let opt = text.style.options();
         assert_eq!(
             opt,
             ",color='red'\
@@ -270,8 +324,8 @@ mod tests {
             .set_bbox_edgecolor("black")
             .set_bbox_alpha(0.3)
             .set_bbox_style("round,pad=0.4");
-        assert_eq!(text.bbox, true);
-        let opt = text.options_bbox();
+        assert_eq!(text.style.bbox, true);
+        let opt = text.style.options_bbox();
         assert_eq!(
             opt,
             "facecolor='pink',\
@@ -287,8 +341,10 @@ mod tests {
         text.draw(1.2, 3.4, &"message".to_string());
         let b: &str = "t=plt.text(1.2,3.4,'message')\n";
         assert_eq!(text.buffer, b);
+        assert_eq!(text.get_primitives().len(), 1);
         text.clear_buffer();
         assert_eq!(text.buffer, "");
+        assert_eq!(text.primitives.len(), 0);
     }
 
     #[test]
@@ -0,0 +1,668 @@
+use super::{matrix_to_array, matrix_to_f64, AsMatrix, GraphMaker, Primitive, Script};
+use num_traits::AsPrimitive;
+use std::fmt::Write;
+
+/// Generates a vector field with arrows (quiver) or streamlines (streamplot), in 2D or 3D
+///
+/// # Notes
+///
+/// * The arrows and streamlines may be colored by the magnitude of the vectors (speed).
+/// * The streamlines require evenly spaced grids, e.g., generated by `generate3d`.
+/// * The 3D arrows are drawn with a solid color.
+///
+/// # Example
+///
+/// ```
+/// use plotpy::{Error, Plot, VectorField};
+/// use russell_lab::generate3d;
+///
+/// fn main() -> Result<(), Error> {
+///     // generate (x,y) grids and the (u,v) components of a rotating field
+///     let n = 11;
+///     let (x, y, u) = generate3d(-2.0, 2.0, -2.0, 2.0, n, n, |_, y| -y);
+///     let (_, _, v) = generate3d(-2.0, 2.0, -2.0, 2.0, n, n, |x, _| x);
+///
+///     // configure and draw arrows
+///     let mut arrows = VectorField::new();
+///     arrows.set_with_colormap(true)
+///         .set_colormap_name("viridis")
+///         .set_colorbar_label("speed")
+///         .set_pivot("mid")
+///         .set_key(1.0, "1 m/s");
+///     arrows.draw(&x, &y, &u, &v);
+///
+///     // add arrows to plot
+///     let mut plot = Plot::new();
+///     plot.add(&arrows).set_equal_axes(true);
+///
+///     // save figure
+///     plot.save("/tmp/plotpy/doc_tests/doc_vector_field.svg")?;
+///     Ok(())
+/// }
+/// ```
+///
+/// See also integration test in the **tests** directory.
+pub struct VectorField {
+    style: VectorFieldStyle,    // Style
+    buffer: String,             // buffer
+    primitives: Vec<Primitive>, // primitives
+}
+
+/// Holds the style of a vector field (see [VectorField])
+///
+/// The style is recorded by the [Primitive] drawn by [VectorField] and may be modified afterwards.
+#[derive(Clone, Debug, PartialEq)]
+pub struct VectorFieldStyle {
+    pub color: String,            // Solid color of arrows or streamlines
+    pub with_colormap: bool,      // Colors the arrows or streamlines by the magnitude of the vectors (2D only)
+    pub colormap_index: usize,    // Colormap index
+    pub colormap_name: String,    // Colormap name
+    pub no_colorbar: bool,        // Skip drawing a colorbar (when colored by magnitude)
+    pub colorbar_label: String,   // Colorbar label
+    pub number_format_cb: String, // Number format for labels in colorbar
+    pub scale: f64,               // Number of data units per arrow length unit (quiver)
+    pub width: f64,               // Width of the arrow shaft as a fraction of the plot width (quiver)
+    pub pivot: String,            // Part of the arrow anchored to the grid point: "tail", "mid", "tip"
+    pub key_length: f64,          // Length of the key arrow (quiver; no key if zero)
+    pub key_label: String,        // Label of the key arrow
+    pub key_x: f64,               // x-coordinate of the key arrow in axes coordinates
+    pub key_y: f64,               // y-coordinate of the key arrow in axes coordinates
+    pub density: f64,             // Density of streamlines (streamplot)
+    pub line_width: f64,          // Width of streamlines (streamplot) or arrows (3D quiver)
+    pub arrow_size: f64,          // Scaling factor of the arrow heads (streamplot)
+    pub length: f64,              // Length of the arrows (3D quiver)
+    pub normalize: bool,          // Draws all arrows with the same length (3D quiver)
+}
+
+impl VectorField {
+    /// Creates a new VectorField object
+    pub fn new() -> Self {
+        VectorField {
+            style: VectorFieldStyle::default(),
+            buffer: String::new(),
+            primitives: Vec::new(),
+        }
+    }
+
+    /// Draws arrows (quiver) in 2D
+    ///
+    /// # Input
+    ///
+    /// * `x` -- matrix with x values
+    /// * `y` -- matrix with y values
+    /// * `u` -- matrix with the x-components of the vectors
+    /// * `v` -- matrix with the y-components of the vectors
+    ///
+    /// # Notes
+    ///
+    /// * The type `U` of the input matrices must be a number.
+    pub fn draw<'a, T, U>(&mut self, x: &'a T, y: &'a T, u: &'a T, v: &'a T)
+    where
+        T: AsMatrix<'a, U>,
+        U: 'a + AsPrimitive<f64>,
+    {
+        let (x, y) = (matrix_to_f64(x), matrix_to_f64(y));
+        let (u, v) = (matrix_to_f64(u), matrix_to_f64(v));
+        let style = self.style.clone();
+        self.push(Primitive::VectorField { x, y, u, v, style });
+    }
+
+    /// Draws streamlines (streamplot) in 2D
+    ///
+    /// # Input
+    ///
+    /// * `x` -- matrix with x values (evenly spaced along the rows)
+    /// * `y` -- matrix with y values (evenly spaced along the columns)
+    /// * `u` -- matrix with the x-components of the vectors
+    /// * `v` -- matrix with the y-components of the vectors
+    ///
+    /// # Notes
+    ///
+    /// * The type `U` of the input matrices must be a number.
+    pub fn draw_streamlines<'a, T, U>(&mut self, x: &'a T, y: &'a T, u: &'a T, v: &'a T)
+    where
+        T: AsMatrix<'a, U>,
+        U: 'a + AsPrimitive<f64>,
+    {
+        let (x, y) = (matrix_to_f64(x), matrix_to_f64(y));
+        let (u, v) = (matrix_to_f64(u), matrix_to_f64(v));
+        let style = self.style.clone();
+        self.push(Primitive::Streamlines { x, y, u, v, style });
+    }
+
+    /// Draws arrows (quiver) in 3D
+    ///
+    /// # Input
+    ///
+    /// * `x`, `y`, `z` -- matrices with the coordinates of the grid points
+    /// * `u`, `v`, `w` -- matrices with the x, y, and z components of the vectors
+    ///
+    /// # Notes
+    ///
+    /// * The type `U` of the input matrices must be a number.
+    pub fn draw_3d<'a, T, U>(&mut self, x: &'a T, y: &'a T, z: &'a T, u: &'a T, v: &'a T, w: &'a T)
+    where
+        T: AsMatrix<'a, U>,
+        U: 'a + AsPrimitive<f64>,
+    {
+        let (x, y, z) = (matrix_to_f64(x), matrix_to_f64(y), matrix_to_f64(z));
+        let (u, v, w) = (matrix_to_f64(u), matrix_to_f64(v), matrix_to_f64(w));
+        let style = self.style.clone();
+        self.push(Primitive::VectorField3d {
+            x,
+            y,
+            z,
+            u,
+            v,
+            w,
+            style,
+        });
+    }
+
+    /// Sets the solid color of arrows or streamlines
+    pub fn set_color(&mut self, color: &str) -> &mut Self {
+        self.style.color = String::from(color);
+        self
+    }
+
+    /// Sets option to color the arrows or streamlines by the magnitude of the vectors
+    ///
+    /// Only the 2D plots use the colormap; it has no effect on [VectorField::draw_3d].
+    pub fn set_with_colormap(&mut self, flag: bool) -> &mut Self {
+        self.style.with_colormap = flag;
+        self
+    }
+
+    /// Sets the colormap index
+    ///
+    /// Options:
+    ///
+    /// * 0 -- bwr
+    /// * 1 -- RdBu
+    /// * 2 -- hsv
+    /// * 3 -- jet
+    /// * 4 -- terrain
+    /// * 5 -- pink
+    /// * 6 -- Greys
+    /// * `>`6 -- starts over from 0
+    pub fn set_colormap_index(&mut self, index: usize) -> &mut Self {
+        self.style.colormap_index = index;
+        self.style.colormap_name = String::new();
+        self
+    }
+
+    /// Sets the colormap name
+    ///
+    /// Colormap names:
+    ///
+    /// * see <https://matplotlib.org/stable/tutorials/colors/colormaps.html>
+    ///
+    /// Will use `colormap_index` instead if `colormap_name` is empty.
+    pub fn set_colormap_name(&mut self, name: &str) -> &mut Self {
+        self.style.colormap_name = String::from(name);
+        self
+    }
+
+    /// Sets option to skip drawing a colorbar
+    pub fn set_no_colorbar(&mut self, flag: bool) -> &mut Self {
+        self.style.no_colorbar = flag;
+        self
+    }
+
+    /// Sets the colorbar label
+    pub fn set_colorbar_label(&mut self, label: &str) -> &mut Self {
+        self.style.colorbar_label = String::from(label);
+        self
+    }
+
+    /// Sets the number format for the labels in the colorbar (cb)
+    pub fn set_number_format_cb(&mut self, format: &str) -> &mut Self {
+        self.style.number_format_cb = String::from(format);
+        self
+    }
+
+    /// Sets the number of data units per arrow length unit (quiver)
+    ///
+    /// A larger scale yields shorter arrows. Matplotlib computes the scale automatically if zero.
+    pub fn set_scale(&mut self, scale: f64) -> &mut Self {
+        self.style.scale = scale;
+        self
+    }
+
+    /// Sets the width of the arrow shaft as a fraction of the plot width (quiver)
+    pub fn set_width(&mut self, width: f64) -> &mut Self {
+        self.style.width = width;
+        self
+    }
+
+    /// Sets the part of the arrow anchored to the grid point
+    ///
+    /// Options: "`tail`", "`mid`", "`tip`" ("`mid`" is given as "`middle`" to the 3D quiver)
+    pub fn set_pivot(&mut self, pivot: &str) -> &mut Self {
+        self.style.pivot = String::from(pivot);
+        self
+    }
+
+    /// Sets the key arrow drawn above the axes to indicate the scale of the arrows (quiver)
+    ///
+    /// # Input
+    ///
+    /// * `length` -- length of the key arrow in data units of the vectors (no key if zero)
+    /// * `label` -- label of the key arrow, e.g., "1 m/s"
+    pub fn set_key(&mut self, length: f64, label: &str) -> &mut Self {
+        self.style.key_length = length;
+        self.style.key_label = String::from(label);
+        self
+    }
+
+    /// Sets the position of the key arrow in axes coordinates
+    pub fn set_key_position(&mut self, x: f64, y: f64) -> &mut Self {
+        self.style.key_x = x;
+        self.style.key_y = y;
+        self
+    }
+
+    /// Sets the density of streamlines (streamplot)
+    pub fn set_density(&mut self, density: f64) -> &mut Self {
+        self.style.density = density;
+        self
+    }
+
+    /// Sets the width of streamlines (streamplot) or arrows (3D quiver)
+    pub fn set_line_width(&mut self, width: f64) -> &mut Self {
+        self.style.line_width = width;
+        self
+    }
+
+    /// Sets the scaling factor of the arrow heads (streamplot)
+    pub fn set_arrow_size(&mut self, size: f64) -> &mut Self {
+        self.style.arrow_size = size;
+        self
+    }
+
+    /// Sets the length of the arrows (3D quiver)
+    pub fn set_length(&mut self, length: f64) -> &mut Self {
+        self.style.length = length;
+        self
+    }
+
+    /// Sets option to draw all arrows with the same length (3D quiver)
+    pub fn set_normalize(&mut self, flag: bool) -> &mut Self {
+        self.style.normalize = flag;
+        self
+    }
+
+    /// Writes the primitive to the buffer and records it
+    fn push(&mut self, primitive: Primitive) {
+        primitive.write_python(&mut self.buffer);
+        self.primitives.push(primitive);
+    }
+}
+
+impl Default for VectorFieldStyle {
+    fn default() -> Self {
+        VectorFieldStyle {
+            color: String::new(),
+            with_colormap: false,
+            colormap_index: 0,
+            colormap_name: String::new(),
+            no_colorbar: false,
+            colorbar_label: String::new(),
+            number_format_cb: String::new(),
+            scale: 0.0,
+            width: 0.0,
+            pivot: String::new(),
+            key_length: 0.0,
+            key_label: String::new(),
+            key_x: 0.85,
+            key_y: 1.02,
+            density: 0.0,
+            line_width: 0.0,
+            arrow_size: 0.0,
+            length: 0.0,
+            normalize: false,
+        }
+    }
+}
+
+impl VectorFieldStyle {
+    /// Returns options for the colors (solid color or colormap)
+    fn options_color(&self) -> String {
+        let mut opt = String::new();
+        if self.with_colormap {
+            if self.colormap_name != "" {
+                write!(&mut opt, ",cmap=plt.get_cmap('{}')", self.colormap_name).unwrap();
+            } else {
+                write!(&mut opt, ",cmap=get_colormap({})", self.colormap_index).unwrap();
+            }
+        } else if self.color != "" {
+            write!(&mut opt, ",color='{}'", self.color).unwrap();
+        }
+        opt
+    }
+
+    /// Returns options for arrows (quiver)
+    pub(crate) fn options_quiver(&self) -> String {
+        let mut opt = self.options_color();
+        if self.scale > 0.0 {
+            write!(&mut opt, ",scale={}", self.scale).unwrap();
+        }
+        if self.width > 0.0 {
+            write!(&mut opt, ",width={}", self.width).unwrap();
+        }
+        if self.pivot != "" {
+            write!(&mut opt, ",pivot='{}'", self.pivot).unwrap();
+        }
+        opt
+    }
+
+    /// Returns options for the key arrow (quiverkey)
+    pub(crate) fn options_key(&self) -> String {
+        format!(
+            ",{},{},{},r'{}',labelpos='E',coordinates='axes'",
+            self.key_x, self.key_y, self.key_length, self.key_label
+        )
+    }
+
+    /// Returns options for streamlines (streamplot)
+    pub(crate) fn options_streamplot(&self) -> String {
+        let mut opt = self.options_color();
+        if self.density > 0.0 {
+            write!(&mut opt, ",density={}", self.density).unwrap();
+        }
+        if self.line_width > 0.0 {
+            write!(&mut opt, ",linewidth={}", self.line_width).unwrap();
+        }
+        if self.arrow_size > 0.0 {
+            write!(&mut opt, ",arrowsize={}", self.arrow_size).unwrap();
+        }
+        opt
+    }
+
+    /// Returns options for arrows in 3D (quiver)
+    pub(crate) fn options_quiver_3d(&self) -> String {
+        let mut opt = String::new();
+        if self.color != "" {
+            write!(&mut opt, ",color='{}'", self.color).unwrap();
+        }
+        if self.length > 0.0 {
+            write!(&mut opt, ",length={}", self.length).unwrap();
+        }
+        if self.normalize {
+            write!(&mut opt, ",normalize=True").unwrap();
+        }
+        if self.line_width > 0.0 {
+            write!(&mut opt, ",linewidth={}", self.line_width).unwrap();
+        }
+        match self.pivot.as_str() {
+            "" => (),
+            "mid" => write!(&mut opt, ",pivot='middle'").unwrap(), // Axes3D.quiver does not accept 'mid'
+            pivot => write!(&mut opt, ",pivot='{}'", pivot).unwrap(),
+        }
+        opt
+    }
+
+    /// Returns options for the colorbar
+    pub(crate) fn options_colorbar(&self) -> String {
+        let mut opt = String::new();
+        if self.number_format_cb != "" {
+            write!(&mut opt, ",format='{}'", self.number_format_cb).unwrap();
+        }
+        opt
+    }
+}
+
+/// Writes the colorbar of a vector field colored by magnitude
+fn write_vector_field_colorbar(buf: &mut String, mappable: &str, style: &VectorFieldStyle) {
+    if style.with_colormap && !style.no_colorbar {
+        let opt_colorbar = style.options_colorbar();
+        write!(buf, "cb=plt.colorbar({}{})\n", mappable, &opt_colorbar).unwrap();
+        if style.colorbar_label != "" {
+            write!(buf, "cb.ax.set_ylabel(r'{}')\n", style.colorbar_label).unwrap();
+        }
+    }
+}
+
+/// Writes the Python commands of arrows in 2D (see [Primitive::VectorField])
+pub(crate) fn write_vector_field(
+    buf: &mut Script,
+    x: &Vec<Vec<f64>>,
+    y: &Vec<Vec<f64>>,
+    u: &Vec<Vec<f64>>,
+    v: &Vec<Vec<f64>>,
+    style: &VectorFieldStyle,
+) {
+    matrix_to_array(buf, "x", x);
+    matrix_to_array(buf, "y", y);
+    matrix_to_array(buf, "u", u);
+    matrix_to_array(buf, "v", v);
+    let opt = style.options_quiver();
+    if style.with_colormap {
+        write!(buf, "m=np.sqrt(u**2+v**2)\n").unwrap();
+        write!(buf, "q=plt.quiver(x,y,u,v,m{})\n", &opt).unwrap();
+    } else {
+        write!(buf, "q=plt.quiver(x,y,u,v{})\n", &opt).unwrap();
+    }
+    if style.key_length > 0.0 {
+        let opt_key = style.options_key();
+        write!(buf, "plt.quiverkey(q{})\n", &opt_key).unwrap();
+    }
+    write_vector_field_colorbar(buf, "q", style);
+}
+
+/// Writes the Python commands of streamlines in 2D (see [Primitive::Streamlines])
+pub(crate) fn write_streamlines(
+    buf: &mut Script,
+    x: &Vec<Vec<f64>>,
+    y: &Vec<Vec<f64>>,
+    u: &Vec<Vec<f64>>,
+    v: &Vec<Vec<f64>>,
+    style: &VectorFieldStyle,
+) {
+    matrix_to_array(buf, "x", x);
+    matrix_to_array(buf, "y", y);
+    matrix_to_array(buf, "u", u);
+    matrix_to_array(buf, "v", v);
+    let opt = style.options_streamplot();
+    if style.with_colormap {
+        write!(buf, "m=np.sqrt(u**2+v**2)\n").unwrap();
+        write!(buf, "sp=plt.streamplot(x,y,u,v,color=m{})\n", &opt).unwrap();
+    } else {
+        write!(buf, "sp=plt.streamplot(x,y,u,v{})\n", &opt).unwrap();
+    }
+    write_vector_field_colorbar(buf, "sp.lines", style);
+}
+
+/// Writes the Python commands of arrows in 3D (see [Primitive::VectorField3d])
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn write_vector_field_3d(
+    buf: &mut Script,
+    x: &Vec<Vec<f64>>,
+    y: &Vec<Vec<f64>>,
+    z: &Vec<Vec<f64>>,
+    u: &Vec<Vec<f64>>,
+    v: &Vec<Vec<f64>>,
+    w: &Vec<Vec<f64>>,
+    style: &VectorFieldStyle,
+) {
+    matrix_to_array(buf, "x", x);
+    matrix_to_array(buf, "y", y);
+    matrix_to_array(buf, "z", z);
+    matrix_to_array(buf, "u", u);
+    matrix_to_array(buf, "v", v);
+    matrix_to_array(buf, "w", w);
+    let opt = style.options_quiver_3d();
+    write!(buf, "maybe_create_ax3d()\n").unwrap();
+    write!(buf, "AX3D.quiver(x,y,z,u,v,w{})\n", &opt).unwrap();
+}
+
+impl GraphMaker for VectorField {
+    fn get_buffer<'a>(&'a self) -> &'a String {
+        &self.buffer
+    }
+    fn clear_buffer(&mut self) {
+        self.buffer.clear();
+        self.primitives.clear();
+    }
+    fn get_primitives(&self) -> &[Primitive] {
+        &self.primitives
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::VectorField;
+    use crate::{GraphMaker, Primitive};
+
+    #[test]
+    fn new_works() {
+        let field = VectorField::new();
+        assert_eq!(field.style.color.len(), 0);
+        assert_eq!(field.style.with_colormap, false);
+        assert_eq!(field.style.colormap_index, 0);
+        assert_eq!(field.style.colormap_name.len(), 0);
+        assert_eq!(field.style.no_colorbar, false);
+        assert_eq!(field.style.colorbar_label.len(), 0);
+        assert_eq!(field.style.number_format_cb.len(), 0);
+        assert_eq!(field.style.scale, 0.0);
+        assert_eq!(field.style.width, 0.0);
+        assert_eq!(field.style.pivot.len(), 0);
+        assert_eq!(field.style.key_length, 0.0);
+        assert_eq!(field.style.key_label.len(), 0);
+        assert_eq!(field.style.key_x, 0.85);
+        assert_eq!(field.style.key_y, 1.02);
+        assert_eq!(field.style.density, 0.0);
+        assert_eq!(field.style.line_width, 0.0);
+        assert_eq!(field.style.arrow_size, 0.0);
+        assert_eq!(field.style.length, 0.0);
+        assert_eq!(field.style.normalize, false);
+        assert_eq!(field.buffer.len(), 0);
+        assert_eq!(field.primitives.len(), 0);
+    }
+
+    #[test]
+    fn options_works() {
+        let mut field = VectorField::new();
+        field
+            .set_color("red")
+            .set_scale(10.0)
+            .set_width(0.005)
+            .set_pivot("mid")
+            .set_density(1.5)
+            .set_line_width(2.0)
+            .set_arrow_size(1.2)
+            .set_length(0.5)
+            .set_normalize(true)
+            .set_number_format_cb("%.2f");
+        assert_eq!(
+            field.style.options_quiver(),
+            ",color='red',scale=10,width=0.005,pivot='mid'"
+        );
+        assert_eq!(
+            field.style.options_streamplot(),
+            ",color='red',density=1.5,linewidth=2,arrowsize=1.2"
+        );
+        assert_eq!(
+            field.style.options_quiver_3d(),
+            ",color='red',length=0.5,normalize=True,linewidth=2,pivot='middle'"
+        );
+        assert_eq!(field.style.options_colorbar(), ",format='%.2f'");
+        field.set_with_colormap(true).set_colormap_index(4);
+        assert_eq!(
+            field.style.options_quiver(),
+            ",cmap=get_colormap(4),scale=10,width=0.005,pivot='mid'"
+        );
+        field.set_colormap_name("viridis");
+        assert_eq!(
+            field.style.options_streamplot(),
+            ",cmap=plt.get_cmap('viridis'),density=1.5,linewidth=2,arrowsize=1.2"
+        );
+        field.set_key(2.0, "2 m/s").set_key_position(0.9, 1.05);
+        assert_eq!(
+            field.style.options_key(),
+            ",0.9,1.05,2,r'2 m/s',labelpos='E',coordinates='axes'"
+        );
+    }
+
+    #[test]
+    fn draw_works() {
+        let mut field = VectorField::new();
+        let x = [[0.0, 1.0], [0.0, 1.0]];
+        let y = [[0.0, 0.0], [1.0, 1.0]];
+        let u = [[1.0, 1.0], [1.0, 1.0]];
+        let v = [[0.0, 1.0], [2.0, 3.0]];
+        field
+            .set_with_colormap(true)
+            .set_colorbar_label("speed")
+            .set_key(1.0, "1");
+        field.draw(&x, &y, &u, &v);
+        let b: &str = "x=np.array([[0,1,],[0,1,],],dtype=float)\n\
+                       y=np.array([[0,0,],[1,1,],],dtype=float)\n\
+                       u=np.array([[1,1,],[1,1,],],dtype=float)\n\
+                       v=np.array([[0,1,],[2,3,],],dtype=float)\n\
+                       m=np.sqrt(u**2+v**2)\n\
+                       q=plt.quiver(x,y,u,v,m,cmap=get_colormap(0))\n\
+                       plt.quiverkey(q,0.85,1.02,1,r'1',labelpos='E',coordinates='axes')\n\
+                       cb=plt.colorbar(q)\n\
+                       cb.ax.set_ylabel(r'speed')\n";
+        assert_eq!(field.buffer, b);
+        assert_eq!(
+            field.get_primitives(),
+            &[Primitive::VectorField {
+                x: vec![vec![0.0, 1.0], vec![0.0, 1.0]],
+                y: vec![vec![0.0, 0.0], vec![1.0, 1.0]],
+                u: vec![vec![1.0, 1.0], vec![1.0, 1.0]],
+                v: vec![vec![0.0, 1.0], vec![2.0, 3.0]],
+                style: field.style.clone(),
+            }]
+        );
+        field.clear_buffer();
+        assert_eq!(field.buffer, "");
+        assert_eq!(field.primitives.len(), 0);
+    }
+
+    #[test]
+    fn draw_streamlines_works() {
+        let mut field = VectorField::new();
+        let x = [[0.0, 1.0], [0.0, 1.0]];
+        let y = [[0.0, 0.0], [1.0, 1.0]];
+        field.set_with_colormap(true).set_no_colorbar(true).set_density(2.0);
+        field.draw_streamlines(&x, &y, &y, &x);
+        let b: &str = "x=np.array([[0,1,],[0,1,],],dtype=float)\n\
+                       y=np.array([[0,0,],[1,1,],],dtype=float)\n\
+                       u=np.array([[0,0,],[1,1,],],dtype=float)\n\
+                       v=np.array([[0,1,],[0,1,],],dtype=float)\n\
+                       m=np.sqrt(u**2+v**2)\n\
+                       sp=plt.streamplot(x,y,u,v,color=m,cmap=get_colormap(0),density=2)\n";
+        assert_eq!(field.buffer, b);
+        field.clear_buffer();
+        field.set_with_colormap(false).set_no_colorbar(false).set_color("blue");
+        field.draw_streamlines(&x, &y, &y, &x);
+        let b: &str = "x=np.array([[0,1,],[0,1,],],dtype=float)\n\
+                       y=np.array([[0,0,],[1,1,],],dtype=float)\n\
+                       u=np.array([[0,0,],[1,1,],],dtype=float)\n\
+                       v=np.array([[0,1,],[0,1,],],dtype=float)\n\
+                       sp=plt.streamplot(x,y,u,v,color='blue',density=2)\n";
+        assert_eq!(field.buffer, b);
+    }
+
+    #[test]
+    fn draw_3d_works() {
+        let mut field = VectorField::new();
+        let a = [[1.0]];
+        let b = [[2.0]];
+        field.set_normalize(true);
+        field.draw_3d(&a, &a, &a, &b, &b, &b);
+        let b: &str = "x=np.array([[1,],],dtype=float)\n\
+                       y=np.array([[1,],],dtype=float)\n\
+                       z=np.array([[1,],],dtype=float)\n\
+                       u=np.array([[2,],],dtype=float)\n\
+                       v=np.array([[2,],],dtype=float)\n\
+                       w=np.array([[2,],],dtype=float)\n\
+                       maybe_create_ax3d()\n\
+                       AX3D.quiver(x,y,z,u,v,w,normalize=True)\n";
+        assert_eq!(field.buffer, b);
+    }
+}
@@ -0,0 +1,40 @@
+use plotpy::{Animation, Curve, Error, Plot};
+use std::fs;
+use std::path::Path;
+
+const OUT_DIR: &str = "/tmp/plotpy/integ_tests";
+
+#[test]
+fn test_animation() -> Result<(), Error> {
+    // frames
+    let x = vec![0.0, 1.0, 2.0, 3.0, 4.0];
+    let mut animation = Animation::new();
+    for i in 0..4 {
+        let y: Vec<f64> = x.iter().map(|x| (i as f64) * x).collect();
+        let mut curve = Curve::new();
+        curve.set_marker_style("o");
+        curve.draw(&x, &y);
+        animation.add_frame(&[&curve]);
+    }
+    animation.add_frames(4, |i, frame| {
+        let y: Vec<f64> = x.iter().map(|x| (4 - i) as f64 * x).collect();
+        let mut curve = Curve::new();
+        curve.draw(&x, &y);
+        frame.add(&curve).set_title(&format!("frame {}", 4 + i));
+        Ok(())
+    })?;
+    assert_eq!(animation.num_frames(), 8);
+
+    // shared commands
+    let mut plot = Plot::new();
+    plot.grid_and_labels("x", "y");
+
+    // save animation
+    let path = Path::new(OUT_DIR).join("integ_animation.gif");
+    animation.set_fps(4.0).save(&plot, &path)?;
+
+    // check file
+    let size = fs::metadata(&path).map_err(|_| "cannot open file")?.len();
+    assert!(size > 0);
+    Ok(())
+}
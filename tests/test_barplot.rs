@@ -0,0 +1,80 @@
+use plotpy::{Barplot, Error, Legend, Plot};
+use std::fs::File;
+use std::io::{BufRead, BufReader};
+use std::path::Path;
+
+const OUT_DIR: &str = "/tmp/plotpy/integ_tests";
+
+#[test]
+fn test_barplot_1() -> Result<(), Error> {
+    let mut barplot = Barplot::new();
+    barplot
+        .set_colors(&vec!["#cd0000", "#1862ab", "#cd8c00"])
+        .set_labels(&["first", "second", "third"])
+        .set_edge_color("black")
+        .set_line_width(0.5)
+        .set_with_value_labels(true);
+
+    // draw barplot
+    let categories = ["A", "B", "C", "D"];
+    let values = vec![
+        vec![1.0, 3.0, 2.0, 5.0], // first series
+        vec![2.0, 2.5, 4.0, 1.0], // second series
+        vec![3.0, 1.0, 1.5, 2.0], // third series
+    ];
+    let errors = vec![
+        vec![0.1, 0.3, 0.2, 0.5], // first series
+        vec![0.2, 0.2, 0.4, 0.1], // second series
+        vec![0.3, 0.1, 0.1, 0.2], // third series
+    ];
+    barplot.draw_with_errors(&categories, &values, &errors)?;
+
+    // add barplot to plot
+    let mut legend = Legend::new();
+    legend.draw();
+    let mut plot = Plot::new();
+    plot.add(&barplot).add(&legend);
+
+    // save figure
+    let path = Path::new(OUT_DIR).join("integ_barplot_1.svg");
+    plot.save(&path)?;
+
+    // check number of lines
+    let file = File::open(path).map_err(|_| "cannot open file")?;
+    let buffered = BufReader::new(file);
+    let lines_iter = buffered.lines();
+    assert!(lines_iter.count() > 500);
+    Ok(())
+}
+
+#[test]
+fn test_barplot_2() -> Result<(), Error> {
+    let mut barplot = Barplot::new();
+    barplot
+        .set_labels(&["first", "second"])
+        .set_stacked(true)
+        .set_horizontal(true)
+        .set_width(0.5)
+        .set_with_value_labels(true)
+        .set_value_format("%.1f");
+
+    // draw barplot
+    let categories = ["A", "B", "C"];
+    let values = [[1, 2, 3], [3, 2, 1]];
+    barplot.draw(&categories, &values);
+
+    // add barplot to plot
+    let mut plot = Plot::new();
+    plot.add(&barplot);
+
+    // save figure
+    let path = Path::new(OUT_DIR).join("integ_barplot_2.svg");
+    plot.save(&path)?;
+
+    // check number of lines
+    let file = File::open(path).map_err(|_| "cannot open file")?;
+    let buffered = BufReader::new(file);
+    let lines_iter = buffered.lines();
+    assert!(lines_iter.count() > 350);
+    Ok(())
+}
@@ -0,0 +1,76 @@
+use plotpy::{Boxplot, Error, Plot};
+use std::fs::File;
+use std::io::{BufRead, BufReader};
+use std::path::Path;
+
+const OUT_DIR: &str = "/tmp/plotpy/integ_tests";
+
+#[test]
+fn test_boxplot_1() -> Result<(), Error> {
+    let mut boxplot = Boxplot::new();
+    boxplot
+        .set_colors(&["#cd0000", "#1862ab", "#cd8c00"])
+        .set_notch(true)
+        .set_with_means(true)
+        .set_whisker(1.0)
+        .set_outlier_marker("x");
+
+    // draw box plot
+    let values = vec![
+        vec![12, 15, 14, 13, 18, 16, 15, 14, 30, 13, 15], // first series
+        vec![8, 9, 9, 10, 11, 9, 8, 12, 2],               // second series
+        vec![20, 22, 19, 25, 21, 23, 24, 20, 22],         // third series
+    ];
+    let labels = ["first", "second", "third"];
+    boxplot.draw(&values, &labels);
+
+    // add box plot to plot
+    let mut plot = Plot::new();
+    plot.add(&boxplot);
+
+    // save figure
+    let path = Path::new(OUT_DIR).join("integ_boxplot_1.svg");
+    plot.save(&path)?;
+
+    // check number of lines
+    let file = File::open(path).map_err(|_| "cannot open file")?;
+    let buffered = BufReader::new(file);
+    let lines_iter = buffered.lines();
+    assert!(lines_iter.count() > 600);
+    Ok(())
+}
+
+#[test]
+fn test_boxplot_2() -> Result<(), Error> {
+    let mut boxplot = Boxplot::new();
+    boxplot
+        .set_violin(true)
+        .set_horizontal(true)
+        .set_bandwidth(0.4)
+        .set_with_quartiles(true)
+        .set_median_color("black")
+        .set_colors(&["#9de19a", "#98a7f2"]);
+
+    // draw violin plot
+    let values = vec![
+        vec![0.12, 0.15, 0.14, 0.13, 0.18, 0.16, 0.15, 0.14, 0.30], // first series
+        vec![0.08, 0.09, 0.09, 0.10, 0.11, 0.09, 0.08, 0.12],       // second series
+    ];
+    let labels = ["first", "second"];
+    boxplot.draw(&values, &labels);
+
+    // add violin plot to plot
+    let mut plot = Plot::new();
+    plot.add(&boxplot).set_label_x("runtime [s]");
+
+    // save figure
+    let path = Path::new(OUT_DIR).join("integ_boxplot_2.svg");
+    plot.save(&path)?;
+
+    // check number of lines
+    let file = File::open(path).map_err(|_| "cannot open file")?;
+    let buffered = BufReader::new(file);
+    let lines_iter = buffered.lines();
+    assert!(lines_iter.count() > 600);
+    Ok(())
+}
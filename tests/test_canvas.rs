@@ -1,4 +1,4 @@
-use plotpy::{Canvas, Curve, Plot, PolyCode, StrError};
+use plotpy::{Canvas, Curve, Error, Plot, PolyCode};
 use std::fs::File;
 use std::io::{BufRead, BufReader};
 use std::path::Path;
@@ -6,7 +6,7 @@ use std::path::Path;
 const OUT_DIR: &str = "/tmp/plotpy/integ_tests";
 
 #[test]
-fn test_canvas() -> Result<(), StrError> {
+fn test_canvas() -> Result<(), Error> {
     // canvas object and common options
     let mut canvas = Canvas::new();
     canvas.set_edge_color("#cd0000").set_face_color("#1862ab");
@@ -49,7 +49,7 @@ fn test_canvas() -> Result<(), StrError> {
 }
 
 #[test]
-fn test_canvas_grid_2d() -> Result<(), StrError> {
+fn test_canvas_grid_2d() -> Result<(), Error> {
     // canvas object and common options
     let mut s2d = Canvas::new();
     s2d.draw_grid(&[-0.2, -0.2], &[0.8, 1.8], &[5, 5], true, true)?;
@@ -72,7 +72,7 @@ fn test_canvas_grid_2d() -> Result<(), StrError> {
 }
 
 #[test]
-fn test_canvas_grid_3d() -> Result<(), StrError> {
+fn test_canvas_grid_3d() -> Result<(), Error> {
     // canvas object and common options
     let mut s3d = Canvas::new();
     s3d.draw_grid(&[-1.0, -1.0, -1.0], &[1.0, 1.0, 1.0], &[2, 2, 2], true, true)?;
@@ -95,7 +95,97 @@ fn test_canvas_grid_3d() -> Result<(), StrError> {
 }
 
 #[test]
-fn test_canvas_polyline_3d_methods() -> Result<(), StrError> {
+fn test_canvas_mesh_2d() -> Result<(), Error> {
+    // mixed mesh: two qua8 (left), two tri6 with a curved edge (bottom right), two tri3 (top right)
+    //
+    //  6-----14-----7-----------8
+    //  |            | [5]     / |
+    //  15   [1]     13      /   |
+    //  |            |     / [4] |
+    //  3-----11-----4-----19----5
+    //  |            | \   [3]   |
+    //  12   [0]     10  17      18
+    //  |            | [2] \     |
+    //  0-----9------1-----16----2
+    #[rustfmt::skip]
+    let points = [
+        [0.0, 0.0], [1.0, 0.0], [2.0, 0.0],
+        [0.0, 1.0], [1.0, 1.0], [2.0, 1.0],
+        [0.0, 2.0], [1.0, 2.0], [2.0, 2.0],
+        [0.5, 0.0], [1.0, 0.5], [0.5, 1.0], [0.0, 0.5],
+        [1.0, 1.5], [0.5, 2.0], [0.0, 1.5],
+        [1.5, 0.0], [1.6, 0.6], [2.0, 0.5], [1.5, 1.0],
+    ];
+    let cells = vec![
+        vec![0, 1, 4, 3, 9, 10, 11, 12],
+        vec![3, 4, 7, 6, 11, 13, 14, 15],
+        vec![1, 2, 4, 16, 17, 10],
+        vec![2, 5, 4, 18, 19, 17],
+        vec![4, 5, 8],
+        vec![4, 8, 7],
+    ];
+
+    // canvas object and options
+    let mut canvas = Canvas::new();
+    canvas
+        .set_edge_color("black")
+        .set_cell_colors(&["#e6f0fa", "#fae6e6", "#eaf5e0"])
+        .set_boundary_color("#1862ab")
+        .set_boundary_line_width(3.0);
+    canvas.draw_mesh(&points, &cells, Some(&[0, 0, 1, 1, 2, 2]), true, true)?;
+
+    // add canvas to plot
+    let mut plot = Plot::new();
+    plot.add(&canvas);
+
+    // save figure
+    let path = Path::new(OUT_DIR).join("integ_canvas_mesh_2d.svg");
+    plot.set_equal_axes(true).set_hide_axes(true);
+    plot.save(&path)?;
+
+    // check number of lines
+    let file = File::open(path).map_err(|_| "cannot open file")?;
+    let buffered = BufReader::new(file);
+    let lines_iter = buffered.lines();
+    assert!(lines_iter.count() > 600);
+    Ok(())
+}
+
+#[test]
+fn test_canvas_mesh_3d() -> Result<(), Error> {
+    // one hex8 and two tet4 on top of it
+    #[rustfmt::skip]
+    let points = [
+        [0.0, 0.0, 0.0], [1.0, 0.0, 0.0], [1.0, 1.0, 0.0], [0.0, 1.0, 0.0],
+        [0.0, 0.0, 1.0], [1.0, 0.0, 1.0], [1.0, 1.0, 1.0], [0.0, 1.0, 1.0],
+        [0.5, 0.5, 1.8],
+    ];
+    let cells = vec![vec![0, 1, 2, 3, 4, 5, 6, 7], vec![4, 5, 6, 8], vec![4, 6, 7, 8]];
+
+    // canvas object and options
+    let mut canvas = Canvas::new();
+    canvas.set_boundary_color("#cd0000").set_boundary_line_width(2.0);
+    canvas.draw_mesh(&points, &cells, None, true, true)?;
+
+    // add canvas to plot
+    let mut plot = Plot::new();
+    plot.add(&canvas);
+
+    // save figure
+    let path = Path::new(OUT_DIR).join("integ_canvas_mesh_3d.svg");
+    plot.set_equal_axes(true);
+    plot.save(&path)?;
+
+    // check number of lines
+    let file = File::open(path).map_err(|_| "cannot open file")?;
+    let buffered = BufReader::new(file);
+    let lines_iter = buffered.lines();
+    assert!(lines_iter.count() > 600);
+    Ok(())
+}
+
+#[test]
+fn test_canvas_polyline_3d_methods() -> Result<(), Error> {
     //           .   .  .   . ,.2|
     //         ' .           ,,'||
     //       '   .         ,,'  ||
@@ -150,7 +240,7 @@ fn test_canvas_polyline_3d_methods() -> Result<(), StrError> {
 }
 
 #[test]
-fn test_canvas_polyline_3d() -> Result<(), StrError> {
+fn test_canvas_polyline_3d() -> Result<(), Error> {
     //           .   .  .   . ,.2|
     //         ' .           ,,'||
     //       '   .         ,,'  ||
@@ -205,7 +295,7 @@ fn test_canvas_polyline_3d() -> Result<(), StrError> {
 }
 
 #[test]
-fn test_canvas_polycurve_quadratic() -> Result<(), StrError> {
+fn test_canvas_polycurve_quadratic() -> Result<(), Error> {
     // coordinates of control points
     let points = &[[0.0, 0.0], [1.0, 0.0], [1.0, 1.0]];
 
@@ -243,7 +333,7 @@ fn test_canvas_polycurve_quadratic() -> Result<(), StrError> {
 }
 
 #[test]
-fn test_canvas_polycurve_cubic() -> Result<(), StrError> {
+fn test_canvas_polycurve_cubic() -> Result<(), Error> {
     // coordinates of control points
     let x = &[1.58, 0.35, -1.75, 0.375, 0.85, 2.2, 3.0, 2.0];
     let y = &[-2.57, -1.1, 2.0, 2.0, 1.15, 3.2, 0.05, -0.5];
@@ -292,7 +382,7 @@ fn test_canvas_polycurve_cubic() -> Result<(), StrError> {
 }
 
 #[test]
-fn test_canvas_polycurve_methods() -> Result<(), StrError> {
+fn test_canvas_polycurve_methods() -> Result<(), Error> {
     // coordinates of control points
     let x = &[1.58, 0.35, -1.75, 0.375, 0.85, 2.2, 3.0, 2.0];
     let y = &[-2.57, -1.1, 2.0, 2.0, 1.15, 3.2, 0.05, -0.5];
@@ -1,4 +1,4 @@
-use plotpy::{Contour, Plot, StrError};
+use plotpy::{Contour, Error, Plot};
 use russell_lab::generate3d;
 use std::fs::File;
 use std::io::{BufRead, BufReader};
@@ -7,7 +7,7 @@ use std::path::Path;
 const OUT_DIR: &str = "/tmp/plotpy/integ_tests";
 
 #[test]
-fn test_contour() -> Result<(), StrError> {
+fn test_contour() -> Result<(), Error> {
     // contour object and options
     let mut contour = Contour::new();
     contour
@@ -43,7 +43,7 @@ fn test_contour() -> Result<(), StrError> {
 }
 
 #[test]
-fn test_contour_colors() -> Result<(), StrError> {
+fn test_contour_colors() -> Result<(), Error> {
     // contour object and options
     let mut contour = Contour::new();
     contour
@@ -76,7 +76,7 @@ fn test_contour_colors() -> Result<(), StrError> {
 }
 
 #[test]
-fn test_contour_colormap_index() -> Result<(), StrError> {
+fn test_contour_colormap_index() -> Result<(), Error> {
     for index in 0..10 {
         // contour object and options
         let mut contour = Contour::new();
@@ -111,7 +111,7 @@ fn test_contour_colormap_index() -> Result<(), StrError> {
 }
 
 #[test]
-fn test_contour_colormap_name() -> Result<(), StrError> {
+fn test_contour_colormap_name() -> Result<(), Error> {
     for name in ["Pastel1", "tab20c", "gnuplot2"] {
         // contour object and options
         let mut contour = Contour::new();
@@ -144,3 +144,86 @@ fn test_contour_colormap_name() -> Result<(), StrError> {
     }
     Ok(())
 }
+
+/// Generates a triangle mesh of a quarter of an annulus
+fn annulus_mesh(nr: usize, na: usize) -> (Vec<f64>, Vec<f64>, Vec<[usize; 3]>) {
+    let (mut x, mut y, mut triangles) = (Vec::new(), Vec::new(), Vec::new());
+    for j in 0..na {
+        let a = (j as f64) * std::f64::consts::FRAC_PI_2 / ((na - 1) as f64);
+        for i in 0..nr {
+            let r = 1.0 + (i as f64) / ((nr - 1) as f64);
+            x.push(r * f64::cos(a));
+            y.push(r * f64::sin(a));
+        }
+    }
+    for j in 0..(na - 1) {
+        for i in 0..(nr - 1) {
+            let p = i + j * nr;
+            triangles.push([p, p + 1, p + nr + 1]);
+            triangles.push([p, p + nr + 1, p + nr]);
+        }
+    }
+    (x, y, triangles)
+}
+
+#[test]
+fn test_contour_triangles() -> Result<(), Error> {
+    // mesh and nodal values
+    let (x, y, triangles) = annulus_mesh(6, 11);
+    let z: Vec<f64> = x.iter().zip(&y).map(|(x, y)| x * y).collect();
+
+    // contour object and options
+    let mut contour = Contour::new();
+    contour
+        .set_colormap_name("viridis")
+        .set_levels(&[0.0, 0.25, 0.5, 0.75, 1.0, 1.5, 2.0])
+        .set_colorbar_label("xy")
+        .set_selected_level(0.5, true);
+
+    // draw contour
+    contour.draw_triangles(&x, &y, &z, &triangles)?;
+
+    // add contour to plot
+    let mut plot = Plot::new();
+    plot.add(&contour).set_equal_axes(true);
+
+    // save figure
+    let path = Path::new(OUT_DIR).join("integ_contour_triangles.svg");
+    plot.save(&path)?;
+
+    // check number of lines
+    let file = File::open(path).map_err(|_| "cannot open file")?;
+    let buffered = BufReader::new(file);
+    let lines_iter = buffered.lines();
+    assert!(lines_iter.count() > 1000);
+    Ok(())
+}
+
+#[test]
+fn test_contour_tripcolor() -> Result<(), Error> {
+    // mesh and nodal values
+    let (x, y, triangles) = annulus_mesh(6, 11);
+    let z: Vec<f64> = x.iter().zip(&y).map(|(x, y)| x * y).collect();
+
+    // contour object and options
+    let mut contour = Contour::new();
+    contour.set_colormap_index(3).set_colorbar_label("xy");
+
+    // draw colored triangles
+    contour.draw_tripcolor(&x, &y, &z, &triangles)?;
+
+    // add contour to plot
+    let mut plot = Plot::new();
+    plot.add(&contour).set_equal_axes(true);
+
+    // save figure
+    let path = Path::new(OUT_DIR).join("integ_contour_tripcolor.svg");
+    plot.save(&path)?;
+
+    // check number of lines
+    let file = File::open(path).map_err(|_| "cannot open file")?;
+    let buffered = BufReader::new(file);
+    let lines_iter = buffered.lines();
+    assert!(lines_iter.count() > 500);
+    Ok(())
+}
@@ -1,4 +1,4 @@
-use plotpy::{Curve, Plot, RayEndpoint, StrError};
+use plotpy::{Curve, Error, Plot, RayEndpoint};
 use std::fs::File;
 use std::io::{BufRead, BufReader};
 use std::path::Path;
@@ -6,7 +6,7 @@ use std::path::Path;
 const OUT_DIR: &str = "/tmp/plotpy/integ_tests";
 
 #[test]
-fn test_curve() -> Result<(), StrError> {
+fn test_curve() -> Result<(), Error> {
     // curve object and options
     let mut curve1 = Curve::new();
     curve1
@@ -75,7 +75,97 @@ fn test_curve() -> Result<(), StrError> {
 }
 
 #[test]
-fn test_curve_points_methods_work() -> Result<(), StrError> {
+fn test_curve_error_bars_and_band() -> Result<(), Error> {
+    // data
+    let x = vec![1.0, 2.0, 3.0, 4.0, 5.0];
+    let y = vec![1.2, 3.9, 9.1, 16.3, 24.8];
+    let y_err = vec![0.5, 0.8, 1.0, 1.5, 2.0];
+    let x_lower = vec![0.1, 0.2, 0.1, 0.3, 0.2];
+    let x_upper = vec![0.2, 0.1, 0.3, 0.1, 0.2];
+    let model: Vec<f64> = x.iter().map(|x| x * x).collect();
+    let lower: Vec<f64> = x.iter().map(|x| x * x - 2.0).collect();
+    let upper: Vec<f64> = x.iter().map(|x| x * x + 2.0).collect();
+
+    // curve with error bars
+    let mut curve1 = Curve::new();
+    curve1
+        .set_label("measured")
+        .set_line_style("None")
+        .set_marker_style("o")
+        .set_error_capsize(3.0)
+        .set_error_line_width(1.0);
+    curve1.draw_with_error_bars(&x, &y, &[&x_lower, &x_upper], &[&y_err])?;
+
+    // curve with band
+    let mut curve2 = Curve::new();
+    curve2.set_label("model").set_line_color("#1862ab").set_band_alpha(0.2);
+    curve2.draw_with_band(&x, &model, &lower, &upper)?;
+
+    // add curves to plot
+    let mut plot = Plot::new();
+    plot.add(&curve1).add(&curve2).legend();
+
+    // save figure
+    let path = Path::new(OUT_DIR).join("integ_curve_error_bars_and_band.svg");
+    plot.save(&path)?;
+
+    // check number of lines
+    let file = File::open(path).map_err(|_| "cannot open file")?;
+    let buffered = BufReader::new(file);
+    let lines_iter = buffered.lines();
+    assert!(lines_iter.count() > 600);
+    Ok(())
+}
+
+#[test]
+fn test_curve_step_stem_and_stairs() -> Result<(), Error> {
+    // data
+    let x: Vec<f64> = (0..12).map(|i| i as f64).collect();
+    let y: Vec<f64> = x.iter().map(|x| f64::sin(x / 2.0)).collect();
+    let edges: Vec<f64> = (0..7).map(|i| 2.0 * i as f64).collect();
+    let values = vec![0.5, 1.0, 0.2, -0.4, -0.8, -0.1];
+
+    // step curve
+    let mut curve1 = Curve::new();
+    curve1.set_label("step (post)").set_step("post");
+    curve1.draw(&x, &y);
+
+    // stems
+    let mut curve2 = Curve::new();
+    curve2
+        .set_label("stem")
+        .set_line_color("#cd0000")
+        .set_marker_void(true)
+        .set_stem_base_color("gray");
+    curve2.draw_stem(&x, &y)?;
+
+    // stairs
+    let mut curve3 = Curve::new();
+    curve3
+        .set_label("stairs")
+        .set_line_color("#1862ab")
+        .set_line_alpha(0.3)
+        .set_stairs_fill(true);
+    curve3.draw_stairs(&edges, &values)?;
+
+    // add curves to plot
+    let mut plot = Plot::new();
+    plot.add(&curve1).add(&curve2).add(&curve3).legend();
+
+    // save figure
+    let path = Path::new(OUT_DIR).join("integ_curve_step_stem_and_stairs.svg");
+    plot.save(&path)?;
+
+    // check number of lines
+    let file = File::open(path).map_err(|_| "cannot open file")?;
+    let buffered = BufReader::new(file);
+    let lines_iter = buffered.lines();
+    assert!(lines_iter.count() > 700);
+    Ok(())
+}
+
+#[test]
+fn test_curve_points_methods_work() -> Result<(), Error> {
     // add points
     let mut curve = Curve::new();
     curve
@@ -102,7 +192,7 @@ fn test_curve_points_methods_work() -> Result<(), StrError> {
 }
 
 #[test]
-fn test_curve_points_3d_methods_work() -> Result<(), StrError> {
+fn test_curve_points_3d_methods_work() -> Result<(), Error> {
     // add points
     let mut curve = Curve::new();
     curve
@@ -130,7 +220,7 @@ fn test_curve_points_3d_methods_work() -> Result<(), StrError> {
 }
 
 #[test]
-fn test_curve_3d() -> Result<(), StrError> {
+fn test_curve_3d() -> Result<(), Error> {
     // curve object and options
     let mut curve = Curve::new();
     curve
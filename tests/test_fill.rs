@@ -0,0 +1,91 @@
+use plotpy::{Curve, Error, Fill, Plot};
+use std::fs::File;
+use std::io::{BufRead, BufReader};
+use std::path::Path;
+
+const OUT_DIR: &str = "/tmp/plotpy/integ_tests";
+
+#[test]
+fn test_fill_1() -> Result<(), Error> {
+    // data
+    let x: Vec<f64> = (0..61).map(|i| (i as f64) / 10.0).collect();
+    let y1: Vec<f64> = x.iter().map(|x| f64::sin(*x)).collect();
+    let y2: Vec<f64> = x.iter().map(|x| 0.5 * f64::cos(*x)).collect();
+    let mask: Vec<bool> = y1.iter().zip(&y2).map(|(a, b)| a > b).collect();
+
+    // curves
+    let mut curve = Curve::new();
+    curve.set_line_color("black");
+    curve.draw(&x, &y1);
+    curve.draw(&x, &y2);
+
+    // areas
+    let mut fill = Fill::new();
+    fill.set_face_color("#9de19a")
+        .set_label("y1 > y2")
+        .set_interpolate(true);
+    fill.draw_where(&x, &y1, &y2, &mask)?;
+    fill.set_face_color("none")
+        .set_edge_color("#cd0000")
+        .set_hatch("xx")
+        .set_label("y1 < 0")
+        .set_interpolate(false);
+    let negative: Vec<bool> = y1.iter().map(|y| *y < 0.0).collect();
+    fill.draw_where(&x, &y1, &vec![0.0; x.len()], &negative)?;
+
+    // add curves and areas to plot
+    let mut plot = Plot::new();
+    plot.add(&curve).add(&fill).legend();
+
+    // save figure
+    let path = Path::new(OUT_DIR).join("integ_fill_1.svg");
+    plot.save(&path)?;
+
+    // check number of lines
+    let file = File::open(path).map_err(|_| "cannot open file")?;
+    let buffered = BufReader::new(file);
+    let lines_iter = buffered.lines();
+    assert!(lines_iter.count() > 600);
+    Ok(())
+}
+
+#[test]
+fn test_fill_2() -> Result<(), Error> {
+    // horizontal area
+    let y = vec![0.0, 1.0, 2.0, 3.0, 4.0];
+    let x = vec![1.0, 2.0, 1.5, 3.0, 2.5];
+    let mut fill1 = Fill::new();
+    fill1.set_horizontal(true).set_alpha(0.5);
+    fill1.draw_to_value(&y, &x, 0.0);
+
+    // stacked areas
+    let t = vec![0.0, 1.0, 2.0, 3.0, 4.0];
+    let series = vec![
+        vec![1.0, 2.0, 3.0, 2.0, 1.0], // first series
+        vec![2.0, 1.0, 1.0, 2.0, 3.0], // second series
+        vec![0.5, 0.5, 1.0, 1.0, 0.5], // third series
+    ];
+    let mut fill2 = Fill::new();
+    fill2
+        .set_colors(&["#cd0000", "#1862ab", "#cd8c00"])
+        .set_labels(&["first", "second", "third"])
+        .set_edge_color("white")
+        .set_line_width(0.5);
+    fill2.draw_stacked(&t, &series);
+
+    // add areas to plot
+    let mut plot = Plot::new();
+    plot.set_subplot(1, 2, 1).add(&fill1);
+    plot.set_subplot(1, 2, 2).add(&fill2).legend();
+
+    // save figure
+    let path = Path::new(OUT_DIR).join("integ_fill_2.svg");
+    plot.save(&path)?;
+
+    // check number of lines
+    let file = File::open(path).map_err(|_| "cannot open file")?;
+    let buffered = BufReader::new(file);
+    let lines_iter = buffered.lines();
+    assert!(lines_iter.count() > 700);
+    Ok(())
+}
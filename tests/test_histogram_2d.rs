@@ -0,0 +1,65 @@
+use plotpy::{Error, Histogram2d, Plot};
+use std::fs::File;
+use std::io::{BufRead, BufReader};
+use std::path::Path;
+
+const OUT_DIR: &str = "/tmp/plotpy/integ_tests";
+
+/// Returns correlated samples from a deterministic pseudo-random sequence
+fn correlated_samples(n: usize) -> (Vec<f64>, Vec<f64>) {
+    let mut x = Vec::with_capacity(n);
+    let mut y = Vec::with_capacity(n);
+    for i in 0..n {
+        let a = f64::sin(i as f64 * 12.9898) * 43758.5453;
+        let b = f64::sin(i as f64 * 78.233) * 12345.6789;
+        let (u, v) = (a - a.floor() - 0.5, b - b.floor() - 0.5);
+        x.push(u + v);
+        y.push(u - 0.5 * v);
+    }
+    (x, y)
+}
+
+#[test]
+fn test_histogram_2d() -> Result<(), Error> {
+    let (x, y) = correlated_samples(3000);
+
+    // rectangular bins
+    let mut histogram1 = Histogram2d::new();
+    histogram1
+        .set_number_bins(25, 20)
+        .set_colormap_name("viridis")
+        .set_min_count(1)
+        .set_colorbar_label("counts");
+    histogram1.draw(&x, &y)?;
+
+    // hexagonal bins
+    let mut histogram2 = Histogram2d::new();
+    histogram2
+        .set_number_bins(20, 12)
+        .set_colormap_index(4)
+        .set_log_scale(true)
+        .set_number_format_cb("%.0f")
+        .set_colorbar_label("counts (log scale)");
+    histogram2.draw_hexbin(&x, &y)?;
+
+    // add histograms to plot
+    let mut plot = Plot::new();
+    plot.set_subplot(1, 2, 1)
+        .add(&histogram1)
+        .set_labels("x", "y")
+        .set_subplot(1, 2, 2)
+        .add(&histogram2)
+        .set_labels("x", "y")
+        .set_figure_size_points(800.0, 300.0);
+
+    // save figure
+    let path = Path::new(OUT_DIR).join("integ_histogram_2d.svg");
+    plot.save(&path)?;
+
+    // check number of lines
+    let file = File::open(path).map_err(|_| "cannot open file")?;
+    let buffered = BufReader::new(file);
+    let lines_iter = buffered.lines();
+    assert!(lines_iter.count() > 1000);
+    Ok(())
+}
@@ -0,0 +1,89 @@
+use plotpy::{Error, Image, Plot};
+use std::fs::File;
+use std::io::{BufRead, BufReader};
+use std::path::Path;
+
+const OUT_DIR: &str = "/tmp/plotpy/integ_tests";
+
+#[test]
+fn test_image_1() -> Result<(), Error> {
+    // stiffness-like matrix
+    let data = vec![
+        vec![4.0, -1.0, 0.0, -1.0, 0.0],
+        vec![-1.0, 4.0, -1.0, 0.0, -1.0],
+        vec![0.0, -1.0, 4.0, -1.0, 0.0],
+        vec![-1.0, 0.0, -1.0, 4.0, -1.0],
+        vec![0.0, -1.0, 0.0, -1.0, 4.0],
+    ];
+
+    // configure and draw image
+    let mut image = Image::new();
+    image
+        .set_colormap_name("RdBu_r")
+        .set_norm("diverging")
+        .set_interpolation("nearest")
+        .set_colorbar_label("K[i,j]")
+        .set_with_annotations(true)
+        .set_number_format_annotations(".0f")
+        .set_annotations_fontsize(8.0);
+    image.draw(&data);
+
+    // add image to plot
+    let mut plot = Plot::new();
+    plot.add(&image);
+
+    // save figure
+    let path = Path::new(OUT_DIR).join("integ_image_1.svg");
+    plot.save(&path)?;
+
+    // check number of lines
+    let file = File::open(path).map_err(|_| "cannot open file")?;
+    let buffered = BufReader::new(file);
+    let lines_iter = buffered.lines();
+    assert!(lines_iter.count() > 600);
+    Ok(())
+}
+
+#[test]
+fn test_image_2() -> Result<(), Error> {
+    // data
+    let data = [[1.0, 10.0, 100.0], [1000.0, 100.0, 10.0]];
+
+    // image with extent and origin
+    let mut image1 = Image::new();
+    image1
+        .set_colormap_index(4)
+        .set_norm("log")
+        .set_extent(&[0.0, 3.0, 0.0, 1.0])
+        .set_origin("lower")
+        .set_number_format_cb("%.0e")
+        .set_with_annotations(true)
+        .set_number_format_annotations(".0f");
+    image1.draw(&data);
+
+    // image with non-uniform cells
+    let mut image2 = Image::new();
+    image2
+        .set_colormap_name("viridis")
+        .set_norm("log")
+        .set_no_colorbar(true)
+        .set_with_annotations(true)
+        .set_annotations_color("red");
+    image2.draw_mesh(&vec![0.0, 1.0, 3.0, 6.0], &vec![0.0, 1.0, 3.0], &data);
+
+    // add images to plot
+    let mut plot = Plot::new();
+    plot.set_subplot(1, 2, 1).add(&image1);
+    plot.set_subplot(1, 2, 2).add(&image2);
+
+    // save figure
+    let path = Path::new(OUT_DIR).join("integ_image_2.svg");
+    plot.save(&path)?;
+
+    // check number of lines
+    let file = File::open(path).map_err(|_| "cannot open file")?;
+    let buffered = BufReader::new(file);
+    let lines_iter = buffered.lines();
+    assert!(lines_iter.count() > 700);
+    Ok(())
+}
@@ -0,0 +1,34 @@
+use plotpy::{Curve, Error, PdfReport, Plot};
+use std::fs;
+use std::path::Path;
+
+const OUT_DIR: &str = "/tmp/plotpy/integ_tests";
+
+#[test]
+fn test_pdf_report() -> Result<(), Error> {
+    // pages
+    let x = &[0.0, 1.0, 2.0, 3.0, 4.0];
+    let y = &[0.0, 1.0, 4.0, 9.0, 16.0];
+    let mut curve = Curve::new();
+    curve.draw(x, y);
+    let mut plot1 = Plot::new();
+    plot1.add(&curve).grid_and_labels("x", "y");
+    let mut plot2 = Plot::new();
+    plot2.add(&curve).set_figure_size_inches(8.0, 3.0).set_npy_threshold(5);
+
+    // save report
+    let mut report = PdfReport::new();
+    report
+        .set_title("Integration test")
+        .set_author("plotpy")
+        .set_subject("multi-page report")
+        .add_page(&plot1, "First page")
+        .add_page(&plot2, "Second page");
+    let path = Path::new(OUT_DIR).join("integ_pdf_report.pdf");
+    report.save(&path)?;
+
+    // check file
+    let size = fs::metadata(&path).map_err(|_| "cannot open file")?.len();
+    assert!(size > 0);
+    Ok(())
+}
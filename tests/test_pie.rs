@@ -0,0 +1,68 @@
+use plotpy::{Error, Pie, Plot};
+use std::fs::File;
+use std::io::{BufRead, BufReader};
+use std::path::Path;
+
+const OUT_DIR: &str = "/tmp/plotpy/integ_tests";
+
+#[test]
+fn test_pie_1() -> Result<(), Error> {
+    let mut pie = Pie::new();
+    pie.set_colors(&["#cd0000", "#1862ab", "#cd8c00", "#7a7a7a"])
+        .set_explode(&[0.1, 0.0, 0.0, 0.0])
+        .set_percent_format("%.1f%%")
+        .set_start_angle(90.0)
+        .set_clockwise(true);
+
+    // draw pie
+    let values = [45, 25, 20, 10];
+    let labels = ["solver", "assembly", "output", "other"];
+    pie.draw(&values, &labels);
+
+    // add pie to plot
+    let mut plot = Plot::new();
+    plot.add(&pie).set_equal_axes(true);
+
+    // save figure
+    let path = Path::new(OUT_DIR).join("integ_pie_1.svg");
+    plot.save(&path)?;
+
+    // check number of lines
+    let file = File::open(path).map_err(|_| "cannot open file")?;
+    let buffered = BufReader::new(file);
+    let lines_iter = buffered.lines();
+    assert!(lines_iter.count() > 400);
+    Ok(())
+}
+
+#[test]
+fn test_pie_2() -> Result<(), Error> {
+    // donut charts in subplots
+    let values = [
+        [60.0, 30.0, 10.0], // first chart
+        [20.0, 50.0, 30.0], // second chart
+    ];
+    let labels = ["cpu", "memory", "disk"];
+    let mut plot = Plot::new();
+    for (i, v) in values.iter().enumerate() {
+        let mut pie = Pie::new();
+        pie.set_percent_format("%.0f%%")
+            .set_hole(0.5)
+            .set_edge_color("white")
+            .set_line_width(2.0)
+            .set_with_legend(i == 1);
+        pie.draw(v, &labels);
+        plot.set_subplot(1, 2, i + 1).add(&pie).set_equal_axes(true);
+    }
+
+    // save figure
+    let path = Path::new(OUT_DIR).join("integ_pie_2.svg");
+    plot.save(&path)?;
+
+    // check number of lines
+    let file = File::open(path).map_err(|_| "cannot open file")?;
+    let buffered = BufReader::new(file);
+    let lines_iter = buffered.lines();
+    assert!(lines_iter.count() > 600);
+    Ok(())
+}
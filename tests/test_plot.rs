@@ -1,4 +1,4 @@
-use plotpy::{Curve, Plot, StrError};
+use plotpy::{Curve, Error, Plot};
 use russell_lab::Vector;
 use std::f64::consts::PI;
 use std::fs::File;
@@ -8,7 +8,7 @@ use std::path::Path;
 const OUT_DIR: &str = "/tmp/plotpy/integ_tests";
 
 #[test]
-fn test_plot() -> Result<(), StrError> {
+fn test_plot() -> Result<(), Error> {
     // curve object and options
     let mut curve = Curve::new();
 
@@ -70,11 +70,42 @@ fn test_plot() -> Result<(), StrError> {
 fn test_plot_error() {
     let plot = Plot::new();
     let path = Path::new(OUT_DIR).join("integ_plot_error.xyz");
-    assert_eq!(plot.save(&path).err(), Some("python3 failed; please see the log file"));
+    match plot.save(&path).err() {
+        Some(Error::PythonFailed { output, exception, .. }) => {
+            assert_eq!(exception, Some("ValueError".to_string()));
+            assert!(output.contains("xyz"));
+        }
+        _ => panic!("python3 should have failed"),
+    }
+}
+
+#[test]
+fn test_plot_render_to_bytes() -> Result<(), Error> {
+    // curve object and options
+    let mut curve = Curve::new();
+
+    // draw curve
+    let x = &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0];
+    let y = &[1.0, 4.0, 9.0, 16.0, 25.0, 36.0, 49.0, 64.0];
+    curve.draw(x, y);
+
+    // add curve to plot
+    let mut plot = Plot::new();
+    plot.add(&curve).grid_and_labels("x", "y");
+
+    // render svg
+    let svg = plot.render_to_bytes("svg")?;
+    let svg = String::from_utf8(svg).map_err(|_| "cannot convert svg to string")?;
+    assert!(svg.lines().count() > 800);
+
+    // render png
+    let png = plot.render_to_bytes("png")?;
+    assert_eq!(&png[0..4], b"\x89PNG");
+    Ok(())
 }
 
 #[test]
-fn test_plot_subplots() -> Result<(), StrError> {
+fn test_plot_subplots() -> Result<(), Error> {
     // curve object and options
     let mut curve = Curve::new();
 
@@ -113,7 +144,7 @@ fn test_plot_subplots() -> Result<(), StrError> {
 }
 
 #[test]
-fn test_plot_log() -> Result<(), StrError> {
+fn test_plot_log() -> Result<(), Error> {
     // curves
     let mut curve1 = Curve::new();
     let mut curve2 = Curve::new();
@@ -165,7 +196,7 @@ fn test_plot_log() -> Result<(), StrError> {
 }
 
 #[test]
-fn test_plot_multiple_of_pi() -> Result<(), StrError> {
+fn test_plot_multiple_of_pi() -> Result<(), Error> {
     // configure curve
     let mut cos_curve = Curve::new();
     let mut sin_curve = Curve::new();
@@ -0,0 +1,81 @@
+use plotpy::{Error, Legend, Plot, Scatter};
+use std::fs::File;
+use std::io::{BufRead, BufReader};
+use std::path::Path;
+
+const OUT_DIR: &str = "/tmp/plotpy/integ_tests";
+
+#[test]
+fn test_scatter() -> Result<(), Error> {
+    // data
+    let x: Vec<f64> = (0..20).map(|i| (i as f64) / 2.0).collect();
+    let y: Vec<f64> = x.iter().map(|x| f64::sin(*x)).collect();
+    let c: Vec<f64> = x.iter().map(|x| x * x).collect();
+    let s: Vec<f64> = (0..20).map(|i| (1 + i % 4) as f64).collect();
+
+    // scatter with mapped colors and sizes
+    let mut scatter1 = Scatter::new();
+    scatter1
+        .set_colormap_name("viridis")
+        .set_colorbar_label("x²")
+        .set_number_format_cb("%.1f")
+        .set_size_scale(20.0)
+        .set_size_legend(4, "class")
+        .set_edge_color("black")
+        .set_line_width(0.5);
+    scatter1.draw(&x, &y, Some(&c), Some(&s))?;
+
+    // scatter with solid color
+    let mut scatter2 = Scatter::new();
+    scatter2
+        .set_color("red")
+        .set_marker_style("^")
+        .set_marker_size(40.0)
+        .set_label("reference");
+    scatter2.draw(&vec![0.0, 5.0, 9.0], &vec![0.0, 0.0, 0.0], None, None)?;
+
+    // add scatters and legend to plot
+    let mut legend = Legend::new();
+    legend.set_location("lower left").draw();
+    let mut plot = Plot::new();
+    plot.add(&scatter1).add(&scatter2).add(&legend);
+
+    // save figure
+    let path = Path::new(OUT_DIR).join("integ_scatter.svg");
+    plot.save(&path)?;
+
+    // check number of lines
+    let file = File::open(path).map_err(|_| "cannot open file")?;
+    let buffered = BufReader::new(file);
+    let lines_iter = buffered.lines();
+    assert!(lines_iter.count() > 900);
+    Ok(())
+}
+
+#[test]
+fn test_scatter_3d() -> Result<(), Error> {
+    // data
+    let t: Vec<f64> = (0..40).map(|i| (i as f64) / 4.0).collect();
+    let x: Vec<f64> = t.iter().map(|t| f64::cos(*t)).collect();
+    let y: Vec<f64> = t.iter().map(|t| f64::sin(*t)).collect();
+
+    // scatter in 3D
+    let mut scatter = Scatter::new();
+    scatter.set_colormap_index(3).set_alpha(0.8);
+    scatter.draw_3d(&x, &y, &t, Some(&t), None)?;
+
+    // add scatter to plot
+    let mut plot = Plot::new();
+    plot.add(&scatter);
+
+    // save figure
+    let path = Path::new(OUT_DIR).join("integ_scatter_3d.svg");
+    plot.save(&path)?;
+
+    // check number of lines
+    let file = File::open(path).map_err(|_| "cannot open file")?;
+    let buffered = BufReader::new(file);
+    let lines_iter = buffered.lines();
+    assert!(lines_iter.count() > 1000);
+    Ok(())
+}
@@ -0,0 +1,90 @@
+use plotpy::{Error, Plot, SparsityPattern};
+use std::fs::File;
+use std::io::{BufRead, BufReader};
+use std::path::Path;
+
+const OUT_DIR: &str = "/tmp/plotpy/integ_tests";
+
+#[test]
+fn test_sparsity_pattern_1() -> Result<(), Error> {
+    // 2D Laplacian (5-point stencil) on a 4 x 4 grid in triplet format
+    let m = 4;
+    let n = m * m;
+    let (mut rows, mut cols) = (Vec::new(), Vec::new());
+    for i in 0..n {
+        let (r, c) = (i / m, i % m);
+        let mut neighbors = vec![i];
+        if r > 0 {
+            neighbors.push(i - m);
+        }
+        if r + 1 < m {
+            neighbors.push(i + m);
+        }
+        if c > 0 {
+            neighbors.push(i - 1);
+        }
+        if c + 1 < m {
+            neighbors.push(i + 1);
+        }
+        for j in neighbors {
+            rows.push(i);
+            cols.push(j);
+        }
+    }
+
+    // configure and draw sparsity pattern
+    let mut spy = SparsityPattern::new();
+    spy.set_color("#1862ab")
+        .set_marker_size(40.0)
+        .set_block_separators(&[4, 8, 12], &[4, 8, 12]);
+    spy.draw_triplet(n, n, &rows, &cols, None)?;
+
+    // add sparsity pattern to plot
+    let mut plot = Plot::new();
+    plot.add(&spy);
+
+    // save figure
+    let path = Path::new(OUT_DIR).join("integ_sparsity_pattern_1.svg");
+    plot.save(&path)?;
+
+    // check number of lines
+    let file = File::open(path).map_err(|_| "cannot open file")?;
+    let buffered = BufReader::new(file);
+    let lines_iter = buffered.lines();
+    assert!(lines_iter.count() > 500);
+    Ok(())
+}
+
+#[test]
+fn test_sparsity_pattern_2() -> Result<(), Error> {
+    // [[ 5,  0, -2,  0],
+    //  [ 0,  3,  0,  0],
+    //  [-2,  0,  4,  1],
+    //  [ 0,  0,  1,  6],
+    //  [ 1,  0,  0,  0]] in CSR format
+    let row_pointers = [0, 2, 3, 6, 8, 9];
+    let col_indices = [0, 2, 1, 0, 2, 3, 2, 3, 0];
+    let values = [5.0, -2.0, 3.0, -2.0, 4.0, 1.0, 1.0, 6.0, 1.0];
+
+    // configure and draw sparsity pattern
+    let mut spy = SparsityPattern::new();
+    spy.set_with_cells(true)
+        .set_colormap_name("RdBu_r")
+        .set_colorbar_label("A[i,j]");
+    spy.draw_csr(5, 4, &row_pointers, &col_indices, Some(&values))?;
+
+    // add sparsity pattern to plot
+    let mut plot = Plot::new();
+    plot.add(&spy);
+
+    // save figure
+    let path = Path::new(OUT_DIR).join("integ_sparsity_pattern_2.svg");
+    plot.save(&path)?;
+
+    // check number of lines
+    let file = File::open(path).map_err(|_| "cannot open file")?;
+    let buffered = BufReader::new(file);
+    let lines_iter = buffered.lines();
+    assert!(lines_iter.count() > 500);
+    Ok(())
+}
@@ -1,4 +1,4 @@
-use plotpy::{Curve, Plot, StrError};
+use plotpy::{Curve, Error, Plot};
 use std::fs::File;
 use std::io::{BufRead, BufReader};
 use std::path::Path;
@@ -6,7 +6,7 @@ use std::path::Path;
 const OUT_DIR: &str = "/tmp/plotpy/integ_tests";
 
 #[test]
-fn test_subplot() -> Result<(), StrError> {
+fn test_subplot() -> Result<(), Error> {
     // curves
     let mut curve1 = Curve::new();
     let mut curve2 = Curve::new();
@@ -51,7 +51,7 @@ fn test_subplot() -> Result<(), StrError> {
 }
 
 #[test]
-fn test_gridspec_1() -> Result<(), StrError> {
+fn test_gridspec_1() -> Result<(), Error> {
     // curves
     let mut curve1 = Curve::new();
     let mut curve2 = Curve::new();
@@ -84,7 +84,7 @@ fn test_gridspec_1() -> Result<(), StrError> {
 }
 
 #[test]
-fn test_gridspec_rotation_and_align_labels() -> Result<(), StrError> {
+fn test_gridspec_rotation_and_align_labels() -> Result<(), Error> {
     // curves
     let mut curve = Curve::new();
     let x = &[1000.0, 2000.0, 3000.0, 4000.0];
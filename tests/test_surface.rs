@@ -1,13 +1,13 @@
-use plotpy::{Plot, StrError, Surface};
+use plotpy::{Error, Plot, Surface};
 use russell_lab::generate3d;
-use std::fs::File;
+use std::fs::{self, File};
 use std::io::{BufRead, BufReader};
 use std::path::Path;
 
 const OUT_DIR: &str = "/tmp/plotpy/integ_tests";
 
 #[test]
-fn test_surface() -> Result<(), StrError> {
+fn test_surface() -> Result<(), Error> {
     let mut surface = Surface::new();
     surface
         .set_row_stride(1)
@@ -43,7 +43,7 @@ fn test_surface() -> Result<(), StrError> {
 }
 
 #[test]
-fn test_surface_wireframe() -> Result<(), StrError> {
+fn test_surface_wireframe() -> Result<(), Error> {
     let mut surface = Surface::new();
     surface.set_with_surface(false).set_with_wireframe(true);
 
@@ -67,3 +67,90 @@ fn test_surface_wireframe() -> Result<(), StrError> {
     assert!(lines_iter.count() > 890);
     Ok(())
 }
+
+#[test]
+fn test_surface_npy_files() -> Result<(), Error> {
+    let mut surface = Surface::new();
+    surface.set_with_surface(false).set_with_wireframe(true);
+
+    // draw wireframe
+    let n = 9;
+    let (x, y, z) = generate3d(-2.0, 2.0, -2.0, 2.0, n, n, |x, y| x * x + y * y);
+    surface.draw(&x, &y, &z);
+
+    // add surface to plot
+    let mut plot = Plot::new();
+    plot.set_npy_threshold(n * n).add(&surface);
+
+    // save figure
+    let path = Path::new(OUT_DIR).join("integ_surface_npy_files.svg");
+    plot.save(&path)?;
+
+    // check python script and data files
+    let script = fs::read_to_string(path.with_extension("py")).map_err(|_| "cannot read file")?;
+    assert_eq!(script.matches("=np.load('").count(), 3);
+    for k in 0..3 {
+        let npy = Path::new(OUT_DIR).join(format!("integ_surface_npy_files_data{}.npy", k));
+        assert_eq!(fs::metadata(npy).map_err(|_| "cannot find file")?.len(), 128 + 8 * 81);
+    }
+
+    // check number of lines
+    let file = File::open(path).map_err(|_| "cannot open file")?;
+    let buffered = BufReader::new(file);
+    let lines_iter = buffered.lines();
+    assert!(lines_iter.count() > 890);
+    Ok(())
+}
+
+#[test]
+fn test_surface_triangles() -> Result<(), Error> {
+    // nodes on a disk (center + rings) and the triangles of a fan mesh
+    let (nring, nsector) = (4, 12);
+    let (mut x, mut y) = (vec![0.0], vec![0.0]);
+    for i in 1..=nring {
+        for j in 0..nsector {
+            let a = (j as f64) * 2.0 * std::f64::consts::PI / (nsector as f64);
+            x.push((i as f64) * f64::cos(a) / (nring as f64));
+            y.push((i as f64) * f64::sin(a) / (nring as f64));
+        }
+    }
+    let node = |i: usize, j: usize| if i == 0 { 0 } else { 1 + (i - 1) * nsector + j % nsector };
+    let mut triangles = Vec::new();
+    for i in 0..nring {
+        for j in 0..nsector {
+            if i == 0 {
+                triangles.push([0, node(1, j), node(1, j + 1)]);
+            } else {
+                triangles.push([node(i, j), node(i + 1, j), node(i + 1, j + 1)]);
+                triangles.push([node(i, j), node(i + 1, j + 1), node(i, j + 1)]);
+            }
+        }
+    }
+    let z: Vec<f64> = x.iter().zip(&y).map(|(x, y)| 1.0 - x * x - y * y).collect();
+
+    // surface object and options
+    let mut surface = Surface::new();
+    surface
+        .set_colormap_name("terrain")
+        .set_with_wireframe(true)
+        .set_line_width(0.3)
+        .set_with_colorbar(true);
+
+    // draw surface on triangle mesh
+    surface.draw_triangles(&x, &y, &z, &triangles)?;
+
+    // add surface to plot
+    let mut plot = Plot::new();
+    plot.add(&surface);
+
+    // save figure
+    let path = Path::new(OUT_DIR).join("integ_surface_triangles.svg");
+    plot.save(&path)?;
+
+    // check number of lines
+    let file = File::open(path).map_err(|_| "cannot open file")?;
+    let buffered = BufReader::new(file);
+    let lines_iter = buffered.lines();
+    assert!(lines_iter.count() > 800);
+    Ok(())
+}
@@ -0,0 +1,130 @@
+#![cfg(feature = "svg")]
+
+use plotpy::{Canvas, Curve, Error, Legend, Plot, Text};
+use std::fs;
+use std::path::Path;
+
+const OUT_DIR: &str = "/tmp/plotpy/integ_tests";
+
+#[test]
+fn test_svg_curve_and_legend() -> Result<(), Error> {
+    // curves
+    let x = &[1.0, 2.0, 3.0, 4.0, 5.0];
+    let y = &[1.0, 4.0, 9.0, 16.0, 25.0];
+    let mut curve1 = Curve::new();
+    curve1
+        .set_label("line")
+        .set_line_color("#cd0000")
+        .set_line_style("--")
+        .set_marker_style("o");
+    curve1.draw(x, y);
+    let mut curve2 = Curve::new();
+    curve2.set_label("points").set_line_style("None").set_marker_style("s");
+    curve2.points_begin();
+    for i in 0..5 {
+        curve2.points_add(x[i], 25.0 - y[i]);
+    }
+    curve2.points_end();
+
+    // legend
+    let mut legend = Legend::new();
+    legend.draw();
+
+    // add to plot
+    let mut plot = Plot::new();
+    plot.add(&curve1)
+        .add(&curve2)
+        .add(&legend)
+        .set_title("curves & legend")
+        .grid_and_labels("x", "y");
+
+    // save figure
+    let path = Path::new(OUT_DIR).join("integ_svg_curve_and_legend.svg");
+    plot.save_svg(&path)?;
+
+    // check file
+    let svg = fs::read_to_string(&path).unwrap();
+    assert!(svg.starts_with("<?xml"));
+    assert!(svg.ends_with("</svg>\n"));
+    assert!(svg.contains("curves &amp; legend"));
+    assert!(svg.contains("#cd0000"));
+    assert!(svg.contains(">line<"));
+    assert!(svg.contains(">points<"));
+    assert!(svg.contains("stroke-dasharray"));
+    Ok(())
+}
+
+#[test]
+fn test_svg_canvas_and_text() -> Result<(), Error> {
+    // canvas
+    let mut canvas = Canvas::new();
+    canvas.set_face_color("#f1c40f").set_edge_color("black");
+    canvas.draw_circle(0.0, 0.0, 1.0);
+    canvas.set_face_color("none").set_edge_color("red");
+    canvas.draw_arc(0.0, 0.0, 1.5, 0.0, 90.0);
+    canvas.draw_polyline(&[[-1.0, -1.0], [1.0, -1.0], [0.0, 1.0]], true);
+    canvas.draw_arrow(-1.5, -1.5, 1.5, 1.5);
+
+    // text
+    let mut text = Text::new();
+    text.set_color("blue").set_align_horizontal("center");
+    text.draw(0.0, -1.8, "shapes");
+
+    // add to plot
+    let mut plot = Plot::new();
+    plot.add(&canvas)
+        .add(&text)
+        .set_equal_axes(true)
+        .set_range(-2.0, 2.0, -2.0, 2.0)
+        .set_figure_size_points(300.0, 300.0);
+
+    // the SVG is also available in memory
+    let svg = plot.render_svg();
+    assert!(svg.contains("#f1c40f"));
+    assert!(svg.contains(">shapes<"));
+    assert!(svg.contains("<ellipse"));
+    assert!(svg.matches("<path").count() >= 3);
+
+    // save figure
+    let path = Path::new(OUT_DIR).join("integ_svg_canvas_and_text.svg");
+    plot.save_svg(&path)?;
+    assert_eq!(fs::read_to_string(&path).unwrap(), svg);
+    Ok(())
+}
+
+#[test]
+fn test_svg_curve_steps() -> Result<(), Error> {
+    // curves with steps
+    let x = &[1.0, 2.0, 3.0, 4.0];
+    let y = &[1.0, 3.0, 2.0, 4.0];
+    let mut plot = Plot::new();
+    for (step, color) in [
+        ("pre", "#cd0000"),
+        ("post", "#1862ab"),
+        ("mid", "#ff8000"),
+        ("", "#000000"),
+    ] {
+        let mut curve = Curve::new();
+        curve.set_line_color(color).set_step(step);
+        curve.draw(x, y);
+        plot.add(&curve);
+    }
+    plot.set_title("steps");
+
+    // check the number of vertices of each line
+    let svg = plot.render_svg();
+    let vertices = |color: &str| {
+        let stroke = format!("stroke=\"{}\"", color);
+        let line = svg.lines().find(|line| line.contains(&stroke)).unwrap();
+        line.matches(['M', 'L']).count()
+    };
+    assert_eq!(vertices("#cd0000"), 7);
+    assert_eq!(vertices("#1862ab"), 7);
+    assert_eq!(vertices("#ff8000"), 8);
+    assert_eq!(vertices("#000000"), 4);
+
+    // save figure
+    let path = Path::new(OUT_DIR).join("integ_svg_curve_steps.svg");
+    plot.save_svg(&path)?;
+    Ok(())
+}
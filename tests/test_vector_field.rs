@@ -0,0 +1,119 @@
+use plotpy::{Error, Plot, VectorField};
+use russell_lab::generate3d;
+use std::fs::File;
+use std::io::{BufRead, BufReader};
+use std::path::Path;
+
+const OUT_DIR: &str = "/tmp/plotpy/integ_tests";
+
+#[test]
+fn test_vector_field_1() -> Result<(), Error> {
+    // gradient of f(x,y) = x² - y²
+    let n = 13;
+    let (x, y, u) = generate3d(-2.0, 2.0, -2.0, 2.0, n, n, |x, _| 2.0 * x);
+    let (_, _, v) = generate3d(-2.0, 2.0, -2.0, 2.0, n, n, |_, y| -2.0 * y);
+
+    // configure and draw arrows
+    let mut arrows = VectorField::new();
+    arrows
+        .set_with_colormap(true)
+        .set_colormap_name("plasma")
+        .set_colorbar_label("|grad f|")
+        .set_number_format_cb("%.1f")
+        .set_pivot("mid")
+        .set_width(0.004)
+        .set_key(2.0, r"$|\nabla f| = 2$");
+    arrows.draw(&x, &y, &u, &v);
+
+    // add arrows to plot
+    let mut plot = Plot::new();
+    plot.add(&arrows).set_equal_axes(true);
+
+    // save figure
+    let path = Path::new(OUT_DIR).join("integ_vector_field_1.svg");
+    plot.save(&path)?;
+
+    // check number of lines
+    let file = File::open(path).map_err(|_| "cannot open file")?;
+    let buffered = BufReader::new(file);
+    let lines_iter = buffered.lines();
+    assert!(lines_iter.count() > 800);
+    Ok(())
+}
+
+#[test]
+fn test_vector_field_2() -> Result<(), Error> {
+    // flow around a cylinder of unit radius
+    let n = 41;
+    let (x, y, u) = generate3d(-3.0, 3.0, -3.0, 3.0, n, n, |x, y| {
+        let r2 = x * x + y * y;
+        1.0 - (x * x - y * y) / (r2 * r2 + 1e-12)
+    });
+    let (_, _, v) = generate3d(-3.0, 3.0, -3.0, 3.0, n, n, |x, y| {
+        let r2 = x * x + y * y;
+        -2.0 * x * y / (r2 * r2 + 1e-12)
+    });
+
+    // configure and draw streamlines
+    let mut streamlines = VectorField::new();
+    streamlines
+        .set_with_colormap(true)
+        .set_colormap_index(3)
+        .set_colorbar_label("speed")
+        .set_density(1.5)
+        .set_line_width(1.2)
+        .set_arrow_size(1.5);
+    streamlines.draw_streamlines(&x, &y, &u, &v);
+
+    // add streamlines to plot
+    let mut plot = Plot::new();
+    plot.add(&streamlines).set_equal_axes(true);
+
+    // save figure
+    let path = Path::new(OUT_DIR).join("integ_vector_field_2.svg");
+    plot.save(&path)?;
+
+    // check number of lines
+    let file = File::open(path).map_err(|_| "cannot open file")?;
+    let buffered = BufReader::new(file);
+    let lines_iter = buffered.lines();
+    assert!(lines_iter.count() > 800);
+    Ok(())
+}
+
+#[test]
+fn test_vector_field_3d() -> Result<(), Error> {
+    // helical field sampled on two horizontal planes
+    let n = 6;
+    let (x, y, z0) = generate3d(-1.0, 1.0, -1.0, 1.0, n, n, |_, _| 0.0);
+    let (_, _, z1) = generate3d(-1.0, 1.0, -1.0, 1.0, n, n, |_, _| 1.0);
+    let (_, _, u) = generate3d(-1.0, 1.0, -1.0, 1.0, n, n, |_, y| -y);
+    let (_, _, v) = generate3d(-1.0, 1.0, -1.0, 1.0, n, n, |x, _| x);
+    let (_, _, w) = generate3d(-1.0, 1.0, -1.0, 1.0, n, n, |_, _| 0.5);
+
+    // configure and draw arrows
+    let mut arrows = VectorField::new();
+    arrows
+        .set_color("#cd0000")
+        .set_length(0.3)
+        .set_normalize(true)
+        .set_line_width(0.8);
+    arrows.draw_3d(&x, &y, &z0, &u, &v, &w);
+    arrows.set_color("#1862ab");
+    arrows.draw_3d(&x, &y, &z1, &u, &v, &w);
+
+    // add arrows to plot
+    let mut plot = Plot::new();
+    plot.add(&arrows);
+
+    // save figure
+    let path = Path::new(OUT_DIR).join("integ_vector_field_3d.svg");
+    plot.save(&path)?;
+
+    // check number of lines
+    let file = File::open(path).map_err(|_| "cannot open file")?;
+    let buffered = BufReader::new(file);
+    let lines_iter = buffered.lines();
+    assert!(lines_iter.count() > 600);
+    Ok(())
+}